@@ -0,0 +1,143 @@
+// commands/backtest_commands.rs
+// Commande Tauri du backtest straddle à ordres en attente.
+// Charge les occurrences d'un type d'événement, les fenêtres M1 autour de chacune,
+// et délègue au service straddle_backtester.
+
+use crate::commands::calendar_commands::CalendarState;
+use crate::commands::retrospective_analysis::helpers::{load_events_by_type, setup_databases};
+use crate::models::backtest::{BacktestConfig, BacktestResult};
+use crate::models::walk_forward::{WalkForwardConfig, WalkForwardResult};
+use crate::models::Candle;
+use crate::services::{straddle_backtester, walk_forward_optimizer};
+use chrono::{DateTime, Duration, Utc};
+use serde::Deserialize;
+use tauri::command;
+
+/// Heure de l'événement et bougies M1 qui l'entourent
+type Occurrence = (DateTime<Utc>, Vec<Candle>);
+
+/// Paramètres envoyés depuis le frontend
+#[derive(Debug, Deserialize)]
+pub struct BacktestParams {
+    pub symbol: String,
+    pub event_type: String,
+    /// Configuration complète (défauts si absente)
+    #[serde(default)]
+    pub config: Option<BacktestConfig>,
+}
+
+/// Rejoue un straddle buy-stop/sell-stop sur toutes les occurrences d'un type d'événement.
+///
+/// Retourne la liste des trades, la courbe d'équité, le drawdown max,
+/// le profit factor et l'espérance par trade.
+#[command]
+pub async fn run_straddle_backtest(
+    params: BacktestParams,
+    state: tauri::State<'_, CalendarState>,
+) -> Result<BacktestResult, String> {
+    if params.symbol.is_empty() || params.event_type.is_empty() {
+        return Err("Symbole et type d'événement requis".to_string());
+    }
+    let config = params.config.unwrap_or_default();
+
+    tracing::info!(
+        "📈 Backtest straddle {} / {} (offset={}min, distance={} pips)",
+        params.symbol,
+        params.event_type,
+        config.entry_offset_minutes,
+        config.straddle_distance_pips
+    );
+
+    let (conn, loader) = setup_databases(&state).await?;
+    let events = load_events_by_type(conn, &params.event_type).await?;
+    if events.is_empty() {
+        return Err(format!("No events: {}", params.event_type));
+    }
+
+    let occurrences = load_occurrence_windows(&loader, &params.symbol, &events, &config)?;
+
+    straddle_backtester::run_backtest(&occurrences, &params.symbol, &params.event_type, &config)
+        .map_err(|e| format!("Backtest échoué: {e}"))
+}
+
+/// Paramètres de l'optimisation walk-forward
+#[derive(Debug, Deserialize)]
+pub struct WalkForwardParams {
+    pub symbol: String,
+    pub event_type: String,
+    #[serde(default)]
+    pub config: Option<WalkForwardConfig>,
+}
+
+/// Optimise offset, distance, SL/TP et durée sur fenêtres glissantes (in-sample → out-of-sample).
+///
+/// Seule la performance out-of-sample est agrégée : elle mesure ce que l'offset
+/// « optimal » aurait réellement rapporté sans connaître le futur.
+#[command]
+pub async fn run_walk_forward_optimization(
+    params: WalkForwardParams,
+    state: tauri::State<'_, CalendarState>,
+) -> Result<WalkForwardResult, String> {
+    if params.symbol.is_empty() || params.event_type.is_empty() {
+        return Err("Symbole et type d'événement requis".to_string());
+    }
+    let config = params.config.unwrap_or_default();
+
+    tracing::info!(
+        "🔁 Walk-forward {} / {} (IS={}, OOS={})",
+        params.symbol,
+        params.event_type,
+        config.in_sample_occurrences,
+        config.out_of_sample_occurrences
+    );
+
+    let (conn, loader) = setup_databases(&state).await?;
+    let events = load_events_by_type(conn, &params.event_type).await?;
+    if events.is_empty() {
+        return Err(format!("No events: {}", params.event_type));
+    }
+
+    let window = walk_forward_optimizer::widest_config(&config.grid, &config.base);
+    let occurrences = load_occurrence_windows(&loader, &params.symbol, &events, &window)?;
+
+    walk_forward_optimizer::run_walk_forward(
+        &occurrences,
+        &params.symbol,
+        &params.event_type,
+        &config,
+    )
+    .map_err(|e| format!("Walk-forward échoué: {e}"))
+}
+
+/// Charge, pour chaque événement, les M1 couvrant ATR + placement + expiration + durée max.
+/// Plusieurs événements du même type à la même minute ne sont rejoués qu'une fois.
+/// Une erreur de lecture des bougies interrompt le chargement plutôt que de vider l'occurrence.
+pub fn load_occurrence_windows(
+    loader: &crate::services::DatabaseLoader,
+    symbol: &str,
+    events: &[crate::models::CalendarEvent],
+    config: &BacktestConfig,
+) -> Result<Vec<Occurrence>, String> {
+    let before = config.entry_offset_minutes + config.atr_period as i64 + 5;
+    let after = config.pending_expiry_minutes + config.max_duration_minutes + 5;
+
+    let mut event_times: Vec<DateTime<Utc>> =
+        events.iter().map(|e| e.event_time.and_utc()).collect();
+    event_times.sort();
+    event_times.dedup();
+
+    event_times
+        .into_iter()
+        .map(|t| {
+            let candles = loader
+                .load_candles_by_pair(
+                    symbol,
+                    "M1",
+                    t - Duration::minutes(before),
+                    t + Duration::minutes(after),
+                )
+                .map_err(|e| format!("Chargement M1 {symbol} autour de {t} échoué: {e}"))?;
+            Ok((t, candles))
+        })
+        .collect()
+}
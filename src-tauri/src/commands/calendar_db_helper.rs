@@ -1,12 +1,22 @@
-use rusqlite::Connection;
+use crate::models::import_session::ReimportMode;
+use crate::services::event_catalog;
+use crate::services::import_sessions;
+use rusqlite::{Connection, OptionalExtension};
 
 type CalendarEventRecord = (String, String, String, String, Option<f64>, Option<f64>, Option<f64>);
 
+/// Annonce canonique de chaque événement (calendar_events.event_id)
+fn resolve_event_ids(events: &[CalendarEventRecord]) -> Vec<String> {
+    event_catalog::resolve_event_ids(events.iter().map(|e| (e.3.as_str(), e.1.as_str())))
+}
+
+/// `mode` : consigné dans la session d'import (None = premier import du calendrier)
 pub fn save_calendar_import(
     conn: &Connection,
     name: &str,
     filename: &str,
     events: &[CalendarEventRecord],
+    mode: Option<ReimportMode>,
 ) -> Result<i32, String> {
     if events.is_empty() {
         return Err("Aucun événement à sauvegarder".to_string());
@@ -34,7 +44,7 @@ pub fn save_calendar_import(
 
     // Insérer l'enregistrement du calendrier
     let calendar_id: i32 = conn.query_row(
-        "INSERT INTO calendar_imports (name, filename, event_count, oldest_event_date, newest_event_date, imported_at) 
+        "INSERT INTO calendar_imports (name, filename, event_count, oldest_event_date, newest_event_date, imported_at)
          VALUES (?1, ?2, ?3, ?4, ?5, ?6)
          RETURNING id",
         rusqlite::params![name, filename, events.len(), &oldest_date, &newest_date, chrono::Utc::now().to_rfc3339()],
@@ -42,15 +52,20 @@ pub fn save_calendar_import(
     )
     .map_err(|e| format!("Failed to insert calendar import record: {}", e))?;
 
+    let session_id =
+        import_sessions::ouvrir_session_calendrier(conn, calendar_id.into(), filename, mode)?;
+
     // Insérer les événements
     let mut stmt = conn
         .prepare(
-            "INSERT INTO calendar_events (symbol, event_time, impact, description, calendar_import_id, created_at, actual, forecast, previous) 
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9)",
+            "INSERT INTO calendar_events (symbol, event_time, impact, description, calendar_import_id, created_at, actual, forecast, previous, import_session_id, event_id)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11)",
         )
         .map_err(|e| format!("Failed to prepare insert statement: {}", e))?;
 
-    for (event_time, symbol, impact, description, actual, forecast, previous) in events {
+    for ((event_time, symbol, impact, description, actual, forecast, previous), event_id) in
+        events.iter().zip(resolve_event_ids(events))
+    {
         stmt.execute(rusqlite::params![
             symbol,
             event_time,
@@ -60,15 +75,86 @@ pub fn save_calendar_import(
             chrono::Utc::now().to_rfc3339(),
             actual,
             forecast,
-            previous
+            previous,
+            session_id,
+            event_id
         ])
         .map_err(|e| format!("Failed to insert event: {}", e))?;
     }
 
+    import_sessions::clore_session_calendrier(conn, session_id, events.len())?;
     Ok(calendar_id)
 }
 
-pub fn delete_calendar_import_by_name(conn: &Connection, name: &str) -> Result<(), String> {
+/// Fusionne des événements dans un calendrier existant : seuls les événements absents
+/// (même heure, devise et intitulé) sont ajoutés, sous une nouvelle session d'import.
+pub fn merge_calendar_import(
+    conn: &Connection,
+    calendar_id: i64,
+    filename: &str,
+    events: &[CalendarEventRecord],
+) -> Result<usize, String> {
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| format!("Erreur début transaction : {}", e))?;
+    let session_id = import_sessions::ouvrir_session_calendrier(
+        &tx,
+        calendar_id,
+        filename,
+        Some(ReimportMode::Merge),
+    )?;
+
+    let mut stmt = tx
+        .prepare(
+            "INSERT INTO calendar_events (symbol, event_time, impact, description, calendar_import_id, created_at, actual, forecast, previous, import_session_id, event_id)
+             SELECT ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11
+             WHERE NOT EXISTS (
+                SELECT 1 FROM calendar_events
+                WHERE calendar_import_id = ?5 AND symbol = ?1 AND event_time = ?2 AND description = ?4)",
+        )
+        .map_err(|e| format!("Failed to prepare insert statement: {}", e))?;
+
+    let mut inserted = 0;
+    for ((event_time, symbol, impact, description, actual, forecast, previous), event_id) in
+        events.iter().zip(resolve_event_ids(events))
+    {
+        inserted += stmt
+            .execute(rusqlite::params![
+                symbol,
+                event_time,
+                impact,
+                description,
+                calendar_id,
+                chrono::Utc::now().to_rfc3339(),
+                actual,
+                forecast,
+                previous,
+                session_id,
+                event_id
+            ])
+            .map_err(|e| format!("Failed to insert event: {}", e))?;
+    }
+    drop(stmt);
+
+    import_sessions::clore_session_calendrier(&tx, session_id, inserted)?;
+    import_sessions::rafraichir_calendrier(&tx, calendar_id)?;
+    tx.commit().map_err(|e| format!("Erreur commit : {}", e))?;
+    Ok(inserted)
+}
+
+/// Id du calendrier portant ce nom, s'il existe
+pub fn find_calendar_import(conn: &Connection, name: &str) -> Result<Option<i64>, String> {
+    conn.query_row(
+        "SELECT id FROM calendar_imports WHERE name = ?1",
+        rusqlite::params![name],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(|e| format!("Failed to read calendar import {}: {}", name, e))
+}
+
+/// Retourne true si un calendrier de ce nom existait (ses sessions passent à "replaced")
+pub fn delete_calendar_import_by_name(conn: &Connection, name: &str) -> Result<bool, String> {
     let id_result: Result<i32, _> = conn.query_row(
         "SELECT id FROM calendar_imports WHERE name = ?1",
         rusqlite::params![name],
@@ -76,6 +162,8 @@ pub fn delete_calendar_import_by_name(conn: &Connection, name: &str) -> Result<(
     );
 
     if let Ok(calendar_id) = id_result {
+        import_sessions::marquer_calendrier_remplace(conn, calendar_id.into())?;
+
         // Supprimer les événements liés
         conn.execute(
             "DELETE FROM calendar_events WHERE calendar_import_id = ?1",
@@ -89,6 +177,7 @@ pub fn delete_calendar_import_by_name(conn: &Connection, name: &str) -> Result<(
             rusqlite::params![calendar_id],
         )
         .map_err(|e| format!("Failed to delete calendar import {}: {}", name, e))?;
+        return Ok(true);
     }
-    Ok(())
+    Ok(false)
 }
@@ -1,11 +1,24 @@
 use crate::commands::calendar_db_helper::save_calendar_import;
 use crate::commands::calendar_parser::parse_record;
+use crate::models::calendar_mapping::CalendarColumnMapping;
+use crate::models::import_session::ReimportMode;
+use crate::services::calendar_converter::column_mapping;
+use crate::services::import_sessions::ALREADY_IMPORTED;
 use csv::ReaderBuilder;
 use rusqlite::Connection;
 use std::fs;
 
+type CalendarEventRecord = (String, String, String, String, Option<f64>, Option<f64>, Option<f64>);
+
+/// `reimport_mode` : remplacer ou fusionner un calendrier déjà importé sous ce nom
+/// (absent = erreur `ALREADY_IMPORTED` pour laisser l'utilisateur choisir).
+/// `mapping` : colonnes d'un export tiers (.csv ou .xlsx) ; absent = formats Forex Factory/legacy.
 #[tauri::command]
-pub async fn import_calendar_files(paths: Vec<String>) -> Result<String, String> {
+pub async fn import_calendar_files(
+    paths: Vec<String>,
+    reimport_mode: Option<ReimportMode>,
+    mapping: Option<CalendarColumnMapping>,
+) -> Result<String, String> {
     tracing::info!("📥 Starting calendar import for {} file(s)", paths.len());
 
     if paths.is_empty() {
@@ -19,6 +32,79 @@ pub async fn import_calendar_files(paths: Vec<String>) -> Result<String, String>
         return Err(format!("Fichier non trouvé: {}", path));
     }
 
+    let events = match &mapping {
+        Some(mapping) => column_mapping::convert_file(path, mapping)?.into_records(),
+        None => parse_default_layout(file_path)?,
+    };
+
+    if events.is_empty() {
+        return Err("Aucun événement trouvé dans le fichier".to_string());
+    }
+
+    let data_dir = dirs::data_local_dir()
+        .ok_or("Failed to get data directory")?
+        .join("volatility-analyzer")
+        .join("volatility.db");
+
+    let conn =
+        Connection::open(&data_dir).map_err(|e| format!("Failed to open volatility.db: {}", e))?;
+
+    let filename = file_path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or("Invalid file path")?
+        .to_string();
+
+    let calendar_name = filename
+        .trim_end_matches(".csv")
+        .trim_end_matches(".xlsx")
+        .to_string();
+
+    use crate::commands::calendar_db_helper::{
+        delete_calendar_import_by_name, find_calendar_import, merge_calendar_import,
+    };
+    let existing = find_calendar_import(&conn, &calendar_name)?;
+    let calendar_id = match (existing, reimport_mode) {
+        (Some(id), None) => {
+            return Err(format!(
+                "{} (calendrier #{} {}) : choisir remplacer ou fusionner",
+                ALREADY_IMPORTED, id, calendar_name
+            ));
+        }
+        (Some(id), Some(ReimportMode::Merge)) => {
+            let inserted = merge_calendar_import(&conn, id, &filename, &events)?;
+            tracing::info!(
+                "✅ Calendar merge complete: {} new events out of {}",
+                inserted,
+                events.len()
+            );
+            return Ok(format!(
+                "Calendrier fusionné : {} nouveaux événements sur {}",
+                inserted,
+                events.len()
+            ));
+        }
+        (Some(_), Some(ReimportMode::Replace)) => {
+            delete_calendar_import_by_name(&conn, &calendar_name)?;
+            save_calendar_import(&conn, &calendar_name, &filename, &events, reimport_mode)?
+        }
+        (None, _) => save_calendar_import(&conn, &calendar_name, &filename, &events, None)?,
+    };
+
+    tracing::info!("📝 Calendar import record created with ID: {}", calendar_id);
+    tracing::info!(
+        "✅ Calendar import complete: {} events imported",
+        events.len()
+    );
+    Ok(format!(
+        "Calendrier importé avec succès: {} événements",
+        events.len()
+    ))
+}
+
+
+/// Formats historiques (Forex Factory, exports legacy) détectés par `parse_record`
+fn parse_default_layout(file_path: &std::path::Path) -> Result<Vec<CalendarEventRecord>, String> {
     let file = fs::File::open(file_path).map_err(|e| format!("Failed to open file: {}", e))?;
     let mut reader = ReaderBuilder::new().delimiter(b',').from_reader(file);
 
@@ -53,40 +139,9 @@ pub async fn import_calendar_files(paths: Vec<String>) -> Result<String, String>
         ));
     }
 
-    let data_dir = dirs::data_local_dir()
-        .ok_or("Failed to get data directory")?
-        .join("volatility-analyzer")
-        .join("volatility.db");
-
-    let conn =
-        Connection::open(&data_dir).map_err(|e| format!("Failed to open volatility.db: {}", e))?;
-
-    let filename = file_path
-        .file_name()
-        .and_then(|n| n.to_str())
-        .ok_or("Invalid file path")?
-        .to_string();
-
-    let calendar_name = filename.trim_end_matches(".csv").to_string();
-
-    // On supprime toujours l'ancien calendrier du même nom s'il existe (remplacement automatique)
-    use crate::commands::calendar_db_helper::delete_calendar_import_by_name;
-    let _ = delete_calendar_import_by_name(&conn, &calendar_name);
-
-    let calendar_id = save_calendar_import(&conn, &calendar_name, &filename, &events)?;
-
-    tracing::info!("📝 Calendar import record created with ID: {}", calendar_id);
-    tracing::info!(
-        "✅ Calendar import complete: {} events imported",
-        events.len()
-    );
-    Ok(format!(
-        "Calendrier importé avec succès: {} événements",
-        events.len()
-    ))
+    Ok(events)
 }
 
-
 #[tauri::command]
 pub async fn process_forex_factory_csv(csv_content: String) -> Result<String, String> {
     if csv_content.trim().starts_with("<!DOCTYPE") || csv_content.trim().starts_with("<html") {
@@ -125,9 +180,10 @@ pub async fn process_forex_factory_csv(csv_content: String) -> Result<String, St
 
     // Suppression de l'ancien si existant
     use crate::commands::calendar_db_helper::delete_calendar_import_by_name;
-    let _ = delete_calendar_import_by_name(&conn, calendar_name);
+    let replaced = delete_calendar_import_by_name(&conn, calendar_name).unwrap_or(false);
+    let mode = replaced.then_some(ReimportMode::Replace);
 
-    save_calendar_import(&conn, calendar_name, filename, &events).map_err(|e| format!("Save error: {}", e))?;
+    save_calendar_import(&conn, calendar_name, filename, &events, mode).map_err(|e| format!("Save error: {}", e))?;
     
     Ok("Import réussi via le frontend".to_string())
 }
@@ -237,9 +293,10 @@ fn process_downloaded_file(path: std::path::PathBuf) -> Result<String, String> {
     let calendar_name = "Forex Factory Week";
 
     use crate::commands::calendar_db_helper::delete_calendar_import_by_name;
-    let _ = delete_calendar_import_by_name(&conn, calendar_name);
+    let replaced = delete_calendar_import_by_name(&conn, calendar_name).unwrap_or(false);
+    let mode = replaced.then_some(ReimportMode::Replace);
 
-    let res = save_calendar_import(&conn, calendar_name, filename, &events);
+    let res = save_calendar_import(&conn, calendar_name, filename, &events, mode);
     
     if res.is_ok() {
         // Cleanup file after successful import
@@ -335,7 +392,14 @@ pub async fn sync_forex_factory_week() -> Result<String, String> {
 
     let conn = Connection::open(&data_dir).map_err(|e| format!("DB Error: {}", e))?;
 
-    // Supprimer les anciens plannings synchronisés
+    // Supprimer les anciens plannings synchronisés (sessions marquées remplacées)
+    conn.execute(
+        "UPDATE calendar_import_log SET status = 'replaced'
+         WHERE status = 'success' AND calendar_import_id IN
+            (SELECT id FROM calendar_imports WHERE name LIKE 'ForexFactory_Sync_%')",
+        [],
+    )
+    .map_err(|e| format!("Update error: {}", e))?;
     conn.execute(
         "DELETE FROM calendar_imports WHERE name LIKE 'ForexFactory_Sync_%'",
         [],
@@ -347,7 +411,7 @@ pub async fn sync_forex_factory_week() -> Result<String, String> {
     let filename = "ff_calendar_thisweek.csv";
 
     // Save
-    save_calendar_import(&conn, &calendar_name, filename, &events).map_err(|e| format!("Save error: {}", e))?;
+    save_calendar_import(&conn, &calendar_name, filename, &events, None).map_err(|e| format!("Save error: {}", e))?;
 
     Ok(format!("Import réussi ({} événements).", events.len()))
 }
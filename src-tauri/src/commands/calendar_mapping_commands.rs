@@ -0,0 +1,29 @@
+// commands/calendar_mapping_commands.rs - Presets et aperçu de l'import par mapping de colonnes
+// L'import lui-même passe par import_calendar_files(paths, reimport_mode, mapping)
+
+use crate::models::calendar_mapping::{CalendarColumnMapping, CalendarMappingPreview};
+use crate::services::calendar_converter::column_mapping;
+
+/// Événements affichés dans l'aperçu
+const PREVIEW_SAMPLE_SIZE: usize = 20;
+
+/// Presets Investing.com, FXStreet et TradingEconomics (point de départ d'un mapping libre)
+#[tauri::command]
+pub fn list_calendar_mapping_presets() -> Result<Vec<CalendarColumnMapping>, String> {
+    Ok(column_mapping::presets())
+}
+
+/// Applique un mapping à un fichier sans rien écrire : en-têtes, événements normalisés
+/// et motifs de rejet, pour ajuster le mapping avant l'import
+#[tauri::command]
+pub async fn preview_calendar_mapping(
+    path: String,
+    mapping: CalendarColumnMapping,
+) -> Result<CalendarMappingPreview, String> {
+    tokio::task::spawn_blocking(move || {
+        column_mapping::convert_file(&path, &mapping)
+            .map(|calendar| calendar.preview(PREVIEW_SAMPLE_SIZE))
+    })
+    .await
+    .map_err(|e| format!("Task join error: {}", e))?
+}
@@ -2,7 +2,10 @@ use chrono::{Duration, Utc};
 use rusqlite::Connection;
 use tauri::State;
 
-use super::heatmap_helpers::{calculer_volatilite_moyenne_evenement_paire_optimise, HeatmapData};
+use super::heatmap_helpers::{
+    appliquer_benjamini_hochberg, calculer_volatilite_moyenne_evenement_paire_optimise,
+    HeatmapData,
+};
 use super::heatmap_queries::{get_all_events_grouped, get_event_types};
 use super::utils::{format_date_fr, parse_db_date};
 use crate::commands::candle_index_commands::CandleIndexState;
@@ -90,6 +93,7 @@ pub async fn get_correlation_heatmap(
             pairs,
             event_types: vec![],
             data: std::collections::HashMap::new(),            volatility_percentages: std::collections::HashMap::new(),            counts: std::collections::HashMap::new(),
+            significance: std::collections::HashMap::new(),
         });
     }
 
@@ -99,6 +103,7 @@ pub async fn get_correlation_heatmap(
         std::collections::HashMap::new();
     let mut counts: std::collections::HashMap<String, std::collections::HashMap<String, i32>> =
         std::collections::HashMap::new();
+    let mut significance = std::collections::HashMap::new();
 
     let mut index_state = state
         .index
@@ -125,7 +130,7 @@ pub async fn get_correlation_heatmap(
         for event_type in &mut event_types {
             let vol_result = calculer_volatilite_moyenne_evenement_paire_optimise(
                 &conn,
-                &event_type.name,
+                &event_type.id,
                 pair,
                 calendar_id,
                 candle_index,
@@ -160,9 +165,19 @@ pub async fn get_correlation_heatmap(
             counts.entry(event_type.name.clone())
                 .or_default()
                 .insert(pair.clone(), vol_result.sample_count);
+
+            if let Some(test) = vol_result.significance {
+                significance
+                    .entry(event_type.name.clone())
+                    .or_insert_with(std::collections::HashMap::new)
+                    .insert(pair.clone(), test);
+            }
         }
     }
 
+    // Nombreuses cellules testées simultanément : contrôle du taux de fausses découvertes
+    appliquer_benjamini_hochberg(&mut significance);
+
     Ok(HeatmapData {
         period: "Calendrier sélectionné".to_string(),
         period_start,
@@ -172,5 +187,6 @@ pub async fn get_correlation_heatmap(
         data,
         volatility_percentages,
         counts,
+        significance,
     })
 }
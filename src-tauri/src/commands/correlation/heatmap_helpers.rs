@@ -1,3 +1,5 @@
+use crate::models::significance::PairedSignificanceResult;
+use crate::services::significance::{benjamini_hochberg, compare_paired};
 use chrono::NaiveDateTime;
 use rusqlite::Connection;
 use serde::{Deserialize, Serialize};
@@ -11,10 +13,14 @@ pub struct VolatilityResult {
     pub has_data: bool,
     /// Nombre d'occurrences (événements avec données) utilisées pour le calcul
     pub sample_count: i32,
+    /// Volatilité de chaque occurrence vs sa propre baseline (test apparié)
+    pub significance: Option<PairedSignificanceResult>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct EventTypeInfo {
+    /// Annonce canonique (calendar_events.event_id)
+    pub id: String,
     pub name: String,
     pub count: i32,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -33,11 +39,13 @@ pub struct HeatmapData {
     pub volatility_percentages: HashMap<String, HashMap<String, f64>>,
     /// Nombre d'occurrences par cellule (event_type → pair → count)
     pub counts: HashMap<String, HashMap<String, i32>>,
+    /// Test événement vs baseline par cellule, p-value corrigée sur toute la heatmap
+    pub significance: HashMap<String, HashMap<String, PairedSignificanceResult>>,
 }
 
 pub fn calculer_volatilite_moyenne_evenement_paire_optimise(
     _conn: &Connection,
-    event_name: &str,
+    event_key: &str,
     pair: &str,
     _calendar_id: Option<i32>,
     candle_index: &crate::services::candle_index::CandleIndex,
@@ -48,7 +56,7 @@ pub fn calculer_volatilite_moyenne_evenement_paire_optimise(
     // Si on a un cache, on l'utilise, sinon ... on devrait éviter ce cas
     let empty_vec = Vec::new();
     let events = if let Some(cache) = events_cache {
-        cache.get(event_name).unwrap_or(&empty_vec).clone()
+        cache.get(event_key).unwrap_or(&empty_vec).clone()
     } else {
         // Fallback pour compatibilité (mais lent)
         return Err("Cache events manquant (Optimisation requise)".to_string());
@@ -60,6 +68,7 @@ pub fn calculer_volatilite_moyenne_evenement_paire_optimise(
             percentage: 0.0,
             has_data: false,
             sample_count: 0,
+            significance: None,
         });
     }
 
@@ -67,6 +76,8 @@ pub fn calculer_volatilite_moyenne_evenement_paire_optimise(
     let mut total_percentage = 0.0;
     let mut valid_count = 0;
     let mut has_data_found = false;
+    let mut event_samples: Vec<f64> = Vec::new();
+    let mut baseline_samples: Vec<f64> = Vec::new();
 
     for event_datetime in events {
         // Vérifier si des candles existent pour cet événement
@@ -102,6 +113,11 @@ pub fn calculer_volatilite_moyenne_evenement_paire_optimise(
             total_score += score;
             total_percentage += percentage;
             valid_count += 1;
+            // Paire conservée seulement si l'occurrence a sa propre baseline
+            if metrics.baseline_volatility > 0.0 {
+                event_samples.push(score);
+                baseline_samples.push(metrics.baseline_volatility);
+            }
         }
     }
 
@@ -121,6 +137,21 @@ pub fn calculer_volatilite_moyenne_evenement_paire_optimise(
         percentage: avg_percentage,
         has_data: has_data_found,
         sample_count: valid_count,
+        significance: compare_paired(&event_samples, &baseline_samples),
     })
 }
 
+/// Correction Benjamini-Hochberg sur l'ensemble des cellules testées (event × paire)
+pub fn appliquer_benjamini_hochberg(
+    significance: &mut HashMap<String, HashMap<String, PairedSignificanceResult>>,
+) {
+    let mut cells: Vec<&mut PairedSignificanceResult> = significance
+        .values_mut()
+        .flat_map(|by_pair| by_pair.values_mut())
+        .collect();
+    let p_values: Vec<f64> = cells.iter().map(|c| c.wilcoxon_p_value).collect();
+    for (cell, adjusted) in cells.iter_mut().zip(benjamini_hochberg(&p_values)) {
+        cell.adjusted_p_value = Some(adjusted);
+    }
+}
+
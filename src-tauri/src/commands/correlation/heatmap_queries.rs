@@ -2,28 +2,30 @@ use rusqlite::Connection;
 use std::collections::HashMap;
 use chrono::NaiveDateTime;
 use super::heatmap_helpers::EventTypeInfo;
+use crate::services::event_catalog;
 
-/// Récupère tous les types d'événements disponibles
+/// Récupère tous les types d'événements disponibles, regroupés par annonce canonique
+/// (les variantes d'intitulé d'une même publication forment un seul type)
 pub fn get_event_types(
     conn: &Connection,
     calendar_id: Option<i32>,
 ) -> Result<Vec<EventTypeInfo>, String> {
     let query = if let Some(cal_id) = calendar_id {
         format!(
-            "SELECT description, COUNT(DISTINCT event_time) as count 
+            "SELECT COALESCE(event_id, description) as event_key, MIN(description), COUNT(DISTINCT event_time) as count 
              FROM calendar_events 
              WHERE calendar_import_id = {} 
-             GROUP BY description 
+             GROUP BY event_key 
              HAVING count >= 1
-             ORDER BY count DESC, description",
+             ORDER BY count DESC, event_key",
             cal_id
         )
     } else {
-        "SELECT description, COUNT(DISTINCT event_time) as count 
+        "SELECT COALESCE(event_id, description) as event_key, MIN(description), COUNT(DISTINCT event_time) as count 
          FROM calendar_events 
-         GROUP BY description 
+         GROUP BY event_key 
          HAVING count >= 1
-         ORDER BY count DESC, description"
+         ORDER BY count DESC, event_key"
             .to_string()
     };
 
@@ -33,9 +35,12 @@ pub fn get_event_types(
 
     let event_types = stmt
         .query_map([], |row| {
+            let id: String = row.get(0)?;
+            let description: String = row.get(1)?;
             Ok(EventTypeInfo {
-                name: row.get(0)?,
-                count: row.get(1)?,
+                name: event_catalog::event_label(&id, &description),
+                id,
+                count: row.get(2)?,
                 has_data: None,
             })
         })
@@ -46,7 +51,7 @@ pub fn get_event_types(
     Ok(event_types)
 }
 
-/// Récupère tous les événements groupés par description pour éviter les requêtes N+1
+/// Récupère tous les événements groupés par annonce (clé = `EventTypeInfo::id`) pour éviter les requêtes N+1
 pub fn get_all_events_grouped(
     conn: &Connection,
     calendar_id: Option<i32>,
@@ -55,14 +60,14 @@ pub fn get_all_events_grouped(
     
     let query = if let Some(cal_id) = calendar_id {
         format!(
-            "SELECT description, datetime(event_time) 
+            "SELECT COALESCE(event_id, description), datetime(event_time) 
              FROM calendar_events 
              WHERE calendar_import_id = {} 
              ORDER BY event_time",
             cal_id
         )
     } else {
-        "SELECT description, datetime(event_time) 
+        "SELECT COALESCE(event_id, description), datetime(event_time) 
          FROM calendar_events 
          ORDER BY event_time"
             .to_string()
@@ -1,3 +1,4 @@
+use crate::services::event_catalog;
 use rusqlite::Connection;
 use serde::{Deserialize, Serialize};
 
@@ -42,26 +43,26 @@ pub async fn get_past_events(
 
     let conn = Connection::open(&db_path).map_err(|e| format!("Failed to open database: {}", e))?;
 
-    // Grouper par description (type d'événement) et compter les occurrences
+    // Grouper par annonce canonique (event_id : variantes d'intitulé réunies) et compter les occurrences
     // IMPORTANT: COUNT(DISTINCT event_time) déduplique les événements de même jour/heure
     // mais différentes devises (ex: "Bank Holiday" du 1er janvier pour JPY, USD, EUR = 1 occurrence)
     // Filtrer par calendar_id si fourni
     // Afficher tous les événements HIGH et MEDIUM (toute la période)
     let query = if let Some(cal_id) = calendar_id {
         format!(
-            "SELECT description, COUNT(DISTINCT event_time) as count
+            "SELECT COALESCE(event_id, description) as event_key, MIN(description), COUNT(DISTINCT event_time) as count
              FROM calendar_events 
              WHERE (UPPER(impact) IN ('H', 'HIGH', 'M', 'MEDIUM', 'N')) AND calendar_import_id = {}
-             GROUP BY description
-             ORDER BY count DESC, description",
+             GROUP BY event_key
+             ORDER BY count DESC, event_key",
             cal_id
         )
     } else {
-        "SELECT description, COUNT(DISTINCT event_time) as count
+        "SELECT COALESCE(event_id, description) as event_key, MIN(description), COUNT(DISTINCT event_time) as count
          FROM calendar_events 
          WHERE UPPER(impact) IN ('H', 'HIGH', 'M', 'MEDIUM', 'N')
-         GROUP BY description
-         ORDER BY count DESC, description"
+         GROUP BY event_key
+         ORDER BY count DESC, event_key"
             .to_string()
     };
 
@@ -71,9 +72,11 @@ pub async fn get_past_events(
 
     let events_iter = stmt
         .query_map([], |row| {
+            let id: String = row.get(0)?;
+            let description: String = row.get(1)?;
             Ok(EventType {
-                name: row.get(0)?,  // description
-                count: row.get(1)?, // count
+                name: event_catalog::event_label(&id, &description),
+                count: row.get(2)?,
             })
         })
         .map_err(|e| format!("Failed to query events: {}", e))?;
@@ -0,0 +1,56 @@
+// commands/data_quality_commands.rs
+// Commande Tauri du rapport de qualité des données M1 d'une paire.
+// Charge toutes les candles M1 et délègue au service data_quality.
+
+use crate::models::data_quality::DataQualityReport;
+use tauri::command;
+
+/// Rapport de couverture par mois (trous hors week-ends/jours fériés, flux gelé,
+/// OHLC incohérents, doublons, spreads aberrants, sauts de prix).
+///
+/// `min_day_coverage_pct` (défaut: 90) fixe le seuil des jours listés comme mal couverts.
+#[command]
+pub async fn analyze_data_quality(
+    symbol: String,
+    min_day_coverage_pct: Option<f64>,
+) -> Result<DataQualityReport, String> {
+    use crate::db;
+    use crate::services::candle_index::CandleIndex;
+    use crate::services::data_quality;
+    use crate::services::database_loader::DatabaseLoader;
+
+    if symbol.is_empty() {
+        return Err("Symbole requis".to_string());
+    }
+    tracing::info!("🔎 Data quality scan: {}", symbol);
+
+    let data_dir =
+        dirs::data_local_dir().ok_or_else(|| "Répertoire de données introuvable".to_string())?;
+    let pairs_db_path = data_dir.join("volatility-analyzer").join("pairs.db");
+    let pairs_pool = db::create_pool(&format!("sqlite://{}", pairs_db_path.display()))
+        .map_err(|e| format!("Connexion BD paires échouée: {e}"))?;
+
+    let mut candle_index = CandleIndex::with_db_loader(DatabaseLoader::new(pairs_pool));
+    candle_index
+        .load_pair_candles(&symbol)
+        .map_err(|e| format!("Chargement paire {} échoué: {e}", symbol))?;
+    let candles = candle_index.get_all_candles(&symbol).unwrap_or_default();
+    if candles.is_empty() {
+        return Err(format!("Aucun candle trouvé pour {}", symbol));
+    }
+
+    let report = data_quality::analyser_qualite(
+        &symbol,
+        &candles,
+        min_day_coverage_pct.unwrap_or(data_quality::DEFAULT_MIN_DAY_COVERAGE_PCT),
+    );
+
+    tracing::info!(
+        "✅ Data quality {}: couverture {:.1}%, {} mois, {} jours sous le seuil",
+        symbol,
+        report.coverage_pct,
+        report.months.len(),
+        report.low_coverage_days.len()
+    );
+    Ok(report)
+}
@@ -56,6 +56,15 @@ pub async fn delete_pair_from_db(
                 timeframe_clean
             );
 
+            // Les timeframes dérivés du M1 disparaissent avec lui
+            if timeframe_clean == "M1" {
+                let derived_deleted = diesel::sql_query("DELETE FROM candle_data WHERE symbol = ? AND source_file = ?")
+                    .bind::<diesel::sql_types::Text, _>(&symbol_clean)
+                    .bind::<diesel::sql_types::Text, _>(crate::services::candle_resampler::RESAMPLE_SOURCE)
+                    .execute(conn)?;
+                tracing::info!("🗑️  Deleted {} resampled candles for {}", derived_deleted, symbol_clean);
+            }
+
             // 2. Supprimer les métadonnées
             tracing::debug!("🗑️ [Delete Pair] Deleting metadata...");
             let metadata_deleted = diesel::sql_query("DELETE FROM pair_metadata WHERE symbol = ? AND timeframe = ?")
@@ -70,6 +79,18 @@ pub async fn delete_pair_from_db(
                 timeframe_clean
             );
 
+            // 3. Sessions d'import clôturées (plus rien à annuler ni à remplacer)
+            diesel::sql_query("DELETE FROM candle_displaced WHERE symbol = ? AND timeframe = ?")
+                .bind::<diesel::sql_types::Text, _>(&symbol_clean)
+                .bind::<diesel::sql_types::Text, _>(&timeframe_clean)
+                .execute(conn)?;
+            diesel::sql_query(
+                "UPDATE import_log SET status = 'deleted' WHERE symbol = ? AND timeframe = ? AND status IN ('success', 'confirmed')",
+            )
+            .bind::<diesel::sql_types::Text, _>(&symbol_clean)
+            .bind::<diesel::sql_types::Text, _>(&timeframe_clean)
+            .execute(conn)?;
+
             if metadata_deleted == 0 && candles_deleted == 0 {
                 // Si rien n'a été supprimé, cela peut être dû à une incohérence.
                 // Essayons une suppression par LIKE pour être plus permissif si le strict a échoué ?
@@ -141,6 +162,13 @@ pub async fn delete_calendar_from_db(
 
             tracing::info!("🗑️  Deleted calendar import record ID {}", calendar_id);
 
+            // 3. Ses sessions d'import ne sont plus annulables
+            diesel::sql_query(
+                "UPDATE calendar_import_log SET status = 'deleted' WHERE calendar_import_id = ? AND status = 'success'",
+            )
+            .bind::<diesel::sql_types::Integer, _>(calendar_id)
+            .execute(conn)?;
+
             Ok(())
         })
         .map_err(|e| format!("Transaction failed: {}", e))?;
@@ -3,13 +3,17 @@
 
 use crate::commands::candle_db_writer;
 use crate::commands::tick_import_commands::ImportTickResult;
+use crate::models::import_session::ReimportMode;
 use crate::services::dukascopy_downloader::{self, DownloadProgress};
 use crate::services::dukascopy_instruments;
+use crate::services::pair_data::symbol_aliases::canonical_symbol;
+use crate::services::staged_import::StagedImport;
 use crate::services::tick_aggregator::{self, RawTick};
+use crate::services::tick_filter::{self, TickFilterConfig};
 use chrono::NaiveDate;
 use serde::{Deserialize, Serialize};
 use tauri::Emitter;
-use tracing::info;
+use tracing::{info, warn};
 
 /// Information instrument pour le frontend.
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,9 +44,18 @@ pub async fn get_dukascopy_instruments(
     
     let mut results = Vec::new();
 
+    // Réglages rapprochés par symbole canonique (EURUSD.m personnalisé = EURUSD)
+    let all_custom: Vec<_> = all_custom
+        .into_iter()
+        .map(|c| (canonical_symbol(&c.symbol), c))
+        .collect();
+
     // 3. Ajouter les instruments Dukascopy s'ils ne sont pas masqués
     for inst in all_duka {
-        let custom = all_custom.iter().find(|c| c.symbol.eq_ignore_ascii_case(inst.id));
+        let custom = all_custom
+            .iter()
+            .find(|(canonical, _)| *canonical == inst.id)
+            .map(|(_, c)| c);
         
         // Si la paire est masquée en BD, on l'exclut du dropdown
         if let Some(c) = custom {
@@ -50,17 +63,16 @@ pub async fn get_dukascopy_instruments(
         }
 
         results.push(InstrumentInfo {
-            id: inst.id.to_string(),
-            display: inst.display.to_string(),
-            category: inst.category.to_string(),
+            id: inst.id,
+            display: inst.display,
+            category: inst.category,
         });
     }
 
     // 4. Ajouter les paires "Custom" créées par l'utilisateur qui ne sont pas déjà dans Dukascopy
-    for custom in all_custom {
+    for (upper, custom) in all_custom {
         if custom.hidden { continue; }
         
-        let upper = custom.symbol.to_uppercase();
         if !results.iter().any(|r| r.id == upper) {
             results.push(InstrumentInfo {
                 id: upper.clone(),
@@ -74,16 +86,23 @@ pub async fn get_dukascopy_instruments(
 }
 
 /// Télécharge les données tick Dukascopy, agrège en M1 et sauvegarde en BD.
+/// `store_ticks` conserve aussi les ticks bruts dans tick_data.
+/// `tick_filter` règle le filtre des mauvais ticks (défauts si absent).
+/// `reimport_mode` : une plage déjà téléchargée est remplacée par défaut.
 #[tauri::command]
 pub async fn download_dukascopy_data(
     app: tauri::AppHandle,
     symbol: String,
     date_from: String,
     date_to: String,
+    store_ticks: Option<bool>,
+    tick_filter: Option<TickFilterConfig>,
+    reimport_mode: Option<ReimportMode>,
 ) -> Result<ImportTickResult, String> {
-    // Validation symbole
-    let instrument = dukascopy_instruments::find_instrument(&symbol)
+    // Validation symbole (alias broker acceptés : EURUSD.m → EURUSD)
+    let instrument = dukascopy_instruments::find_instrument(&canonical_symbol(&symbol))
         .ok_or_else(|| format!("Symbole non supporté : {}", symbol))?;
+    let symbol = instrument.id.to_string();
 
     // Validation dates
     let from = NaiveDate::parse_from_str(&date_from, "%Y-%m-%d")
@@ -135,7 +154,12 @@ pub async fn download_dukascopy_data(
         .collect();
 
     let total_ticks = raw_ticks.len();
-    info!("🔄 Agrégation de {} ticks en M1…", total_ticks);
+    let (raw_ticks, rejected_ticks) =
+        tick_filter::filtrer_ticks(raw_ticks, &tick_filter.unwrap_or_default());
+    if rejected_ticks.total() > 0 {
+        warn!("⚠ {} ticks rejetés par le filtre", rejected_ticks.total());
+    }
+    info!("🔄 Agrégation de {} ticks en M1…", raw_ticks.len());
 
     let candles = tick_aggregator::aggregate_to_m1(&raw_ticks);
     if candles.is_empty() {
@@ -143,14 +167,21 @@ pub async fn download_dukascopy_data(
     }
 
     let avg_spread = candles.iter().map(|c| c.spread_mean).sum::<f64>() / candles.len() as f64;
-    let avg_ticks = total_ticks as f64 / candles.len() as f64;
+    let avg_ticks = raw_ticks.len() as f64 / candles.len() as f64;
     let date_start = candles.first().map(|c| c.datetime_utc.to_rfc3339()).unwrap_or_default();
     let date_end = candles.last().map(|c| c.datetime_utc.to_rfc3339()).unwrap_or_default();
 
     // Sauvegarde en BD
     let source = format!("dukascopy-{}-{}-{}", symbol, date_from, date_to);
     let conn = candle_db_writer::open_pairs_db()?;
-    candle_db_writer::save_enriched_candles(&conn, &symbol, &candles, &source)?;
+    // Horodatages bi5 déjà en millisecondes UTC
+    let mode = reimport_mode.unwrap_or(ReimportMode::Replace);
+    let mut staged = StagedImport::begin(&conn, &symbol, "M1", &source, Some(mode))?;
+    staged.stage_enriched(&candles)?;
+    if store_ticks.unwrap_or(false) {
+        staged.stage_ticks(&raw_ticks)?;
+    }
+    let (_, ticks_stored) = staged.commit(Some("UTC"))?;
 
     info!("✅ Import Dukascopy terminé : {} M1 pour {}", candles.len(), symbol);
 
@@ -162,5 +193,9 @@ pub async fn download_dukascopy_data(
         date_end,
         avg_spread,
         avg_ticks_per_minute: avg_ticks,
+        ticks_stored,
+        rejected_ticks,
+        // Ticks bi5 regroupés par minute en mémoire : aucun tick hors ordre
+        late_ticks: 0,
     })
 }
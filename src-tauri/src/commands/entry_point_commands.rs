@@ -2,9 +2,14 @@
 // Commande Tauri pour l'analyse de point d'entrée optimal (Phase 2).
 // Charge toutes les candles M1 d'un symbole et délègue au service entry_point_analyzer.
 
+use crate::commands::calendar_commands::CalendarState;
 use crate::models::entry_analysis::{EntryAnalysisConfig, EntryAnalysisResult};
+use crate::models::release_seconds::SecondBarSize;
+use crate::models::{CalendarEvent, Candle};
+use chrono::{DateTime, NaiveDate, Timelike, Utc};
 use serde::Deserialize;
-use tauri::command;
+use std::collections::{BTreeSet, HashSet};
+use tauri::{command, State};
 
 /// Paramètres optionnels envoyés depuis le frontend
 #[derive(Debug, Deserialize)]
@@ -24,6 +29,15 @@ pub struct EntryPointParams {
     /// Échantillons minimum par offset (défaut: 5)
     #[serde(default)]
     pub min_samples: Option<usize>,
+    /// Résolution des barres tick pour l'analyse en secondes (défaut: S1)
+    #[serde(default)]
+    pub bar_size: Option<SecondBarSize>,
+    /// Durée de détention pour l'analyse en secondes (défaut: 120)
+    #[serde(default)]
+    pub forward_seconds: Option<i64>,
+    /// Couverture M1 minimale (%) d'un jour pour être analysé. Aucun filtre si absent
+    #[serde(default)]
+    pub min_day_coverage_pct: Option<f64>,
 }
 
 fn default_event_type() -> String {
@@ -38,11 +52,18 @@ fn default_forward() -> Option<usize> {
 /// Retourne un `EntryAnalysisResult` avec le profit net réel (après spread),
 /// le vrai win rate par comptage, les zones non-tradables et le profil de mouvement.
 #[command]
-pub async fn analyze_entry_points(params: EntryPointParams) -> Result<EntryAnalysisResult, String> {
+pub async fn analyze_entry_points(
+    params: EntryPointParams,
+    state: State<'_, CalendarState>,
+) -> Result<EntryAnalysisResult, String> {
     use crate::db;
     use crate::services::candle_index::CandleIndex;
+    use crate::services::data_quality;
     use crate::services::database_loader::DatabaseLoader;
     use crate::services::entry_point_analyzer;
+    use crate::services::pre_release_analyzer;
+    use crate::services::release_seconds_analyzer::{self, MAX_ENTRY_OFFSET_SECONDS};
+    use crate::services::second_bars;
 
     tracing::info!(
         "🎯 Entry point analysis: {} @ {:02}:{:02} (quarter={}) event={}",
@@ -75,16 +96,28 @@ pub async fn analyze_entry_points(params: EntryPointParams) -> Result<EntryAnaly
 
     // Charger toutes les candles M1 du symbole
     let db_loader = DatabaseLoader::new(pairs_pool);
-    let mut candle_index = CandleIndex::with_db_loader(db_loader);
+    let mut candle_index = CandleIndex::with_db_loader(db_loader.clone());
 
     candle_index
         .load_pair_candles(&params.symbol)
         .map_err(|e| format!("Chargement paire {} échoué: {e}", params.symbol))?;
 
-    let all_candles = candle_index
+    let mut all_candles = candle_index
         .get_all_candles(&params.symbol)
         .unwrap_or_default();
 
+    // Exclusion des jours à couverture insuffisante (trous de flux)
+    let mut excluded_days = 0;
+    if let Some(min_coverage) = params.min_day_coverage_pct {
+        (all_candles, excluded_days) =
+            data_quality::filtrer_jours_couverts(&params.symbol, &all_candles, min_coverage);
+        tracing::info!(
+            "🧹 {} jours sous {:.0}% de couverture exclus",
+            excluded_days,
+            min_coverage
+        );
+    }
+
     if all_candles.is_empty() {
         return Err(format!(
             "Aucun candle trouvé pour {}",
@@ -106,7 +139,7 @@ pub async fn analyze_entry_points(params: EntryPointParams) -> Result<EntryAnaly
     };
 
     // Analyse
-    let result = entry_point_analyzer::analyze_entry_points(
+    let mut result = entry_point_analyzer::analyze_entry_points(
         &all_candles,
         &params.symbol,
         &params.event_type,
@@ -115,6 +148,61 @@ pub async fn analyze_entry_points(params: EntryPointParams) -> Result<EntryAnaly
         &config,
     )
     .map_err(|e| format!("Analyse échouée: {e}"))?;
+    result.excluded_days = excluded_days;
+
+    // Mouvement anticipé avant les annonces du quarter, comparé à la même fenêtre les jours
+    // sans annonce : straddle à avancer, voire à éviter
+    let pip_value = crate::services::pair_data::get_asset_properties(&params.symbol).pip_value;
+    let events = load_quarter_events(&state, &params).await;
+    match &events {
+        Ok(events) => {
+            let announced = release_times(events, params.hour, params.quarter);
+            let event_days: HashSet<NaiveDate> =
+                announced.iter().map(|t| t.date_naive()).collect();
+            let reference = pre_release_analyzer::heures_reference(&announced, &event_days);
+            result.pre_release = pre_release_analyzer::profil_pre_annonce(
+                &pre_release_analyzer::mesurer_sur_serie(&all_candles, &announced, pip_value),
+                &pre_release_analyzer::mesurer_sur_serie(&all_candles, &reference, pip_value),
+            );
+            entry_point_analyzer::appliquer_pre_annonce(&mut result, params.hour, params.quarter);
+        }
+        Err(e) => tracing::warn!("Étude pré-annonce ignorée: {e}"),
+    }
+
+    // Analyse en secondes autour des publications du type d'événement (chaque jour pour
+    // "quarter"), si des ticks y sont stockés
+    let releases = if params.event_type == default_event_type() {
+        quarter_starts(&all_candles, params.hour, params.quarter)
+    } else {
+        events
+            .map(|events| release_times(&events, params.hour, params.quarter))
+            .unwrap_or_default()
+    };
+    let bar_size = params.bar_size.unwrap_or_default();
+    let forward_seconds = params.forward_seconds.unwrap_or(120).max(1);
+    match second_bars::charger_fenetres(
+        &db_loader,
+        &params.symbol,
+        &releases,
+        bar_size,
+        chrono::Duration::seconds(release_seconds_analyzer::PRE_RELEASE_SECONDS),
+        chrono::Duration::seconds(MAX_ENTRY_OFFSET_SECONDS + forward_seconds),
+    ) {
+        Ok(windows) if !windows.is_empty() => {
+            let details = release_seconds_analyzer::analyser_entrees(
+                &windows,
+                bar_size,
+                pip_value,
+                forward_seconds,
+                config.spread_threshold_pips,
+            );
+            result.optimal_offset_seconds =
+                release_seconds_analyzer::offset_optimal(&details, config.min_samples);
+            result.second_details = details;
+        }
+        Ok(_) => {}
+        Err(e) => tracing::warn!("Analyse en secondes ignorée: {e}"),
+    }
 
     tracing::info!(
         "✅ Entry point: offset={}min, win_rate={:.1}%, profit={:.1} pips, spread={:.1} pips",
@@ -126,3 +214,44 @@ pub async fn analyze_entry_points(params: EntryPointParams) -> Result<EntryAnaly
 
     Ok(result)
 }
+
+/// Annonces de `params.event_type` ; pour "quarter", toutes celles des devises du symbole
+async fn load_quarter_events(
+    state: &State<'_, CalendarState>,
+    params: &EntryPointParams,
+) -> Result<Vec<CalendarEvent>, String> {
+    use crate::commands::retrospective_analysis::helpers;
+
+    let conn = {
+        let pool_guard = state.pool.lock().map_err(|_| "DB lock failed")?;
+        let pool = pool_guard.as_ref().ok_or("DB not initialized")?;
+        pool.get().map_err(|e| format!("Connection failed: {}", e))?
+    };
+    if params.event_type == default_event_type() {
+        let (base, quote) = crate::services::directional_bias::pair_currencies(&params.symbol);
+        let currencies: Vec<String> = base.into_iter().chain([quote]).collect();
+        helpers::load_events_for_currencies(conn, &currencies, None, false).await
+    } else {
+        helpers::load_events_by_type(conn, &params.event_type).await
+    }
+}
+
+/// Heures de publication (UTC) tombant dans le quarter analysé, sans doublon
+fn release_times(events: &[CalendarEvent], hour: u8, quarter: u8) -> Vec<DateTime<Utc>> {
+    let times: BTreeSet<DateTime<Utc>> = events
+        .iter()
+        .map(|e| e.event_time.and_utc())
+        .filter(|t| t.hour() == hour as u32 && t.minute() / 15 == quarter as u32)
+        .collect();
+    times.into_iter().collect()
+}
+
+/// Début du quarter (UTC) pour chaque jour présent dans les candles
+fn quarter_starts(candles: &[Candle], hour: u8, quarter: u8) -> Vec<DateTime<Utc>> {
+    let dates: BTreeSet<NaiveDate> = candles.iter().map(|c| c.datetime.date_naive()).collect();
+    dates
+        .into_iter()
+        .filter_map(|d| d.and_hms_opt(hour as u32, quarter as u32 * 15, 0))
+        .map(|n| n.and_utc())
+        .collect()
+}
@@ -0,0 +1,78 @@
+// commands/event_catalog_commands.rs - Commandes Tauri du catalogue canonique des annonces
+// Conforme .clinerules : < 200 lines
+
+use crate::commands::calendar_commands::CalendarState;
+use crate::db::DbPool;
+use crate::models::event_catalog::{CanonicalEvent, EventResolution};
+use crate::services::event_catalog::{self, db as catalog_db};
+
+#[tauri::command]
+pub fn list_event_catalog() -> Result<Vec<CanonicalEvent>, String> {
+    Ok(event_catalog::list_events().to_vec())
+}
+
+/// Annonce canonique d'un intitulé brut pour une devise (aperçu avant ajout d'alias)
+#[tauri::command]
+pub fn resolve_event_name(
+    description: String,
+    currency: String,
+) -> Result<EventResolution, String> {
+    if description.trim().is_empty() {
+        return Err("Description cannot be empty".to_string());
+    }
+    Ok(event_catalog::resolve_event(&description, &currency))
+}
+
+/// Crée ou modifie une annonce ; les événements déjà importés sont rattachés à nouveau.
+/// Retourne le nombre d'événements dont le rattachement a changé.
+#[tauri::command]
+pub fn save_canonical_event(
+    state: tauri::State<'_, CalendarState>,
+    mut event: CanonicalEvent,
+) -> Result<usize, String> {
+    event.id = event.id.trim().to_lowercase();
+    event.currency = event.currency.trim().to_uppercase();
+    event.default_impact = event.default_impact.trim().to_uppercase();
+    if event.id.is_empty() || event.name.trim().is_empty() {
+        return Err("id et name sont obligatoires".to_string());
+    }
+    if event
+        .id
+        .starts_with(event_catalog::matcher::UNCATALOGUED_PREFIX)
+    {
+        return Err(format!("Préfixe réservé : {}", event.id));
+    }
+    if !matches!(event.default_impact.as_str(), "H" | "M" | "L") {
+        return Err(format!("Impact invalide : {}", event.default_impact));
+    }
+    event.aliases.retain(|a| !a.alias.trim().is_empty());
+
+    let pool = calendar_pool(&state)?;
+    catalog_db::upsert_event(&pool, &event).map_err(|e| format!("Save failed: {}", e))?;
+    event_catalog::invalidate_catalog_cache();
+    event_catalog::assign_event_ids(&pool, false)
+}
+
+/// Supprime une annonce personnalisée ; une annonce d'origine reprend ses alias par défaut.
+/// Retourne le nombre d'événements dont le rattachement a changé.
+#[tauri::command]
+pub fn delete_canonical_event(
+    state: tauri::State<'_, CalendarState>,
+    id: String,
+) -> Result<usize, String> {
+    let pool = calendar_pool(&state)?;
+    catalog_db::delete_event(&pool, &id).map_err(|e| format!("Delete failed: {}", e))?;
+    if let Some(default) = event_catalog::default_event(&id) {
+        catalog_db::insert_missing(&pool, &[default])
+            .map_err(|e| format!("Reset failed: {}", e))?;
+    }
+    event_catalog::invalidate_catalog_cache();
+    event_catalog::assign_event_ids(&pool, false)
+}
+
+fn calendar_pool(state: &tauri::State<'_, CalendarState>) -> Result<DbPool, String> {
+    let pool_opt = state.pool.lock().map_err(|_| "Failed to lock pool")?;
+    pool_opt
+        .clone()
+        .ok_or_else(|| "DB pool not initialized".to_string())
+}
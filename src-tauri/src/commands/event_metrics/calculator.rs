@@ -1,10 +1,13 @@
 use super::CandlesState;
+use crate::commands::calendar_commands::CalendarState;
 use crate::models::{Candle, EventMetrics};
 use crate::services::event_metrics_aggregator::{EventMetricsAggregator, MetricsConfig};
+use crate::services::excursion_analyzer::stops_depuis_occurrences;
+use crate::services::pair_data::get_asset_properties;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use tauri::State;
-use tracing::{error, info};
+use tracing::{error, info, warn};
 
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct CalculateMetricsParams {
@@ -15,6 +18,10 @@ pub struct CalculateMetricsParams {
     pub atr_multiplier_sl: Option<f64>,
     pub atr_multiplier_tp: Option<f64>,
     pub max_trade_duration_minutes: Option<usize>,
+    /// SL/TP en pips imposés. Absents : percentiles MAE/MFE des publications passées,
+    /// multiplicateurs ATR en dernier recours
+    pub stop_loss_pips: Option<f64>,
+    pub take_profit_pips: Option<f64>,
 }
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -36,10 +43,33 @@ pub fn get_unique_symbols(candles: &[Candle]) -> Vec<String> {
     symbols
 }
 
+/// Publications de l'événement antérieures à `before` (pas de look-ahead sur les stops)
+async fn past_releases(
+    state: &State<'_, CalendarState>,
+    event_name: &str,
+    before: DateTime<Utc>,
+) -> Result<Vec<DateTime<Utc>>, String> {
+    use crate::commands::retrospective_analysis::helpers;
+
+    let conn = {
+        let pool_guard = state.pool.lock().map_err(|_| "DB lock failed")?;
+        let pool = pool_guard.as_ref().ok_or("DB not initialized")?;
+        pool.get()
+            .map_err(|e| format!("Connection failed: {}", e))?
+    };
+    let events = helpers::load_events_by_type(conn, event_name).await?;
+    Ok(events
+        .iter()
+        .map(|e| e.event_time.and_utc())
+        .filter(|t| *t < before)
+        .collect())
+}
+
 #[tauri::command]
 pub async fn calculer_metriques_evenement(
     params: CalculateMetricsParams,
     candles_state: State<'_, CandlesState>,
+    calendar_state: State<'_, CalendarState>,
 ) -> Result<MetricsCommandResult, String> {
     info!("Calculating metrics for event: {}", params.event_name);
 
@@ -47,6 +77,17 @@ pub async fn calculer_metriques_evenement(
         .map_err(|e| format!("Invalid event time format: {}", e))?
         .with_timezone(&Utc);
 
+    let releases = if params.stop_loss_pips.is_none() && params.take_profit_pips.is_none() {
+        past_releases(&calendar_state, &params.event_name, event_time)
+            .await
+            .unwrap_or_else(|e| {
+                warn!("Excursions indisponibles, SL/TP sur l'ATR : {}", e);
+                Vec::new()
+            })
+    } else {
+        Vec::new()
+    };
+
     let candles_guard = candles_state
         .candles
         .lock()
@@ -60,11 +101,12 @@ pub async fn calculer_metriques_evenement(
         });
     }
 
-    let symbol_candles: Vec<Candle> = candles_guard
+    let mut symbol_candles: Vec<Candle> = candles_guard
         .iter()
         .filter(|c| c.symbol == params.symbol)
         .cloned()
         .collect();
+    symbol_candles.sort_by_key(|c| c.datetime);
 
     if symbol_candles.is_empty() {
         return Ok(MetricsCommandResult {
@@ -83,7 +125,15 @@ pub async fn calculer_metriques_evenement(
         atr_multiplier_sl: params.atr_multiplier_sl.unwrap_or(2.0),
         atr_multiplier_tp: params.atr_multiplier_tp.unwrap_or(3.0),
         max_trade_duration_minutes: params.max_trade_duration_minutes.unwrap_or(120),
+        stop_loss_pips: params.stop_loss_pips,
+        take_profit_pips: params.take_profit_pips,
     };
+    let stops = stops_depuis_occurrences(
+        releases.iter().map(|t| (symbol_candles.as_slice(), *t)),
+        config.max_trade_duration_minutes as u32,
+        get_asset_properties(&params.symbol).pip_value,
+    );
+    let config = config.avec_stops_excursions(stops);
 
     let aggregator =
         EventMetricsAggregator::new(&symbol_candles, event_time, params.event_name.clone());
@@ -1,5 +1,10 @@
-use super::db_helpers::{insert_candles_to_db, insert_pair_metadata};
+use crate::commands::candle_db_writer;
+use crate::models::import_session::ReimportMode;
+use crate::services::import_sessions::ALREADY_IMPORTED;
+use crate::services::import_stream::{ImportControl, ImportStage, IMPORT_CANCELLED};
 use crate::services::process_file_with_cleaning;
+use crate::services::source_timezone::SourceTimezone;
+use crate::services::staged_import::StagedImport;
 use crate::services::ProcessResult;
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -20,12 +25,21 @@ pub struct ImportCleanResult {
     pub lines_imported: usize,
     pub cleaning_stats: Option<FileCleaningStats>,
     pub error_message: Option<String>,
+    /// Ticks hors ordre ignorés (exports tick HistData/MT5 non triés)
+    #[serde(default)]
+    pub late_ticks: usize,
 }
 
+/// Nettoie puis importe un fichier en continu : lots en zone de transit, publiés
+/// en une transaction à la fin (rien en base si l'import échoue ou est annulé).
+/// Statut "already_imported" si le fichier a déjà été importé sans `reimport_mode`.
 pub fn process_single_file(
     source_path: &str,
     temp_dir: &Path,
     data_dir: &Path,
+    source_timezone: Option<&SourceTimezone>,
+    reimport_mode: Option<ReimportMode>,
+    ctrl: &mut ImportControl,
 ) -> ImportCleanResult {
     let file_name = Path::new(source_path)
         .file_name()
@@ -33,32 +47,26 @@ pub fn process_single_file(
         .unwrap_or("unknown")
         .to_string();
 
-    match process_file_with_cleaning(source_path, temp_dir, data_dir) {
+    match import_file(
+        source_path,
+        temp_dir,
+        data_dir,
+        source_timezone,
+        reimport_mode,
+        &file_name,
+        ctrl,
+    ) {
         Ok(ProcessResult {
             pair,
             timeframe,
             lines_cleaned,
             errors,
             error_rate,
-            cleaned_file_path,
+            late_ticks,
+            ..
         }) => {
             tracing::info!("✅ Fichier importé avec succès: {} ({})", pair, timeframe);
 
-            if let Err(e) = insert_candles_to_db(&cleaned_file_path, &pair, &timeframe, &file_name)
-            {
-                tracing::warn!("⚠️  Erreur insertion candles: {}", e);
-            }
-
-            if let Err(e) =
-                insert_pair_metadata(&pair, &timeframe, lines_cleaned as i32, &file_name)
-            {
-                tracing::warn!("⚠️  Erreur insertion métadonnées: {}", e);
-            }
-
-            if let Err(e) = fs::remove_file(&cleaned_file_path) {
-                tracing::warn!("  ⚠️  Impossible de supprimer le fichier temporaire: {}", e);
-            }
-
             ImportCleanResult {
                 original_file: file_name,
                 import_status: if error_rate >= 1.0 {
@@ -74,18 +82,75 @@ pub fn process_single_file(
                     warnings: Vec::new(),
                 }),
                 error_message: None,
+                late_ticks,
             }
         }
         Err(e) => {
             tracing::error!("❌ Erreur: {}", e);
+            if e == IMPORT_CANCELLED {
+                ctrl.stage(ImportStage::Cancelled);
+            }
+
+            let import_status = if e.starts_with(ALREADY_IMPORTED) {
+                "already_imported"
+            } else {
+                "failed"
+            };
 
             ImportCleanResult {
                 original_file: file_name,
-                import_status: "failed".to_string(),
+                import_status: import_status.to_string(),
                 lines_imported: 0,
                 cleaning_stats: None,
                 error_message: Some(e),
+                late_ticks: 0,
             }
         }
     }
 }
+
+fn import_file(
+    source_path: &str,
+    temp_dir: &Path,
+    data_dir: &Path,
+    source_timezone: Option<&SourceTimezone>,
+    reimport_mode: Option<ReimportMode>,
+    file_name: &str,
+    ctrl: &mut ImportControl,
+) -> Result<ProcessResult, String> {
+    let conn = candle_db_writer::open_pairs_db()?;
+    let mut staged: Option<StagedImport> = None;
+    let processed = process_file_with_cleaning(
+        source_path,
+        temp_dir,
+        data_dir,
+        source_timezone,
+        ctrl,
+        &mut |metadata, batch| {
+            let import = if let Some(import) = staged.as_mut() {
+                import
+            } else {
+                staged.insert(StagedImport::begin(
+                    &conn,
+                    &metadata.pair,
+                    &metadata.timeframe,
+                    file_name,
+                    reimport_mode,
+                )?)
+            };
+            import.stage_normalized(batch)
+        },
+    );
+
+    let result = processed?;
+    if let Err(e) = fs::remove_file(&result.cleaned_file_path) {
+        tracing::warn!("  ⚠️  Impossible de supprimer le fichier temporaire: {}", e);
+    }
+
+    let staged = staged.ok_or("Aucune donnée valide trouvée")?;
+    ctrl.stage(ImportStage::Committing);
+    let known_timezone = result.source_timezone.map(|tz| tz.to_string());
+    staged.commit(known_timezone.as_deref())?;
+    ctrl.stage(ImportStage::Done);
+    Ok(result)
+}
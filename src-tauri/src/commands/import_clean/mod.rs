@@ -1,7 +1,10 @@
-mod db_helpers;
 mod file_processor;
 
+use crate::commands::import_control_commands::{self, ImportCancelState};
+use crate::models::import_session::ReimportMode;
 use crate::services::create_cleaned_dir;
+use crate::services::import_stream::{ImportControl, ImportProgress, IMPORT_CANCELLED};
+use crate::services::source_timezone::SourceTimezone;
 pub use file_processor::ImportCleanResult;
 use serde::{Deserialize, Serialize};
 use std::fs;
@@ -14,12 +17,26 @@ pub struct ImportCleanReport {
     pub results: Vec<ImportCleanResult>,
 }
 
+/// `source_timezone` : fuseau des horodatages des fichiers (None = défaut du format)
+/// `reimport_mode` : remplacer ou fusionner les fichiers déjà importés (absent = ils
+/// sont signalés "already_imported" sans être importés).
+/// Progression émise sur "import-progress" ; `cancel_import` interrompt le fichier en
+/// cours (rien n'en est écrit) et les fichiers suivants ne sont pas traités.
 #[tauri::command]
-pub async fn import_and_clean_files(paths: Vec<String>) -> Result<ImportCleanReport, String> {
+pub async fn import_and_clean_files(
+    app: tauri::AppHandle,
+    cancel: tauri::State<'_, ImportCancelState>,
+    paths: Vec<String>,
+    source_timezone: Option<String>,
+    reimport_mode: Option<ReimportMode>,
+) -> Result<ImportCleanReport, String> {
     tracing::info!(
         "📥 Import avec nettoyage automatique de {} fichiers",
         paths.len()
     );
+    let source_timezone = SourceTimezone::parse_optional(source_timezone.as_deref())?;
+    cancel.reset();
+    let mut on_progress = |p: &ImportProgress| import_control_commands::emit_progress(&app, p);
 
     let mut report = ImportCleanReport {
         total_files: paths.len(),
@@ -46,14 +63,34 @@ pub async fn import_and_clean_files(paths: Vec<String>) -> Result<ImportCleanRep
     for (index, path) in paths.iter().enumerate() {
         tracing::info!("[{}/{}] Traitement: {}", index + 1, paths.len(), path);
 
-        let result = file_processor::process_single_file(path, &temp_dir, &data_dir);
+        let file_name = std::path::Path::new(path)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown");
+        let total_bytes = fs::metadata(path).map(|m| m.len()).unwrap_or(0);
+        let mut ctrl =
+            ImportControl::new(file_name, total_bytes, &cancel.cancelled, &mut on_progress);
+
+        let result = file_processor::process_single_file(
+            path,
+            &temp_dir,
+            &data_dir,
+            source_timezone.as_ref(),
+            reimport_mode,
+            &mut ctrl,
+        );
 
         match &result.import_status as &str {
             "success" => report.successful += 1,
             _ => report.failed += 1,
         }
 
+        let cancelled = result.error_message.as_deref() == Some(IMPORT_CANCELLED);
         report.results.push(result);
+        if cancelled {
+            tracing::info!("⏹ Import annulé, fichiers suivants ignorés");
+            break;
+        }
     }
 
     tracing::info!(
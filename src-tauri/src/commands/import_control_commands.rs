@@ -0,0 +1,36 @@
+// commands/import_control_commands.rs
+// Suivi des imports en continu : progression émise au frontend ("import-progress")
+// et annulation en cours de fichier.
+
+use crate::services::import_stream::ImportProgress;
+use std::sync::atomic::{AtomicBool, Ordering};
+use tauri::Emitter;
+
+/// Événement de progression écouté par le frontend
+pub const IMPORT_PROGRESS_EVENT: &str = "import-progress";
+
+/// Drapeau d'annulation partagé par les imports (un import à la fois côté UI)
+#[derive(Default)]
+pub struct ImportCancelState {
+    pub cancelled: AtomicBool,
+}
+
+impl ImportCancelState {
+    /// Réarme le drapeau au début d'un import
+    pub fn reset(&self) {
+        self.cancelled.store(false, Ordering::Relaxed);
+    }
+}
+
+/// Émet la progression d'un import vers le frontend
+pub fn emit_progress(app: &tauri::AppHandle, progress: &ImportProgress) {
+    let _ = app.emit(IMPORT_PROGRESS_EVENT, progress);
+}
+
+/// Demande l'arrêt de l'import en cours : il s'interrompt au prochain lot
+/// sans rien laisser en base.
+#[tauri::command]
+pub fn cancel_import(state: tauri::State<'_, ImportCancelState>) {
+    tracing::info!("⏹ Annulation de l'import demandée");
+    state.cancelled.store(true, Ordering::Relaxed);
+}
@@ -0,0 +1,101 @@
+// commands/import_session_commands.rs
+// Historique des imports (bougies et calendriers) : liste, détail et annulation
+// d'une session précise.
+
+use crate::commands::candle_db_writer;
+use crate::commands::metadata::calendar_db_queries::open_volatility_db;
+use crate::models::import_session::{
+    BackupPurgeResult, ImportKind, ImportSession, ImportSessionDetail, RollbackResult,
+};
+use crate::services::import_sessions;
+
+/// Nombre de sessions retournées par défaut
+const DEFAULT_SESSION_LIMIT: usize = 200;
+
+/// Sessions d'import, plus récentes d'abord. `kind` absent = bougies et calendriers ;
+/// `symbol` filtre les sessions de bougies d'une paire.
+#[tauri::command]
+pub async fn list_import_sessions(
+    kind: Option<ImportKind>,
+    symbol: Option<String>,
+    limit: Option<usize>,
+) -> Result<Vec<ImportSession>, String> {
+    let limit = limit.unwrap_or(DEFAULT_SESSION_LIMIT);
+    let mut sessions = Vec::new();
+
+    if kind != Some(ImportKind::Calendar) {
+        let conn = candle_db_writer::open_pairs_db()?;
+        sessions.extend(import_sessions::lister_sessions_bougies(
+            &conn,
+            symbol.as_deref(),
+            limit,
+        )?);
+    }
+    if kind != Some(ImportKind::Candles) && symbol.is_none() {
+        let conn = open_volatility_db()?;
+        sessions.extend(import_sessions::lister_sessions_calendrier(&conn, limit)?);
+    }
+
+    // Horodatages RFC 3339 : l'ordre lexicographique suit l'ordre chronologique
+    sessions.sort_by(|a, b| b.imported_at.cmp(&a.imported_at));
+    sessions.truncate(limit);
+    Ok(sessions)
+}
+
+/// Détail d'une session : plage couverte, lignes encore en base, effet d'une annulation
+#[tauri::command]
+pub async fn get_import_session(kind: ImportKind, id: i64) -> Result<ImportSessionDetail, String> {
+    match kind {
+        ImportKind::Candles => {
+            let conn = candle_db_writer::open_pairs_db()?;
+            import_sessions::detail_session_bougies(&conn, id)
+        }
+        ImportKind::Calendar => {
+            let conn = open_volatility_db()?;
+            import_sessions::detail_session_calendrier(&conn, id)
+        }
+    }
+}
+
+/// Annule une session : ses lignes sont retirées et, pour les bougies, celles
+/// qu'elle avait écrasées sont remises en place.
+#[tauri::command]
+pub async fn rollback_import(kind: ImportKind, id: i64) -> Result<RollbackResult, String> {
+    tracing::info!("↩️ Annulation de l'import {:?} #{}", kind, id);
+    match kind {
+        ImportKind::Candles => {
+            let conn = candle_db_writer::open_pairs_db()?;
+            import_sessions::annuler_session_bougies(&conn, id)
+        }
+        ImportKind::Calendar => {
+            let conn = open_volatility_db()?;
+            import_sessions::annuler_session_calendrier(&conn, id)
+        }
+    }
+}
+
+/// Valide une session de bougies : ses sauvegardes sont purgées, elle n'est plus annulable
+#[tauri::command]
+pub async fn confirm_import_session(id: i64) -> Result<BackupPurgeResult, String> {
+    let conn = candle_db_writer::open_pairs_db()?;
+    import_sessions::confirmer_session_bougies(&conn, id)
+}
+
+/// Valide les sessions de bougies de plus de `older_than_days` jours (défaut : rétention
+/// appliquée à chaque import) et purge leurs sauvegardes
+#[tauri::command]
+pub async fn purge_import_backups(
+    older_than_days: Option<i64>,
+) -> Result<BackupPurgeResult, String> {
+    let conn = candle_db_writer::open_pairs_db()?;
+    import_sessions::purger_sauvegardes_anciennes(
+        &conn,
+        older_than_days.unwrap_or(import_sessions::DEFAULT_BACKUP_RETENTION_DAYS),
+    )
+}
+
+/// Délai de rétention des sauvegardes appliqué à chaque import, en jours
+#[tauri::command]
+pub async fn get_backup_retention_days() -> Result<i64, String> {
+    Ok(import_sessions::DEFAULT_BACKUP_RETENTION_DAYS)
+}
@@ -1,8 +1,10 @@
 // commands/mod.rs - Exports des commandes Tauri
 pub mod archive_commands;
+pub mod backtest_commands;
 pub mod calendar_commands;
 pub mod calendar_db_helper;
 pub mod calendar_import_commands;
+pub mod calendar_mapping_commands;
 pub mod calendar_parser;
 pub mod parser_test;
 pub mod candle_helpers;
@@ -13,23 +15,29 @@ pub mod config_commands;
 pub mod correlation;
 pub mod candle_db_writer;
 pub mod csv_cleaner_commands;
+pub mod data_quality_commands;
 pub mod deletion_commands;
 pub mod dukascopy_commands;
 pub mod economic_commands;
 pub mod entry_point_commands;
 pub mod entry_window_analysis_commands;
+pub mod event_catalog_commands;
 pub mod event_metrics;
 pub mod export_pdf_commands;
 pub mod file_listing;
 pub mod global_analysis_commands;
 pub mod import_clean;
+pub mod import_control_commands;
+pub mod import_session_commands;
 pub mod metadata;
 pub mod movement_analysis_commands;
 pub mod pair_data;
+pub mod parquet_commands;
 // pair_importer supprimé (Phase 3.2) : code mort, remplacé par pair_data/processor.rs
 pub mod planning;
 pub mod retrospective_analysis;
 pub mod tick_import_commands;
+pub mod timezone_detection_commands;
 
 pub mod session_commands;
 pub mod volatility;
@@ -72,9 +80,14 @@ pub use metadata::{
 pub use movement_analysis_commands::{analyze_movement_quality, get_movement_qualities};
 pub use pair_data::{import_pair_data, get_symbol_properties};
 pub use pair_data::conversion_commands::{get_all_conversions, save_conversion, delete_conversion, restore_conversion, invalidate_analysis_cache};
+pub use pair_data::alias_commands::{list_symbol_aliases, save_symbol_alias, delete_symbol_alias, resolve_symbol_alias};
+pub use pair_data::instrument_commands::{list_instruments, get_instrument, save_instrument, delete_instrument};
 pub use planning::projection::project_stats_on_calendar;
 // Phase 7: Retrospective analysis commands (fully integrated)
-pub use retrospective_analysis::{analyze_decay_profile, analyze_peak_delay, get_event_types};
+pub use retrospective_analysis::{
+    analyze_decay_profile, analyze_directional_bias, analyze_excursions, analyze_peak_delay,
+    analyze_pre_release_drift, analyze_surprise_buckets, get_event_types, scan_pre_release_drift,
+};
 pub use session_commands::*;
 pub use volatility::{
     analyze_quarter_entry_timing, analyze_slice_metrics, analyze_symbol,
@@ -0,0 +1,57 @@
+// commands/pair_data/alias_commands.rs - Commandes Tauri pour les alias de symboles
+// Conforme .clinerules : < 200 lines
+
+use super::PairDataState;
+use crate::models::symbol_conversion::SymbolAlias;
+use crate::services::pair_data::{conversion_db, symbol_aliases};
+
+#[tauri::command]
+pub fn list_symbol_aliases(
+    state: tauri::State<'_, PairDataState>,
+) -> Result<Vec<SymbolAlias>, String> {
+    let pool = {
+        let pool_opt = state.pool.lock().map_err(|_| "Failed to lock pool")?;
+        pool_opt.clone().ok_or("DB pool not initialized")?
+    };
+
+    conversion_db::get_all_aliases(&pool).map_err(|e| format!("DB error: {}", e))
+}
+
+/// Enregistre une règle d'alias ; les imports suivants utilisent le symbole canonique
+#[tauri::command]
+pub fn save_symbol_alias(
+    state: tauri::State<'_, PairDataState>,
+    alias: SymbolAlias,
+) -> Result<(), String> {
+    let alias = symbol_aliases::validate_alias(alias)?;
+
+    let pool = {
+        let pool_opt = state.pool.lock().map_err(|_| "Failed to lock pool")?;
+        pool_opt.clone().ok_or("DB pool not initialized")?
+    };
+
+    conversion_db::upsert_alias(&pool, &alias).map_err(|e| format!("Save failed: {}", e))?;
+    symbol_aliases::invalidate_alias_cache();
+    Ok(())
+}
+
+#[tauri::command]
+pub fn delete_symbol_alias(state: tauri::State<'_, PairDataState>, id: i32) -> Result<(), String> {
+    let pool = {
+        let pool_opt = state.pool.lock().map_err(|_| "Failed to lock pool")?;
+        pool_opt.clone().ok_or("DB pool not initialized")?
+    };
+
+    conversion_db::delete_alias(&pool, id).map_err(|e| format!("Delete failed: {}", e))?;
+    symbol_aliases::invalidate_alias_cache();
+    Ok(())
+}
+
+/// Aperçu : symbole canonique sous lequel `symbol` serait importé
+#[tauri::command]
+pub fn resolve_symbol_alias(symbol: String) -> Result<String, String> {
+    if symbol.trim().is_empty() {
+        return Err("Symbol cannot be empty".to_string());
+    }
+    Ok(symbol_aliases::canonical_symbol(&symbol))
+}
@@ -19,7 +19,7 @@ pub struct ConversionEntry {
 pub fn get_all_conversions(
     state: tauri::State<'_, PairDataState>,
 ) -> Result<Vec<ConversionEntry>, String> {
-    use crate::models::AssetProperties;
+    use crate::services::instrument_registry;
     use crate::services::pair_data::conversion_db;
 
     let pool = {
@@ -35,21 +35,15 @@ pub fn get_all_conversions(
         .map(|c| (c.symbol.clone(), c))
         .collect();
 
-    let known_symbols = [
-        "EURUSD", "GBPUSD", "USDCAD", "USDCHF", "AUDUSD", "NZDUSD",
-        "USDJPY", "GBPJPY", "CADJPY", "EURJPY", "AUDJPY",
-        "XAUUSD", "XAGUSD",
-        "BTCUSD", "ETHUSD", "ETHUS0",
-        "US30", "US39", "USATEC", "DEUIDX", "DEUIDS", "SPX500", "VIX",
-        "WTI", "BRENT", "NGAS",
-    ];
+    // Symboles connus : fiches du registre instruments
+    let instruments = instrument_registry::list_instruments();
 
     let mut entries: Vec<ConversionEntry> = Vec::new();
     let mut seen: std::collections::HashSet<String> = std::collections::HashSet::new();
 
-    for sym in &known_symbols {
-        let symbol = sym.to_string();
-        let props = AssetProperties::from_symbol(sym);
+    for instrument in instruments.iter() {
+        let symbol = instrument.symbol.clone();
+        let props = instrument.asset_properties();
         
         if let Some(custom) = db_map.get(&symbol) {
             // ✅ FILTRER les conversions cachées
@@ -171,8 +165,7 @@ pub fn delete_conversion(
         }
         Ok(None) => {
             // N'existe pas en BD (paire hardcodée): créer l'entrée + marquer hidden
-            use crate::models::AssetProperties;
-            let props = AssetProperties::from_symbol(&symbol);
+            let props = crate::services::pair_data::get_asset_properties(&symbol);
             let conversion = SymbolConversion {
                 symbol: symbol.clone(),
                 pip_value: props.pip_value,
@@ -0,0 +1,71 @@
+// commands/pair_data/instrument_commands.rs - Commandes Tauri du registre des instruments
+// Conforme .clinerules : < 200 lines
+
+use super::PairDataState;
+use crate::models::instrument::Instrument;
+use crate::services::instrument_registry::{self, db as instrument_db};
+use crate::services::pair_data::symbol_aliases::canonical_symbol;
+
+#[tauri::command]
+pub fn list_instruments() -> Result<Vec<Instrument>, String> {
+    Ok(instrument_registry::list_instruments().to_vec())
+}
+
+/// Fiche d'un symbole (alias broker résolus) ; déduite du nom si absente du registre
+#[tauri::command]
+pub fn get_instrument(symbol: String) -> Result<Instrument, String> {
+    if symbol.trim().is_empty() {
+        return Err("Symbol cannot be empty".to_string());
+    }
+    Ok(instrument_registry::resolve_instrument(&symbol))
+}
+
+/// Crée ou modifie une fiche ; les analyses suivantes utilisent les nouvelles valeurs
+#[tauri::command]
+pub fn save_instrument(
+    state: tauri::State<'_, PairDataState>,
+    mut instrument: Instrument,
+) -> Result<(), String> {
+    instrument.symbol = canonical_symbol(&instrument.symbol);
+    if instrument.symbol.is_empty() {
+        return Err("Symbol cannot be empty".to_string());
+    }
+    if instrument.pip_size <= 0.0 || instrument.contract_size <= 0.0 {
+        return Err("pip_size et contract_size doivent être positifs".to_string());
+    }
+    if !(0..=10).contains(&instrument.digits) {
+        return Err(format!("digits invalide : {}", instrument.digits));
+    }
+
+    let pool = {
+        let pool_opt = state.pool.lock().map_err(|_| "Failed to lock pool")?;
+        pool_opt.clone().ok_or("DB pool not initialized")?
+    };
+
+    instrument_db::upsert_instrument(&pool, &instrument)
+        .map_err(|e| format!("Save failed: {}", e))?;
+    instrument_registry::invalidate_registry_cache();
+    Ok(())
+}
+
+/// Supprime une fiche personnalisée ; une fiche d'origine reprend ses valeurs par défaut
+#[tauri::command]
+pub fn delete_instrument(
+    state: tauri::State<'_, PairDataState>,
+    symbol: String,
+) -> Result<(), String> {
+    let symbol = canonical_symbol(&symbol);
+    let pool = {
+        let pool_opt = state.pool.lock().map_err(|_| "Failed to lock pool")?;
+        pool_opt.clone().ok_or("DB pool not initialized")?
+    };
+
+    instrument_db::delete_instrument(&pool, &symbol)
+        .map_err(|e| format!("Delete failed: {}", e))?;
+    if let Some(default) = instrument_registry::default_instrument(&symbol) {
+        instrument_db::insert_missing(&pool, &[default])
+            .map_err(|e| format!("Reset failed: {}", e))?;
+    }
+    instrument_registry::invalidate_registry_cache();
+    Ok(())
+}
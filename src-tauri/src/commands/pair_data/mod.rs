@@ -1,7 +1,15 @@
 mod processor;
+pub mod alias_commands;
 pub mod conversion_commands;
+pub mod instrument_commands;
 
+use crate::commands::import_control_commands::{self, ImportCancelState};
 use crate::db::DbPool;
+use crate::models::import_session::ReimportMode;
+use crate::services::import_sessions::ALREADY_IMPORTED;
+use crate::services::import_stream::{
+    ImportControl, ImportProgress, ImportStage, IMPORT_CANCELLED,
+};
 pub use processor::process_single_file;
 use serde::{Deserialize, Serialize};
 use std::sync::Mutex;
@@ -20,15 +28,36 @@ pub struct ImportSummary {
     pub pairs_updated: Vec<String>,
     pub timeframes: Vec<String>,
     pub errors: Vec<String>,
+    /// Fichiers déjà importés, laissés en place faute de `reimport_mode`
+    #[serde(default)]
+    pub already_imported: Vec<String>,
+    /// Ticks hors ordre ignorés (exports tick HistData/MT5 non triés)
+    #[serde(default)]
+    pub late_ticks: usize,
 }
 
+/// `source_timezone` : fuseau des horodatages des fichiers (IANA ou "broker NY-close"),
+/// absent = défaut du format (UTC, EST pour HistData, NY-close pour MT5).
+/// `reimport_mode` : remplacer ou fusionner les fichiers déjà importés (absent = ils
+/// sont listés dans `already_imported` sans être importés).
+/// Progression émise sur "import-progress" ; `cancel_import` interrompt le fichier en
+/// cours (rien n'en est écrit) et les fichiers suivants ne sont pas importés.
 #[tauri::command]
 pub async fn import_pair_data(
+    app: tauri::AppHandle,
     state: tauri::State<'_, PairDataState>,
+    cancel: tauri::State<'_, ImportCancelState>,
     paths: Vec<String>,
+    source_timezone: Option<String>,
+    reimport_mode: Option<ReimportMode>,
 ) -> Result<ImportSummary, String> {
+    use crate::services::source_timezone::SourceTimezone;
     use std::path::Path;
 
+    let source_timezone = SourceTimezone::parse_optional(source_timezone.as_deref())?;
+    cancel.reset();
+    let mut on_progress = |p: &ImportProgress| import_control_commands::emit_progress(&app, p);
+
     info!("📥 ========== DÉBUT IMPORT PAIR DATA ==========");
     info!("📥 Import de {} fichiers de paires vers BD", paths.len());
     for (idx, path) in paths.iter().enumerate() {
@@ -42,6 +71,8 @@ pub async fn import_pair_data(
         pairs_updated: Vec::new(),
         timeframes: Vec::new(),
         errors: Vec::new(),
+        already_imported: Vec::new(),
+        late_ticks: 0,
     };
 
     info!("🔐 Tentative d'accès au pool DB paires...");
@@ -66,9 +97,24 @@ pub async fn import_pair_data(
             path
         );
 
-        match process_single_file(&path, &pool) {
-            Ok((pair, timeframe, row_count)) => {
+        let file_name = Path::new(&path)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or("unknown");
+        let total_bytes = std::fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        let mut ctrl =
+            ImportControl::new(file_name, total_bytes, &cancel.cancelled, &mut on_progress);
+
+        match process_single_file(
+            &path,
+            &pool,
+            source_timezone.as_ref(),
+            reimport_mode,
+            &mut ctrl,
+        ) {
+            Ok((pair, timeframe, streamed)) => {
                 summary.successful += 1;
+                summary.late_ticks += streamed.late_ticks;
 
                 if !summary.pairs_updated.contains(&pair) {
                     summary.pairs_updated.push(pair.clone());
@@ -83,15 +129,24 @@ pub async fn import_pair_data(
                     file_idx + 1,
                     summary.total_files,
                     path,
-                    row_count
+                    streamed.rows
                 );
             }
+            Err(e) if e == IMPORT_CANCELLED => {
+                info!("⏹ Import annulé pendant: {}", path);
+                ctrl.stage(ImportStage::Cancelled);
+                summary.failed += 1;
+                summary.errors.push(format!("{}: {}", file_name, e));
+                break;
+            }
+            Err(e) if e.starts_with(ALREADY_IMPORTED) => {
+                info!("⏸ Déjà importé, mode de ré-import attendu : {}", path);
+                summary.failed += 1;
+                summary.errors.push(format!("{}: {}", file_name, e));
+                summary.already_imported.push(path.clone());
+            }
             Err(e) => {
                 summary.failed += 1;
-                let file_name = Path::new(&path)
-                    .file_name()
-                    .and_then(|n| n.to_str())
-                    .unwrap_or("unknown");
                 let error_msg = format!("{}: {}", file_name, e);
                 summary.errors.push(error_msg);
             }
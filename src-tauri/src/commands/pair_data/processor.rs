@@ -1,21 +1,35 @@
+use crate::commands::candle_db_writer;
 use crate::db::DbPool;
+use crate::models::import_session::ReimportMode;
+use crate::services::import_stream::{ImportControl, ImportStage};
+use crate::services::pair_data::StreamedRows;
+use crate::services::source_timezone::SourceTimezone;
+use crate::services::staged_import::StagedImport;
 use crate::services::PairDataConverter;
-use chrono::Utc;
 use std::path::Path;
-use tracing::info;
+use tracing::{info, warn};
 
+/// `source_timezone` : fuseau des horodatages du fichier (None = défaut du format).
+/// `reimport_mode` : remplacer ou fusionner si le fichier a déjà été importé.
+/// Les CSV sont lus en continu : lots en zone de transit puis publication en une
+/// transaction ; `ctrl` remonte la progression et permet d'annuler sans import partiel.
 pub fn process_single_file(
     source_path: &str,
     _pool: &DbPool,
-) -> Result<(String, String, usize), String> {
-    info!("🔄 Normalisation: {}", source_path);
-    let candles = PairDataConverter::read_and_normalize(source_path)?;
-
-    if candles.is_empty() {
-        return Err("Aucune donnée valide trouvée".to_string());
+    source_timezone: Option<&SourceTimezone>,
+    reimport_mode: Option<ReimportMode>,
+    ctrl: &mut ImportControl,
+) -> Result<(String, String, StreamedRows), String> {
+    if is_parquet(source_path) {
+        return process_parquet_file(source_path, reimport_mode);
     }
 
-    let row_count = candles.len();
+    let known_timezone = PairDataConverter::known_source_timezone(source_path, source_timezone)?;
+    let timezone = known_timezone.unwrap_or_default();
+    info!(
+        "🔄 Normalisation: {} (fuseau source: {})",
+        source_path, timezone
+    );
 
     let filename = Path::new(source_path)
         .file_name()
@@ -23,145 +37,124 @@ pub fn process_single_file(
         .to_str()
         .ok_or("Nom de fichier non-UTF8")?;
 
-    info!("📊 Extraction métadonnées de: {}", filename);
-    let metadata = PairDataConverter::extract_metadata(&candles, filename)?;
-
-    info!("   Paire: {}", metadata.pair);
-    info!("   Timeframe: {}", metadata.timeframe);
+    let conn = candle_db_writer::open_pairs_db()?;
+
+    // Paire et timeframe déduits du premier lot (trié) ; zone de transit vidée si l'import échoue
+    let mut staged: Option<StagedImport> = None;
+    let streamed =
+        PairDataConverter::stream_normalized(source_path, Some(&timezone), ctrl, &mut |batch| {
+            let import = if let Some(import) = staged.as_mut() {
+                import
+            } else {
+                info!("📊 Extraction métadonnées de: {}", filename);
+                let metadata = PairDataConverter::extract_metadata(&batch, filename)?;
+                info!("   Paire: {}", metadata.pair);
+                info!("   Timeframe: {}", metadata.timeframe);
+                staged.insert(StagedImport::begin(
+                    &conn,
+                    &metadata.pair,
+                    &metadata.timeframe,
+                    filename,
+                    reimport_mode,
+                )?)
+            };
+            import.stage_normalized(&batch)
+        })?;
+    let staged = staged.ok_or("Aucune donnée valide trouvée")?;
+    let row_count = streamed.rows;
+    let (pair, timeframe) = (staged.symbol().to_string(), staged.timeframe().to_string());
+
+    if let Some((first, last)) = staged.range() {
+        info!(
+            "   Période: {} → {} ({} candles)",
+            first.format("%Y-%m-%d"),
+            last.format("%Y-%m-%d"),
+            row_count
+        );
+    }
     info!(
-        "   Période: {} → {} ({} candles)",
-        metadata.start_date, metadata.end_date, row_count
+        "💾 Publication en BD: {}/{} ({} lignes)",
+        pair, timeframe, row_count
     );
 
-    let db_path = dirs::data_local_dir()
-        .ok_or("Failed to get data directory")?
-        .join("volatility-analyzer")
-        .join("pairs.db");
-
-    let mut conn = rusqlite::Connection::open(&db_path)
-        .map_err(|e| format!("Failed to open pairs.db: {}", e))?;
-
-    // Set busy timeout and WAL mode
-    conn.busy_timeout(std::time::Duration::from_millis(5000))
-        .map_err(|e| format!("Failed to set busy_timeout: {}", e))?;
-    conn.pragma_update(None, "journal_mode", "WAL")
-        .map_err(|e| format!("Failed to set WAL mode: {}", e))?;
-    conn.pragma_update(None, "synchronous", "NORMAL")
-        .map_err(|e| format!("Failed to set synchronous mode: {}", e))?;
-
-    let imported_at = Utc::now().to_rfc3339();
+    // Un CSV de bougies n'a qu'une série OHLC : les colonnes bid/ask d'un import tick antérieur
+    // sont effacées (les exports tick HistData/MT5 apportent les leurs)
+    ctrl.stage(ImportStage::Committing);
+    // Fuseau inconnu (NULL) si le fichier a seulement été supposé en UTC
+    let known_timezone = known_timezone.map(|tz| tz.to_string());
+    staged.commit(known_timezone.as_deref())?;
+    ctrl.stage(ImportStage::Done);
 
+    // Conservation du fichier source (Modification demandée : ne pas supprimer)
+    info!("✅ Fichier source conservé: {}", source_path);
+    // fs::remove_file(source_path).map_err(...) // Suppression désactivée
     info!(
-        "💾 Insertion en BD: {}/{} ({} lignes)",
-        metadata.pair, metadata.timeframe, row_count
+        "🎉 Import réussi: {}/{} ({} candles)",
+        pair, timeframe, row_count
     );
 
-    let tx = conn
-        .transaction()
-        .map_err(|e| format!("Transaction begin error: {}", e))?;
-
-    let mut stmt = tx
-        .prepare(
-            "INSERT INTO candle_data (symbol, timeframe, time, open, high, low, close, volume, imported_at, source_file)
-             VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
-             ON CONFLICT(symbol, timeframe, time) DO UPDATE SET
-                open = excluded.open,
-                high = excluded.high,
-                low = excluded.low,
-                close = excluded.close,
-                volume = excluded.volume,
-                imported_at = excluded.imported_at,
-                source_file = excluded.source_file"
-        )
-        .map_err(|e| format!("Prepare error: {}", e))?;
-
-    info!("📋 Prepared INSERT statement for candle_data (with UPSERT)");
+    Ok((pair, timeframe, streamed))
+}
 
-    for (idx, candle) in candles.iter().enumerate() {
-        let dt = chrono::DateTime::<Utc>::from_timestamp(candle.timestamp, 0)
-            .ok_or(format!("Invalid timestamp: {}", candle.timestamp))?;
-        let time_str = dt.to_rfc3339();
+fn is_parquet(source_path: &str) -> bool {
+    Path::new(source_path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .is_some_and(|e| e.eq_ignore_ascii_case("parquet"))
+}
 
-        stmt.execute(rusqlite::params![
-            &metadata.pair,
-            &metadata.timeframe,
-            &time_str,
-            candle.open,
-            candle.high,
-            candle.low,
-            candle.close,
-            candle.volume,
-            &imported_at,
-            filename,
-        ])
-        .map_err(|e| format!("INSERT candle_data error at row {}: {}", idx, e))?;
+/// Import d'un export Parquet : paire et timeframe lus dans le fichier,
+/// colonnes spread/tick_count/bid/ask conservées.
+fn process_parquet_file(
+    source_path: &str,
+    reimport_mode: Option<ReimportMode>,
+) -> Result<(String, String, StreamedRows), String> {
+    use crate::services::parquet_io;
 
-        if idx % 50000 == 0 && idx > 0 {
-            info!("  ✓ {} candles processed", idx);
-        }
+    info!("🔄 Lecture Parquet: {}", source_path);
+    let parquet = parquet_io::importer_parquet(Path::new(source_path))?;
+    if parquet.candles.is_empty() {
+        return Err("Aucune donnée valide trouvée".to_string());
+    }
+    if parquet.rejected_rows > 0 {
+        warn!(
+            "⚠️ {} lignes Parquet ignorées (horodatage/OHLC absent ou incohérent)",
+            parquet.rejected_rows
+        );
     }
 
-    drop(stmt);
-
-    info!("✅ {} candles traités (insérés ou mis à jour)", row_count);
-
-    // Recalculer le nombre réel de lignes pour cette paire/timeframe pour garantir l'exactitude
-    let actual_count: i64 = tx.query_row(
-        "SELECT COUNT(*) FROM candle_data WHERE symbol = ? AND timeframe = ?",
-        rusqlite::params![&metadata.pair, &metadata.timeframe],
-        |row| row.get(0),
-    ).map_err(|e| format!("COUNT error: {}", e))?;
-
-    tx.execute(
-        "INSERT INTO pair_metadata (symbol, timeframe, row_count, last_updated, last_imported_file)
-         VALUES (?, ?, ?, ?, ?)
-         ON CONFLICT(symbol, timeframe) DO UPDATE SET
-            row_count = excluded.row_count,
-            last_updated = excluded.last_updated,
-            last_imported_file = excluded.last_imported_file",
-        rusqlite::params![
-            &metadata.pair,
-            &metadata.timeframe,
-            actual_count,
-            &imported_at,
-            filename,
-        ],
-    )
-    .map_err(|e| format!("UPDATE pair_metadata error: {}", e))?;
-
-    info!("✅ Métadonnées mises à jour");
-
-    info!("📋 INSERT import_log entry");
-    tx.execute(
-        "INSERT INTO import_log (filename, symbol, timeframe, row_count, expected_row_count, status, imported_at)
-         VALUES (?, ?, ?, ?, ?, 'success', ?)",
-        rusqlite::params![
-            filename,
-            &metadata.pair,
-            &metadata.timeframe,
-            row_count as i32,
-            row_count as i32,
-            &imported_at,
-        ]
-    )
-    .map_err(|e| format!("INSERT import_log error: {}", e))?;
-
-    info!("✅ Import loggé");
-
-    tx.commit()
-        .map_err(|e| format!("Transaction commit error: {}", e))?;
-
-    // REINDEX après import pour éviter la corruption d'index (rusqlite/Diesel dual-access)
-    conn.execute_batch("REINDEX;")
-        .map_err(|e| format!("REINDEX error: {}", e))?;
+    let filename = Path::new(source_path)
+        .file_name()
+        .and_then(|n| n.to_str())
+        .ok_or("Nom de fichier invalide")?;
 
-    // Conservation du fichier source (Modification demandée : ne pas supprimer)
-    info!("✅ Fichier source conservé: {}", source_path);
-    // fs::remove_file(source_path).map_err(...) // Suppression désactivée
+    info!(
+        "💾 Insertion en BD: {}/{} ({} lignes)",
+        parquet.symbol,
+        parquet.timeframe,
+        parquet.candles.len()
+    );
+    let conn = candle_db_writer::open_pairs_db()?;
+    let mut staged = StagedImport::begin(
+        &conn,
+        &parquet.symbol,
+        &parquet.timeframe,
+        filename,
+        reimport_mode,
+    )?;
+    staged.stage_candles(&parquet.candles)?;
+    let staged_symbol = staged.symbol().to_string();
+    // Colonne time Parquet toujours en UTC
+    let (saved, _) = staged.commit(Some("UTC"))?;
+
+    let pair = staged_symbol;
     info!(
         "🎉 Import réussi: {}/{} ({} candles)",
-        metadata.pair, metadata.timeframe, row_count
+        pair, parquet.timeframe, saved.candles_saved
     );
-
-    Ok((metadata.pair, metadata.timeframe, row_count))
+    let streamed = StreamedRows {
+        rows: saved.candles_saved,
+        late_ticks: 0,
+    };
+    Ok((pair, parquet.timeframe, streamed))
 }
@@ -0,0 +1,122 @@
+// commands/parquet_commands.rs
+// Export Parquet d'une paire/timeframe/plage de dates depuis candle_data.
+// L'import Parquet passe par import_pair_data (fichiers .parquet).
+
+use crate::commands::pair_data::PairDataState;
+use crate::services::parquet_io;
+use chrono::{NaiveDate, TimeZone, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tauri::State;
+use tracing::info;
+
+/// Résultat de l'export
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParquetExportResult {
+    pub symbol: String,
+    pub timeframe: String,
+    pub rows_exported: usize,
+    pub output_path: String,
+}
+
+/// Exporte `symbol`/`timeframe` vers `output_path` (.parquet).
+/// `date_from`/`date_to` (YYYY-MM-DD, inclus) bornent la plage ; une borne absente
+/// est remplacée par la première/dernière bougie stockée (M1 pour un timeframe dérivé non persisté).
+#[tauri::command]
+pub async fn export_candles_parquet(
+    symbol: String,
+    timeframe: String,
+    date_from: Option<String>,
+    date_to: Option<String>,
+    output_path: String,
+    pair_state: State<'_, PairDataState>,
+) -> Result<ParquetExportResult, String> {
+    use crate::services::candle_loader;
+    use crate::services::database_loader::DatabaseLoader;
+
+    let parse = |date: &Option<String>, label: &str| -> Result<Option<NaiveDate>, String> {
+        date.as_deref()
+            .map(|d| {
+                NaiveDate::parse_from_str(d, "%Y-%m-%d")
+                    .map_err(|e| format!("Date {} invalide '{}' : {}", label, d, e))
+            })
+            .transpose()
+    };
+    let from = parse(&date_from, "début")?;
+    let to = parse(&date_to, "fin")?;
+    if let (Some(from), Some(to)) = (from, to) {
+        if from > to {
+            return Err("La date de début doit être avant la date de fin".into());
+        }
+    }
+
+    let pairs_pool = pair_state
+        .pool
+        .lock()
+        .map_err(|e| format!("Failed to lock pair pool: {}", e))?
+        .clone()
+        .ok_or("Pair database pool not initialized")?;
+    let loader = DatabaseLoader::new(pairs_pool);
+
+    let stored_range = if from.is_none() || to.is_none() {
+        let range = match loader
+            .get_date_range(&symbol, &timeframe)
+            .map_err(|e| e.to_string())?
+        {
+            Some(range) => Some(range),
+            None => loader
+                .get_date_range(&symbol, "M1")
+                .map_err(|e| e.to_string())?,
+        };
+        Some(range.ok_or_else(|| format!("Aucun candle {} {} en base", symbol, timeframe))?)
+    } else {
+        None
+    };
+
+    let start = match from {
+        Some(d) => d
+            .and_hms_opt(0, 0, 0)
+            .map(|d| Utc.from_utc_datetime(&d))
+            .ok_or("Date de début invalide")?,
+        None => stored_range
+            .map(|(first, _)| first)
+            .ok_or("Date de début invalide")?,
+    };
+    let end = match to {
+        Some(d) => d
+            .and_hms_opt(23, 59, 59)
+            .map(|d| Utc.from_utc_datetime(&d))
+            .ok_or("Date de fin invalide")?,
+        None => stored_range
+            .map(|(_, last)| last)
+            .ok_or("Date de fin invalide")?,
+    };
+
+    let candles = candle_loader::load_pair_candles_in_range_strategy(
+        Some(&loader),
+        &symbol,
+        &timeframe,
+        start,
+        end,
+    )?;
+    if candles.is_empty() {
+        return Err(format!(
+            "Aucun candle {} {} sur la période demandée",
+            symbol, timeframe
+        ));
+    }
+
+    let rows_exported =
+        parquet_io::exporter_parquet(Path::new(&output_path), &symbol, &timeframe, &candles)?;
+    info!(
+        "📦 Export Parquet {}/{} : {} lignes → {}",
+        symbol, timeframe, rows_exported, output_path
+    );
+
+    Ok(ParquetExportResult {
+        symbol,
+        timeframe,
+        rows_exported,
+        output_path,
+    })
+}
@@ -7,6 +7,7 @@ use chrono::Timelike;
 pub async fn analyze_peak_delay(
     pair: String,
     event_type: String,
+    bar_size: Option<crate::models::release_seconds::SecondBarSize>,
     state: tauri::State<'_, crate::commands::calendar_commands::CalendarState>,
 ) -> Result<crate::commands::retrospective_analysis::types::PeakDelayResult, String> {
     let (conn, loader) = setup_databases(&state).await?;
@@ -20,6 +21,13 @@ pub async fn analyze_peak_delay(
 
     let avg_delay = (peak_delays.iter().sum::<i16>() as f64 / peak_delays.len() as f64) as i16;
     let avg_peak_atr = peak_atrs.iter().sum::<f64>() / peak_atrs.len() as f64;
+    let release_seconds = RetroAnalysisService::calculer_delai_pic_secondes(
+        &pair,
+        &events,
+        &loader,
+        bar_size.unwrap_or_default(),
+    )
+    .await;
 
     Ok(
         crate::commands::retrospective_analysis::types::PeakDelayResult {
@@ -41,6 +49,7 @@ pub async fn analyze_peak_delay(
                 .last()
                 .map(|e| e.event_time.to_string())
                 .unwrap_or_default(),
+            release_seconds,
         },
     )
 }
@@ -110,6 +119,170 @@ pub async fn analyze_volatility_profile(
     RetroAnalysisService::calculer_impact_evenement(&pair, &event_type, &events, &loader).await
 }
 
+/// Étude d'événement découpée en grandes/petites surprises positives/négatives.
+/// Score = (actual - forecast) / écart-type des surprises de l'annonce.
+#[tauri::command]
+pub async fn analyze_surprise_buckets(
+    pair: String,
+    event_type: String,
+    horizon_minutes: Option<u32>,
+    big_surprise_score: Option<f64>,
+    state: tauri::State<'_, crate::commands::calendar_commands::CalendarState>,
+) -> Result<crate::models::surprise_analysis::SurpriseAnalysisResult, String> {
+    use crate::services::surprise_analyzer::{DEFAULT_BIG_SURPRISE_SCORE, DEFAULT_HORIZON_MINUTES};
+
+    let horizon = horizon_minutes.unwrap_or(DEFAULT_HORIZON_MINUTES);
+    let big_score = big_surprise_score.unwrap_or(DEFAULT_BIG_SURPRISE_SCORE);
+    if horizon == 0 || big_score <= 0.0 {
+        return Err("horizon_minutes et big_surprise_score doivent être positifs".into());
+    }
+
+    let (conn, loader) = setup_databases(&state).await?;
+    let events = super::helpers::load_events_by_type(conn, &event_type).await?;
+    if events.is_empty() {
+        return Err(format!("No events: {}", event_type));
+    }
+
+    RetroAnalysisService::calculer_analyse_surprise(
+        &pair,
+        &event_type,
+        &events,
+        &loader,
+        horizon,
+        big_score,
+    )
+    .await
+}
+
+/// Sens réalisé à 1 / 5 / 15 min selon le signe de la surprise et la position de la
+/// devise dans la paire, sur toutes les annonces de la devise de base et de cotation
+#[tauri::command]
+pub async fn analyze_directional_bias(
+    pair: String,
+    calendar_id: Option<i32>,
+    min_occurrences: Option<usize>,
+    state: tauri::State<'_, crate::commands::calendar_commands::CalendarState>,
+) -> Result<crate::models::directional_bias::DirectionalBiasResult, String> {
+    use crate::services::directional_bias::{pair_currencies, DEFAULT_MIN_OCCURRENCES};
+
+    let (base, quote) = pair_currencies(&pair);
+    let currencies: Vec<String> = base.iter().cloned().chain([quote.clone()]).collect();
+
+    let (conn, loader) = setup_databases(&state).await?;
+    let events =
+        super::helpers::load_events_for_currencies(conn, &currencies, calendar_id, true).await?;
+    if events.is_empty() {
+        return Err(format!(
+            "No events with actual/forecast for {}",
+            currencies.join("/")
+        ));
+    }
+
+    RetroAnalysisService::calculer_biais_directionnel(
+        &pair,
+        base,
+        quote,
+        &events,
+        &loader,
+        min_occurrences.unwrap_or(DEFAULT_MIN_OCCURRENCES),
+    )
+    .await
+}
+
+/// Dérive, compression d'ATR et élargissement du spread de T-60 à T-5 pour une annonce
+#[tauri::command]
+pub async fn analyze_pre_release_drift(
+    pair: String,
+    event_type: String,
+    state: tauri::State<'_, crate::commands::calendar_commands::CalendarState>,
+) -> Result<crate::models::pre_release::PreReleaseProfile, String> {
+    let (conn, loader) = setup_databases(&state).await?;
+    let events = super::helpers::load_events_by_type(conn, &event_type).await?;
+    if events.is_empty() {
+        return Err(format!("No events: {}", event_type));
+    }
+
+    RetroAnalysisService::calculer_pre_annonce(&pair, &event_type, &events, &loader).await
+}
+
+/// Annonces des devises de la paire dont le mouvement tend à commencer avant T0
+#[tauri::command]
+pub async fn scan_pre_release_drift(
+    pair: String,
+    calendar_id: Option<i32>,
+    min_occurrences: Option<usize>,
+    state: tauri::State<'_, crate::commands::calendar_commands::CalendarState>,
+) -> Result<crate::models::pre_release::PreReleaseScanResult, String> {
+    use crate::services::directional_bias::{pair_currencies, DEFAULT_MIN_OCCURRENCES};
+
+    let (base, quote) = pair_currencies(&pair);
+    let currencies: Vec<String> = base.into_iter().chain([quote]).collect();
+
+    let (conn, loader) = setup_databases(&state).await?;
+    let events =
+        super::helpers::load_events_for_currencies(conn, &currencies, calendar_id, false).await?;
+    if events.is_empty() {
+        return Err(format!("No events for {}", currencies.join("/")));
+    }
+
+    RetroAnalysisService::balayer_pre_annonce(
+        &pair,
+        &events,
+        &loader,
+        min_occurrences.unwrap_or(DEFAULT_MIN_OCCURRENCES),
+    )
+    .await
+}
+
+/// MFE/MAE des deux jambes du straddle par horizon, SL/TP tirés des percentiles
+#[tauri::command]
+pub async fn analyze_excursions(
+    pair: String,
+    event_type: String,
+    horizons_minutes: Option<Vec<u32>>,
+    straddle_distance_pips: Option<f64>,
+    sl_percentile: Option<f64>,
+    tp_percentile: Option<f64>,
+    state: tauri::State<'_, crate::commands::calendar_commands::CalendarState>,
+) -> Result<crate::models::excursion::ExcursionStudyResult, String> {
+    use crate::services::excursion_analyzer::{
+        DEFAULT_HORIZONS_MINUTES, DEFAULT_SL_PERCENTILE, DEFAULT_STRADDLE_DISTANCE_PIPS,
+        DEFAULT_TP_PERCENTILE,
+    };
+
+    let mut horizons = horizons_minutes.unwrap_or_else(|| DEFAULT_HORIZONS_MINUTES.to_vec());
+    horizons.retain(|h| *h > 0);
+    horizons.sort_unstable();
+    horizons.dedup();
+    if horizons.is_empty() {
+        return Err("horizons_minutes doit contenir au moins un horizon positif".into());
+    }
+    let distance = straddle_distance_pips.unwrap_or(DEFAULT_STRADDLE_DISTANCE_PIPS);
+    let sl_pct = sl_percentile.unwrap_or(DEFAULT_SL_PERCENTILE);
+    let tp_pct = tp_percentile.unwrap_or(DEFAULT_TP_PERCENTILE);
+    if distance < 0.0 || !(0.0..=100.0).contains(&sl_pct) || !(0.0..=100.0).contains(&tp_pct) {
+        return Err("Distance positive et percentiles entre 0 et 100 requis".into());
+    }
+
+    let (conn, loader) = setup_databases(&state).await?;
+    let events = super::helpers::load_events_by_type(conn, &event_type).await?;
+    if events.is_empty() {
+        return Err(format!("No events: {}", event_type));
+    }
+
+    RetroAnalysisService::calculer_excursions(
+        &pair,
+        &event_type,
+        &events,
+        &loader,
+        &horizons,
+        distance,
+        sl_pct,
+        tp_pct,
+    )
+    .await
+}
+
 #[tauri::command]
 pub async fn get_event_types(calendar_id: Option<i32>) -> Result<EventTypeList, String> {
     let data_dir = dirs::data_local_dir().ok_or("No data dir")?;
@@ -0,0 +1,84 @@
+/// Analyseur du sens réalisé après annonce selon le signe de la surprise
+use crate::models::directional_bias::{DirectionalBiasResult, SurpriseSign};
+use crate::services::directional_bias::{
+    self, currency_side, mouvements_horizons, BiasObservation, BIAS_HORIZONS_MINUTES,
+};
+use crate::services::event_catalog;
+use crate::services::pair_data::get_asset_properties;
+use chrono::Duration;
+
+pub struct DirectionalBiasAnalyzer;
+
+impl DirectionalBiasAnalyzer {
+    pub async fn calculer(
+        pair: &str,
+        base: Option<String>,
+        quote: String,
+        events: &[crate::models::CalendarEvent],
+        loader: &crate::services::DatabaseLoader,
+        min_occurrences: usize,
+    ) -> Result<DirectionalBiasResult, String> {
+        let pip_value = get_asset_properties(pair).pip_value;
+        let horizon = BIAS_HORIZONS_MINUTES[BIAS_HORIZONS_MINUTES.len() - 1] as i64;
+        let event_ids = event_catalog::resolve_event_ids(
+            events
+                .iter()
+                .map(|e| (e.description.as_str(), e.symbol.as_str())),
+        );
+
+        let mut event_count = 0;
+        let mut observations = Vec::new();
+        for (event, event_id) in events.iter().zip(event_ids) {
+            let (Some(actual), Some(forecast)) = (event.actual, event.forecast) else {
+                continue;
+            };
+            // Chiffre conforme au consensus : pas de signe exploitable
+            let surprise = actual - forecast;
+            if surprise.abs() <= f64::EPSILON {
+                continue;
+            }
+            let Some(side) = currency_side(base.as_deref(), &quote, &event.symbol) else {
+                continue;
+            };
+            event_count += 1;
+
+            let t0 = event.event_time.and_utc();
+            let candles = loader
+                .load_candles_by_pair(pair, "M1", t0, t0 + Duration::minutes(horizon))
+                .unwrap_or_default();
+            let Some(moves) = mouvements_horizons(&candles, t0, pip_value) else {
+                continue;
+            };
+            observations.push(BiasObservation {
+                event_type: event_catalog::event_label(&event_id, &event.description),
+                event_id,
+                currency: event.symbol.clone(),
+                side,
+                has_base_currency: base.is_some(),
+                sign: if surprise > 0.0 {
+                    SurpriseSign::Positive
+                } else {
+                    SurpriseSign::Negative
+                },
+                moves,
+            });
+        }
+
+        if observations.is_empty() {
+            return Err(format!(
+                "No {} candles around {} surprise releases",
+                pair, event_count
+            ));
+        }
+
+        Ok(DirectionalBiasResult {
+            pair: pair.into(),
+            base_currency: base,
+            quote_currency: quote,
+            event_count,
+            analyzed_count: observations.len(),
+            overall: directional_bias::taux_global(&observations),
+            rows: directional_bias::tableau_biais(&observations, min_occurrences),
+        })
+    }
+}
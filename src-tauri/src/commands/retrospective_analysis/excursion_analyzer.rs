@@ -0,0 +1,69 @@
+/// Analyseur MFE/MAE : excursions des deux jambes du straddle par horizon
+use crate::models::excursion::{ExcursionStudyResult, OccurrenceExcursion};
+use crate::services::excursion_analyzer::{distributions_par_horizon, mesurer_excursions};
+use crate::services::pair_data::get_asset_properties;
+use chrono::Duration;
+
+pub struct ExcursionAnalyzer;
+
+impl ExcursionAnalyzer {
+    #[allow(clippy::too_many_arguments)]
+    pub async fn calculer(
+        pair: &str,
+        event_type: &str,
+        events: &[crate::models::CalendarEvent],
+        loader: &crate::services::DatabaseLoader,
+        horizons: &[u32],
+        straddle_distance_pips: f64,
+        sl_percentile: f64,
+        tp_percentile: f64,
+    ) -> Result<ExcursionStudyResult, String> {
+        let pip_value = get_asset_properties(pair).pip_value;
+        let max_horizon = horizons.iter().copied().max().unwrap_or(0) as i64;
+
+        let occurrences: Vec<OccurrenceExcursion> = events
+            .iter()
+            .filter_map(|event| {
+                let t0 = event.event_time.and_utc();
+                let candles = loader
+                    .load_candles_by_pair(
+                        pair,
+                        "M1",
+                        t0 - Duration::minutes(5),
+                        t0 + Duration::minutes(max_horizon),
+                    )
+                    .unwrap_or_default();
+                let horizons =
+                    mesurer_excursions(&candles, t0, horizons, straddle_distance_pips, pip_value)?;
+                Some(OccurrenceExcursion {
+                    event_time: t0.to_rfc3339(),
+                    horizons,
+                })
+            })
+            .collect();
+
+        if occurrences.is_empty() {
+            return Err(format!(
+                "No {} candles around {} releases",
+                pair, event_type
+            ));
+        }
+
+        Ok(ExcursionStudyResult {
+            pair: pair.into(),
+            event_type: event_type.into(),
+            straddle_distance_pips,
+            sl_percentile,
+            tp_percentile,
+            event_count: events.len(),
+            analyzed_count: occurrences.len(),
+            horizons: distributions_par_horizon(
+                &occurrences,
+                horizons,
+                sl_percentile,
+                tp_percentile,
+            ),
+            occurrences,
+        })
+    }
+}
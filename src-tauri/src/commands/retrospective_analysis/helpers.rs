@@ -5,6 +5,7 @@ use crate::schema::calendar_events::dsl::*;
 use diesel::prelude::*;
 use diesel::sqlite::SqliteConnection;
 use rusqlite;
+use std::collections::HashSet;
 
 pub async fn setup_databases(
     state: &tauri::State<'_, crate::commands::calendar_commands::CalendarState>,
@@ -35,12 +36,57 @@ pub async fn load_events_by_type(
     event_type_param: &str,
 ) -> Result<Vec<crate::models::CalendarEvent>, String> {
     use diesel::SelectableHelper;
-    calendar_events
-        .filter(description.eq(event_type_param))
-        .select(crate::models::CalendarEvent::as_select())
-        .order(event_time.asc())
+    // Le type reçu est un libellé d'analyse : toutes ses variantes d'intitulé sont chargées
+    let canonical_id = crate::services::event_catalog::event_id_for_label(event_type_param);
+    let rows = calendar_events
+        .filter(event_id.eq(canonical_id))
+        .select((crate::models::CalendarEvent::as_select(), event_id))
+        .order((event_time.asc(), calendar_import_id.desc()))
         .load(&mut conn)
-        .map_err(|e| format!("Load failed: {}", e))
+        .map_err(|e| format!("Load failed: {}", e))?;
+    Ok(dedupe_events(rows))
+}
+
+/// Événements des devises données ; `with_surprise` : seulement ceux publiés avec actual et forecast
+pub async fn load_events_for_currencies(
+    mut conn: diesel::r2d2::PooledConnection<diesel::r2d2::ConnectionManager<SqliteConnection>>,
+    currencies: &[String],
+    calendar_id: Option<i32>,
+    with_surprise: bool,
+) -> Result<Vec<crate::models::CalendarEvent>, String> {
+    use diesel::SelectableHelper;
+    let mut query = calendar_events
+        .filter(symbol.eq_any(currencies))
+        .into_boxed();
+    if with_surprise {
+        query = query
+            .filter(actual.is_not_null())
+            .filter(forecast.is_not_null());
+    }
+    if let Some(cal_id) = calendar_id {
+        query = query.filter(calendar_import_id.eq(cal_id));
+    }
+    let rows = query
+        .select((crate::models::CalendarEvent::as_select(), event_id))
+        .order((event_time.asc(), calendar_import_id.desc()))
+        .load(&mut conn)
+        .map_err(|e| format!("Load failed: {}", e))?;
+    Ok(dedupe_events(rows))
+}
+
+/// Une annonce présente dans plusieurs calendriers importés n'est gardée qu'une fois
+/// (même devise, même heure, même event_id) : la ligne de l'import le plus récent, lue en premier
+fn dedupe_events(
+    rows: Vec<(crate::models::CalendarEvent, Option<String>)>,
+) -> Vec<crate::models::CalendarEvent> {
+    let mut seen = HashSet::new();
+    rows.into_iter()
+        .filter_map(|(event, key)| {
+            let key = key.unwrap_or_else(|| event.description.clone());
+            seen.insert((event.symbol.clone(), event.event_time, key))
+                .then_some(event)
+        })
+        .collect()
 }
 
 /// DÉPRÉCIÉ : utiliser crate::services::atr::calculate_true_range() à la place
@@ -59,6 +105,7 @@ pub fn calculer_true_range(high: f64, low: f64, prev_close: f64) -> f64 {
         .max((low - prev_close).abs())
 }
 
+/// Types d'événements (libellé canonique, occurrences), regroupés par annonce
 pub fn get_event_types_from_db(
     db_path: &std::path::PathBuf,
     calendar_id: Option<i32>,
@@ -66,17 +113,24 @@ pub fn get_event_types_from_db(
     let conn = rusqlite::Connection::open(db_path).map_err(|e| format!("Open: {}", e))?;
     let query = if let Some(cal_id) = calendar_id {
         format!(
-            "SELECT description, COUNT(*) FROM calendar_events WHERE calendar_import_id = {} GROUP BY description ORDER BY COUNT(*) DESC",
+            "SELECT COALESCE(event_id, description) AS event_key, MIN(description), COUNT(*) FROM calendar_events WHERE calendar_import_id = {} GROUP BY event_key ORDER BY COUNT(*) DESC",
             cal_id
         )
     } else {
-        "SELECT description, COUNT(*) FROM calendar_events GROUP BY description ORDER BY COUNT(*) DESC"
+        "SELECT COALESCE(event_id, description) AS event_key, MIN(description), COUNT(*) FROM calendar_events GROUP BY event_key ORDER BY COUNT(*) DESC"
             .to_string()
     };
 
     let mut stmt = conn.prepare(&query).map_err(|e| format!("Prep: {}", e))?;
     let types: Vec<(String, usize)> = stmt
-        .query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .query_map([], |row| {
+            let key: String = row.get(0)?;
+            let first_description: String = row.get(1)?;
+            Ok((
+                crate::services::event_catalog::event_label(&key, &first_description),
+                row.get(2)?,
+            ))
+        })
         .map_err(|e| format!("Query: {}", e))?
         .collect::<Result<Vec<_>, _>>()
         .map_err(|e| format!("Collect: {}", e))?;
@@ -105,3 +159,45 @@ pub fn calculate_avg_timestamp(events: &[crate::models::CalendarEvent], event_co
         0
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn event(
+        row_id: i32,
+        currency: &str,
+        hour: u32,
+        import_id: i32,
+    ) -> crate::models::CalendarEvent {
+        let at = NaiveDate::from_ymd_opt(2025, 1, 10)
+            .and_then(|d| d.and_hms_opt(hour, 30, 0))
+            .unwrap_or_default();
+        crate::models::CalendarEvent {
+            id: row_id,
+            symbol: currency.to_string(),
+            event_time: at,
+            impact: "H".to_string(),
+            description: "Non-Farm Employment Change".to_string(),
+            actual: None,
+            forecast: None,
+            previous: None,
+            created_at: at,
+            calendar_import_id: import_id,
+        }
+    }
+
+    #[test]
+    fn test_annonce_importee_deux_fois_comptee_une_fois() {
+        let nfp = Some("usd_nfp".to_string());
+        let events = dedupe_events(vec![
+            (event(2, "USD", 13, 2), nfp.clone()),
+            (event(1, "USD", 13, 1), nfp.clone()),
+            (event(3, "CAD", 13, 2), None),
+            (event(4, "USD", 15, 1), nfp),
+        ]);
+        let ids: Vec<i32> = events.iter().map(|e| e.id).collect();
+        assert_eq!(ids, vec![2, 3, 4]);
+    }
+}
@@ -1,11 +1,17 @@
 pub mod commands;
+pub mod directional_bias_analyzer;
+pub mod excursion_analyzer;
 pub mod helpers;
 pub mod impact_analyzer;
 pub mod impact_data_processor;
+pub mod pre_release_analyzer;
 pub mod services;
 pub mod simple_analyzers;
+pub mod surprise_analyzer;
 pub mod types;
 
 pub use commands::{
-    analyze_decay_profile, analyze_peak_delay, analyze_volatility_profile, get_event_types,
+    analyze_decay_profile, analyze_directional_bias, analyze_excursions, analyze_peak_delay,
+    analyze_pre_release_drift, analyze_surprise_buckets, analyze_volatility_profile,
+    get_event_types, scan_pre_release_drift,
 };
@@ -0,0 +1,132 @@
+/// Analyseur pré-annonce : dérive, compression d'ATR et spread de T-60 à T-5
+use crate::models::pre_release::{PreReleaseEventRow, PreReleaseProfile, PreReleaseScanResult};
+use crate::services::event_catalog;
+use crate::services::pair_data::get_asset_properties;
+use crate::services::pre_release_analyzer::{
+    heures_reference, mesurer_fenetre, profil_pre_annonce, PreReleaseObservation,
+    POST_HORIZON_MINUTES, PRE_WINDOW_START_MINUTES,
+};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::{BTreeMap, HashSet};
+
+pub struct PreReleaseAnalyzer;
+
+impl PreReleaseAnalyzer {
+    /// Profil pré-annonce des occurrences d'une annonce
+    pub async fn calculer(
+        pair: &str,
+        event_type: &str,
+        events: &[crate::models::CalendarEvent],
+        loader: &crate::services::DatabaseLoader,
+    ) -> Result<PreReleaseProfile, String> {
+        let pip_value = get_asset_properties(pair).pip_value;
+        let releases: Vec<DateTime<Utc>> = events.iter().map(|e| e.event_time.and_utc()).collect();
+        let (observations, reference) =
+            Self::observer_avec_reference(pair, &releases, loader, pip_value);
+        profil_pre_annonce(&observations, &reference)
+            .ok_or_else(|| format!("No {} candles before {} releases", pair, event_type))
+    }
+
+    /// Profil de chaque annonce de la paire, annonces à mouvement anticipé en premier
+    pub async fn balayer(
+        pair: &str,
+        events: &[crate::models::CalendarEvent],
+        loader: &crate::services::DatabaseLoader,
+        min_occurrences: usize,
+    ) -> Result<PreReleaseScanResult, String> {
+        let pip_value = get_asset_properties(pair).pip_value;
+        let event_ids = event_catalog::resolve_event_ids(
+            events
+                .iter()
+                .map(|e| (e.description.as_str(), e.symbol.as_str())),
+        );
+        let mut groups: BTreeMap<String, Vec<&crate::models::CalendarEvent>> = BTreeMap::new();
+        for (event, event_id) in events.iter().zip(event_ids) {
+            groups.entry(event_id).or_default().push(event);
+        }
+
+        let mut rows = Vec::new();
+        for (event_id, members) in groups {
+            if members.len() < min_occurrences.max(1) {
+                continue;
+            }
+            let releases: Vec<DateTime<Utc>> =
+                members.iter().map(|e| e.event_time.and_utc()).collect();
+            let (observations, reference) =
+                Self::observer_avec_reference(pair, &releases, loader, pip_value);
+            if observations.len() < min_occurrences.max(1) {
+                continue;
+            }
+            let Some(profile) = profil_pre_annonce(&observations, &reference) else {
+                continue;
+            };
+            let first = members[0];
+            rows.push(PreReleaseEventRow {
+                event_type: event_catalog::event_label(&event_id, &first.description),
+                event_id,
+                currency: first.symbol.clone(),
+                profile,
+            });
+        }
+
+        if rows.is_empty() {
+            return Err(format!(
+                "No event type with {} occurrences and {} candles",
+                min_occurrences, pair
+            ));
+        }
+        rows.sort_by(|a, b| {
+            b.profile
+                .early_move
+                .cmp(&a.profile.early_move)
+                .then_with(|| {
+                    b.profile
+                        .drift_share
+                        .partial_cmp(&a.profile.drift_share)
+                        .unwrap_or(std::cmp::Ordering::Equal)
+                })
+        });
+
+        Ok(PreReleaseScanResult {
+            pair: pair.into(),
+            rows,
+        })
+    }
+
+    /// Mesures des occurrences et de la même fenêtre les jours précédents sans annonce
+    fn observer_avec_reference(
+        pair: &str,
+        releases: &[DateTime<Utc>],
+        loader: &crate::services::DatabaseLoader,
+        pip_value: f64,
+    ) -> (Vec<PreReleaseObservation>, Vec<PreReleaseObservation>) {
+        let event_days: HashSet<_> = releases.iter().map(|t| t.date_naive()).collect();
+        let observe = |times: &[DateTime<Utc>]| -> Vec<PreReleaseObservation> {
+            times
+                .iter()
+                .filter_map(|&t0| Self::observer(pair, t0, loader, pip_value))
+                .collect()
+        };
+        (
+            observe(releases),
+            observe(&heures_reference(releases, &event_days)),
+        )
+    }
+
+    fn observer(
+        pair: &str,
+        t0: DateTime<Utc>,
+        loader: &crate::services::DatabaseLoader,
+        pip_value: f64,
+    ) -> Option<PreReleaseObservation> {
+        let candles = loader
+            .load_candles_by_pair(
+                pair,
+                "M1",
+                t0 - Duration::minutes(PRE_WINDOW_START_MINUTES),
+                t0 + Duration::minutes(POST_HORIZON_MINUTES),
+            )
+            .unwrap_or_default();
+        mesurer_fenetre(&candles, t0, pip_value)
+    }
+}
@@ -1,5 +1,9 @@
 use super::impact_analyzer::ImpactAnalyzer;
+use super::directional_bias_analyzer::DirectionalBiasAnalyzer;
+use super::excursion_analyzer::ExcursionAnalyzer;
+use super::pre_release_analyzer::PreReleaseAnalyzer;
 use super::simple_analyzers::{DecayProfileAnalyzer, PeakDelayAnalyzer};
+use super::surprise_analyzer::SurpriseAnalyzer;
 
 pub struct RetroAnalysisService;
 
@@ -18,6 +22,15 @@ impl RetroAnalysisService {
         PeakDelayAnalyzer::calculer(pair, event_type, events, loader).await
     }
 
+    pub async fn calculer_delai_pic_secondes(
+        pair: &str,
+        events: &[crate::models::CalendarEvent],
+        loader: &crate::services::DatabaseLoader,
+        bar_size: crate::models::release_seconds::SecondBarSize,
+    ) -> Option<crate::models::release_seconds::ReleaseSecondsProfile> {
+        PeakDelayAnalyzer::calculer_secondes(pair, events, loader, bar_size).await
+    }
+
     pub async fn calculer_profil_decroissance(
         pair: &str,
         events: &[crate::models::CalendarEvent],
@@ -34,5 +47,76 @@ impl RetroAnalysisService {
     ) -> Result<super::types::EventImpactResult, String> {
         ImpactAnalyzer::calculer(pair, event_type, events, loader).await
     }
-}
 
+    pub async fn calculer_analyse_surprise(
+        pair: &str,
+        event_type: &str,
+        events: &[crate::models::CalendarEvent],
+        loader: &crate::services::DatabaseLoader,
+        horizon_minutes: u32,
+        big_surprise_score: f64,
+    ) -> Result<crate::models::surprise_analysis::SurpriseAnalysisResult, String> {
+        SurpriseAnalyzer::calculer(
+            pair,
+            event_type,
+            events,
+            loader,
+            horizon_minutes,
+            big_surprise_score,
+        )
+        .await
+    }
+
+    pub async fn calculer_biais_directionnel(
+        pair: &str,
+        base: Option<String>,
+        quote: String,
+        events: &[crate::models::CalendarEvent],
+        loader: &crate::services::DatabaseLoader,
+        min_occurrences: usize,
+    ) -> Result<crate::models::directional_bias::DirectionalBiasResult, String> {
+        DirectionalBiasAnalyzer::calculer(pair, base, quote, events, loader, min_occurrences).await
+    }
+
+    pub async fn calculer_pre_annonce(
+        pair: &str,
+        event_type: &str,
+        events: &[crate::models::CalendarEvent],
+        loader: &crate::services::DatabaseLoader,
+    ) -> Result<crate::models::pre_release::PreReleaseProfile, String> {
+        PreReleaseAnalyzer::calculer(pair, event_type, events, loader).await
+    }
+
+    pub async fn balayer_pre_annonce(
+        pair: &str,
+        events: &[crate::models::CalendarEvent],
+        loader: &crate::services::DatabaseLoader,
+        min_occurrences: usize,
+    ) -> Result<crate::models::pre_release::PreReleaseScanResult, String> {
+        PreReleaseAnalyzer::balayer(pair, events, loader, min_occurrences).await
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub async fn calculer_excursions(
+        pair: &str,
+        event_type: &str,
+        events: &[crate::models::CalendarEvent],
+        loader: &crate::services::DatabaseLoader,
+        horizons: &[u32],
+        straddle_distance_pips: f64,
+        sl_percentile: f64,
+        tp_percentile: f64,
+    ) -> Result<crate::models::excursion::ExcursionStudyResult, String> {
+        ExcursionAnalyzer::calculer(
+            pair,
+            event_type,
+            events,
+            loader,
+            horizons,
+            straddle_distance_pips,
+            sl_percentile,
+            tp_percentile,
+        )
+        .await
+    }
+}
@@ -1,6 +1,12 @@
 /// Analyseurs simples pour peak delay et decay profile
-use crate::services::VolatilityDurationAnalyzer;
+use crate::models::release_seconds::{ReleaseSecondsProfile, SecondBarSize};
+use crate::services::pair_data::get_asset_properties;
+use crate::services::release_seconds_analyzer::{
+    self, PRE_RELEASE_SECONDS, PROFILE_HORIZON_SECONDS,
+};
+use crate::services::{second_bars, VolatilityDurationAnalyzer};
 use chrono::{Duration, Timelike};
+use tracing::warn;
 
 pub struct PeakDelayAnalyzer;
 pub struct DecayProfileAnalyzer;
@@ -43,6 +49,30 @@ impl PeakDelayAnalyzer {
 
         Ok((peak_delays, peak_atrs))
     }
+
+    /// Pic et délais en secondes après l'annonce, sur barres construites depuis les ticks.
+    /// None si aucun tick n'est stocké autour des événements.
+    pub async fn calculer_secondes(
+        pair: &str,
+        events: &[crate::models::CalendarEvent],
+        loader: &crate::services::DatabaseLoader,
+        bar_size: SecondBarSize,
+    ) -> Option<ReleaseSecondsProfile> {
+        let releases: Vec<_> = events.iter().map(|e| e.event_time.and_utc()).collect();
+        let windows = second_bars::charger_fenetres(
+            loader,
+            pair,
+            &releases,
+            bar_size,
+            Duration::seconds(PRE_RELEASE_SECONDS),
+            Duration::seconds(PROFILE_HORIZON_SECONDS),
+        )
+        .map_err(|e| warn!("Ticks indisponibles pour {}: {}", pair, e))
+        .ok()?;
+
+        let pip_value = get_asset_properties(pair).pip_value;
+        release_seconds_analyzer::profil_annonce(&windows, bar_size, pip_value)
+    }
 }
 
 impl DecayProfileAnalyzer {
@@ -0,0 +1,91 @@
+/// Analyseur de la réaction par classe de surprise (actual vs forecast)
+use crate::models::surprise_analysis::{SurpriseAnalysisResult, SurpriseEventReaction};
+use crate::services::pair_data::get_asset_properties;
+use crate::services::surprise_analyzer::{
+    self, classer_surprise, mesurer_reaction, scores_surprise, PRE_EVENT_MINUTES,
+};
+use chrono::Duration;
+
+pub struct SurpriseAnalyzer;
+
+impl SurpriseAnalyzer {
+    pub async fn calculer(
+        pair: &str,
+        event_type: &str,
+        events: &[crate::models::CalendarEvent],
+        loader: &crate::services::DatabaseLoader,
+        horizon_minutes: u32,
+        big_surprise_score: f64,
+    ) -> Result<SurpriseAnalysisResult, String> {
+        // Scores sur l'historique antérieur de l'annonce, bougies disponibles ou non
+        let mut with_surprise: Vec<(&crate::models::CalendarEvent, f64, f64)> = events
+            .iter()
+            .filter_map(|e| match (e.actual, e.forecast) {
+                (Some(actual), Some(forecast)) => Some((e, actual, forecast)),
+                _ => None,
+            })
+            .collect();
+        with_surprise.sort_by_key(|(e, _, _)| e.event_time);
+        let surprises: Vec<f64> = with_surprise.iter().map(|(_, a, f)| a - f).collect();
+        let scores = scores_surprise(&surprises);
+        if scores.iter().all(Option::is_none) {
+            return Err(format!(
+                "Not enough actual/forecast history for {} ({} occurrences)",
+                event_type,
+                surprises.len()
+            ));
+        }
+
+        let pip_value = get_asset_properties(pair).pip_value;
+        let mut reactions = Vec::new();
+        for ((event, actual, forecast), score) in with_surprise.into_iter().zip(scores) {
+            // Chauffe : pas assez d'occurrences antérieures pour scorer
+            let Some(scored) = score else {
+                continue;
+            };
+            let t0 = event.event_time.and_utc();
+            let candles = loader
+                .load_candles_by_pair(
+                    pair,
+                    "M1",
+                    t0 - Duration::minutes(PRE_EVENT_MINUTES),
+                    t0 + Duration::minutes(horizon_minutes as i64),
+                )
+                .unwrap_or_default();
+            let Some(measured) = mesurer_reaction(&candles, t0, horizon_minutes, pip_value) else {
+                continue;
+            };
+            let surprise = actual - forecast;
+            reactions.push(SurpriseEventReaction {
+                event_time: t0.format("%Y-%m-%dT%H:%M:%SZ").to_string(),
+                actual,
+                forecast,
+                surprise,
+                surprise_score: scored.score,
+                surprise_std: scored.std,
+                bucket: classer_surprise(surprise, scored.score, big_surprise_score),
+                reaction_pips: measured.reaction_pips,
+                move_pips: measured.move_pips,
+                whipsaw: measured.whipsaw,
+            });
+        }
+
+        if reactions.is_empty() {
+            return Err(format!(
+                "No {} candles around {} releases",
+                pair, event_type
+            ));
+        }
+
+        Ok(SurpriseAnalysisResult {
+            event_type: event_type.into(),
+            pair: pair.into(),
+            horizon_minutes,
+            big_surprise_score,
+            event_count: events.len(),
+            analyzed_count: reactions.len(),
+            buckets: surprise_analyzer::statistiques_par_classe(&reactions),
+            reactions,
+        })
+    }
+}
@@ -1,3 +1,4 @@
+use crate::models::release_seconds::ReleaseSecondsProfile;
 use serde::{Deserialize, Serialize};
 
 /// Peak delay analysis result
@@ -9,9 +10,13 @@ pub struct PeakDelayResult {
     pub confidence: f64,
     pub event_count: usize,
     pub event_type: String,
+    /// Heuristique M1 ; préférer `release_seconds` quand des ticks sont stockés
     pub optimal_entry_seconds_before: i32,
     pub event_date_min: String, // ISO 8601: 1er événement analysé
     pub event_date_max: String, // ISO 8601: dernier événement analysé
+    /// Profil sub-minute (S1/S5/S10) après l'annonce. None sans ticks stockés.
+    #[serde(default)]
+    pub release_seconds: Option<ReleaseSecondsProfile>,
 }
 
 /// Decay profile analysis result
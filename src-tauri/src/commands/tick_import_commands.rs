@@ -1,8 +1,19 @@
 // commands/tick_import_commands.rs
 // Commande Tauri pour importer un fichier tick Dukascopy → M1 enrichies en BD.
+// Fichier lu en continu, lots en zone de transit puis publication en une transaction.
 
 use crate::commands::candle_db_writer;
+use crate::commands::import_control_commands::{self, ImportCancelState};
+use crate::models::import_session::ReimportMode;
+use crate::services::import_stream::{
+    ImportControl, ImportProgress, ImportStage, IMPORT_CANCELLED,
+};
+use crate::services::pair_data::symbol_aliases::canonical_symbol;
+use crate::services::source_timezone::SourceTimezone;
+use crate::services::staged_import::StagedImport;
 use crate::services::tick_aggregator;
+use crate::services::tick_filter::{TickFilterConfig, TickFilterStats};
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use tracing::info;
 
@@ -16,45 +27,105 @@ pub struct ImportTickResult {
     pub date_end: String,
     pub avg_spread: f64,
     pub avg_ticks_per_minute: f64,
+    /// Ticks bruts conservés dans tick_data (0 si stockage non demandé)
+    #[serde(default)]
+    pub ticks_stored: usize,
+    /// Ticks écartés avant agrégation (croisés, spread excessif, pics)
+    #[serde(default)]
+    pub rejected_ticks: TickFilterStats,
+    /// Ticks arrivés après la clôture de leur minute (fichier non trié), ignorés
+    #[serde(default)]
+    pub late_ticks: usize,
 }
 
 /// Importe un fichier tick Dukascopy (bid+ask) et stocke les M1 enrichies en BD.
+/// Lecture en continu (mémoire bornée) : progression émise sur "import-progress",
+/// annulable via `cancel_import` sans rien laisser en base.
+/// `store_ticks` conserve aussi les ticks bruts (analyse sub-minute sans ré-import).
+/// `tick_filter` règle le filtre des mauvais ticks (défauts si absent).
+/// `source_timezone` : fuseau des horodatages (IANA ou "broker NY-close", défaut EET).
+/// `reimport_mode` : remplacer ou fusionner si le fichier a déjà été importé.
 #[tauri::command]
-pub async fn import_tick_file(file_path: String) -> Result<ImportTickResult, String> {
+pub async fn import_tick_file(
+    app: tauri::AppHandle,
+    cancel: tauri::State<'_, ImportCancelState>,
+    file_path: String,
+    store_ticks: Option<bool>,
+    tick_filter: Option<TickFilterConfig>,
+    source_timezone: Option<String>,
+    reimport_mode: Option<ReimportMode>,
+) -> Result<ImportTickResult, String> {
     if file_path.is_empty() {
         return Err("Chemin de fichier vide".to_string());
     }
-
-    // 1. Agréger les ticks en M1 enrichies
-    let result = tick_aggregator::aggregate_ticks_to_m1(&file_path)?;
-
-    if result.candles.is_empty() {
-        return Err("Aucune bougie M1 générée à partir des ticks".to_string());
-    }
-
-    // 2. Sauvegarder en BD via le writer partagé
+    let timezone = SourceTimezone::parse_optional(source_timezone.as_deref())?
+        .unwrap_or_else(tick_aggregator::dukascopy_csv_timezone);
+    let symbol = canonical_symbol(&tick_aggregator::extract_symbol_from_filename(&file_path)?);
     let filename = std::path::Path::new(&file_path)
         .file_name()
         .and_then(|f| f.to_str())
         .unwrap_or("tick_import");
 
+    cancel.reset();
+    let total_bytes = std::fs::metadata(&file_path).map(|m| m.len()).unwrap_or(0);
+    let mut on_progress = |p: &ImportProgress| import_control_commands::emit_progress(&app, p);
+    let mut ctrl = ImportControl::new(filename, total_bytes, &cancel.cancelled, &mut on_progress);
+
+    // 1. Agréger les ticks en M1 enrichies, lots écrits en zone de transit
+    let store_ticks = store_ticks.unwrap_or(false);
     let conn = candle_db_writer::open_pairs_db()?;
-    candle_db_writer::save_enriched_candles(&conn, &result.symbol, &result.candles, filename)?;
+    let mut staged = StagedImport::begin(&conn, &symbol, "M1", filename, reimport_mode)?;
+    let streamed = tick_aggregator::stream_ticks_to_m1(
+        &file_path,
+        &tick_filter.unwrap_or_default(),
+        &timezone,
+        store_ticks,
+        &mut ctrl,
+        &mut |bars, ticks| {
+            staged.stage_enriched(bars)?;
+            staged.stage_ticks(ticks)
+        },
+    );
+    // En cas d'échec, la zone de transit est vidée au drop de `staged`
+    let summary = match streamed {
+        Ok(summary) => summary,
+        Err(e) => {
+            if e == IMPORT_CANCELLED {
+                info!("⏹ Import tick annulé : {}", filename);
+                ctrl.stage(ImportStage::Cancelled);
+            }
+            return Err(e);
+        }
+    };
+
+    if summary.minutes_generated == 0 {
+        return Err("Aucune bougie M1 générée à partir des ticks".to_string());
+    }
+
+    // 2. Publier en une transaction via le writer partagé
+    ctrl.stage(ImportStage::Committing);
+    let (_, ticks_stored) = staged.commit(Some(&timezone.to_string()))?;
+    ctrl.stage(ImportStage::Done);
 
     info!(
         "✅ Import tick terminé : {} M1 enrichies pour {} (spread moyen {:.5})",
-        result.candles.len(),
-        result.symbol,
-        result.avg_spread
+        summary.minutes_generated,
+        symbol,
+        summary.avg_spread()
     );
 
+    let format_date =
+        |date: Option<DateTime<Utc>>| date.map(|d| d.to_rfc3339()).unwrap_or_default();
     Ok(ImportTickResult {
-        symbol: result.symbol,
-        minutes_generated: result.candles.len(),
-        total_ticks: result.total_ticks,
-        date_start: result.date_start,
-        date_end: result.date_end,
-        avg_spread: result.avg_spread,
-        avg_ticks_per_minute: result.avg_ticks_per_minute,
+        symbol,
+        minutes_generated: summary.minutes_generated,
+        total_ticks: summary.total_ticks,
+        date_start: format_date(summary.date_start),
+        date_end: format_date(summary.date_end),
+        avg_spread: summary.avg_spread(),
+        avg_ticks_per_minute: summary.avg_ticks_per_minute(),
+        ticks_stored,
+        rejected_ticks: summary.rejected_ticks,
+        late_ticks: summary.late_ticks,
     })
 }
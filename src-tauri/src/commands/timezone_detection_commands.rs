@@ -0,0 +1,151 @@
+// commands/timezone_detection_commands.rs
+// Détection du fuseau source d'une paire importée (alignement des pics sur les annonces
+// à fort impact) et réécriture des horodatages en UTC.
+
+use crate::commands::calendar_commands::CalendarState;
+use crate::commands::retrospective_analysis::helpers::setup_databases;
+use crate::models::timezone_detection::{TimezoneCorrectionResult, TimezoneDetectionReport};
+use crate::services::source_timezone::SourceTimezone;
+use crate::services::timezone_detector;
+use chrono::{DateTime, Duration, Utc};
+use tauri::command;
+
+/// Marge de chargement autour des annonces : couvre les décalages UTC-12…UTC+14
+const LOAD_MARGIN_HOURS: i64 = 15;
+/// Annonces testées au plus (réparties sur tout l'historique) : borne la mémoire chargée
+const MAX_SAMPLED_EVENTS: usize = 200;
+
+/// Suggère le fuseau des horodatages de `symbol` en testant décalages fixes et variantes
+/// avec heure d'été sur les annonces à fort impact des devises de la paire.
+#[command]
+pub async fn detect_source_timezone(
+    symbol: String,
+    state: tauri::State<'_, CalendarState>,
+) -> Result<TimezoneDetectionReport, String> {
+    use crate::schema::calendar_events::dsl;
+    use diesel::prelude::*;
+
+    if symbol.is_empty() {
+        return Err("Symbole requis".to_string());
+    }
+    tracing::info!("🕒 Détection du fuseau source: {}", symbol);
+
+    let (mut conn, loader) = setup_databases(&state).await?;
+    let high_impact: Vec<(String, chrono::NaiveDateTime)> = dsl::calendar_events
+        .filter(dsl::impact.eq_any(["HIGH", "H"]))
+        .select((dsl::symbol, dsl::event_time))
+        .order(dsl::event_time.asc())
+        .load(&mut conn)
+        .map_err(|e| format!("Chargement des annonces échoué: {}", e))?;
+
+    // Annonces des devises de la paire (toutes si aucune ne correspond)
+    let pair = symbol.to_uppercase();
+    let currency_events: Vec<DateTime<Utc>> = high_impact
+        .iter()
+        .filter(|(currency, _)| !currency.is_empty() && pair.contains(&currency.to_uppercase()))
+        .map(|(_, time)| time.and_utc())
+        .collect();
+    let mut events = if currency_events.is_empty() {
+        high_impact.iter().map(|(_, time)| time.and_utc()).collect()
+    } else {
+        currency_events
+    };
+    events.dedup();
+
+    if events.is_empty() {
+        return Err("Aucune annonce à fort impact dans le calendrier".to_string());
+    }
+    let Some((data_start, data_end)) = loader
+        .get_date_range(&symbol, "M1")
+        .map_err(|e| format!("Chargement paire {} échoué: {}", symbol, e))?
+    else {
+        return Err(format!("Aucun candle M1 trouvé pour {}", symbol));
+    };
+
+    // Annonces couvertes par l'historique importé (avec marge de décalage), échantillonnées
+    let margin = Duration::hours(LOAD_MARGIN_HOURS);
+    events.retain(|e| *e >= data_start - margin && *e <= data_end + margin);
+    let events = echantillonner(&events, MAX_SAMPLED_EVENTS);
+
+    // Seules les fenêtres autour des annonces retenues sont chargées (fusionnées si elles se chevauchent)
+    let mut candles = Vec::new();
+    for (start, end) in fenetres(&events, margin) {
+        candles.extend(
+            loader
+                .load_candles_by_pair(&symbol, "M1", start, end)
+                .map_err(|e| format!("Chargement paire {} échoué: {}", symbol, e))?,
+        );
+    }
+
+    let report = timezone_detector::detecter_fuseau(&symbol, &candles, &events);
+    tracing::info!(
+        "✅ Fuseau suggéré pour {}: {:?} (confiance {:.0}%, {} annonces)",
+        symbol,
+        report.suggested_timezone,
+        report.confidence,
+        report.events_tested
+    );
+    Ok(report)
+}
+
+/// Réécrit en UTC les bougies de `symbol` lues comme heures locales de `source_timezone`.
+/// `source_file` limite la correction à un fichier importé ; les timeframes dérivés sont recalculés.
+#[command]
+pub async fn apply_source_timezone(
+    symbol: String,
+    source_timezone: String,
+    source_file: Option<String>,
+) -> Result<TimezoneCorrectionResult, String> {
+    if symbol.is_empty() {
+        return Err("Symbole requis".to_string());
+    }
+    let timezone = SourceTimezone::parse(&source_timezone)?;
+    // Fuseau inconnu = horodatages lus comme UTC : seul un fichier au fuseau déclaré peut revenir à UTC
+    if timezone == SourceTimezone::Utc && source_file.is_none() {
+        return Err("Les données sont déjà en UTC : rien à réécrire".to_string());
+    }
+
+    let db_path = dirs::data_local_dir()
+        .ok_or_else(|| "Répertoire de données introuvable".to_string())?
+        .join("volatility-analyzer")
+        .join("pairs.db");
+    let mut conn = rusqlite::Connection::open(&db_path)
+        .map_err(|e| format!("Ouverture pairs.db impossible: {}", e))?;
+
+    tracing::info!(
+        "🕒 Réécriture {} ({}) : {} → UTC",
+        symbol,
+        source_file.as_deref().unwrap_or("tous les fichiers"),
+        timezone
+    );
+    let result =
+        timezone_detector::reecrire_en_utc(&mut conn, &symbol, source_file.as_deref(), &timezone)?;
+    tracing::info!(
+        "✅ {} bougies réécrites, {} supprimées, {} dérivées recalculées",
+        result.rows_rewritten,
+        result.rows_deleted,
+        result.derived_rows
+    );
+    Ok(result)
+}
+
+/// Au plus `max` annonces réparties uniformément (triées)
+fn echantillonner(events: &[DateTime<Utc>], max: usize) -> Vec<DateTime<Utc>> {
+    if events.len() <= max {
+        return events.to_vec();
+    }
+    (0..max).map(|i| events[i * events.len() / max]).collect()
+}
+
+/// Fenêtres [annonce - marge, annonce + marge] fusionnées quand elles se chevauchent
+fn fenetres(events: &[DateTime<Utc>], margin: Duration) -> Vec<(DateTime<Utc>, DateTime<Utc>)> {
+    let mut windows: Vec<(DateTime<Utc>, DateTime<Utc>)> = Vec::new();
+    for event in events {
+        let (start, end) = (*event - margin, *event + margin);
+        match windows.last_mut() {
+            Some((_, last_end)) if start <= *last_end => *last_end = end,
+            _ => windows.push((start, end)),
+        }
+    }
+    windows
+}
@@ -1,5 +1,5 @@
 use crate::commands::calendar_commands::CalendarState;
-use crate::models::{AnalysisResult, VolatilityError};
+use crate::models::{AnalysisResult, VolatilityError, VolatilityEstimator};
 use crate::services::cache_service::CacheService;
 use crate::services::{CsvLoader, VolatilityAnalyzer};
 use chrono::{DateTime, Utc};
@@ -94,11 +94,13 @@ pub async fn load_symbols(
 }
 
 #[tauri::command]
+#[allow(clippy::too_many_arguments)]
 pub async fn analyze_symbol(
     symbol: String,
     calendar_id: i32,
     date_start: Option<String>,
     date_end: Option<String>,
+    volatility_estimator: Option<VolatilityEstimator>,
     calendar_state: State<'_, CalendarState>,
     pair_state: State<'_, super::super::pair_data::PairDataState>,
     analysis_cache: State<'_, AnalysisCacheState>,
@@ -108,12 +110,17 @@ pub async fn analyze_symbol(
         symbol, calendar_id, date_start, date_end
     );
 
-    // Construire une clé de cache unique incluant les dates
-    let cache_key = if date_start.is_some() || date_end.is_some() {
+    let estimator = volatility_estimator.unwrap_or_default();
+
+    // Construire une clé de cache unique incluant les dates et l'estimateur
+    let mut cache_key = if date_start.is_some() || date_end.is_some() {
         format!("{}:{}:{}:{}", symbol, calendar_id, date_start.as_deref().unwrap_or(""), date_end.as_deref().unwrap_or(""))
     } else {
         format!("{}:{}", symbol, calendar_id)
     };
+    if estimator != VolatilityEstimator::CloseToClose {
+        cache_key.push_str(&format!(":{:?}", estimator));
+    }
 
     // Vérifier le cache d'abord
     if let Some(cached) = analysis_cache.cache.get(&cache_key) {
@@ -212,7 +219,7 @@ pub async fn analyze_symbol(
 
         info!("Total candles loaded for {}: {}", symbol_clone, candles.len());
 
-        let analyzer = VolatilityAnalyzer::new(candles);
+        let analyzer = VolatilityAnalyzer::new(candles).with_estimator(estimator);
         analyzer.analyze(&symbol_clone, cal_pool)
     })
     .await
@@ -26,7 +26,7 @@ pub async fn analyze_volatility_duration_for_slice(
     use crate::services::database_loader::DatabaseLoader;
     use crate::services::slice_metrics_analyzer;
     use crate::services::volatility_duration_calculator::calculer_duree_volatilite;
-    use crate::models::asset_class::AssetProperties;
+    use crate::services::pair_data::get_asset_properties;
 
     let pool = pair_state
         .pool
@@ -52,7 +52,7 @@ pub async fn analyze_volatility_duration_for_slice(
     )?;
 
     // FIX 2.3: Get asset type and retrieve profile from DB
-    let asset_props = AssetProperties::from_symbol(&symbol);
+    let asset_props = get_asset_properties(&symbol);
     let profile = volatility_profile_service
         .get_profile_by_type(asset_props.asset_type)
         .map_err(|e| format!("Failed to get volatility profile: {}", e))?;
@@ -26,7 +26,7 @@ pub async fn get_hourly_stats(
         });
     }
 
-    let result = analyze_symbol(symbol, calendar_id, None, None, calendar_state, pair_state, analysis_cache).await?;
+    let result = analyze_symbol(symbol, calendar_id, None, None, None, calendar_state, pair_state, analysis_cache).await?;
 
     let stats = result
         .hourly_stats
@@ -53,6 +53,6 @@ pub async fn get_best_hours(
         symbol, calendar_id
     );
 
-    let result = analyze_symbol(symbol, calendar_id, None, None, calendar_state, pair_state, analysis_cache).await?;
+    let result = analyze_symbol(symbol, calendar_id, None, None, None, calendar_state, pair_state, analysis_cache).await?;
     Ok(result.best_quarter)
 }
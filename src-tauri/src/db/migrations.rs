@@ -54,6 +54,22 @@ pub fn ensure_calendar_table(pool: &DbPool) -> Result<(), Box<dyn std::error::Er
         .execute(&mut conn);
     let _ = diesel::sql_query("ALTER TABLE calendar_events ADD COLUMN peak_delay_json TEXT")
         .execute(&mut conn);
+    // Session d'import ayant inséré l'événement (calendar_import_log.id)
+    let _ = diesel::sql_query("ALTER TABLE calendar_events ADD COLUMN import_session_id INTEGER")
+        .execute(&mut conn);
+
+    diesel::sql_query(
+        "CREATE INDEX IF NOT EXISTS idx_calendar_events_import_session ON calendar_events(import_session_id)",
+    )
+    .execute(&mut conn)?;
+    // Annonce canonique (event_catalog.id), renseignée à l'import
+    let _ = diesel::sql_query("ALTER TABLE calendar_events ADD COLUMN event_id TEXT")
+        .execute(&mut conn);
+
+    diesel::sql_query(
+        "CREATE INDEX IF NOT EXISTS idx_calendar_events_event_id ON calendar_events(event_id)",
+    )
+    .execute(&mut conn)?;
 
     Ok(())
 }
@@ -81,12 +97,20 @@ pub fn ensure_pair_tables(pool: &DbPool) -> Result<(), Box<dyn std::error::Error
             spread_low REAL,
             spread_close REAL,
             spread_mean REAL,
-            tick_count INTEGER
+            tick_count INTEGER,
+            bid_open REAL,
+            bid_high REAL,
+            bid_low REAL,
+            bid_close REAL,
+            ask_open REAL,
+            ask_high REAL,
+            ask_low REAL,
+            ask_close REAL
         )",
     )
     .execute(&mut conn)?;
 
-    // Migration idempotente : ajouter les colonnes spread si elles n'existent pas encore
+    // Migration idempotente : ajouter les colonnes spread et bid/ask si elles n'existent pas encore
     for col in &[
         "spread_open REAL",
         "spread_high REAL",
@@ -94,6 +118,14 @@ pub fn ensure_pair_tables(pool: &DbPool) -> Result<(), Box<dyn std::error::Error
         "spread_close REAL",
         "spread_mean REAL",
         "tick_count INTEGER",
+        "bid_open REAL",
+        "bid_high REAL",
+        "bid_low REAL",
+        "bid_close REAL",
+        "ask_open REAL",
+        "ask_high REAL",
+        "ask_low REAL",
+        "ask_close REAL",
     ] {
         let _ = diesel::sql_query(format!(
             "ALTER TABLE candle_data ADD COLUMN {}",
@@ -117,6 +149,15 @@ pub fn ensure_pair_tables(pool: &DbPool) -> Result<(), Box<dyn std::error::Error
     )
     .execute(&mut conn)?;
 
+    // Session d'import ayant écrit la bougie (import_log.id, NULL pour les dérivées)
+    let _ = diesel::sql_query("ALTER TABLE candle_data ADD COLUMN import_id INTEGER")
+        .execute(&mut conn);
+
+    diesel::sql_query(
+        "CREATE INDEX IF NOT EXISTS idx_candle_data_import_id ON candle_data(import_id)",
+    )
+    .execute(&mut conn)?;
+
     diesel::sql_query(
         "CREATE TABLE IF NOT EXISTS pair_metadata (
             id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
@@ -152,6 +193,13 @@ pub fn ensure_pair_tables(pool: &DbPool) -> Result<(), Box<dyn std::error::Error
     )
     .execute(&mut conn)?;
 
+    // Fuseau source des horodatages importés (convertis en UTC à l'import)
+    let _ = diesel::sql_query("ALTER TABLE import_log ADD COLUMN source_timezone TEXT")
+        .execute(&mut conn);
+    // Conduite face à un fichier déjà importé : "new", "replace" ou "merge"
+    let _ = diesel::sql_query("ALTER TABLE import_log ADD COLUMN import_mode TEXT")
+        .execute(&mut conn);
+
     diesel::sql_query(
         "CREATE INDEX IF NOT EXISTS idx_import_log_imported_at ON import_log(imported_at)",
     )
@@ -162,6 +210,157 @@ pub fn ensure_pair_tables(pool: &DbPool) -> Result<(), Box<dyn std::error::Error
     )
     .execute(&mut conn)?;
 
+    // Ticks bruts (optionnel) : horodatage en millisecondes epoch UTC pour rester compact
+    diesel::sql_query(
+        "CREATE TABLE IF NOT EXISTS tick_data (
+            symbol TEXT NOT NULL,
+            time_ms INTEGER NOT NULL,
+            bid REAL NOT NULL,
+            ask REAL NOT NULL,
+            bid_volume REAL NOT NULL,
+            ask_volume REAL NOT NULL
+        )",
+    )
+    .execute(&mut conn)?;
+
+    diesel::sql_query(
+        "CREATE INDEX IF NOT EXISTS idx_tick_data_symbol_time ON tick_data(symbol, time_ms)",
+    )
+    .execute(&mut conn)?;
+
+    let _ = diesel::sql_query("ALTER TABLE tick_data ADD COLUMN import_id INTEGER")
+        .execute(&mut conn);
+
+    diesel::sql_query(
+        "CREATE INDEX IF NOT EXISTS idx_tick_data_import_id ON tick_data(import_id)",
+    )
+    .execute(&mut conn)?;
+
+    // Bougies écrasées par un import (displaced_by = import_log.id), remises en place
+    // si cet import est annulé ; import_id = session d'origine de la bougie
+    diesel::sql_query(
+        "CREATE TABLE IF NOT EXISTS candle_displaced (
+            displaced_by INTEGER NOT NULL,
+            import_id INTEGER,
+            symbol TEXT NOT NULL,
+            timeframe TEXT NOT NULL,
+            time TIMESTAMP NOT NULL,
+            open REAL NOT NULL,
+            high REAL NOT NULL,
+            low REAL NOT NULL,
+            close REAL NOT NULL,
+            volume REAL NOT NULL,
+            spread_open REAL,
+            spread_high REAL,
+            spread_low REAL,
+            spread_close REAL,
+            spread_mean REAL,
+            tick_count INTEGER,
+            bid_open REAL,
+            bid_high REAL,
+            bid_low REAL,
+            bid_close REAL,
+            ask_open REAL,
+            ask_high REAL,
+            ask_low REAL,
+            ask_close REAL,
+            imported_at TIMESTAMP NOT NULL,
+            source_file TEXT NOT NULL
+        )",
+    )
+    .execute(&mut conn)?;
+
+    diesel::sql_query(
+        "CREATE INDEX IF NOT EXISTS idx_candle_displaced_by ON candle_displaced(displaced_by)",
+    )
+    .execute(&mut conn)?;
+
+    diesel::sql_query(
+        "CREATE INDEX IF NOT EXISTS idx_candle_displaced_import ON candle_displaced(import_id)",
+    )
+    .execute(&mut conn)?;
+
+    // Ticks purgés par un import sur sa plage (displaced_by = import_log.id), remis en
+    // place si cet import est annulé ; import_id = session d'origine du tick
+    diesel::sql_query(
+        "CREATE TABLE IF NOT EXISTS tick_displaced (
+            displaced_by INTEGER NOT NULL,
+            import_id INTEGER,
+            symbol TEXT NOT NULL,
+            time_ms INTEGER NOT NULL,
+            bid REAL NOT NULL,
+            ask REAL NOT NULL,
+            bid_volume REAL NOT NULL,
+            ask_volume REAL NOT NULL
+        )",
+    )
+    .execute(&mut conn)?;
+
+    diesel::sql_query(
+        "CREATE INDEX IF NOT EXISTS idx_tick_displaced_by ON tick_displaced(displaced_by)",
+    )
+    .execute(&mut conn)?;
+
+    diesel::sql_query(
+        "CREATE INDEX IF NOT EXISTS idx_tick_displaced_import ON tick_displaced(import_id)",
+    )
+    .execute(&mut conn)?;
+
+    // Zones de transit des imports en continu : lots écrits au fil de la lecture,
+    // publiés en une transaction à la fin (rien dans candle_data si l'import est annulé)
+    diesel::sql_query(
+        "CREATE TABLE IF NOT EXISTS candle_staging (
+            import_id TEXT NOT NULL,
+            time TIMESTAMP NOT NULL,
+            open REAL NOT NULL,
+            high REAL NOT NULL,
+            low REAL NOT NULL,
+            close REAL NOT NULL,
+            volume REAL NOT NULL,
+            spread_open REAL,
+            spread_high REAL,
+            spread_low REAL,
+            spread_close REAL,
+            spread_mean REAL,
+            tick_count INTEGER,
+            bid_open REAL,
+            bid_high REAL,
+            bid_low REAL,
+            bid_close REAL,
+            ask_open REAL,
+            ask_high REAL,
+            ask_low REAL,
+            ask_close REAL
+        )",
+    )
+    .execute(&mut conn)?;
+
+    diesel::sql_query(
+        "CREATE TABLE IF NOT EXISTS tick_staging (
+            import_id TEXT NOT NULL,
+            time_ms INTEGER NOT NULL,
+            bid REAL NOT NULL,
+            ask REAL NOT NULL,
+            bid_volume REAL NOT NULL,
+            ask_volume REAL NOT NULL
+        )",
+    )
+    .execute(&mut conn)?;
+
+    diesel::sql_query(
+        "CREATE INDEX IF NOT EXISTS idx_candle_staging_import ON candle_staging(import_id)",
+    )
+    .execute(&mut conn)?;
+
+    diesel::sql_query(
+        "CREATE INDEX IF NOT EXISTS idx_tick_staging_import ON tick_staging(import_id)",
+    )
+    .execute(&mut conn)?;
+
+    // Restes d'un import interrompu (arrêt de l'application pendant la lecture)
+    diesel::sql_query("DELETE FROM candle_staging").execute(&mut conn)?;
+    diesel::sql_query("DELETE FROM tick_staging").execute(&mut conn)?;
+
     Ok(())
 }
 
@@ -188,6 +387,41 @@ pub fn ensure_calendar_imports_table(pool: &DbPool) -> Result<(), Box<dyn std::e
     )
     .execute(&mut conn)?;
 
+    // Sessions d'import d'un calendrier (import initial, remplacement, fusion)
+    diesel::sql_query(
+        "CREATE TABLE IF NOT EXISTS calendar_import_log (
+            id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+            calendar_import_id INTEGER NOT NULL,
+            filename TEXT NOT NULL,
+            import_mode TEXT,
+            event_count INTEGER NOT NULL DEFAULT 0,
+            status TEXT NOT NULL,
+            imported_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )",
+    )
+    .execute(&mut conn)?;
+
+    diesel::sql_query(
+        "CREATE INDEX IF NOT EXISTS idx_calendar_import_log_calendar ON calendar_import_log(calendar_import_id)",
+    )
+    .execute(&mut conn)?;
+
+    // Calendriers importés avant les sessions : une session par calendrier, reprise par ses événements
+    diesel::sql_query(
+        "INSERT INTO calendar_import_log (calendar_import_id, filename, event_count, status, imported_at)
+         SELECT id, filename, event_count, 'success', imported_at FROM calendar_imports
+         WHERE id NOT IN (SELECT calendar_import_id FROM calendar_import_log)",
+    )
+    .execute(&mut conn)?;
+
+    diesel::sql_query(
+        "UPDATE calendar_events SET import_session_id =
+            (SELECT MIN(l.id) FROM calendar_import_log l
+             WHERE l.calendar_import_id = calendar_events.calendar_import_id)
+         WHERE import_session_id IS NULL AND calendar_import_id IS NOT NULL",
+    )
+    .execute(&mut conn)?;
+
     Ok(())
 }
 
@@ -215,10 +449,92 @@ pub fn ensure_symbol_conversions_table(pool: &DbPool) -> Result<(), Box<dyn std:
     let _ = diesel::sql_query("CREATE INDEX IF NOT EXISTS idx_symbol_conversions_hidden ON symbol_conversions(hidden)")
         .execute(&mut conn);
 
+    // Règles d'alias : symboles broker (EURUSD.m, #US30...) → symbole canonique
+    diesel::sql_query(
+        "CREATE TABLE IF NOT EXISTS symbol_aliases (
+            id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+            pattern TEXT NOT NULL,
+            match_kind TEXT NOT NULL DEFAULT 'exact',
+            canonical TEXT NOT NULL DEFAULT '',
+            priority INTEGER NOT NULL DEFAULT 0,
+            updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
+            UNIQUE(pattern, match_kind)
+        )",
+    )
+    .execute(&mut conn)?;
+
     tracing::info!("✅ Table symbol_conversions vérifiée/créée");
     Ok(())
 }
 
+/// Crée le registre des instruments (dans pairs.db) : spécifications de contrat
+/// éditables, pré-remplies au démarrage par `instrument_registry::seed_defaults`
+pub fn ensure_instruments_table(pool: &DbPool) -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = pool.get()?;
+
+    diesel::sql_query(
+        "CREATE TABLE IF NOT EXISTS instruments (
+            symbol TEXT PRIMARY KEY NOT NULL,
+            display_name TEXT NOT NULL,
+            category TEXT NOT NULL,
+            asset_class TEXT NOT NULL,
+            digits INTEGER NOT NULL,
+            pip_size REAL NOT NULL,
+            pip_unit TEXT NOT NULL DEFAULT 'pips',
+            display_digits INTEGER NOT NULL DEFAULT 4,
+            contract_size REAL NOT NULL,
+            point_value REAL NOT NULL,
+            quote_currency TEXT NOT NULL,
+            trading_hours TEXT NOT NULL DEFAULT '24/5',
+            dukascopy BOOLEAN NOT NULL DEFAULT 0,
+            spread_min REAL NOT NULL,
+            spread_max REAL NOT NULL,
+            spread_avg REAL NOT NULL,
+            slippage REAL NOT NULL,
+            spread_multiplier_event REAL NOT NULL,
+            entry_slippage_multiplier REAL NOT NULL,
+            updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )",
+    )
+    .execute(&mut conn)?;
+
+    tracing::info!("✅ Table instruments vérifiée/créée");
+    Ok(())
+}
+
+/// Crée les tables du catalogue d'annonces (dans volatility.db)
+/// Une annonce canonique par identifiant stable, ses intitulés connus dans event_aliases
+pub fn ensure_event_catalog_tables(pool: &DbPool) -> Result<(), Box<dyn std::error::Error>> {
+    let mut conn = pool.get()?;
+
+    diesel::sql_query(
+        "CREATE TABLE IF NOT EXISTS event_catalog (
+            id TEXT PRIMARY KEY NOT NULL,
+            name TEXT NOT NULL,
+            country TEXT NOT NULL DEFAULT '',
+            currency TEXT NOT NULL DEFAULT '',
+            category TEXT NOT NULL DEFAULT 'other',
+            default_impact TEXT NOT NULL DEFAULT 'M',
+            updated_at TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP
+        )",
+    )
+    .execute(&mut conn)?;
+
+    diesel::sql_query(
+        "CREATE TABLE IF NOT EXISTS event_aliases (
+            id INTEGER PRIMARY KEY AUTOINCREMENT NOT NULL,
+            event_id TEXT NOT NULL REFERENCES event_catalog(id) ON DELETE CASCADE,
+            alias TEXT NOT NULL,
+            match_kind TEXT NOT NULL DEFAULT 'exact',
+            UNIQUE(event_id, alias, match_kind)
+        )",
+    )
+    .execute(&mut conn)?;
+
+    tracing::info!("✅ Tables event_catalog / event_aliases vérifiées/créées");
+    Ok(())
+}
+
 /// Crée la table archives si elle n'existe pas
 pub fn ensure_archives_table(pool: &DbPool) -> Result<(), Box<dyn std::error::Error>> {
     let mut conn = pool.get()?;
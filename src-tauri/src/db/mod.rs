@@ -9,7 +9,7 @@ use std::sync::Arc;
 
 pub type DbPool = Arc<r2d2::Pool<ConnectionManager<SqliteConnection>>>;
 
-pub use migrations::{ensure_calendar_imports_table, ensure_calendar_table, ensure_pair_tables, ensure_archives_table, ensure_symbol_conversions_table, ensure_volatility_profiles_table};
+pub use migrations::{ensure_calendar_imports_table, ensure_calendar_table, ensure_event_catalog_tables, ensure_pair_tables, ensure_archives_table, ensure_instruments_table, ensure_symbol_conversions_table, ensure_volatility_profiles_table};
 
 /// Initialise un pool de connexions SQLite optimisé
 ///
@@ -64,3 +64,14 @@ pub fn init_wal_mode(database_url: &str) -> Result<(), Box<dyn std::error::Error
     tracing::info!("✅ WAL mode enabled for {}", database_url);
     Ok(())
 }
+
+/// DB paires temporaire au schéma complet, ouverte en rusqlite (tests)
+#[cfg(test)]
+pub fn temp_pairs_db() -> (tempfile::TempDir, rusqlite::Connection) {
+    let dir = tempfile::tempdir().expect("tempdir");
+    let path = dir.path().join("pairs.db");
+    let url = path.to_string_lossy().to_string();
+    ensure_pair_tables(&create_pool(&url).expect("pool")).expect("schema");
+    let conn = rusqlite::Connection::open(&path).expect("open");
+    (dir, conn)
+}
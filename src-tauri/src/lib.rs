@@ -114,6 +114,19 @@ pub fn run() {
 
     tracing::info!("✅ Table calendar_imports vérifiée/créée");
 
+    // Catalogue des annonces : tables, annonces par défaut, rattachement des événements
+    if let Err(e) = db::ensure_event_catalog_tables(&calendar_pool) {
+        tracing::error!("❌ ERREUR: Impossible de créer le catalogue d'annonces: {}", e);
+        std::process::exit(1);
+    }
+    if let Err(e) = services::event_catalog::seed_defaults(&calendar_pool) {
+        tracing::warn!("⚠️ Annonces par défaut non insérées: {}", e);
+    }
+    services::event_catalog::set_catalog_pool(calendar_pool.clone());
+    if let Err(e) = services::event_catalog::assign_event_ids(&calendar_pool, true) {
+        tracing::warn!("⚠️ Rattachement des événements au catalogue incomplet: {}", e);
+    }
+
     let calendar_state = calendar_commands::CalendarState {
         pool: Mutex::new(Some(calendar_pool.clone())),
     };
@@ -156,6 +169,15 @@ pub fn run() {
         std::process::exit(1);
     }
 
+    // Registre des instruments : table + fiches par défaut
+    if let Err(e) = db::ensure_instruments_table(&pairs_pool) {
+        tracing::error!("❌ ERREUR: Impossible de créer la table instruments: {}", e);
+        std::process::exit(1);
+    }
+    if let Err(e) = services::instrument_registry::seed_defaults(&pairs_pool) {
+        tracing::warn!("⚠️ Fiches instruments par défaut non insérées: {}", e);
+    }
+
     // Enregistre le pool pour les conversions dynamiques
     services::pair_data::symbol_properties::set_pairs_pool(pairs_pool.clone());
 
@@ -203,6 +225,7 @@ pub fn run() {
         .manage(archive_service)
         .manage(volatility_profile_service)
         .manage(analysis_cache)
+        .manage(commands::import_control_commands::ImportCancelState::default())
         .invoke_handler(tauri::generate_handler![
             // Volatility commands (Phase 1)
             ping,
@@ -234,6 +257,16 @@ pub fn run() {
             delete_conversion,     // NEW: Masquer une conversion
             restore_conversion,    // NEW: Restaurer une conversion masquée
             invalidate_analysis_cache, // NEW: Invalider cache après changements conversions
+            // Alias de symboles broker (EURUSD.m, #US30...) → symbole canonique
+            list_symbol_aliases,
+            save_symbol_alias,
+            delete_symbol_alias,
+            resolve_symbol_alias,
+            // Registre des instruments (digits, pip, contrat, horaires, coûts)
+            list_instruments,
+            get_instrument,
+            save_instrument,
+            delete_instrument,
             clean_csv_files,        // Nouveau: nettoyage CSV européens
             import_and_clean_files, // Nouveau: import unifié (clean + import)
             // Session analysis commands (Phase 5)
@@ -302,22 +335,58 @@ pub fn run() {
             analyze_peak_delay,
             analyze_decay_profile,
             analyze_volatility_profile,
+            // Réaction par classe de surprise (actual vs forecast)
+            analyze_surprise_buckets,
+            // Sens attendu selon la surprise : stop unique ou straddle
+            analyze_directional_bias,
+            // Dérive et compression avant l'annonce (T-60 à T-5)
+            analyze_pre_release_drift,
+            scan_pre_release_drift,
+            // Distributions MFE/MAE des deux jambes du straddle, SL/TP par percentiles
+            analyze_excursions,
             get_event_types,
             // PDF export commands
             exporter_formules_pdf,
             // Entry point analysis (Phase 2 — profit net après spread réel)
             commands::entry_point_commands::analyze_entry_points,
+            // Rapport de qualité des données (trous, flux gelé, anomalies)
+            commands::data_quality_commands::analyze_data_quality,
+            // Backtest straddle à ordres en attente (OCO, SL/TP, courbe d'équité)
+            commands::backtest_commands::run_straddle_backtest,
+            commands::backtest_commands::run_walk_forward_optimization,
             // Tick import commands (Phase 1 — données enrichies spread)
             commands::tick_import_commands::import_tick_file,
+            // Annulation d'un import en cours (progression via l'événement "import-progress")
+            commands::import_control_commands::cancel_import,
+            // Historique des imports : détail et annulation d'une session
+            commands::import_session_commands::list_import_sessions,
+            commands::import_session_commands::get_import_session,
+            commands::import_session_commands::rollback_import,
+            commands::import_session_commands::confirm_import_session,
+            commands::import_session_commands::purge_import_backups,
+            commands::import_session_commands::get_backup_retention_days,
             // Dukascopy download commands (auto-download tick data)
             commands::dukascopy_commands::get_dukascopy_instruments,
             commands::dukascopy_commands::download_dukascopy_data,
+            // Export Parquet de l'historique (l'import passe par import_pair_data)
+            commands::parquet_commands::export_candles_parquet,
+            // Détection du fuseau source (pics alignés sur les annonces) et réécriture en UTC
+            commands::timezone_detection_commands::detect_source_timezone,
+            commands::timezone_detection_commands::apply_source_timezone,
             // Planning commands
             project_stats_on_calendar,
             sync_forex_factory_week,
             commands::calendar_import_commands::process_forex_factory_csv,
             commands::calendar_import_commands::check_download_folder_for_forex_factory,
             commands::calendar_import_commands::clean_old_calendar_files_from_downloads,
+            // Import de calendriers tiers par mapping de colonnes (Investing, FXStreet, TE…)
+            commands::calendar_mapping_commands::list_calendar_mapping_presets,
+            commands::calendar_mapping_commands::preview_calendar_mapping,
+            // Catalogue canonique des annonces (alias d'intitulés, regroupement des analyses)
+            commands::event_catalog_commands::list_event_catalog,
+            commands::event_catalog_commands::resolve_event_name,
+            commands::event_catalog_commands::save_canonical_event,
+            commands::event_catalog_commands::delete_canonical_event,
             // PDF export commands
             commands::export_pdf_commands::export_comparative_analysis_pdf,
         ])
@@ -1,6 +1,8 @@
 // models/analysis_result.rs - Résultat d'analyse complète
 use super::calendar_event::CalendarEvent;
+use super::significance::SignificanceResult;
 use super::{HourlyStats, Stats15Min};
+use crate::models::range_volatility::VolatilityEstimator;
 use crate::models::trading_recommendation::{RiskLevel, TradingRecommendation};
 use serde::{Deserialize, Serialize};
 
@@ -19,6 +21,9 @@ pub struct AnalysisResult {
     pub global_metrics: GlobalMetrics,
     pub point_value: f64, // Valeur d'un point pour normalisation (ex: 0.001 pour JPY)
     pub unit: String,     // Unité d'affichage (pips, points, $)
+    /// Estimateur de volatilité utilisé pour le classement des quarters
+    #[serde(default)]
+    pub volatility_estimator: VolatilityEstimator,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -40,4 +45,7 @@ pub struct CorrelatedEvent {
     pub volatility_hour: u8,
     pub volatility_increase: f64,
     pub correlation_score: f64,
+    /// Test fenêtre événement vs baseline (même heure, jours précédents)
+    #[serde(default)]
+    pub significance: Option<SignificanceResult>,
 }
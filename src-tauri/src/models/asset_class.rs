@@ -16,6 +16,35 @@ pub enum AssetType {
     Unknown,
 }
 
+impl AssetType {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AssetType::ForexMajor => "ForexMajor",
+            AssetType::ForexJpy => "ForexJpy",
+            AssetType::Gold => "Gold",
+            AssetType::Silver => "Silver",
+            AssetType::Crypto => "Crypto",
+            AssetType::Index => "Index",
+            AssetType::Commodity => "Commodity",
+            AssetType::Unknown => "Unknown",
+        }
+    }
+
+    /// Inverse de `as_str` (Unknown si la valeur n'est pas reconnue)
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "ForexMajor" => AssetType::ForexMajor,
+            "ForexJpy" => AssetType::ForexJpy,
+            "Gold" => AssetType::Gold,
+            "Silver" => AssetType::Silver,
+            "Crypto" => AssetType::Crypto,
+            "Index" => AssetType::Index,
+            "Commodity" => AssetType::Commodity,
+            _ => AssetType::Unknown,
+        }
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AssetProperties {
     pub asset_type: AssetType,
@@ -26,7 +55,7 @@ pub struct AssetProperties {
 
 impl AssetProperties {
     pub fn from_symbol(symbol: &str) -> Self {
-        let s = symbol.to_uppercase();
+        let s = strip_broker_decorations(symbol);
 
         if s.contains("JPY") {
             AssetProperties {
@@ -105,7 +134,13 @@ impl AssetProperties {
     /// Crée les propriétés depuis un override DB (si fourni), sinon fallback hardcodé
     /// Ignore l'override si pip_value est invalide (<= 0.0) ou trop éloigné du hardcodé (ratio > 100x)
     pub fn from_symbol_with_override(symbol: &str, db_override: Option<SymbolConversion>) -> Self {
-        let base = Self::from_symbol(symbol);
+        Self::from_symbol(symbol).with_override(symbol, db_override)
+    }
+
+    /// Applique un override DB à des propriétés de base (registre ou hardcodé),
+    /// avec les mêmes garde-fous que `from_symbol_with_override`
+    pub fn with_override(self, symbol: &str, db_override: Option<SymbolConversion>) -> Self {
+        let base = self;
         match db_override {
             Some(conv) if conv.pip_value > 0.0 => {
                 // Protection: rejeter les overrides aberrants (ratio > 100x du hardcodé)
@@ -165,6 +200,76 @@ impl AssetProperties {
     }
 }
 
+/// Devises (et métaux cotés comme des devises) reconnues dans une paire collée
+const CURRENCY_CODES: [&str; 26] = [
+    "EUR", "USD", "GBP", "JPY", "CHF", "AUD", "NZD", "CAD", "SEK", "NOK", "DKK", "PLN", "HUF",
+    "CZK", "TRY", "ZAR", "MXN", "SGD", "HKD", "CNH", "RUB", "ILS", "XAU", "XAG", "XPT", "XPD",
+];
+
+/// Suffixes de compte collés à une paire par les brokers (EURUSDpro, EURUSDm...)
+const GLUED_SUFFIXES: [&str; 15] = [
+    "M", "I", "C", "E", "X", "PRO", "ECN", "RAW", "STP", "STD", "MICRO", "MINI", "PLUS", "CASH", "SB",
+];
+
+/// Instruments sans chiffres ni devise de cotation reconnus devant un suffixe séparé (WTI.cash)
+const NAMED_INSTRUMENTS: [&str; 11] = [
+    "GOLD", "SILVER", "USOIL", "UKOIL", "WTI", "BRENT", "NGAS", "USTEC", "USATEC", "DXY", "VIX",
+];
+
+/// Retire les décorations ajoutées par les brokers : préfixes (#US30, .DE40),
+/// suffixes séparés (EURUSD.m, EURUSD_i, GER40.cash) ou collés à une paire
+/// forex (EURUSDpro), et le séparateur de devises (EUR/USD, EUR_USD, USD-JPY).
+/// Résultat en majuscules.
+pub fn strip_broker_decorations(symbol: &str) -> String {
+    let upper = symbol.trim().to_uppercase().replace('/', "");
+    let trimmed = upper.trim_start_matches(['#', '.', '_', '$', '!', '+', '-']);
+    let joined = join_currency_pair(trimmed);
+
+    // Suffixe séparé : retiré seulement derrière un symbole complet (EURUSD.m, SPX500_USD)
+    let base = match joined.find(['.', '_', '-', '+', '!', '#']) {
+        Some(i) if is_complete_symbol(strip_glued_suffix(&joined[..i])) => &joined[..i],
+        _ => &joined,
+    };
+    strip_glued_suffix(base).to_string()
+}
+
+fn is_currency(code: Option<&str>) -> bool {
+    code.is_some_and(|c| CURRENCY_CODES.contains(&c))
+}
+
+/// Séparateur entre deux codes devise retiré : EUR_USD, USD-JPY → EURUSD, USDJPY
+fn join_currency_pair(symbol: &str) -> String {
+    let separated = symbol.len() >= 7 && matches!(symbol.as_bytes()[3], b'_' | b'-');
+    if separated && is_currency(symbol.get(..3)) && is_currency(symbol.get(4..7)) {
+        return format!("{}{}", &symbol[..3], &symbol[4..]);
+    }
+    symbol.to_string()
+}
+
+/// Suffixe collé retiré, seulement derrière deux codes devise connus (EURUSDpro)
+fn strip_glued_suffix(base: &str) -> &str {
+    if base.len() > 6
+        && is_currency(base.get(..3))
+        && is_currency(base.get(3..6))
+        && base.get(6..).is_some_and(|suffix| GLUED_SUFFIXES.contains(&suffix))
+    {
+        return &base[..6];
+    }
+    base
+}
+
+/// Symbole complet : actif coté contre une devise (EURUSD, BTCUSD), indice/CFD
+/// lettres + chiffres (GER40, SPX500) ou instrument nommé connu
+fn is_complete_symbol(base: &str) -> bool {
+    let alphabetic = |s: &str| !s.is_empty() && s.chars().all(|c| c.is_ascii_alphabetic());
+    let letters = base.trim_end_matches(|c: char| c.is_ascii_digit());
+    let index = letters.len() >= 2 && base.len() - letters.len() >= 2 && alphabetic(letters);
+    let quoted = (6..=8).contains(&base.len())
+        && alphabetic(base)
+        && is_currency(base.get(base.len() - 3..));
+    index || quoted || NAMED_INSTRUMENTS.contains(&base)
+}
+
 #[cfg(test)]
 #[path = "asset_class_tests.rs"]
 mod tests;
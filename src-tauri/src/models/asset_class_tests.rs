@@ -130,3 +130,38 @@ fn test_override_invalide_ignore() {
     assert_eq!(props.pip_value, 0.001);
     assert_eq!(props.unit, "points");
 }
+
+#[test]
+fn test_strip_broker_decorations() {
+    let cases = [
+        ("EURUSD.m", "EURUSD"),
+        ("EURUSDpro", "EURUSD"),
+        ("EURUSD_i", "EURUSD"),
+        ("GER40.cash", "GER40"),
+        ("#US30", "US30"),
+        ("eur/usd", "EURUSD"),
+        ("XAUUSDm", "XAUUSD"),
+        ("USATEC", "USATEC"),
+        ("ETHUS0", "ETHUS0"),
+        ("EUR_USD", "EURUSD"),
+        ("USD-JPY", "USDJPY"),
+        ("EUR-GBP", "EURGBP"),
+        ("EUR_USD.m", "EURUSD"),
+        ("SPX500_USD", "SPX500"),
+        ("WTI.cash", "WTI"),
+        // Partie avant le séparateur incomplète : rien n'est coupé
+        ("ABC_DEF", "ABC_DEF"),
+    ];
+    for (raw, expected) in cases {
+        assert_eq!(strip_broker_decorations(raw), expected, "Failed for {}", raw);
+    }
+}
+
+#[test]
+fn test_detection_broker_suffixes() {
+    // ".uni" ne doit plus faire passer la paire pour une crypto (UNI)
+    let props = AssetProperties::from_symbol("GBPUSD.uni");
+    assert_eq!(props.asset_type, AssetType::ForexMajor);
+    let props = AssetProperties::from_symbol("USDJPYpro");
+    assert_eq!(props.asset_type, AssetType::ForexJpy);
+}
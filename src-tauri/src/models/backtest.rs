@@ -0,0 +1,110 @@
+// models/backtest.rs - Modèles du backtest straddle à ordres en attente
+// Conforme .clinerules : structures uniquement, pas de logique métier
+
+use serde::{Deserialize, Serialize};
+
+/// Configuration d'un backtest straddle (buy-stop + sell-stop OCO)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacktestConfig {
+    /// Minutes avant l'événement où les ordres sont placés (défaut: 2)
+    pub entry_offset_minutes: i64,
+    /// Distance des ordres stop par rapport au prix de référence, en pips (défaut: 10.0)
+    pub straddle_distance_pips: f64,
+    /// Multiplicateur ATR pour le stop loss (défaut: 2.0)
+    pub atr_multiplier_sl: f64,
+    /// Multiplicateur ATR pour le take profit (défaut: 3.0)
+    pub atr_multiplier_tp: f64,
+    /// Période ATR M1 mesurée avant le placement (défaut: 14)
+    pub atr_period: usize,
+    /// Durée max d'un trade après déclenchement, en minutes (défaut: 30)
+    pub max_duration_minutes: i64,
+    /// Expiration des ordres non déclenchés après l'événement, en minutes (défaut: 15)
+    pub pending_expiry_minutes: i64,
+    /// Spread utilisé quand la bougie n'a pas de champ spread_* (défaut: 1.0 pip)
+    pub fallback_spread_pips: f64,
+}
+
+impl Default for BacktestConfig {
+    fn default() -> Self {
+        Self {
+            entry_offset_minutes: 2,
+            straddle_distance_pips: 10.0,
+            atr_multiplier_sl: 2.0,
+            atr_multiplier_tp: 3.0,
+            atr_period: 14,
+            max_duration_minutes: 30,
+            pending_expiry_minutes: 15,
+            fallback_spread_pips: 1.0,
+        }
+    }
+}
+
+/// Côté déclenché du straddle
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TradeSide {
+    Long,
+    Short,
+}
+
+/// Raison de sortie d'un trade
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExitReason {
+    TakeProfit,
+    StopLoss,
+    Timeout,
+}
+
+/// Trade simulé sur une occurrence historique
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacktestTrade {
+    /// Horodatage de l'événement (ISO 8601)
+    pub event_time: String,
+    pub side: TradeSide,
+    pub entry_time: String,
+    pub entry_price: f64,
+    pub exit_time: String,
+    pub exit_price: f64,
+    pub exit_reason: ExitReason,
+    /// Profit net en pips (fills bid/ask, spread inclus)
+    pub profit_pips: f64,
+    /// Coût de spread payé (entrée + sortie), en pips
+    pub spread_cost_pips: f64,
+    /// true si les deux ordres étaient touchés dans la même bougie M1
+    pub ambiguous_fill: bool,
+}
+
+/// Point de la courbe d'équité (cumul en pips)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EquityPoint {
+    pub time: String,
+    pub equity_pips: f64,
+    pub drawdown_pips: f64,
+}
+
+/// Résultat complet d'un backtest symbol × event_type
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BacktestResult {
+    pub symbol: String,
+    pub event_type: String,
+    pub config: BacktestConfig,
+    /// Occurrences historiques rejouées
+    pub occurrences: usize,
+    /// Occurrences sans déclenchement (ordres expirés)
+    pub unfilled_count: usize,
+    /// Occurrences ignorées faute de données M1
+    pub skipped_count: usize,
+    pub trades: Vec<BacktestTrade>,
+    pub equity_curve: Vec<EquityPoint>,
+    pub total_pips: f64,
+    pub win_rate: f64,
+    pub avg_win_pips: f64,
+    pub avg_loss_pips: f64,
+    /// Gains bruts / pertes brutes. None si aucune perte.
+    pub profit_factor: Option<f64>,
+    /// Espérance par trade en pips
+    pub expectancy_pips: f64,
+    /// Drawdown max de la courbe d'équité, en pips
+    pub max_drawdown_pips: f64,
+    /// Unité d'affichage (pips, $, points)
+    pub unit: String,
+}
@@ -0,0 +1,74 @@
+// models/calendar_mapping.rs - Mapping de colonnes pour l'import de calendriers tiers
+// Conforme .clinerules : structures uniquement, pas de logique métier
+
+use serde::{Deserialize, Serialize};
+
+/// Échelle d'impact de la source, normalisée en H/M/L à l'import
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImpactScale {
+    /// Détection par valeur (mots, chiffres ou taureaux)
+    #[default]
+    Auto,
+    /// Taureaux Investing.com : "***", "Bull3", "High Volatility Expected"
+    Bulls,
+    /// Niveaux 1 à 3 (TradingEconomics)
+    Numeric,
+    /// Low / Medium / High (ou L/M/H)
+    Words,
+}
+
+/// Description des colonnes d'un export de calendrier.
+/// Une colonne est désignée par son en-tête (insensible à la casse)
+/// ou par son index (0 = première colonne).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalendarColumnMapping {
+    /// "investing", "fxstreet", "tradingeconomics" ou "custom"
+    pub preset: String,
+    /// Date, ou date + heure si `time_column` est absent
+    pub date_column: String,
+    pub time_column: Option<String>,
+    /// Devise de l'événement (USD, EUR…)
+    pub currency_column: Option<String>,
+    /// Pays, converti en devise quand la colonne devise est absente ou vide
+    pub country_column: Option<String>,
+    pub event_column: String,
+    pub impact_column: String,
+    pub actual_column: Option<String>,
+    pub forecast_column: Option<String>,
+    pub previous_column: Option<String>,
+    /// Format chrono de la date ("%d/%m/%Y", "%b %d, %Y"…) ; "auto" = formats courants
+    pub date_format: String,
+    /// Fuseau des horodatages : "UTC", nom IANA ou "broker NY-close"
+    pub timezone: String,
+    pub impact_scale: ImpactScale,
+    /// Séparateur CSV (ignoré pour les fichiers .xlsx)
+    pub delimiter: char,
+}
+
+/// Aperçu d'un import avec mapping (avant écriture en base)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarMappingPreview {
+    pub headers: Vec<String>,
+    pub rows_read: usize,
+    pub events_parsed: usize,
+    pub rows_rejected: usize,
+    /// Premières lignes rejetées avec leur motif
+    pub rejections: Vec<String>,
+    /// Premiers événements normalisés (UTC, impact H/M/L)
+    pub sample: Vec<CalendarMappedEvent>,
+}
+
+/// Événement normalisé par le mapping
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CalendarMappedEvent {
+    /// "YYYY-MM-DD HH:MM:SS" en UTC
+    pub event_time: String,
+    pub currency: String,
+    /// "H", "M" ou "L"
+    pub impact: String,
+    pub description: String,
+    pub actual: Option<f64>,
+    pub forecast: Option<f64>,
+    pub previous: Option<f64>,
+}
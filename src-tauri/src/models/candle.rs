@@ -38,6 +38,40 @@ pub struct Candle {
     /// Nombre de ticks dans la minute (proxy liquidité)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tick_count: Option<i32>,
+    // --- OHLC bid (données tick/Dukascopy uniquement) ---
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid_open: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid_high: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid_low: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bid_close: Option<f64>,
+    // --- OHLC ask (données tick/Dukascopy uniquement) ---
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ask_open: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ask_high: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ask_low: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ask_close: Option<f64>,
+}
+
+/// Côté de cotation : bid (ventes, sorties de long) ou ask (achats, sorties de short)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteSide {
+    Bid,
+    Ask,
+}
+
+/// OHLC d'un côté de cotation
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuoteOhlc {
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
 }
 
 impl Candle {
@@ -81,6 +115,14 @@ impl Candle {
             spread_close: None,
             spread_mean: None,
             tick_count: None,
+            bid_open: None,
+            bid_high: None,
+            bid_low: None,
+            bid_close: None,
+            ask_open: None,
+            ask_high: None,
+            ask_low: None,
+            ask_close: None,
         };
         
         candle.validate().map_err(|e| {
@@ -127,6 +169,33 @@ impl Candle {
         upper_wick / lower_wick
     }
 
+    /// OHLC du côté demandé. Sans OHLC bid/ask stocké (imports CSV classiques),
+    /// reconstruit depuis l'OHLC principal ± `half_spread`.
+    pub fn side_ohlc(&self, side: QuoteSide, half_spread: f64) -> QuoteOhlc {
+        let stored = match side {
+            QuoteSide::Bid => (self.bid_open, self.bid_high, self.bid_low, self.bid_close),
+            QuoteSide::Ask => (self.ask_open, self.ask_high, self.ask_low, self.ask_close),
+        };
+        if let (Some(open), Some(high), Some(low), Some(close)) = stored {
+            return QuoteOhlc {
+                open,
+                high,
+                low,
+                close,
+            };
+        }
+        let shift = match side {
+            QuoteSide::Bid => -half_spread,
+            QuoteSide::Ask => half_spread,
+        };
+        QuoteOhlc {
+            open: self.open + shift,
+            high: self.high + shift,
+            low: self.low + shift,
+            close: self.close + shift,
+        }
+    }
+
     pub fn hour_utc(&self) -> u32 {
         self.datetime.hour()
     }
@@ -2,7 +2,7 @@
 #[cfg(test)]
 #[allow(clippy::module_inception)]
 mod tests {
-    use crate::models::candle::Candle;
+    use crate::models::candle::{Candle, QuoteSide};
     use chrono::Utc;
 
     #[test]
@@ -254,4 +254,31 @@ mod tests {
         };
         assert!((candle.shadow_ratio() - 2.0).abs() < 1e-10);
     }
+
+    #[test]
+    fn test_side_ohlc_stored_and_fallback() {
+        let mut candle = Candle {
+            symbol: "EURUSD".to_string(),
+            datetime: Utc::now(),
+            open: 1.1000,
+            high: 1.1010,
+            low: 1.0990,
+            close: 1.1005,
+            volume: 100.0,
+            ..Default::default()
+        };
+        // Sans OHLC bid/ask : mid ± demi-spread
+        let ask = candle.side_ohlc(QuoteSide::Ask, 0.0001);
+        assert!((ask.high - 1.1011).abs() < 1e-10);
+        let bid = candle.side_ohlc(QuoteSide::Bid, 0.0001);
+        assert!((bid.low - 1.0989).abs() < 1e-10);
+
+        // OHLC ask stocké : prioritaire sur la reconstruction
+        candle.ask_open = Some(1.1001);
+        candle.ask_high = Some(1.1020);
+        candle.ask_low = Some(1.0991);
+        candle.ask_close = Some(1.1006);
+        assert!((candle.side_ohlc(QuoteSide::Ask, 0.0001).high - 1.1020).abs() < 1e-10);
+        assert!((candle.side_ohlc(QuoteSide::Bid, 0.0001).high - 1.1009).abs() < 1e-10);
+    }
 }
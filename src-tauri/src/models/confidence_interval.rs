@@ -0,0 +1,13 @@
+// models/confidence_interval.rs - Intervalle de confiance attaché aux taux et moyennes
+// Conforme .clinerules : structures uniquement, pas de logique métier
+
+use serde::{Deserialize, Serialize};
+
+/// Bornes 5% / 95% obtenues par rééchantillonnage (bootstrap)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct ConfidenceInterval {
+    /// Percentile 5% de la statistique rééchantillonnée
+    pub lower: f64,
+    /// Percentile 95% de la statistique rééchantillonnée
+    pub upper: f64,
+}
@@ -0,0 +1,63 @@
+// models/data_quality.rs - Rapport de qualité des données M1 d'une paire
+// Conforme .clinerules : structures uniquement, pas de logique métier
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Trou dans l'historique (minutes de marché ouvert sans bougie)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataGap {
+    /// Première minute manquante
+    pub start: DateTime<Utc>,
+    /// Dernière minute manquante
+    pub end: DateTime<Utc>,
+    /// Minutes de marché ouvert manquantes (week-ends et jours fériés exclus)
+    pub missing_minutes: u32,
+}
+
+/// Couverture d'une journée UTC
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DayCoverage {
+    pub date: NaiveDate,
+    pub expected_minutes: u32,
+    pub present_minutes: u32,
+    pub coverage_pct: f64,
+}
+
+/// Couverture et anomalies d'un mois ("YYYY-MM")
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct MonthlyQuality {
+    pub month: String,
+    pub expected_minutes: u32,
+    pub present_minutes: u32,
+    pub coverage_pct: f64,
+    /// Nombre de trous commençant dans le mois
+    pub gap_count: usize,
+    /// Séquences de bougies figées (high = low, prix inchangé) : flux gelé
+    pub zero_range_runs: usize,
+    /// Bougies incohérentes (high < max(open, close), low > min(open, close), prix ≤ 0)
+    pub ohlc_errors: usize,
+    /// Horodatages présents plusieurs fois
+    pub duplicate_timestamps: usize,
+    /// Spreads moyens très au-dessus de la médiane de la paire
+    pub spread_outliers: usize,
+    /// Écarts open/close précédent anormaux entre deux minutes consécutives
+    pub price_jumps: usize,
+}
+
+/// Rapport complet pour une paire
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataQualityReport {
+    pub symbol: String,
+    pub total_candles: usize,
+    pub first_candle: Option<DateTime<Utc>>,
+    pub last_candle: Option<DateTime<Utc>>,
+    pub expected_minutes: u32,
+    pub present_minutes: u32,
+    pub coverage_pct: f64,
+    pub months: Vec<MonthlyQuality>,
+    /// Plus grands trous, du plus long au plus court
+    pub largest_gaps: Vec<DataGap>,
+    /// Jours sous le seuil de couverture demandé
+    pub low_coverage_days: Vec<DayCoverage>,
+}
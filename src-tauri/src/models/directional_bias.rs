@@ -0,0 +1,97 @@
+// models/directional_bias.rs - Sens du mouvement après annonce selon le signe de la surprise
+// Conforme .clinerules : structures uniquement, pas de logique métier
+
+use crate::models::ConfidenceInterval;
+use serde::{Deserialize, Serialize};
+
+/// Position de la devise de l'annonce dans la paire
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CurrencySide {
+    /// EUR dans EURUSD : devise renforcée → paire en hausse
+    Base,
+    /// USD dans EURUSD : devise renforcée → paire en baisse
+    Quote,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SurpriseSign {
+    /// actual > forecast
+    Positive,
+    /// actual < forecast
+    Negative,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MoveDirection {
+    Up,
+    Down,
+}
+
+/// Type d'entrée conseillé pour une annonce et un signe de surprise
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EntryMode {
+    /// Stop unique dans le sens attendu : biais confirmé à 5 minutes après correction
+    /// des tests multiples
+    OneSided,
+    /// Straddle symétrique : sens non prévisible
+    Straddle,
+}
+
+/// Sens réalisé à un horizon (clôture T0 + n min vs ouverture T0)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HorizonHitRate {
+    pub minutes: u32,
+    pub sample_size: usize,
+    /// Part des occurrences en hausse
+    pub up_rate: f64,
+    /// Part des occurrences dans le sens théorique (None sans sens théorique, p. ex. un indice,
+    /// ou sans bougie à cet horizon)
+    pub hit_rate: Option<f64>,
+    pub hit_rate_ci: Option<ConfidenceInterval>,
+    /// Mouvement moyen (pips, positif = hausse)
+    pub avg_move_pips: f64,
+}
+
+/// Ligne du tableau "sens attendu selon la surprise"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectionalBiasRow {
+    pub event_id: String,
+    pub event_type: String,
+    pub currency: String,
+    pub side: CurrencySide,
+    pub surprise_sign: SurpriseSign,
+    /// Sens déduit de la devise : chiffre fort → devise renforcée (inversé pour chômage).
+    /// None pour un indice : sa devise de cotation ne dit rien de son sens
+    pub theoretical_direction: Option<MoveDirection>,
+    /// Sens majoritaire observé à 5 minutes
+    pub expected_direction: MoveDirection,
+    /// Part des occurrences dans `expected_direction` à 5 minutes
+    pub bias_strength: f64,
+    pub bias_strength_ci: ConfidenceInterval,
+    /// p-value bilatérale du test de signe à 5 min, ajustée Benjamini-Hochberg (toutes lignes)
+    pub adjusted_p_value: f64,
+    pub entry_mode: EntryMode,
+    pub sample_size: usize,
+    /// Horizons 1, 5 et 15 minutes
+    pub horizons: Vec<HorizonHitRate>,
+}
+
+/// Biais directionnel d'une paire sur les annonces de ses deux devises
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DirectionalBiasResult {
+    pub pair: String,
+    pub base_currency: Option<String>,
+    pub quote_currency: String,
+    /// Occurrences avec actual, forecast et surprise non nulle
+    pub event_count: usize,
+    /// Occurrences avec bougies M1 autour de T0
+    pub analyzed_count: usize,
+    /// Taux de réussite du sens théorique, toutes annonces confondues
+    pub overall: Vec<HorizonHitRate>,
+    /// Une ligne par annonce et signe de surprise, biais le plus fort en premier
+    pub rows: Vec<DirectionalBiasRow>,
+}
@@ -1,6 +1,9 @@
 // models/entry_analysis.rs — Modèle de résultat d'analyse de point d'entrée straddle
 // Remplace l'ancien scoring heuristique par des métriques basées sur le profit net réel.
 
+use crate::models::pre_release::{PreReleaseAdvice, PreReleaseProfile};
+use crate::models::release_seconds::SecondDetail;
+use crate::models::ConfidenceInterval;
 use serde::{Deserialize, Serialize};
 
 /// Configuration de l'analyse de point d'entrée
@@ -29,12 +32,31 @@ impl Default for EntryAnalysisConfig {
 pub struct MinuteDetail {
     pub offset: u8,
     pub win_rate: f64,
+    /// Bornes bootstrap 5% / 95% du win rate
+    pub win_rate_ci: ConfidenceInterval,
     pub avg_net_profit_pips: f64,
+    pub avg_net_profit_ci: ConfidenceInterval,
     pub avg_spread_pips: f64,
+    pub avg_spread_ci: ConfidenceInterval,
     pub sample_size: usize,
     pub tradable: bool,
 }
 
+/// Offset choisi en walk-forward : meilleur offset des jours précédents appliqué aux suivants
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EntryWalkForward {
+    /// Fenêtres in-sample ayant retenu un offset
+    pub windows: usize,
+    /// Offset le plus souvent retenu in-sample
+    pub most_selected_offset_minutes: u8,
+    /// Nombre de jours out-of-sample simulés avec l'offset retenu
+    pub out_of_sample_sample_size: usize,
+    pub out_of_sample_win_rate: f64,
+    /// Profit net moyen hors échantillon (après spread)
+    pub out_of_sample_avg_net_profit_pips: f64,
+    pub out_of_sample_avg_net_profit_ci: ConfidenceInterval,
+}
+
 /// Résultat complet d'analyse d'entrée pour un symbol × event_type
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct EntryAnalysisResult {
@@ -46,10 +68,14 @@ pub struct EntryAnalysisResult {
     pub optimal_entry_time_label: String,
     /// Win rate réel = count(profit > 0) / count(total)
     pub real_win_rate: f64,
+    /// Bornes bootstrap 5% / 95% du win rate réel
+    pub real_win_rate_ci: ConfidenceInterval,
     /// Profit net moyen en pips (après spread)
     pub avg_net_profit_pips: f64,
+    pub avg_net_profit_ci: ConfidenceInterval,
     /// Spread moyen à l'entrée en pips
     pub avg_spread_at_entry_pips: f64,
+    pub avg_spread_at_entry_ci: ConfidenceInterval,
     /// Mouvement brut moyen en pips (avant spread)
     pub avg_movement_pips: f64,
     /// Minute forward où le mouvement atteint son pic
@@ -68,4 +94,23 @@ pub struct EntryAnalysisResult {
     pub minute_details: Vec<MinuteDetail>,
     /// Unité d'affichage (pips, $, pts)
     pub unit: String,
+    /// Offset optimal en secondes après le début du quarter (barres tick). None sans ticks.
+    #[serde(default)]
+    pub optimal_offset_seconds: Option<u32>,
+    /// Détail par offset en secondes (vide sans ticks stockés)
+    #[serde(default)]
+    pub second_details: Vec<SecondDetail>,
+    /// Jours exclus pour couverture M1 insuffisante
+    #[serde(default)]
+    pub excluded_days: usize,
+    /// Dérive, compression d'ATR et spread de T-60 à T-5 avant les annonces du quarter
+    #[serde(default)]
+    pub pre_release: Option<PreReleaseProfile>,
+    /// Conseil pré-annonce répercuté sur l'entrée (heure avancée ou straddle déconseillé)
+    #[serde(default)]
+    pub entry_advice: PreReleaseAdvice,
+    /// Performance hors échantillon de l'offset choisi en walk-forward (l'offset optimal
+    /// ci-dessus est ajusté sur tout l'historique). None sans assez de jours.
+    #[serde(default)]
+    pub walk_forward: Option<EntryWalkForward>,
 }
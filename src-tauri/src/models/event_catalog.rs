@@ -0,0 +1,107 @@
+// models/event_catalog.rs - Catalogue canonique des annonces économiques
+// Conforme .clinerules : structures uniquement, pas de logique métier
+
+use serde::{Deserialize, Serialize};
+
+/// Famille d'annonce
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventCategory {
+    Employment,
+    Inflation,
+    Rates,
+    Pmi,
+    Speech,
+    Growth,
+    Consumption,
+    Other,
+}
+
+impl EventCategory {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventCategory::Employment => "employment",
+            EventCategory::Inflation => "inflation",
+            EventCategory::Rates => "rates",
+            EventCategory::Pmi => "pmi",
+            EventCategory::Speech => "speech",
+            EventCategory::Growth => "growth",
+            EventCategory::Consumption => "consumption",
+            EventCategory::Other => "other",
+        }
+    }
+
+    /// Valeur stockée en base ; inconnue = Other
+    pub fn parse(value: &str) -> Self {
+        match value {
+            "employment" => EventCategory::Employment,
+            "inflation" => EventCategory::Inflation,
+            "rates" => EventCategory::Rates,
+            "pmi" => EventCategory::Pmi,
+            "speech" => EventCategory::Speech,
+            "growth" => EventCategory::Growth,
+            "consumption" => EventCategory::Consumption,
+            _ => EventCategory::Other,
+        }
+    }
+}
+
+/// Comparaison d'un intitulé brut avec un alias
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventAliasKind {
+    /// Intitulé identique une fois normalisé (casse, ponctuation, période "(Dec)")
+    Exact,
+    /// Mots majoritairement communs ("Fed Chair Powell Speaks" ~ "Fed Chair Speaks")
+    Fuzzy,
+}
+
+impl EventAliasKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            EventAliasKind::Exact => "exact",
+            EventAliasKind::Fuzzy => "fuzzy",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "exact" => Some(EventAliasKind::Exact),
+            "fuzzy" => Some(EventAliasKind::Fuzzy),
+            _ => None,
+        }
+    }
+}
+
+/// Intitulé rencontré selon la source ou l'année
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventAlias {
+    pub alias: String,
+    pub kind: EventAliasKind,
+}
+
+/// Annonce canonique : toutes ses variantes d'intitulé partagent `id`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CanonicalEvent {
+    /// Identifiant stable ("usd_nfp"), stocké dans calendar_events.event_id
+    pub id: String,
+    /// Libellé affiché et regroupé dans les analyses
+    pub name: String,
+    pub country: String,
+    /// Devise des lignes du calendrier (calendar_events.symbol)
+    pub currency: String,
+    pub category: EventCategory,
+    /// "H", "M" ou "L"
+    pub default_impact: String,
+    pub aliases: Vec<EventAlias>,
+}
+
+/// Rattachement d'un intitulé brut au catalogue
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct EventResolution {
+    pub event_id: String,
+    pub name: String,
+    /// false : intitulé hors catalogue, regroupé sur sa forme normalisée
+    pub catalogued: bool,
+    pub category: Option<EventCategory>,
+}
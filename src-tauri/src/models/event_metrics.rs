@@ -1,6 +1,7 @@
 // models/event_metrics.rs - Métriques événementielles pour optimisation EA Straddle
 // Conforme .clinerules : < 150L, structures uniquement, pas de logique métier
 
+use crate::models::ConfidenceInterval;
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 
@@ -18,6 +19,10 @@ pub struct EventMetrics {
     pub win_rate: f64,
     pub loss_rate: f64,
     pub whipsaw_rate: f64,
+    /// Bornes bootstrap 5% / 95% des taux ci-dessus
+    pub win_rate_ci: ConfidenceInterval,
+    pub loss_rate_ci: ConfidenceInterval,
+    pub whipsaw_rate_ci: ConfidenceInterval,
     pub risk_reward_ratio: f64,
     pub best_entry_minutes_before: i32,
     pub best_entry_win_rate: f64,
@@ -0,0 +1,101 @@
+// models/excursion.rs - Excursions favorables/adverses (MFE/MAE) des deux jambes du straddle
+// Conforme .clinerules : structures uniquement, pas de logique métier
+
+use serde::{Deserialize, Serialize};
+
+/// Excursions d'une jambe déclenchée, mesurées depuis son prix d'entrée
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct LegExcursion {
+    /// Minutes après T0 du déclenchement
+    pub trigger_minute: u32,
+    /// Maximum favorable excursion (pips)
+    pub mfe_pips: f64,
+    /// Maximum adverse excursion (pips)
+    pub mae_pips: f64,
+}
+
+/// Excursions d'une occurrence à un horizon. None : jambe non déclenchée avant l'horizon
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HorizonExcursion {
+    pub horizon_minutes: u32,
+    pub long: Option<LegExcursion>,
+    pub short: Option<LegExcursion>,
+}
+
+/// Excursions d'une occurrence historique
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OccurrenceExcursion {
+    /// ISO 8601 UTC
+    pub event_time: String,
+    pub horizons: Vec<HorizonExcursion>,
+}
+
+/// Classe d'histogramme [lower_pips, upper_pips)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HistogramBin {
+    pub lower_pips: f64,
+    pub upper_pips: f64,
+    pub count: usize,
+}
+
+/// Distribution complète d'une excursion (pips)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExcursionDistribution {
+    pub count: usize,
+    pub mean: f64,
+    pub p25: f64,
+    pub p50: f64,
+    pub p75: f64,
+    pub p90: f64,
+    pub p95: f64,
+    pub max: f64,
+    pub histogram: Vec<HistogramBin>,
+}
+
+/// MFE et MAE d'une jambe sur ses déclenchements
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LegDistribution {
+    pub triggered_count: usize,
+    pub mfe: ExcursionDistribution,
+    pub mae: ExcursionDistribution,
+}
+
+/// SL/TP déduits des percentiles MAE/MFE des deux jambes réunies
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ExcursionStops {
+    pub stop_loss_pips: f64,
+    pub take_profit_pips: f64,
+    /// Part des déclenchements dont la MAE reste sous le SL
+    pub sl_survival_rate: f64,
+    /// Part des déclenchements dont la MFE atteint le TP
+    pub tp_reach_rate: f64,
+}
+
+/// Distributions à un horizon après T0
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HorizonDistribution {
+    pub horizon_minutes: u32,
+    /// None si aucune jambe de ce côté ne s'est déclenchée
+    pub long: Option<LegDistribution>,
+    pub short: Option<LegDistribution>,
+    pub stops: Option<ExcursionStops>,
+}
+
+/// Étude MFE/MAE d'une annonce sur une paire
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExcursionStudyResult {
+    pub pair: String,
+    pub event_type: String,
+    /// Distance des ordres stop au prix de référence (pips)
+    pub straddle_distance_pips: f64,
+    /// Percentile de MAE retenu pour le SL
+    pub sl_percentile: f64,
+    /// Percentile de MFE retenu pour le TP
+    pub tp_percentile: f64,
+    /// Occurrences de l'annonce chargées
+    pub event_count: usize,
+    /// Occurrences avec bougies M1 autour de T0
+    pub analyzed_count: usize,
+    pub horizons: Vec<HorizonDistribution>,
+    pub occurrences: Vec<OccurrenceExcursion>,
+}
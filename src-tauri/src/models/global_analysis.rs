@@ -1,4 +1,5 @@
 // models/global_analysis.rs - Structures pour l'analyse globale (IA Statistique)
+use crate::models::ConfidenceInterval;
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,9 +34,12 @@ pub struct GlobalStats {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BestPair {
     pub symbol: String,
-    pub score: f64, // Score composite (Volatilité * Confiance)
+    pub score: f64,                   // Score composite (Volatilité * Confiance)
+    pub score_ci: ConfidenceInterval, // Bornes bootstrap 5% / 95%
     pub avg_volatility: f64,
+    pub avg_volatility_ci: ConfidenceInterval,
     pub win_rate: f64, // Basé sur la qualité des mouvements
+    pub win_rate_ci: ConfidenceInterval,
     pub analysis_count: usize,
 }
 
@@ -44,7 +48,9 @@ pub struct GoldenHour {
     pub hour: u8,
     pub score: f64, // Fréquence d'apparition dans "best_hours"
     pub avg_volatility: f64,
-    pub reliability: f64, // % de fois où cette heure est positive
+    pub avg_volatility_ci: ConfidenceInterval, // Bornes bootstrap 5% / 95%
+    pub reliability: f64,                      // % de fois où cette heure est positive
+    pub reliability_ci: ConfidenceInterval,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -1,5 +1,6 @@
 // models/hourly_stats.rs - Statistiques par heure UTC
 use super::hourly_stats_thresholds::*;
+use super::RangeVolatility;
 use serde::{Deserialize, Serialize};
 
 /// Événement économique dans une heure spécifique
@@ -26,6 +27,9 @@ pub struct HourlyStats {
     pub volume_imbalance_mean: f64,
     pub noise_ratio_mean: f64,
     pub breakout_percentage: f64,
+    /// Estimateurs de volatilité par range (% par bougie M1)
+    #[serde(default)]
+    pub range_volatility: RangeVolatility,
     pub events: Vec<EventInHour>,
 }
 
@@ -2,7 +2,7 @@
 #[cfg(test)]
 #[allow(clippy::module_inception)]
 mod tests {
-    use crate::models::HourlyStats;
+    use crate::models::{HourlyStats, RangeVolatility};
 
     #[test]
     fn test_quality_score_calculation() {
@@ -19,6 +19,7 @@ mod tests {
             volume_imbalance_mean: 0.15,
             noise_ratio_mean: 1.8,
             breakout_percentage: 25.0,
+            range_volatility: RangeVolatility::default(),
             events: Vec::new(),
         };
 
@@ -42,6 +43,7 @@ mod tests {
             volume_imbalance_mean: 0.0,
             noise_ratio_mean: 0.0,
             breakout_percentage: 0.0,
+            range_volatility: RangeVolatility::default(),
             events: Vec::new(),
         };
 
@@ -63,6 +65,7 @@ mod tests {
             volume_imbalance_mean: 0.2,
             noise_ratio_mean: 1.5,
             breakout_percentage: 30.0,
+            range_volatility: RangeVolatility::default(),
             events: Vec::new(),
         };
 
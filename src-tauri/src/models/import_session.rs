@@ -0,0 +1,92 @@
+// models/import_session.rs - Sessions d'import (historique, inspection, annulation)
+// Conforme .clinerules : structures uniquement, pas de logique métier
+
+use serde::{Deserialize, Serialize};
+
+/// Base concernée par une session d'import
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportKind {
+    /// Bougies (et ticks) de pairs.db, session = ligne d'import_log
+    Candles,
+    /// Événements de volatility.db, session = ligne de calendar_import_log
+    Calendar,
+}
+
+/// Conduite à tenir quand le fichier a déjà été importé
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ReimportMode {
+    /// Retire l'import précédent du fichier, puis importe (écrase les lignes en place)
+    Replace,
+    /// Conserve les lignes en place, n'ajoute que les lignes absentes
+    Merge,
+}
+
+impl ReimportMode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReimportMode::Replace => "replace",
+            ReimportMode::Merge => "merge",
+        }
+    }
+}
+
+/// Session d'import listée dans l'historique
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportSession {
+    pub kind: ImportKind,
+    pub id: i64,
+    pub filename: String,
+    /// Paire (bougies) ou nom du calendrier
+    pub target: String,
+    /// Timeframe des bougies (None pour un calendrier)
+    pub timeframe: Option<String>,
+    pub imported_at: String,
+    /// "new", "replace" ou "merge" (None pour les imports antérieurs aux sessions)
+    pub mode: Option<String>,
+    /// "success", "confirmed" (sauvegardes purgées), "replaced", "rolled_back" ou "deleted"
+    pub status: String,
+    /// Lignes écrites par l'import
+    pub row_count: i64,
+    /// Lignes encore en base portant l'identifiant de la session
+    pub live_rows: i64,
+    pub source_timezone: Option<String>,
+}
+
+/// Détail d'une session : plage couverte et effet d'une annulation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportSessionDetail {
+    pub session: ImportSession,
+    pub first_time: Option<String>,
+    pub last_time: Option<String>,
+    /// Ticks bruts portant l'identifiant de la session
+    pub tick_rows: i64,
+    /// Lignes d'autres imports écrasées par celui-ci, restaurées à l'annulation
+    pub displaced_rows: i64,
+    /// Ticks d'autres imports purgés par celui-ci, restaurés à l'annulation
+    pub displaced_ticks: i64,
+    /// false si la session a déjà été validée, remplacée, annulée ou supprimée
+    pub can_rollback: bool,
+}
+
+/// Bilan d'une validation de sessions (sauvegardes des lignes écrasées purgées)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BackupPurgeResult {
+    pub sessions_confirmed: usize,
+    pub rows_purged: usize,
+    pub ticks_purged: usize,
+}
+
+/// Bilan de l'annulation d'une session
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RollbackResult {
+    pub kind: ImportKind,
+    pub id: i64,
+    pub rows_deleted: usize,
+    /// Lignes d'imports antérieurs remises en place
+    pub rows_restored: usize,
+    pub ticks_deleted: usize,
+    /// Ticks d'imports antérieurs remis en place
+    pub ticks_restored: usize,
+}
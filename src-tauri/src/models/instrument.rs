@@ -0,0 +1,46 @@
+// models/instrument.rs - Fiche instrument du registre (spécifications de contrat)
+// Conforme .clinerules : structures uniquement, pas de logique métier
+
+use crate::models::asset_class::{AssetProperties, AssetType};
+use crate::models::trading_costs::TradingCostProfile;
+use serde::{Deserialize, Serialize};
+
+/// Instrument du registre `instruments` (pairs.db), sous son symbole canonique
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Instrument {
+    pub symbol: String,
+    pub display_name: String,
+    /// Catégorie affichée (ex: "Forex Major", "Indice")
+    pub category: String,
+    pub asset_class: AssetType,
+    /// Décimales du prix coté (5 pour EURUSD, 3 pour USDJPY)
+    pub digits: i32,
+    /// Taille d'un pip/point d'analyse en prix (0.0001 pour EURUSD, 1.0 pour un indice)
+    pub pip_size: f64,
+    /// Unité affichée : "pips", "points" ou "$"
+    pub pip_unit: String,
+    pub display_digits: i32,
+    /// Unités de l'actif sous-jacent par contrat (100 000 pour un lot forex)
+    pub contract_size: f64,
+    /// Valeur d'un pip pour un contrat, en devise de cotation
+    pub point_value: f64,
+    pub quote_currency: String,
+    /// Plage de cotation (ex: "24/5", "24/7", "23/5")
+    pub trading_hours: String,
+    /// Disponible au téléchargement sur le CDN Dukascopy
+    pub dukascopy: bool,
+    /// Coûts de trading de repli (M1 sans données tick)
+    pub costs: TradingCostProfile,
+}
+
+impl Instrument {
+    /// Propriétés d'analyse (normalisation en pips/points) de l'instrument
+    pub fn asset_properties(&self) -> AssetProperties {
+        AssetProperties {
+            asset_type: self.asset_class,
+            pip_value: self.pip_size,
+            unit: self.pip_unit.clone(),
+            display_digits: self.display_digits.max(0) as usize,
+        }
+    }
+}
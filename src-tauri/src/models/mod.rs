@@ -3,25 +3,41 @@
 
 pub mod analysis_result;
 pub mod archive;
+pub mod backtest;
 pub mod calendar_event;
+pub mod calendar_mapping;
 pub mod candle;
 pub mod confidence_breakdown;
+pub mod confidence_interval;
+pub mod data_quality;
+pub mod directional_bias;
 pub mod entry_analysis;
 pub mod entry_window_analysis;
 pub mod errors;
+pub mod event_catalog;
 pub mod event_metrics;
 pub mod event_movement_quality;
+pub mod excursion;
 pub mod global_analysis;
 pub mod hourly_stats;
 pub mod hourly_stats_thresholds;
+pub mod import_session;
+pub mod instrument;
 pub mod metric_unit;
+pub mod significance;
 pub mod stats_15min;
 pub mod trading_recommendation;
 pub mod volatility_duration;
 pub mod volatility_profile;
+pub mod walk_forward;
 pub mod asset_class;
 pub mod planning;
+pub mod pre_release;
+pub mod range_volatility;
+pub mod release_seconds;
+pub mod surprise_analysis;
 pub mod symbol_conversion;
+pub mod timezone_detection;
 pub mod trading_costs;
 
 
@@ -31,6 +47,7 @@ pub use archive::Archive;
 pub use calendar_event::CalendarEvent;
 pub use candle::Candle;
 pub use confidence_breakdown::ConfidenceBreakdown;
+pub use confidence_interval::ConfidenceInterval;
 pub use metric_unit::{MetricUnit, Metric};
 // pub use entry_analysis::{EntryAnalysisConfig, EntryAnalysisResult, MinuteDetail};
 pub use entry_window_analysis::{EntryOffsetMetrics, EntryWindowAnalysisResult};
@@ -39,6 +56,7 @@ pub use event_metrics::EventMetrics;
 pub use event_movement_quality::EventMovementQuality;
 pub use global_analysis::*;
 pub use hourly_stats::{EventInHour, HourlyStats};
+pub use range_volatility::{RangeVolatility, VolatilityEstimator};
 pub use stats_15min::Stats15Min;
 pub use trading_recommendation::{RiskLevel, TradingRecommendation};
 pub use volatility_duration::VolatilityDuration;
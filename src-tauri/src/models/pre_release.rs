@@ -0,0 +1,69 @@
+// models/pre_release.rs - Comportement du prix entre T-60 et T-5 avant une annonce
+// Conforme .clinerules : structures uniquement, pas de logique métier
+
+use crate::models::ConfidenceInterval;
+use serde::{Deserialize, Serialize};
+
+/// Conséquence pour le placement du straddle
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PreReleaseAdvice {
+    /// Pas de mouvement anticipé : placement habituel
+    #[default]
+    Normal,
+    /// Le mouvement démarre avant T0 : placer le straddle avant `onset_minutes_before`
+    EnterEarlier,
+    /// Une part importante du mouvement est faite avant T-5 : straddle déconseillé
+    SkipStraddle,
+}
+
+/// Étude pré-annonce agrégée sur les occurrences d'une annonce (ou d'un quarter)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreReleaseProfile {
+    /// Occurrences avec bougies M1 de T-60 à T+15
+    pub event_count: usize,
+    /// Fenêtres de référence : même heure, jours sans annonce
+    #[serde(default)]
+    pub reference_count: usize,
+    /// Dérive moyenne |clôture T-5 - ouverture T-60| (pips)
+    pub avg_drift_pips: f64,
+    /// Dérive moyenne de la même fenêtre les jours sans annonce (pips)
+    #[serde(default)]
+    pub reference_drift_pips: f64,
+    pub avg_drift_ci: ConfidenceInterval,
+    /// Mouvement moyen |clôture T+15 - ouverture T0| (pips)
+    pub avg_post_move_pips: f64,
+    /// Dérive rapportée au mouvement post-annonce
+    pub drift_share: f64,
+    /// Part des occurrences où la dérive a le sens du mouvement post-annonce
+    pub aligned_drift_rate: f64,
+    pub aligned_drift_rate_ci: ConfidenceInterval,
+    /// ATR M1 de T-15 à T-5 / ATR M1 de T-60 à T-15 (< 1 : compression)
+    pub atr_compression: f64,
+    /// Spread moyen de T-15 à T-5 / spread moyen de T-60 à T-15. None sans spreads stockés
+    pub spread_widening: Option<f64>,
+    /// Première minute avant T0 à partir de laquelle l'ATR reste au-dessus de celui
+    /// des jours sans annonce à la même minute
+    pub onset_minutes_before: Option<u32>,
+    /// Le mouvement tend à commencer avant l'annonce
+    pub early_move: bool,
+    pub advice: PreReleaseAdvice,
+    /// True range moyen par minute, de T-60 à T-6 (pips)
+    pub atr_profile_pips: Vec<f64>,
+}
+
+/// Ligne du balayage par annonce
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreReleaseEventRow {
+    pub event_id: String,
+    pub event_type: String,
+    pub currency: String,
+    pub profile: PreReleaseProfile,
+}
+
+/// Balayage des annonces d'une paire, annonces à mouvement anticipé en premier
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PreReleaseScanResult {
+    pub pair: String,
+    pub rows: Vec<PreReleaseEventRow>,
+}
@@ -0,0 +1,30 @@
+// models/range_volatility.rs - Volatilité réalisée par estimateurs de range (OHLC M1)
+// Conforme .clinerules : structures uniquement, pas de logique métier
+
+use serde::{Deserialize, Serialize};
+
+/// Estimateur de volatilité retenu pour une analyse
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum VolatilityEstimator {
+    /// Écart-type des rendements close-to-close (comportement historique)
+    #[default]
+    CloseToClose,
+    Parkinson,
+    GarmanKlass,
+    RogersSatchell,
+    YangZhang,
+}
+
+/// Volatilité par bougie, en % (écart-type de rendement log × 100)
+/// Insensible au niveau de prix : comparable d'une paire à l'autre.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct RangeVolatility {
+    /// High/Low uniquement
+    pub parkinson: f64,
+    /// High/Low + Open/Close
+    pub garman_klass: f64,
+    /// Robuste à la tendance intra-bougie
+    pub rogers_satchell: f64,
+    /// Intègre les sauts d'ouverture entre bougies contiguës
+    pub yang_zhang: f64,
+}
@@ -0,0 +1,57 @@
+// models/release_seconds.rs - Métriques sub-minute autour de l'annonce (barres S1/S5/S10)
+// Conforme .clinerules : structures uniquement, pas de logique métier
+
+use crate::models::ConfidenceInterval;
+use serde::{Deserialize, Serialize};
+
+/// Résolution des barres construites depuis les ticks
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum SecondBarSize {
+    #[default]
+    S1,
+    S5,
+    S10,
+}
+
+impl SecondBarSize {
+    pub fn seconds(self) -> i64 {
+        match self {
+            SecondBarSize::S1 => 1,
+            SecondBarSize::S5 => 5,
+            SecondBarSize::S10 => 10,
+        }
+    }
+}
+
+/// Profil moyen des premières secondes après l'annonce (occurrences avec ticks)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReleaseSecondsProfile {
+    pub bar_size: SecondBarSize,
+    /// Occurrences disposant de ticks stockés
+    pub event_count: usize,
+    /// Délai moyen (s) entre T0 et la barre de plus grand range
+    pub peak_delay_seconds: f64,
+    /// Range moyen de la barre pic (pips)
+    pub peak_range_pips: f64,
+    /// Délai moyen (s) avant une excursion supérieure à 2× le spread pré-annonce
+    pub first_move_seconds: Option<f64>,
+    /// Délai moyen (s) avant retour du spread à 1.5× son niveau pré-annonce
+    pub spread_normalization_seconds: Option<f64>,
+    /// Range moyen par barre depuis T0 (pips)
+    pub range_profile_pips: Vec<f64>,
+    /// Spread moyen par barre depuis T0 (pips)
+    pub spread_profile_pips: Vec<f64>,
+}
+
+/// Straddle simulé à T0 + offset_seconds
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SecondDetail {
+    pub offset_seconds: u32,
+    pub win_rate: f64,
+    pub win_rate_ci: ConfidenceInterval,
+    pub avg_net_profit_pips: f64,
+    pub avg_net_profit_ci: ConfidenceInterval,
+    pub avg_spread_pips: f64,
+    pub sample_size: usize,
+    pub tradable: bool,
+}
@@ -0,0 +1,48 @@
+// models/significance.rs - Test statistique fenêtre événement vs baseline
+// Conforme .clinerules : structures uniquement, pas de logique métier
+
+use serde::{Deserialize, Serialize};
+
+/// Comparaison de la volatilité d'une fenêtre événement à sa baseline
+/// (Mann-Whitney U + Welch t, p-values bilatérales)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SignificanceResult {
+    pub event_sample_size: usize,
+    pub baseline_sample_size: usize,
+    pub event_mean: f64,
+    pub baseline_mean: f64,
+    /// Statistique U de l'échantillon événement
+    pub mann_whitney_u: f64,
+    /// p-value Mann-Whitney (approximation normale, correction des ex-aequo)
+    pub mann_whitney_p_value: f64,
+    pub welch_t: f64,
+    /// Degrés de liberté Welch-Satterthwaite
+    pub welch_df: f64,
+    pub welch_p_value: f64,
+    /// Écart des moyennes / écart-type poolé (>0 = événement plus volatil)
+    pub cohens_d: f64,
+    /// Corrélation rang-bisériale dans [-1, 1] (>0 = événement plus volatil)
+    pub rank_biserial: f64,
+    /// p-value Mann-Whitney corrigée Benjamini-Hochberg. None hors test multiple.
+    pub adjusted_p_value: Option<f64>,
+}
+
+/// Test apparié occurrence par occurrence : (événement, baseline de la même occurrence)
+/// (Wilcoxon signé des rangs sur les différences, p-value bilatérale)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PairedSignificanceResult {
+    /// Paires (occurrences) testées
+    pub sample_size: usize,
+    pub event_mean: f64,
+    pub baseline_mean: f64,
+    /// Moyenne des différences événement - baseline
+    pub mean_difference: f64,
+    /// Somme des rangs des différences positives (W+)
+    pub wilcoxon_w: f64,
+    /// p-value Wilcoxon (approximation normale, correction des ex-aequo et de continuité)
+    pub wilcoxon_p_value: f64,
+    /// Rang-bisériale apparié dans [-1, 1] (>0 = événement plus volatil)
+    pub rank_biserial: f64,
+    /// p-value Wilcoxon corrigée Benjamini-Hochberg. None hors test multiple.
+    pub adjusted_p_value: Option<f64>,
+}
@@ -1,5 +1,6 @@
 // models/stats_15min.rs - Statistiques par 15 minutes (pour scalping)
 use crate::models::EventInHour;
+use crate::models::RangeVolatility;
 use crate::models::hourly_stats_thresholds::*;
 use serde::{Deserialize, Serialize};
 
@@ -20,6 +21,9 @@ pub struct Stats15Min {
     pub volume_imbalance_mean: f64,
     pub noise_ratio_mean: f64,
     pub breakout_percentage: f64,
+    /// Estimateurs de volatilité par range (% par bougie M1)
+    #[serde(default)]
+    pub range_volatility: RangeVolatility,
     pub events: Vec<EventInHour>,
     // Analyse de décroissance de volatilité (TÂCHE 4) - par jour
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -2,7 +2,7 @@
 #[cfg(test)]
 #[allow(clippy::module_inception)]
 mod tests {
-    use crate::models::Stats15Min;
+    use crate::models::{RangeVolatility, Stats15Min};
 
     #[test]
     fn test_time_label() {
@@ -19,6 +19,7 @@ mod tests {
             volume_imbalance_mean: 0.0,
             noise_ratio_mean: 0.0,
             breakout_percentage: 0.0,
+            range_volatility: RangeVolatility::default(),
             events: Vec::new(),
             peak_duration_minutes: None,
             volatility_half_life_minutes: None,
@@ -56,6 +57,7 @@ mod tests {
             volume_imbalance_mean: 0.15,
             noise_ratio_mean: 1.8,
             breakout_percentage: 25.0,
+            range_volatility: RangeVolatility::default(),
             events: Vec::new(),
             peak_duration_minutes: None,
             volatility_half_life_minutes: None,
@@ -89,6 +91,7 @@ mod tests {
             volume_imbalance_mean: 0.0,
             noise_ratio_mean: 0.0,
             breakout_percentage: 0.0,
+            range_volatility: RangeVolatility::default(),
             events: Vec::new(),
             peak_duration_minutes: None,
             volatility_half_life_minutes: None,
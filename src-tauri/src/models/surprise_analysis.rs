@@ -0,0 +1,74 @@
+// models/surprise_analysis.rs - Réaction du marché selon la surprise (actual - forecast)
+// Conforme .clinerules : structures uniquement, pas de logique métier
+
+use crate::models::ConfidenceInterval;
+use serde::{Deserialize, Serialize};
+
+/// Classe de surprise d'une occurrence : sens de actual - forecast, taille selon |score|
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SurpriseBucket {
+    /// Chiffre inférieur au consensus, |score| >= seuil
+    BigNegative,
+    SmallNegative,
+    /// actual == forecast
+    InLine,
+    SmallPositive,
+    /// Chiffre supérieur au consensus, |score| >= seuil
+    BigPositive,
+}
+
+/// Réaction mesurée sur une occurrence (bougies M1 depuis T0)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SurpriseEventReaction {
+    /// ISO 8601 UTC
+    pub event_time: String,
+    pub actual: f64,
+    pub forecast: f64,
+    /// actual - forecast
+    pub surprise: f64,
+    /// (surprise - moyenne) / écart-type des surprises antérieures de l'annonce
+    pub surprise_score: f64,
+    /// Écart-type des surprises antérieures ayant servi au score (unité du chiffre publié)
+    pub surprise_std: f64,
+    pub bucket: SurpriseBucket,
+    /// Range (plus haut - plus bas) entre T0 et T0 + horizon (pips)
+    pub reaction_pips: f64,
+    /// Clôture à T0 + horizon - ouverture à T0 (pips, positif = hausse)
+    pub move_pips: f64,
+    /// Les deux côtés de l'ouverture T0 dépassés d'au moins le seuil de whipsaw
+    pub whipsaw: bool,
+}
+
+/// Statistiques d'une classe de surprise
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SurpriseBucketStats {
+    pub bucket: SurpriseBucket,
+    pub event_count: usize,
+    pub avg_surprise_score: f64,
+    pub avg_reaction_pips: f64,
+    pub avg_reaction_ci: ConfidenceInterval,
+    pub avg_move_pips: f64,
+    /// Part des occurrences clôturant en hausse à l'horizon
+    pub up_rate: f64,
+    pub up_rate_ci: ConfidenceInterval,
+    pub whipsaw_rate: f64,
+    pub whipsaw_rate_ci: ConfidenceInterval,
+}
+
+/// Étude d'événement découpée par taille et signe de la surprise
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SurpriseAnalysisResult {
+    pub event_type: String,
+    pub pair: String,
+    pub horizon_minutes: u32,
+    /// |score| à partir duquel une surprise est "grande"
+    pub big_surprise_score: f64,
+    /// Occurrences de l'annonce chargées
+    pub event_count: usize,
+    /// Occurrences avec actual et forecast et bougies autour de T0
+    pub analyzed_count: usize,
+    /// Classes non vides, de la plus négative à la plus positive
+    pub buckets: Vec<SurpriseBucketStats>,
+    pub reactions: Vec<SurpriseEventReaction>,
+}
@@ -13,3 +13,54 @@ pub struct SymbolConversion {
     #[serde(default)]
     pub hidden: bool,
 }
+
+/// Type de règle d'alias de symbole
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AliasMatchKind {
+    /// Symbole identique au motif
+    Exact,
+    /// Symbole commençant par le motif (retiré si `canonical` est vide)
+    Prefix,
+    /// Symbole finissant par le motif (retiré si `canonical` est vide)
+    Suffix,
+    /// Expression régulière, `canonical` peut reprendre les groupes ($1)
+    Regex,
+}
+
+impl AliasMatchKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AliasMatchKind::Exact => "exact",
+            AliasMatchKind::Prefix => "prefix",
+            AliasMatchKind::Suffix => "suffix",
+            AliasMatchKind::Regex => "regex",
+        }
+    }
+
+    pub fn parse(value: &str) -> Option<Self> {
+        match value {
+            "exact" => Some(AliasMatchKind::Exact),
+            "prefix" => Some(AliasMatchKind::Prefix),
+            "suffix" => Some(AliasMatchKind::Suffix),
+            "regex" => Some(AliasMatchKind::Regex),
+            _ => None,
+        }
+    }
+}
+
+/// Règle d'alias stockée en DB : ramène un symbole broker à son symbole canonique
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SymbolAlias {
+    /// None pour une nouvelle règle
+    #[serde(default)]
+    pub id: Option<i32>,
+    pub pattern: String,
+    pub match_kind: AliasMatchKind,
+    /// Symbole canonique (vide = simple retrait du préfixe/suffixe)
+    #[serde(default)]
+    pub canonical: String,
+    /// Règles appliquées par priorité croissante
+    #[serde(default)]
+    pub priority: i32,
+}
@@ -0,0 +1,45 @@
+// models/timezone_detection.rs - Détection du fuseau source d'une paire importée
+// Conforme .clinerules : structures uniquement, pas de logique métier
+
+use serde::{Deserialize, Serialize};
+
+/// Score d'un fuseau candidat sur les annonces à fort impact
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimezoneCandidate {
+    /// "UTC", nom IANA (Etc/GMT-2, Europe/Athens…) ou "broker NY-close"
+    pub timezone: String,
+    /// Annonces disposant de bougies autour de la minute attendue
+    pub events_scored: usize,
+    /// Médiane du ratio true range à l'annonce / true range médian des 30 min précédentes
+    pub median_spike_ratio: f64,
+    /// Part des annonces avec un ratio ≥ seuil de pic (0-1)
+    pub hit_rate: f64,
+}
+
+/// Résultat de la détection : fuseau suggéré et classement des candidats
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimezoneDetectionReport {
+    pub symbol: String,
+    /// Annonces à fort impact testées
+    pub events_tested: usize,
+    /// Fuseau dont les pics tombent le mieux sur les annonces (None si données insuffisantes)
+    pub suggested_timezone: Option<String>,
+    /// Confiance dans la suggestion (0-100)
+    pub confidence: f64,
+    /// true si le fuseau suggéré est UTC : aucune réécriture nécessaire
+    pub already_utc: bool,
+    /// Meilleurs candidats, du plus au moins probable (variantes équivalentes fusionnées)
+    pub candidates: Vec<TimezoneCandidate>,
+}
+
+/// Bilan de la réécriture des horodatages en UTC
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TimezoneCorrectionResult {
+    pub symbol: String,
+    pub source_timezone: String,
+    pub rows_rewritten: usize,
+    /// Bougies supprimées faute d'heure UTC (heure d'été inexistante, doublon, illisible)
+    pub rows_deleted: usize,
+    /// Bougies dérivées (M5…D1) recalculées
+    pub derived_rows: usize,
+}
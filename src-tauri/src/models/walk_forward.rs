@@ -0,0 +1,117 @@
+// models/walk_forward.rs - Modèles de l'optimisation walk-forward des paramètres straddle
+// Conforme .clinerules : structures uniquement, pas de logique métier
+
+use crate::models::backtest::{BacktestConfig, BacktestTrade, EquityPoint};
+use serde::{Deserialize, Serialize};
+
+/// Valeurs candidates pour chaque paramètre du straddle
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ParameterGrid {
+    pub entry_offsets_minutes: Vec<i64>,
+    pub straddle_distances_pips: Vec<f64>,
+    pub atr_multipliers_sl: Vec<f64>,
+    pub atr_multipliers_tp: Vec<f64>,
+    pub max_durations_minutes: Vec<i64>,
+}
+
+impl Default for ParameterGrid {
+    fn default() -> Self {
+        Self {
+            entry_offsets_minutes: vec![1, 2, 5],
+            straddle_distances_pips: vec![5.0, 10.0, 15.0],
+            atr_multipliers_sl: vec![1.5, 2.0, 3.0],
+            atr_multipliers_tp: vec![2.0, 3.0, 4.0],
+            max_durations_minutes: vec![15, 30, 60],
+        }
+    }
+}
+
+/// Mode d'exploration de la grille
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "mode")]
+pub enum SearchMode {
+    /// Toutes les combinaisons
+    Grid,
+    /// `samples` combinaisons tirées au hasard (reproductible via `seed`)
+    Random { samples: usize, seed: u64 },
+}
+
+/// Critère maximisé sur la fenêtre in-sample
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OptimizationObjective {
+    Expectancy,
+    ProfitFactor,
+    TotalPips,
+}
+
+/// Configuration de l'optimisation walk-forward
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalkForwardConfig {
+    /// Occurrences utilisées pour l'ajustement (défaut: 24)
+    pub in_sample_occurrences: usize,
+    /// Occurrences de test hors échantillon, aussi pas d'avancement (défaut: 6)
+    pub out_of_sample_occurrences: usize,
+    /// Trades minimum en in-sample pour qu'une combinaison soit éligible (défaut: 5)
+    pub min_in_sample_trades: usize,
+    pub objective: OptimizationObjective,
+    pub search: SearchMode,
+    pub grid: ParameterGrid,
+    /// Paramètres non optimisés (période ATR, expiration, spread de repli)
+    pub base: BacktestConfig,
+}
+
+impl Default for WalkForwardConfig {
+    fn default() -> Self {
+        Self {
+            in_sample_occurrences: 24,
+            out_of_sample_occurrences: 6,
+            min_in_sample_trades: 5,
+            objective: OptimizationObjective::Expectancy,
+            search: SearchMode::Grid,
+            grid: ParameterGrid::default(),
+            base: BacktestConfig::default(),
+        }
+    }
+}
+
+/// Résultat d'une fenêtre glissante (ajustement in-sample puis test out-of-sample)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalkForwardWindow {
+    pub index: usize,
+    pub in_sample_start: String,
+    pub in_sample_end: String,
+    pub out_of_sample_start: String,
+    pub out_of_sample_end: String,
+    /// Meilleure combinaison in-sample. None si aucune n'a assez de trades.
+    pub best_config: Option<BacktestConfig>,
+    /// Score de l'objectif. Profit factor plafonné à `PROFIT_FACTOR_CAP` (fenêtre sans perte)
+    pub in_sample_score: f64,
+    pub in_sample_trades: usize,
+    pub out_of_sample_trades: usize,
+    pub out_of_sample_total_pips: f64,
+    pub out_of_sample_win_rate: f64,
+    pub out_of_sample_expectancy_pips: f64,
+    pub out_of_sample_profit_factor: Option<f64>,
+}
+
+/// Résultat complet : performance out-of-sample fenêtre par fenêtre et cumulée
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WalkForwardResult {
+    pub symbol: String,
+    pub event_type: String,
+    pub combinations_tested: usize,
+    pub windows: Vec<WalkForwardWindow>,
+    /// Trades out-of-sample chaînés de toutes les fenêtres
+    pub out_of_sample_trades: Vec<BacktestTrade>,
+    pub out_of_sample_equity_curve: Vec<EquityPoint>,
+    pub out_of_sample_total_pips: f64,
+    pub out_of_sample_win_rate: f64,
+    pub out_of_sample_expectancy_pips: f64,
+    pub out_of_sample_profit_factor: Option<f64>,
+    pub out_of_sample_max_drawdown_pips: f64,
+    /// Espérance OOS / espérance IS moyenne (proche de 1 = peu de sur-ajustement)
+    pub walk_forward_efficiency: f64,
+    /// Offset d'entrée le plus souvent retenu sur l'ensemble des fenêtres
+    pub most_selected_entry_offset: Option<i64>,
+    pub unit: String,
+}
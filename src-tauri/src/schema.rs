@@ -38,6 +38,7 @@ diesel::table! {
         previous -> Nullable<Double>,
         created_at -> Timestamp,
         calendar_import_id -> Integer,
+        event_id -> Nullable<Text>,
     }
 }
 
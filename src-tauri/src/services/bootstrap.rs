@@ -0,0 +1,116 @@
+// services/bootstrap.rs
+// Rééchantillonnage bootstrap : bornes 5% / 95% des taux et moyennes calculés
+// sur peu d'occurrences (souvent 5 à 20). Graine fixe → résultats reproductibles.
+
+use crate::models::ConfidenceInterval;
+use crate::services::sampling::SplitMix64;
+
+/// Nombre de rééchantillonnages par intervalle
+pub const BOOTSTRAP_RESAMPLES: usize = 1000;
+const BOOTSTRAP_SEED: u64 = 0x5EED_B007_5EED_B007;
+const LOWER_PERCENTILE: f64 = 0.05;
+const UPPER_PERCENTILE: f64 = 0.95;
+
+/// Intervalle d'une statistique quelconque : `statistic` reçoit les indices tirés
+/// avec remise parmi `n` observations. Un tirage renvoyant None est ignoré.
+pub fn resample_interval<F>(n: usize, mut statistic: F) -> ConfidenceInterval
+where
+    F: FnMut(&[usize]) -> Option<f64>,
+{
+    if n == 0 {
+        return ConfidenceInterval::default();
+    }
+    let mut rng = SplitMix64::new(BOOTSTRAP_SEED);
+    let mut indices = vec![0usize; n];
+    let mut stats = Vec::with_capacity(BOOTSTRAP_RESAMPLES);
+    for _ in 0..BOOTSTRAP_RESAMPLES {
+        for idx in indices.iter_mut() {
+            *idx = rng.next_index(n);
+        }
+        if let Some(value) = statistic(&indices).filter(|v| v.is_finite()) {
+            stats.push(value);
+        }
+    }
+    percentile_bounds(&mut stats)
+}
+
+/// Intervalle de la moyenne arithmétique
+pub fn mean_interval(samples: &[f64]) -> ConfidenceInterval {
+    resample_interval(samples.len(), |idx| {
+        Some(idx.iter().map(|&i| samples[i]).sum::<f64>() / idx.len() as f64)
+    })
+}
+
+/// Intervalle de la moyenne pondérée, échantillons (valeur, poids)
+pub fn weighted_mean_interval(samples: &[(f64, f64)]) -> ConfidenceInterval {
+    resample_interval(samples.len(), |idx| {
+        let (sum, weight) = idx.iter().fold((0.0, 0.0), |(s, w), &i| {
+            (s + samples[i].0 * samples[i].1, w + samples[i].1)
+        });
+        (weight > 0.0).then(|| sum / weight)
+    })
+}
+
+/// Intervalle d'une proportion de valeurs dans [0, 1].
+/// Un succès et un échec fictifs sont ajoutés (lissage de Laplace) : sans eux,
+/// 3 gains sur 3 donneraient l'intervalle dégénéré [1, 1].
+pub fn proportion_interval(values: &[f64]) -> ConfidenceInterval {
+    if values.is_empty() {
+        return ConfidenceInterval::default();
+    }
+    let mut smoothed = Vec::with_capacity(values.len() + 2);
+    smoothed.extend_from_slice(values);
+    smoothed.extend_from_slice(&[1.0, 0.0]);
+    mean_interval(&smoothed)
+}
+
+/// Intervalle d'un taux `successes / total`
+pub fn rate_interval(successes: usize, total: usize) -> ConfidenceInterval {
+    let values: Vec<f64> = (0..total)
+        .map(|i| if i < successes { 1.0 } else { 0.0 })
+        .collect();
+    proportion_interval(&values)
+}
+
+fn percentile_bounds(stats: &mut [f64]) -> ConfidenceInterval {
+    if stats.is_empty() {
+        return ConfidenceInterval::default();
+    }
+    stats.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let last = (stats.len() - 1) as f64;
+    ConfidenceInterval {
+        lower: stats[(LOWER_PERCENTILE * last).floor() as usize],
+        upper: stats[(UPPER_PERCENTILE * last).ceil() as usize],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_small_perfect_record_ranks_below_large_sample() {
+        let small = rate_interval(3, 3);
+        let large = rate_interval(60, 80);
+        assert!(small.upper > large.upper);
+        assert!(small.lower < large.lower);
+        assert!(large.lower < 0.75 && large.upper > 0.75);
+    }
+
+    #[test]
+    fn test_mean_interval_brackets_mean() {
+        let samples = [4.0, -2.0, 7.5, 1.0, 3.0, -1.5, 6.0, 2.0];
+        let mean = samples.iter().sum::<f64>() / samples.len() as f64;
+        let ci = mean_interval(&samples);
+        assert!(ci.lower < mean && mean < ci.upper);
+        assert_eq!(ci, mean_interval(&samples));
+    }
+
+    #[test]
+    fn test_empty_and_constant_samples() {
+        assert_eq!(mean_interval(&[]), ConfidenceInterval::default());
+        assert_eq!(rate_interval(0, 0), ConfidenceInterval::default());
+        let ci = weighted_mean_interval(&[(2.0, 1.0), (2.0, 3.0)]);
+        assert!((ci.lower - 2.0).abs() < 1e-12 && (ci.upper - 2.0).abs() < 1e-12);
+    }
+}
@@ -0,0 +1,141 @@
+// services/calendar_converter/column_mapping/mod.rs - Import de calendriers par mapping de colonnes
+// Presets Investing.com, FXStreet, TradingEconomics + mapping libre : dates, fuseaux
+// et échelles d'impact hétérogènes normalisés en UTC et H/M/L (valeurs de calendar_events.impact).
+
+mod normalisation;
+mod presets;
+mod rows;
+
+pub use normalisation::{normalize_impact, AUTO_DATE_FORMAT};
+pub use presets::presets;
+pub use rows::map_rows;
+
+use super::normalizer::cell_to_string;
+use crate::models::calendar_mapping::{
+    CalendarColumnMapping, CalendarMappedEvent, CalendarMappingPreview,
+};
+use calamine::{open_workbook_auto, Data, Reader};
+use std::path::Path;
+
+type CalendarEventRecord = (
+    String,
+    String,
+    String,
+    String,
+    Option<f64>,
+    Option<f64>,
+    Option<f64>,
+);
+
+/// Calendrier lu avec un mapping
+#[derive(Debug, Clone)]
+pub struct MappedCalendar {
+    pub headers: Vec<String>,
+    pub events: Vec<CalendarMappedEvent>,
+    pub rows_read: usize,
+    pub rows_rejected: usize,
+    pub rejections: Vec<String>,
+}
+
+impl MappedCalendar {
+    /// Aperçu limité aux `sample_size` premiers événements
+    pub fn preview(&self, sample_size: usize) -> CalendarMappingPreview {
+        CalendarMappingPreview {
+            headers: self.headers.clone(),
+            rows_read: self.rows_read,
+            events_parsed: self.events.len(),
+            rows_rejected: self.rows_rejected,
+            rejections: self.rejections.clone(),
+            sample: self.events.iter().take(sample_size).cloned().collect(),
+        }
+    }
+
+    /// Tuples attendus par `save_calendar_import`
+    pub fn into_records(self) -> Vec<CalendarEventRecord> {
+        self.events
+            .into_iter()
+            .map(|e| {
+                (
+                    e.event_time,
+                    e.currency,
+                    e.impact,
+                    e.description,
+                    e.actual,
+                    e.forecast,
+                    e.previous,
+                )
+            })
+            .collect()
+    }
+}
+
+/// Lit un fichier .csv, .xlsx ou .xls et applique le mapping
+pub fn convert_file(path: &str, mapping: &CalendarColumnMapping) -> Result<MappedCalendar, String> {
+    let rows = read_rows(path, mapping.delimiter)?;
+    map_rows(&rows, mapping)
+}
+
+/// Cellules brutes d'un fichier (première feuille pour un classeur)
+fn read_rows(path: &str, delimiter: char) -> Result<Vec<Vec<String>>, String> {
+    let extension = Path::new(path)
+        .extension()
+        .and_then(|e| e.to_str())
+        .map(str::to_lowercase)
+        .unwrap_or_default();
+
+    match extension.as_str() {
+        "xlsx" | "xls" => read_workbook(path),
+        _ => {
+            let delimiter = u8::try_from(delimiter)
+                .map_err(|_| format!("Séparateur CSV invalide : {:?}", delimiter))?;
+            let mut reader = csv::ReaderBuilder::new()
+                .has_headers(false)
+                .flexible(true)
+                .delimiter(delimiter)
+                .from_path(path)
+                .map_err(|e| format!("Impossible d'ouvrir {} : {}", path, e))?;
+            reader
+                .records()
+                .map(|record| {
+                    record
+                        .map(|r| r.iter().map(|c| c.trim().to_string()).collect())
+                        .map_err(|e| format!("CSV parsing error: {}", e))
+                })
+                .collect()
+        }
+    }
+}
+
+fn read_workbook(path: &str) -> Result<Vec<Vec<String>>, String> {
+    let mut workbook =
+        open_workbook_auto(path).map_err(|e| format!("Impossible d'ouvrir le classeur : {}", e))?;
+    let sheet_name = workbook
+        .sheet_names()
+        .first()
+        .cloned()
+        .ok_or("Le fichier Excel ne contient aucune feuille")?;
+    let range = workbook
+        .worksheet_range(&sheet_name)
+        .map_err(|e| format!("Impossible de lire la feuille {} : {}", sheet_name, e))?;
+    Ok(range
+        .rows()
+        .map(|row| row.iter().map(workbook_cell).collect())
+        .collect())
+}
+
+/// Les dates Excel sont des nombres de jours : rendues en ISO pour le parseur de dates
+fn workbook_cell(cell: &Data) -> String {
+    if let Data::DateTime(dt) = cell {
+        if let Some(datetime) = dt.as_datetime() {
+            let serial = dt.as_f64();
+            return if serial < 1.0 {
+                datetime.format("%H:%M:%S").to_string()
+            } else if serial.fract() == 0.0 {
+                datetime.format("%Y-%m-%d").to_string()
+            } else {
+                datetime.format("%Y-%m-%d %H:%M:%S").to_string()
+            };
+        }
+    }
+    cell_to_string(cell).trim().to_string()
+}
@@ -0,0 +1,253 @@
+// services/calendar_converter/column_mapping/normalisation.rs - Dates, heures, impacts et valeurs
+// des calendriers mappés : formats automatiques, ordre jour/mois fixé par fichier, échelles H/M/L
+
+use crate::models::calendar_mapping::ImpactScale;
+use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
+
+/// Valeur de `date_format` déclenchant la détection parmi les formats courants
+pub const AUTO_DATE_FORMAT: &str = "auto";
+
+/// Date et heure dans une même colonne
+const AUTO_DATETIME_FORMATS: &[&str] = &[
+    "%Y-%m-%dT%H:%M:%S%.f",
+    "%Y-%m-%dT%H:%M:%S",
+    "%Y-%m-%dT%H:%M",
+    "%Y-%m-%d %H:%M:%S",
+    "%Y-%m-%d %H:%M",
+    "%Y/%m/%d %H:%M:%S",
+    "%Y/%m/%d %H:%M",
+    "%m/%d/%Y %H:%M:%S",
+    "%m/%d/%Y %H:%M",
+    "%m/%d/%Y %I:%M %p",
+    "%d/%m/%Y %H:%M:%S",
+    "%d/%m/%Y %H:%M",
+    "%d.%m.%Y %H:%M:%S",
+    "%d.%m.%Y %H:%M",
+];
+
+/// Dates seules ; l'ordre jour/mois des dates numériques est fixé par fichier (`DateOrder`)
+const AUTO_DATE_FORMATS: &[&str] = &[
+    "%Y-%m-%d",
+    "%Y/%m/%d",
+    "%Y.%m.%d",
+    "%m/%d/%Y",
+    "%d/%m/%Y",
+    "%d.%m.%Y",
+    "%m-%d-%Y",
+    "%d-%m-%Y",
+    "%b %d, %Y",
+    "%B %d, %Y",
+    "%A, %B %d, %Y",
+    "%d %b %Y",
+    "%d %B %Y",
+];
+
+const TIME_FORMATS: &[&str] = &["%H:%M:%S", "%H:%M", "%I:%M%p", "%I:%M %p", "%I:%M:%S %p"];
+
+/// Ordre jour/mois des dates numériques (01/02/2025), déterminé une fois par fichier
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(super) enum DateOrder {
+    DayFirst,
+    MonthFirst,
+}
+
+impl DateOrder {
+    /// Format automatique compatible avec cet ordre ("%m/%d/%Y" écarté en jour d'abord)
+    fn accepts(order: Option<Self>, format: &str) -> bool {
+        let month_first = format.starts_with("%m/") || format.starts_with("%m-");
+        let day_first = format.starts_with("%d/") || format.starts_with("%d-");
+        match order {
+            Some(Self::DayFirst) => !month_first,
+            Some(Self::MonthFirst) => !day_first,
+            None => true,
+        }
+    }
+}
+
+/// Impact H/M/L selon l'échelle de la source (None : jour férié, non classé…)
+pub fn normalize_impact(raw: &str, scale: ImpactScale) -> Option<&'static str> {
+    let value = raw.trim().to_lowercase();
+    if value.is_empty() {
+        return None;
+    }
+    // "High", "Moderate Volatility Expected", "L"… (premier mot seulement)
+    let words = || match value.split_whitespace().next().unwrap_or("") {
+        "high" | "h" => Some(3),
+        "medium" | "med" | "moderate" | "m" => Some(2),
+        "low" | "l" => Some(1),
+        _ => None,
+    };
+    let stars = || {
+        let count = value.chars().filter(|c| matches!(c, '*' | '★')).count();
+        (count > 0 && value.chars().all(|c| matches!(c, '*' | '★' | '☆' | ' '))).then_some(count)
+    };
+    let digits = || {
+        let digits: String = value.chars().filter(char::is_ascii_digit).collect();
+        let is_level =
+            value.chars().all(|c| c.is_ascii_digit() || c == '.') || value.starts_with("bull");
+        digits.parse::<usize>().ok().filter(|_| is_level)
+    };
+
+    let level = match scale {
+        ImpactScale::Words => words(),
+        ImpactScale::Numeric => digits(),
+        ImpactScale::Bulls => stars().or_else(digits).or_else(words),
+        ImpactScale::Auto => words().or_else(stars).or_else(digits),
+    }?;
+    match level {
+        3.. => Some("H"),
+        2 => Some("M"),
+        1 => Some("L"),
+        _ => None,
+    }
+}
+
+/// Valeur publiée : "2.5%", "215K", "-0.3", "1,234.5", "2,5" (virgule décimale)
+pub(super) fn parse_value(raw: &str) -> Option<f64> {
+    let trimmed = raw
+        .trim()
+        .trim_end_matches(['%', 'K', 'k', 'M', 'B', 'T'])
+        .trim();
+    let normalized = if trimmed.contains('.') {
+        trimmed.replace(',', "")
+    } else if trimmed.matches(',').count() == 1
+        && trimmed.rsplit(',').next().map(str::len) != Some(3)
+    {
+        trimmed.replace(',', ".")
+    } else {
+        trimmed.replace(',', "")
+    };
+    normalized.parse::<f64>().ok().filter(|v| v.is_finite())
+}
+
+pub(super) fn is_auto_format(format: &str) -> bool {
+    format.trim().is_empty() || format.trim().eq_ignore_ascii_case(AUTO_DATE_FORMAT)
+}
+
+/// Jour et mois candidats d'une date numérique "13/01/2025" ou "01-13-2025 08:30"
+/// (None pour l'ISO, les dates pointées toujours jour d'abord et les dates en toutes lettres)
+fn numeric_day_month(date: &str) -> Option<(u32, u32)> {
+    let date = date.split_whitespace().next()?;
+    let separator = ['/', '-'].into_iter().find(|s| date.contains(*s))?;
+    let parts: Vec<&str> = date.split(separator).collect();
+    match parts.as_slice() {
+        [first, second, year] if year.len() == 4 => {
+            Some((first.parse().ok()?, second.parse().ok()?))
+        }
+        _ => None,
+    }
+}
+
+/// Ordre jour/mois du fichier, déduit des dates où une partie dépasse 12.
+/// Erreur si le fichier mélange les deux ordres ou ne contient que des dates ambiguës.
+pub(super) fn detect_date_order<'a>(
+    dates: impl Iterator<Item = &'a str>,
+) -> Result<Option<DateOrder>, String> {
+    let (mut numeric, mut day_first, mut month_first) = (false, false, false);
+    for (first, second) in dates.filter_map(|d| numeric_day_month(d.trim())) {
+        numeric = true;
+        day_first |= first > 12;
+        month_first |= second > 12;
+    }
+    match (day_first, month_first) {
+        (true, true) => Err("Dates jj/mm et mm/jj mélangées dans le fichier".to_string()),
+        (true, false) => Ok(Some(DateOrder::DayFirst)),
+        (false, true) => Ok(Some(DateOrder::MonthFirst)),
+        (false, false) if numeric => Err(
+            "Ordre jour/mois indéterminable (aucun jour > 12) : préciser le format de date"
+                .to_string(),
+        ),
+        (false, false) => Ok(None),
+    }
+}
+
+/// Heure locale de la source ; une heure non chiffrée ("All Day", "Tentative") rejette
+/// la ligne plutôt que de placer l'événement à minuit
+pub(super) fn parse_local_datetime(
+    date: &str,
+    time: Option<&str>,
+    format: &str,
+    date_order: Option<DateOrder>,
+) -> Result<NaiveDateTime, String> {
+    let date = date.trim().trim_end_matches('Z');
+    if date.is_empty() {
+        return Err("date vide".to_string());
+    }
+    let auto = is_auto_format(format);
+    let auto_formats = |formats: &[&'static str]| -> Vec<&'static str> {
+        formats
+            .iter()
+            .copied()
+            .filter(|f| DateOrder::accepts(date_order, f))
+            .collect()
+    };
+    // Un format explicite reste prioritaire ; l'ISO couvre les dates des classeurs
+    let datetime_formats: Vec<&str> = if auto {
+        auto_formats(AUTO_DATETIME_FORMATS)
+    } else {
+        [format.trim()]
+            .into_iter()
+            .chain(AUTO_DATETIME_FORMATS.iter().take(5).copied())
+            .collect()
+    };
+    let date_formats: Vec<&str> = if auto {
+        auto_formats(AUTO_DATE_FORMATS)
+    } else {
+        [format.trim(), "%Y-%m-%d"].to_vec()
+    };
+
+    if time.is_none() {
+        if let Some(dt) = datetime_formats
+            .iter()
+            .find_map(|f| NaiveDateTime::parse_from_str(date, f).ok())
+        {
+            return Ok(dt);
+        }
+    }
+
+    let day = date_formats
+        .iter()
+        .find_map(|f| NaiveDate::parse_from_str(date, f).ok())
+        .ok_or_else(|| format!("date illisible '{}'", date))?;
+    let time_of_day = match time.map(str::trim) {
+        Some(t) if !t.contains(':') => {
+            return Err(format!("heure non précisée '{}'", t));
+        }
+        Some(t) => TIME_FORMATS
+            .iter()
+            .find_map(|f| NaiveTime::parse_from_str(t, f).ok())
+            .ok_or_else(|| format!("heure illisible '{}'", t))?,
+        None => NaiveTime::MIN,
+    };
+    Ok(day.and_time(time_of_day))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_normalisation_impact() {
+        assert_eq!(normalize_impact("***", ImpactScale::Bulls), Some("H"));
+        assert_eq!(normalize_impact("Bull2", ImpactScale::Bulls), Some("M"));
+        assert_eq!(
+            normalize_impact("Low Volatility Expected", ImpactScale::Bulls),
+            Some("L")
+        );
+        assert_eq!(normalize_impact("3", ImpactScale::Numeric), Some("H"));
+        assert_eq!(normalize_impact("1", ImpactScale::Numeric), Some("L"));
+        assert_eq!(normalize_impact("Medium", ImpactScale::Words), Some("M"));
+        assert_eq!(normalize_impact("HIGH", ImpactScale::Auto), Some("H"));
+        assert_eq!(normalize_impact("Holiday", ImpactScale::Auto), None);
+        assert_eq!(normalize_impact("0", ImpactScale::Numeric), None);
+        assert_eq!(normalize_impact("High", ImpactScale::Numeric), None);
+    }
+
+    #[test]
+    fn test_parse_value() {
+        assert_eq!(parse_value("1,234.5"), Some(1234.5));
+        assert_eq!(parse_value("-0.3%"), Some(-0.3));
+        assert_eq!(parse_value("1,234"), Some(1234.0));
+        assert_eq!(parse_value(""), None);
+    }
+}
@@ -0,0 +1,86 @@
+// services/calendar_converter/column_mapping/presets.rs - Presets des exports connus
+// et devise déduite du pays pour les exports sans colonne devise
+
+use super::normalisation::AUTO_DATE_FORMAT;
+use crate::models::calendar_mapping::{CalendarColumnMapping, ImpactScale};
+
+/// Pays des exports sans colonne devise (TradingEconomics, FXStreet)
+const COUNTRY_CURRENCIES: &[(&str, &str)] = &[
+    ("united states", "USD"),
+    ("euro area", "EUR"),
+    ("european union", "EUR"),
+    ("germany", "EUR"),
+    ("france", "EUR"),
+    ("italy", "EUR"),
+    ("spain", "EUR"),
+    ("united kingdom", "GBP"),
+    ("japan", "JPY"),
+    ("switzerland", "CHF"),
+    ("canada", "CAD"),
+    ("australia", "AUD"),
+    ("new zealand", "NZD"),
+    ("china", "CNY"),
+];
+
+/// Presets des exports connus (fuseau UTC par défaut, modifiable avant import)
+pub fn presets() -> Vec<CalendarColumnMapping> {
+    let column = |name: &str| Some(name.to_string());
+    vec![
+        CalendarColumnMapping {
+            preset: "investing".to_string(),
+            date_column: "Date".to_string(),
+            time_column: column("Time"),
+            currency_column: column("Cur."),
+            country_column: None,
+            event_column: "Event".to_string(),
+            impact_column: "Imp.".to_string(),
+            actual_column: column("Actual"),
+            forecast_column: column("Forecast"),
+            previous_column: column("Previous"),
+            date_format: AUTO_DATE_FORMAT.to_string(),
+            timezone: "UTC".to_string(),
+            impact_scale: ImpactScale::Bulls,
+            delimiter: ',',
+        },
+        CalendarColumnMapping {
+            preset: "fxstreet".to_string(),
+            date_column: "Start".to_string(),
+            time_column: None,
+            currency_column: column("Currency"),
+            country_column: column("Country"),
+            event_column: "Name".to_string(),
+            impact_column: "Impact".to_string(),
+            actual_column: column("Actual"),
+            forecast_column: column("Consensus"),
+            previous_column: column("Previous"),
+            date_format: "%m/%d/%Y %H:%M:%S".to_string(),
+            timezone: "UTC".to_string(),
+            impact_scale: ImpactScale::Words,
+            delimiter: ',',
+        },
+        CalendarColumnMapping {
+            preset: "tradingeconomics".to_string(),
+            date_column: "Date".to_string(),
+            time_column: None,
+            currency_column: column("Currency"),
+            country_column: column("Country"),
+            event_column: "Event".to_string(),
+            impact_column: "Importance".to_string(),
+            actual_column: column("Actual"),
+            forecast_column: column("Forecast"),
+            previous_column: column("Previous"),
+            date_format: AUTO_DATE_FORMAT.to_string(),
+            timezone: "UTC".to_string(),
+            impact_scale: ImpactScale::Numeric,
+            delimiter: ',',
+        },
+    ]
+}
+
+pub(super) fn country_currency(country: &str) -> Option<&'static str> {
+    let country = country.trim().to_lowercase();
+    COUNTRY_CURRENCIES
+        .iter()
+        .find(|(name, _)| *name == country)
+        .map(|(_, currency)| *currency)
+}
@@ -0,0 +1,222 @@
+// services/calendar_converter/column_mapping/rows.rs - Application du mapping aux lignes brutes
+// En-tête repéré sous les titres d'export, colonnes résolues par nom ou index, une ligne
+// invalide est rejetée avec son motif sans bloquer le reste du fichier.
+
+use super::normalisation::{
+    detect_date_order, is_auto_format, normalize_impact, parse_local_datetime, parse_value,
+    DateOrder,
+};
+use super::presets::country_currency;
+use super::MappedCalendar;
+use crate::models::calendar_mapping::{CalendarColumnMapping, CalendarMappedEvent};
+use crate::services::source_timezone::SourceTimezone;
+
+/// Lignes examinées pour trouver la ligne d'en-tête (titres d'export au-dessus)
+const HEADER_SCAN_ROWS: usize = 10;
+
+/// Lignes rejetées détaillées dans l'aperçu
+const MAX_REJECTIONS: usize = 20;
+
+/// Index des colonnes du mapping, résolus sur la ligne d'en-tête
+struct ResolvedColumns {
+    date: usize,
+    time: Option<usize>,
+    currency: Option<usize>,
+    country: Option<usize>,
+    event: usize,
+    impact: usize,
+    actual: Option<usize>,
+    forecast: Option<usize>,
+    previous: Option<usize>,
+}
+
+/// Applique le mapping aux lignes brutes (ligne d'en-tête comprise)
+pub fn map_rows(
+    rows: &[Vec<String>],
+    mapping: &CalendarColumnMapping,
+) -> Result<MappedCalendar, String> {
+    let timezone = SourceTimezone::parse(&mapping.timezone)?;
+    // Mapping entièrement par index : fichier sans ligne d'en-tête
+    let header_index = if is_index(&mapping.date_column) && is_index(&mapping.event_column) {
+        None
+    } else {
+        Some(find_header_row(rows, mapping).ok_or_else(|| {
+            format!(
+                "En-têtes '{}' / '{}' introuvables dans les {} premières lignes",
+                mapping.date_column, mapping.event_column, HEADER_SCAN_ROWS
+            )
+        })?)
+    };
+    let headers = header_index
+        .and_then(|i| rows.get(i))
+        .cloned()
+        .unwrap_or_default();
+    let columns = resolve_columns(&headers, mapping)?;
+    let first_data_row = header_index.map_or(0, |i| i + 1);
+    let auto = is_auto_format(&mapping.date_format);
+    let date_order = if auto {
+        detect_date_order(
+            rows.iter()
+                .skip(first_data_row)
+                .filter_map(|row| row.get(columns.date))
+                .map(String::as_str),
+        )?
+    } else {
+        None
+    };
+
+    let mut calendar = MappedCalendar {
+        headers,
+        events: Vec::new(),
+        rows_read: 0,
+        rows_rejected: 0,
+        rejections: Vec::new(),
+    };
+
+    for (offset, row) in rows.iter().enumerate().skip(first_data_row) {
+        if row.iter().all(|c| c.trim().is_empty()) {
+            continue;
+        }
+        calendar.rows_read += 1;
+        match map_row(row, &columns, mapping, &timezone, date_order) {
+            Ok(event) => calendar.events.push(event),
+            Err(reason) => {
+                calendar.rows_rejected += 1;
+                if calendar.rejections.len() < MAX_REJECTIONS {
+                    calendar
+                        .rejections
+                        .push(format!("Ligne {} : {}", offset + 1, reason));
+                }
+            }
+        }
+    }
+
+    tracing::info!(
+        "📊 Mapping {} : {} événements, {} lignes rejetées sur {}",
+        mapping.preset,
+        calendar.events.len(),
+        calendar.rows_rejected,
+        calendar.rows_read
+    );
+    Ok(calendar)
+}
+
+fn map_row(
+    row: &[String],
+    columns: &ResolvedColumns,
+    mapping: &CalendarColumnMapping,
+    timezone: &SourceTimezone,
+    date_order: Option<DateOrder>,
+) -> Result<CalendarMappedEvent, String> {
+    let cell = |index: Option<usize>| {
+        index
+            .and_then(|i| row.get(i))
+            .map(|c| c.trim())
+            .unwrap_or("")
+    };
+
+    let description = cell(Some(columns.event));
+    if description.is_empty() {
+        return Err("événement vide".to_string());
+    }
+
+    let raw_impact = cell(Some(columns.impact));
+    let impact = normalize_impact(raw_impact, mapping.impact_scale)
+        .ok_or_else(|| format!("impact non reconnu '{}'", raw_impact))?;
+
+    let currency = Some(cell(columns.currency).to_uppercase())
+        .filter(|c| c.len() == 3 && c.chars().all(|ch| ch.is_ascii_alphabetic()))
+        .or_else(|| country_currency(cell(columns.country)).map(str::to_string))
+        .ok_or_else(|| "devise absente".to_string())?;
+
+    let local = parse_local_datetime(
+        cell(Some(columns.date)),
+        columns.time.map(|i| cell(Some(i))),
+        &mapping.date_format,
+        date_order,
+    )?;
+    let event_time = timezone
+        .to_utc(local)?
+        .format("%Y-%m-%d %H:%M:%S")
+        .to_string();
+
+    Ok(CalendarMappedEvent {
+        event_time,
+        currency,
+        impact: impact.to_string(),
+        description: description.to_string(),
+        actual: parse_value(cell(columns.actual)),
+        forecast: parse_value(cell(columns.forecast)),
+        previous: parse_value(cell(columns.previous)),
+    })
+}
+
+/// En-tête comparable : minuscules, sans ponctuation ni espaces ("Cur." = "cur")
+fn header_key(name: &str) -> String {
+    name.chars()
+        .filter(|c| c.is_alphanumeric())
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn is_index(column: &str) -> bool {
+    column.trim().parse::<usize>().is_ok()
+}
+
+/// Première ligne contenant les colonnes date et événement désignées par nom
+fn find_header_row(rows: &[Vec<String>], mapping: &CalendarColumnMapping) -> Option<usize> {
+    let named: Vec<String> = [&mapping.date_column, &mapping.event_column]
+        .into_iter()
+        .filter(|c| !is_index(c))
+        .map(|c| header_key(c))
+        .collect();
+    rows.iter().take(HEADER_SCAN_ROWS).position(|row| {
+        let keys: Vec<String> = row.iter().map(|c| header_key(c)).collect();
+        named.iter().all(|n| keys.contains(n))
+    })
+}
+
+fn resolve_columns(
+    headers: &[String],
+    mapping: &CalendarColumnMapping,
+) -> Result<ResolvedColumns, String> {
+    let keys: Vec<String> = headers.iter().map(|h| header_key(h)).collect();
+    let find = |name: &str| -> Option<usize> {
+        let name = name.trim();
+        if name.is_empty() {
+            return None;
+        }
+        name.parse::<usize>()
+            .ok()
+            .or_else(|| keys.iter().position(|k| *k == header_key(name)))
+    };
+    let required = |name: &str| {
+        find(name).ok_or_else(|| format!("Colonne '{}' introuvable dans l'en-tête", name))
+    };
+    let optional = |name: &Option<String>| -> Result<Option<usize>, String> {
+        match name.as_deref().map(str::trim).filter(|n| !n.is_empty()) {
+            Some(n) => required(n).map(Some),
+            None => Ok(None),
+        }
+    };
+
+    let columns = ResolvedColumns {
+        date: required(&mapping.date_column)?,
+        time: optional(&mapping.time_column)?,
+        currency: mapping.currency_column.as_deref().and_then(find),
+        country: mapping.country_column.as_deref().and_then(find),
+        event: required(&mapping.event_column)?,
+        impact: required(&mapping.impact_column)?,
+        actual: optional(&mapping.actual_column)?,
+        forecast: optional(&mapping.forecast_column)?,
+        previous: optional(&mapping.previous_column)?,
+    };
+    if columns.currency.is_none() && columns.country.is_none() {
+        return Err("Colonne devise ou pays requise".to_string());
+    }
+    Ok(columns)
+}
+
+#[cfg(test)]
+#[path = "rows_tests.rs"]
+mod tests;
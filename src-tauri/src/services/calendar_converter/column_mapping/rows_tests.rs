@@ -0,0 +1,202 @@
+// services/calendar_converter/column_mapping/rows_tests.rs - Tests du mapping de lignes
+
+use super::*;
+use crate::models::calendar_mapping::ImpactScale;
+use crate::services::calendar_converter::column_mapping::presets;
+
+fn rows(lines: &[&[&str]]) -> Vec<Vec<String>> {
+    lines
+        .iter()
+        .map(|l| l.iter().map(|c| c.to_string()).collect())
+        .collect()
+}
+
+fn preset(name: &str) -> CalendarColumnMapping {
+    presets()
+        .into_iter()
+        .find(|p| p.preset == name)
+        .expect("preset connu")
+}
+
+/// Preset sans colonnes Actual/Forecast/Previous
+fn without_values(mut mapping: CalendarColumnMapping) -> CalendarColumnMapping {
+    mapping.actual_column = None;
+    mapping.forecast_column = None;
+    mapping.previous_column = None;
+    mapping
+}
+
+#[test]
+fn test_preset_investing_fuseau() {
+    let mut mapping = preset("investing");
+    mapping.timezone = "America/New_York".to_string();
+    let data = rows(&[
+        &["Economic Calendar"],
+        &[
+            "Date", "Time", "Cur.", "Imp.", "Event", "Actual", "Forecast", "Previous",
+        ],
+        &[
+            "Jan 10, 2025",
+            "08:30",
+            "USD",
+            "***",
+            "Nonfarm Payrolls",
+            "256K",
+            "164K",
+            "212K",
+        ],
+        &[
+            "Jan 10, 2025",
+            "All Day",
+            "JPY",
+            "Holiday",
+            "Bank Holiday",
+            "",
+            "",
+            "",
+        ],
+    ]);
+    let calendar = map_rows(&data, &mapping).expect("mapping valide");
+    assert_eq!(calendar.rows_read, 2);
+    assert_eq!(calendar.rows_rejected, 1);
+    let event = &calendar.events[0];
+    assert_eq!(event.event_time, "2025-01-10 13:30:00");
+    assert_eq!(event.currency, "USD");
+    assert_eq!(event.impact, "H");
+    assert_eq!(event.actual, Some(256.0));
+    assert_eq!(event.forecast, Some(164.0));
+}
+
+#[test]
+fn test_preset_tradingeconomics_pays() {
+    let data = rows(&[
+        &[
+            "Date",
+            "Country",
+            "Event",
+            "Actual",
+            "Previous",
+            "Forecast",
+            "Importance",
+            "Currency",
+        ],
+        &[
+            "2025-01-15T13:30:00",
+            "United States",
+            "CPI YoY",
+            "2.9%",
+            "2.7%",
+            "2.9%",
+            "3",
+            "",
+        ],
+        &[
+            "2025-01-15T07:00:00",
+            "United Kingdom",
+            "CPI YoY",
+            "2.5%",
+            "2.6%",
+            "2.6%",
+            "2",
+            "",
+        ],
+    ]);
+    let calendar = map_rows(&data, &preset("tradingeconomics")).expect("mapping valide");
+    assert_eq!(calendar.events.len(), 2);
+    assert_eq!(calendar.events[0].currency, "USD");
+    assert_eq!(calendar.events[0].impact, "H");
+    assert_eq!(calendar.events[1].currency, "GBP");
+    assert_eq!(calendar.events[1].impact, "M");
+    assert_eq!(calendar.events[1].previous, Some(2.6));
+}
+
+#[test]
+fn test_mapping_libre_par_index() {
+    let mapping = CalendarColumnMapping {
+        preset: "custom".to_string(),
+        date_column: "0".to_string(),
+        time_column: Some("1".to_string()),
+        currency_column: Some("2".to_string()),
+        country_column: None,
+        event_column: "3".to_string(),
+        impact_column: "4".to_string(),
+        actual_column: None,
+        forecast_column: Some("5".to_string()),
+        previous_column: None,
+        date_format: "%d/%m/%Y".to_string(),
+        timezone: "Europe/Paris".to_string(),
+        impact_scale: ImpactScale::Auto,
+        delimiter: ';',
+    };
+    let data = rows(&[&["15/07/2025", "2:30 PM", "eur", "Taux BCE", "Élevé", "2,15"]]);
+    let calendar = map_rows(&data, &mapping).expect("mapping valide");
+    // "Élevé" n'est pas une échelle connue : ligne rejetée avec son motif
+    assert_eq!(calendar.rows_rejected, 1);
+    assert!(calendar.rejections[0].contains("impact"));
+
+    let data = rows(&[&["15/07/2025", "2:30 PM", "eur", "Taux BCE", "H", "2,15"]]);
+    let event = map_rows(&data, &mapping).expect("mapping valide").events[0].clone();
+    assert_eq!(event.event_time, "2025-07-15 12:30:00");
+    assert_eq!(event.currency, "EUR");
+    assert_eq!(event.forecast, Some(2.15));
+}
+
+#[test]
+fn test_ordre_jour_mois_par_fichier() {
+    let mapping = without_values(preset("tradingeconomics"));
+    let header: &[&str] = &["Date", "Country", "Event", "Importance"];
+    let calendar = |dates: &[&str]| {
+        let mut lines = vec![header.to_vec()];
+        lines.extend(dates.iter().map(|d| vec![*d, "Euro Area", "CPI", "3"]));
+        let lines: Vec<&[&str]> = lines.iter().map(Vec::as_slice).collect();
+        map_rows(&rows(&lines), &mapping)
+    };
+
+    // 13/01 fixe le jour d'abord : 02/01 est le 2 janvier, pas le 1er février
+    let events = calendar(&["02/01/2025 10:00", "13/01/2025 10:00"])
+        .expect("ordre déduit")
+        .events;
+    assert_eq!(events[0].event_time, "2025-01-02 10:00:00");
+    // 01/13 fixe le mois d'abord
+    let events = calendar(&["02/01/2025 10:00", "01/13/2025 10:00"])
+        .expect("ordre déduit")
+        .events;
+    assert_eq!(events[0].event_time, "2025-02-01 10:00:00");
+
+    let err = calendar(&["02/01/2025 10:00", "03/01/2025 10:00"]).err();
+    assert!(err.unwrap_or_default().contains("format de date"));
+    let err = calendar(&["13/01/2025 10:00", "01/13/2025 10:00"]).err();
+    assert!(err.unwrap_or_default().contains("mélangées"));
+    // Dates ISO : aucune ambiguïté
+    assert!(calendar(&["2025-01-02T10:00:00"]).is_ok());
+}
+
+#[test]
+fn test_heure_non_precisee_rejetee() {
+    let data = rows(&[
+        &["Date", "Time", "Cur.", "Imp.", "Event"],
+        &[
+            "Jan 10, 2025",
+            "Tentative",
+            "USD",
+            "***",
+            "Treasury Auction",
+        ],
+        &["Jan 10, 2025", "All Day", "EUR", "*", "ECOFIN Meetings"],
+        &["Jan 10, 2025", "14:00", "USD", "**", "Fed Speech"],
+    ]);
+    let calendar = map_rows(&data, &without_values(preset("investing"))).expect("mapping valide");
+    assert_eq!(calendar.events.len(), 1);
+    assert_eq!(calendar.rows_rejected, 2);
+    assert!(calendar.rejections[0].contains("Tentative"));
+    assert!(calendar.rejections[1].contains("All Day"));
+}
+
+#[test]
+fn test_colonne_manquante() {
+    let mut mapping = preset("fxstreet");
+    mapping.impact_column = "Volatility".to_string();
+    let data = rows(&[&["Start", "Name", "Impact", "Currency"]]);
+    let err = map_rows(&data, &mapping).err().unwrap_or_default();
+    assert!(err.contains("Volatility"));
+}
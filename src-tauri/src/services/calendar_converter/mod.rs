@@ -1,3 +1,4 @@
+pub mod column_mapping;
 mod normalizer;
 
 pub use normalizer::{cell_to_string, normalize_date};
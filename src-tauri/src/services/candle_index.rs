@@ -4,6 +4,7 @@
 
 use crate::models::Candle;
 use crate::services::candle_loader::{load_pair_candles_in_range_strategy, load_pair_candles_strategy};
+use crate::services::pair_data::symbol_aliases::canonical_symbol;
 use crate::services::{CsvLoader, DatabaseLoader};
 use chrono::{DateTime, NaiveDate, Timelike, Utc};
 use std::collections::{BTreeMap, HashMap};
@@ -15,6 +16,8 @@ pub struct CandleIndex {
     data: HashMap<String, BTreeMap<NaiveDate, Vec<Candle>>>,
     /// Optional DatabaseLoader pour charger les paires depuis la BD
     db_loader: Option<DatabaseLoader>,
+    /// Timeframe chargé (M1 par défaut, M5/M15/H1/H4/D1 pour les analyses de contexte)
+    timeframe: String,
 }
 
 impl Default for CandleIndex {
@@ -29,6 +32,7 @@ impl CandleIndex {
         Self {
             data: HashMap::new(),
             db_loader: None,
+            timeframe: "M1".to_string(),
         }
     }
 
@@ -37,9 +41,28 @@ impl CandleIndex {
         Self {
             data: HashMap::new(),
             db_loader: Some(loader),
+            timeframe: "M1".to_string(),
         }
     }
 
+    /// Crée un index d'une seule paire à partir de candles déjà chargées
+    pub fn from_candles(symbol: &str, candles: Vec<Candle>) -> Self {
+        let mut index = Self::new();
+        index.add_candles(symbol, candles);
+        index
+    }
+
+    /// Change le timeframe chargé par l'index (ex: "D1" pour les régimes d'ATR journalier)
+    pub fn with_timeframe(mut self, timeframe: &str) -> Self {
+        self.timeframe = timeframe.to_string();
+        self
+    }
+
+    /// Timeframe des candles indexées
+    pub fn timeframe(&self) -> &str {
+        &self.timeframe
+    }
+
     /// Crée un index vide SANS charger les CSV (lazy loading)
     /// Les paires sont chargées à la demande avec load_pair_candles()
     #[allow(dead_code)]
@@ -85,11 +108,17 @@ impl CandleIndex {
     /// Utilise DatabaseLoader si disponible, sinon fallback sur CsvLoader
     pub fn load_pair_candles(&mut self, symbol: &str) -> Result<bool, String> {
         // Vérifier si déjà chargée
-        if self.data.contains_key(symbol) {
+        if self.series(symbol).is_some() {
             return Ok(false); // Déjà en cache
         }
 
-        let candles = load_pair_candles_strategy(self.db_loader.as_ref(), symbol)?;
+        let canonical = canonical_symbol(symbol);
+        let mut candles =
+            load_pair_candles_strategy(self.db_loader.as_ref(), &canonical, &self.timeframe)?;
+        // Série importée sous le symbole broker avant l'introduction des alias
+        if candles.is_empty() && canonical != symbol {
+            candles = load_pair_candles_strategy(self.db_loader.as_ref(), symbol, &self.timeframe)?;
+        }
 
         if !candles.is_empty() {
             self.add_candles(symbol, candles);
@@ -102,7 +131,13 @@ impl CandleIndex {
     /// Charge une paire spécifique pour une plage de dates donnée
     /// Ne vérifie PAS si les données sont déjà chargées (force le chargement et le merge)
     pub fn load_pair_candles_in_range(&mut self, symbol: &str, start: DateTime<Utc>, end: DateTime<Utc>) -> Result<bool, String> {
-        let candles = load_pair_candles_in_range_strategy(self.db_loader.as_ref(), symbol, start, end)?;
+        let candles = load_pair_candles_in_range_strategy(
+            self.db_loader.as_ref(),
+            &canonical_symbol(symbol),
+            &self.timeframe,
+            start,
+            end,
+        )?;
 
         if !candles.is_empty() {
             self.add_candles(symbol, candles);
@@ -113,7 +148,14 @@ impl CandleIndex {
         }
     }
 
-    /// Ajoute des candles indexées par pair et date
+    /// Série d'une paire, cherchée sous le symbole demandé puis sous sa forme canonique
+    fn series(&self, symbol: &str) -> Option<&BTreeMap<NaiveDate, Vec<Candle>>> {
+        self.data
+            .get(symbol)
+            .or_else(|| self.data.get(&canonical_symbol(symbol)))
+    }
+
+    /// Ajoute des candles indexées par pair (symbole canonique) et date
     fn add_candles(&mut self, symbol: &str, candles: Vec<Candle>) {
         let mut date_map = BTreeMap::new();
 
@@ -122,20 +164,19 @@ impl CandleIndex {
             date_map.entry(date).or_insert_with(Vec::new).push(candle);
         }
 
-        self.data.insert(symbol.to_string(), date_map);
+        self.data.insert(canonical_symbol(symbol), date_map);
     }
 
     /// Retourne true si une paire est chargée, false sinon
     pub fn is_pair_loaded(&self, symbol: &str) -> bool {
-        self.data.contains_key(symbol)
+        self.series(symbol).is_some()
     }
 
     /// Récupère ALL candles pour une paire
     /// NOTE: Cette fonction est conservée pour usage futur
     #[allow(dead_code)]
     pub fn get_all_candles(&self, symbol: &str) -> Option<Vec<Candle>> {
-        self.data
-            .get(symbol)
+        self.series(symbol)
             .map(|date_map| date_map.values().flat_map(|v| v.clone()).collect())
     }
 
@@ -147,7 +188,7 @@ impl CandleIndex {
         start_date: NaiveDate,
         end_date: NaiveDate,
     ) -> Option<Vec<(DateTime<Utc>, f64, f64)>> {
-        self.data.get(symbol).map(|date_map| {
+        self.series(symbol).map(|date_map| {
             date_map
                 .range(start_date..=end_date)
                 .flat_map(|(_, candles)| candles.iter())
@@ -163,7 +204,7 @@ impl CandleIndex {
         start_date: NaiveDate,
         end_date: NaiveDate,
     ) -> Option<Vec<Candle>> {
-        self.data.get(symbol).map(|date_map| {
+        self.series(symbol).map(|date_map| {
             date_map
                 .range(start_date..=end_date)
                 .flat_map(|(_, candles)| candles.iter())
@@ -180,8 +221,7 @@ impl CandleIndex {
         symbol: &str,
         date: NaiveDate,
     ) -> Option<Vec<(DateTime<Utc>, f64, f64)>> {
-        self.data
-            .get(symbol)
+        self.series(symbol)
             .and_then(|date_map| date_map.get(&date))
             .map(|candles| {
                 candles
@@ -203,7 +243,7 @@ impl CandleIndex {
         let baseline_start = event_date - chrono::Duration::days(baseline_days_back);
         let event_hour = event_dt.hour();
 
-        self.data.get(symbol).map(|date_map| {
+        self.series(symbol).map(|date_map| {
             date_map
                 .range(baseline_start..event_date)
                 .flat_map(|(_, candles)| candles.iter())
@@ -228,8 +268,7 @@ impl CandleIndex {
         date: NaiveDate,
         hour: u32,
     ) -> Option<Vec<Candle>> {
-        self.data
-            .get(symbol)
+        self.series(symbol)
             .and_then(|date_map| date_map.get(&date))
             .map(|candles| {
                 candles
@@ -249,7 +288,7 @@ impl CandleIndex {
         start_minute: u32,
         end_minute: u32,
     ) -> Vec<Candle> {
-        if let Some(date_map) = self.data.get(symbol) {
+        if let Some(date_map) = self.series(symbol) {
             date_map
                 .values() // Itère sur Vec<Candle> de chaque jour
                 .flat_map(|day_candles| {
@@ -2,6 +2,7 @@
 // Extrait la logique de chargement de CandleIndex
 
 use crate::models::Candle;
+use crate::services::candle_resampler;
 use crate::services::{CsvLoader, DatabaseLoader};
 use chrono::{DateTime, Utc};
 
@@ -10,6 +11,7 @@ use chrono::{DateTime, Utc};
 pub fn load_pair_candles_strategy(
     db_loader: Option<&DatabaseLoader>,
     symbol: &str,
+    timeframe: &str,
 ) -> Result<Vec<Candle>, String> {
     if let Some(loader) = db_loader {
         // Charger depuis la BD via DatabaseLoader
@@ -20,15 +22,14 @@ pub fn load_pair_candles_strategy(
             DateTime::from_timestamp(2_000_000_000, 0) // ~2033
                 .ok_or_else(|| "Failed to create end datetime".to_string())?;
 
-        loader
-            .load_candles_by_pair(symbol, "M1", start_time, end_time)
-            .map_err(|e| format!("Failed to load candles for {} from DB: {}", symbol, e))
+        load_timeframe_from_db(loader, symbol, timeframe, start_time, end_time)
     } else {
         // Fallback sur CsvLoader si pas de DatabaseLoader
         let loader = CsvLoader::new();
-        loader
+        let candles = loader
             .load_candles(symbol)
-            .map_err(|e| format!("Failed to load candles for {}: {}", symbol, e))
+            .map_err(|e| format!("Failed to load candles for {}: {}", symbol, e))?;
+        resample_if_needed(candles, timeframe)
     }
 }
 
@@ -36,13 +37,12 @@ pub fn load_pair_candles_strategy(
 pub fn load_pair_candles_in_range_strategy(
     db_loader: Option<&DatabaseLoader>,
     symbol: &str,
+    timeframe: &str,
     start: DateTime<Utc>,
     end: DateTime<Utc>,
 ) -> Result<Vec<Candle>, String> {
     if let Some(loader) = db_loader {
-        loader
-            .load_candles_by_pair(symbol, "M1", start, end)
-            .map_err(|e| format!("Failed to load candles for {} from DB: {}", symbol, e))
+        load_timeframe_from_db(loader, symbol, timeframe, start, end)
     } else {
         // Fallback sur CsvLoader (qui charge tout de toute façon)
         // Note: CsvLoader ne supporte pas le range loading natif pour l'instant
@@ -52,8 +52,39 @@ pub fn load_pair_candles_in_range_strategy(
             .map_err(|e| format!("Failed to load candles for {}: {}", symbol, e))?;
             
         // Filtrer manuellement
-        Ok(all_candles.into_iter()
+        let in_range = all_candles.into_iter()
             .filter(|c| c.datetime >= start && c.datetime <= end)
-            .collect())
+            .collect();
+        resample_if_needed(in_range, timeframe)
+    }
+}
+
+/// Timeframe stocké en BD ; si un timeframe dérivé n'a pas encore été persisté
+/// (paire importée avant le rééchantillonnage), il est recalculé depuis le M1.
+fn load_timeframe_from_db(
+    loader: &DatabaseLoader,
+    symbol: &str,
+    timeframe: &str,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<Vec<Candle>, String> {
+    let stored = loader
+        .load_candles_by_pair(symbol, timeframe, start, end)
+        .map_err(|e| format!("Failed to load candles for {} from DB: {}", symbol, e))?;
+    if !stored.is_empty() || timeframe == "M1" {
+        return Ok(stored);
+    }
+    let m1 = loader
+        .load_candles_by_pair(symbol, "M1", start, end)
+        .map_err(|e| format!("Failed to load candles for {} from DB: {}", symbol, e))?;
+    resample_if_needed(m1, timeframe)
+}
+
+/// Rééchantillonne des M1 vers `timeframe` (inchangé pour M1)
+fn resample_if_needed(candles: Vec<Candle>, timeframe: &str) -> Result<Vec<Candle>, String> {
+    match candle_resampler::timeframe_seconds(timeframe) {
+        Some(60) => Ok(candles),
+        Some(secs) => Ok(candle_resampler::resample(&candles, secs)),
+        None => Err(format!("Timeframe non supporté : {}", timeframe)),
     }
 }
@@ -0,0 +1,355 @@
+// services/candle_resampler.rs - Timeframes dérivés (M5/M15/H1/H4/D1) calculés depuis le M1
+// Les barres dérivées sont stockées dans candle_data sous leur propre timeframe,
+// avec source_file = RESAMPLE_SOURCE pour ne jamais écraser des données natives.
+
+use crate::models::candle::{QuoteOhlc, QuoteSide};
+use crate::models::Candle;
+use chrono::{DateTime, Duration, Utc};
+use std::collections::BTreeMap;
+use tracing::info;
+
+/// Timeframes dérivés du M1 : (nom, durée en secondes)
+pub const DERIVED_TIMEFRAMES: [(&str, i64); 5] = [
+    ("M5", 300),
+    ("M15", 900),
+    ("H1", 3_600),
+    ("H4", 14_400),
+    ("D1", 86_400),
+];
+
+/// Valeur de source_file des barres rééchantillonnées
+pub const RESAMPLE_SOURCE: &str = "resample:M1";
+
+/// Durée en secondes d'un timeframe connu (M1 inclus)
+pub fn timeframe_seconds(timeframe: &str) -> Option<i64> {
+    if timeframe == "M1" {
+        return Some(60);
+    }
+    DERIVED_TIMEFRAMES
+        .iter()
+        .find(|(name, _)| *name == timeframe)
+        .map(|(_, secs)| *secs)
+}
+
+/// Agrège des bougies (triées ou non) en barres de `bar_seconds`, alignées sur l'epoch UTC
+/// (D1 = minuit UTC, H4 = 00h/04h/…). Spread moyen pondéré par tick_count si disponible.
+pub fn resample(candles: &[Candle], bar_seconds: i64) -> Vec<Candle> {
+    if bar_seconds <= 0 {
+        return Vec::new();
+    }
+    let mut buckets: BTreeMap<i64, Vec<&Candle>> = BTreeMap::new();
+    for c in candles {
+        let ts = c.datetime.timestamp();
+        buckets
+            .entry(ts - ts.rem_euclid(bar_seconds))
+            .or_default()
+            .push(c);
+    }
+
+    buckets
+        .into_iter()
+        .filter_map(|(start, mut group)| {
+            group.sort_by_key(|c| c.datetime);
+            let first = group.first()?;
+            let last = group.last()?;
+            let spreads: Vec<(f64, f64)> = group
+                .iter()
+                .filter_map(|c| {
+                    let weight = c.tick_count.filter(|t| *t > 0).unwrap_or(1) as f64;
+                    c.spread_mean.map(|s| (s, weight))
+                })
+                .collect();
+            let weight_sum: f64 = spreads.iter().map(|(_, w)| w).sum();
+            let ticks: Vec<i32> = group.iter().filter_map(|c| c.tick_count).collect();
+            let bid = side_ohlc(&group, QuoteSide::Bid);
+            let ask = side_ohlc(&group, QuoteSide::Ask);
+
+            Some(Candle {
+                symbol: first.symbol.clone(),
+                datetime: DateTime::from_timestamp(start, 0)?,
+                open: first.open,
+                high: group
+                    .iter()
+                    .map(|c| c.high)
+                    .fold(f64::NEG_INFINITY, f64::max),
+                low: group.iter().map(|c| c.low).fold(f64::INFINITY, f64::min),
+                close: last.close,
+                volume: group.iter().map(|c| c.volume).sum(),
+                spread_open: first.spread_open,
+                spread_high: group.iter().filter_map(|c| c.spread_high).reduce(f64::max),
+                spread_low: group.iter().filter_map(|c| c.spread_low).reduce(f64::min),
+                spread_close: last.spread_close,
+                spread_mean: (weight_sum > 0.0)
+                    .then(|| spreads.iter().map(|(s, w)| s * w).sum::<f64>() / weight_sum),
+                tick_count: (!ticks.is_empty()).then(|| ticks.iter().sum()),
+                bid_open: bid.map(|q| q.open),
+                bid_high: bid.map(|q| q.high),
+                bid_low: bid.map(|q| q.low),
+                bid_close: bid.map(|q| q.close),
+                ask_open: ask.map(|q| q.open),
+                ask_high: ask.map(|q| q.high),
+                ask_low: ask.map(|q| q.low),
+                ask_close: ask.map(|q| q.close),
+                ..Default::default()
+            })
+        })
+        .collect()
+}
+
+/// OHLC d'un côté sur un groupe trié, seulement si toutes les bougies le portent
+fn side_ohlc(group: &[&Candle], side: QuoteSide) -> Option<QuoteOhlc> {
+    let has_side = |c: &Candle| {
+        let fields = match side {
+            QuoteSide::Bid => [c.bid_open, c.bid_high, c.bid_low, c.bid_close],
+            QuoteSide::Ask => [c.ask_open, c.ask_high, c.ask_low, c.ask_close],
+        };
+        fields.iter().all(Option::is_some)
+    };
+    if group.is_empty() || !group.iter().all(|c| has_side(c)) {
+        return None;
+    }
+    let quotes: Vec<QuoteOhlc> = group.iter().map(|c| c.side_ohlc(side, 0.0)).collect();
+    Some(QuoteOhlc {
+        open: quotes.first()?.open,
+        high: quotes
+            .iter()
+            .map(|q| q.high)
+            .fold(f64::NEG_INFINITY, f64::max),
+        low: quotes.iter().map(|q| q.low).fold(f64::INFINITY, f64::min),
+        close: quotes.last()?.close,
+    })
+}
+
+/// Recalcule et stocke tous les timeframes dérivés sur les jours UTC couvrant [start, end].
+/// Appelé après chaque import M1 pour garder les timeframes dérivés synchronisés.
+pub fn sync_derived_timeframes(
+    conn: &rusqlite::Connection,
+    symbol: &str,
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+) -> Result<usize, String> {
+    let day_start =
+        DateTime::from_timestamp(start.timestamp() - start.timestamp().rem_euclid(86_400), 0)
+            .ok_or("Date de début invalide")?;
+    let day_end = DateTime::from_timestamp(end.timestamp() - end.timestamp().rem_euclid(86_400), 0)
+        .ok_or("Date de fin invalide")?
+        + Duration::days(1);
+    let (from, to) = (day_start.to_rfc3339(), day_end.to_rfc3339());
+
+    let m1 = load_m1(conn, symbol, &from, &to)?;
+    let imported_at = Utc::now().to_rfc3339();
+
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| format!("Erreur début transaction : {}", e))?;
+    let mut written = 0usize;
+
+    for (timeframe, secs) in DERIVED_TIMEFRAMES {
+        tx.execute(
+            "DELETE FROM candle_data
+             WHERE symbol = ? AND timeframe = ? AND time >= ? AND time < ? AND source_file = ?",
+            rusqlite::params![symbol, timeframe, &from, &to, RESAMPLE_SOURCE],
+        )
+        .map_err(|e| format!("Erreur purge {} : {}", timeframe, e))?;
+
+        // ON CONFLICT DO NOTHING : une barre native importée pour ce timeframe est conservée
+        let mut stmt = tx
+            .prepare(
+                "INSERT INTO candle_data (symbol, timeframe, time, open, high, low, close, volume,
+                    spread_open, spread_high, spread_low, spread_close, spread_mean, tick_count,
+                    bid_open, bid_high, bid_low, bid_close, ask_open, ask_high, ask_low, ask_close,
+                    imported_at, source_file)
+                 VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                 ON CONFLICT(symbol, timeframe, time) DO NOTHING",
+            )
+            .map_err(|e| format!("Erreur prepare : {}", e))?;
+
+        for bar in resample(&m1, secs) {
+            written += stmt
+                .execute(rusqlite::params![
+                    symbol,
+                    timeframe,
+                    bar.datetime.to_rfc3339(),
+                    bar.open,
+                    bar.high,
+                    bar.low,
+                    bar.close,
+                    bar.volume,
+                    bar.spread_open,
+                    bar.spread_high,
+                    bar.spread_low,
+                    bar.spread_close,
+                    bar.spread_mean,
+                    bar.tick_count,
+                    bar.bid_open,
+                    bar.bid_high,
+                    bar.bid_low,
+                    bar.bid_close,
+                    bar.ask_open,
+                    bar.ask_high,
+                    bar.ask_low,
+                    bar.ask_close,
+                    &imported_at,
+                    RESAMPLE_SOURCE,
+                ])
+                .map_err(|e| format!("Erreur INSERT {} : {}", timeframe, e))?;
+        }
+    }
+
+    tx.commit().map_err(|e| format!("Erreur commit : {}", e))?;
+    info!(
+        "✅ Timeframes dérivés synchronisés pour {} ({} → {}) : {} barres",
+        symbol, from, to, written
+    );
+    Ok(written)
+}
+
+/// Lit les M1 de [from, to[ (bornes RFC 3339) via la connexion d'import
+fn load_m1(
+    conn: &rusqlite::Connection,
+    symbol: &str,
+    from: &str,
+    to: &str,
+) -> Result<Vec<Candle>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT time, open, high, low, close, volume,
+                    spread_open, spread_high, spread_low, spread_close, spread_mean, tick_count,
+                    bid_open, bid_high, bid_low, bid_close, ask_open, ask_high, ask_low, ask_close
+             FROM candle_data
+             WHERE symbol = ? AND timeframe = 'M1' AND time >= ? AND time < ?",
+        )
+        .map_err(|e| format!("Erreur prepare M1 : {}", e))?;
+
+    let rows = stmt
+        .query_map(rusqlite::params![symbol, from, to], |row| {
+            Ok((
+                row.get::<_, String>(0)?,
+                Candle {
+                    symbol: symbol.to_string(),
+                    open: row.get(1)?,
+                    high: row.get(2)?,
+                    low: row.get(3)?,
+                    close: row.get(4)?,
+                    volume: row.get(5)?,
+                    spread_open: row.get(6)?,
+                    spread_high: row.get(7)?,
+                    spread_low: row.get(8)?,
+                    spread_close: row.get(9)?,
+                    spread_mean: row.get(10)?,
+                    tick_count: row.get(11)?,
+                    bid_open: row.get(12)?,
+                    bid_high: row.get(13)?,
+                    bid_low: row.get(14)?,
+                    bid_close: row.get(15)?,
+                    ask_open: row.get(16)?,
+                    ask_high: row.get(17)?,
+                    ask_low: row.get(18)?,
+                    ask_close: row.get(19)?,
+                    ..Default::default()
+                },
+            ))
+        })
+        .map_err(|e| format!("Erreur lecture M1 : {}", e))?;
+
+    let mut candles = Vec::new();
+    for row in rows {
+        let (time, mut candle) = row.map_err(|e| format!("Erreur ligne M1 : {}", e))?;
+        // Lignes au format non RFC 3339 ignorées (anciens imports)
+        if let Ok(dt) = DateTime::parse_from_rfc3339(&time) {
+            candle.datetime = dt.with_timezone(&Utc);
+            candles.push(candle);
+        }
+    }
+    Ok(candles)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn m1(
+        minute: i64,
+        open: f64,
+        high: f64,
+        low: f64,
+        close: f64,
+        spread: f64,
+        ticks: i32,
+    ) -> Candle {
+        Candle {
+            symbol: "EURUSD".to_string(),
+            datetime: Utc.with_ymd_and_hms(2025, 3, 3, 13, 58, 0).unwrap()
+                + Duration::minutes(minute),
+            open,
+            high,
+            low,
+            close,
+            volume: 10.0,
+            spread_open: Some(spread),
+            spread_high: Some(spread * 2.0),
+            spread_low: Some(spread / 2.0),
+            spread_close: Some(spread),
+            spread_mean: Some(spread),
+            tick_count: Some(ticks),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_resample_m5_ohlc_and_spread() {
+        // 13:58, 13:59 → barre 13:55 ; 14:00..14:02 → barre 14:00
+        let candles = [
+            m1(2, 1.1003, 1.1006, 1.1001, 1.1004, 0.0002, 10),
+            m1(0, 1.1000, 1.1005, 1.0998, 1.1002, 0.0001, 10),
+            m1(3, 1.1004, 1.1009, 1.1003, 1.1008, 0.0004, 30),
+            m1(4, 1.1008, 1.1010, 1.0990, 1.0995, 0.0002, 10),
+            m1(1, 1.1002, 1.1004, 1.1000, 1.1003, 0.0001, 10),
+        ];
+        let bars = resample(&candles, 300);
+        assert_eq!(bars.len(), 2);
+
+        let (a, b) = (&bars[0], &bars[1]);
+        assert_eq!(
+            a.datetime,
+            Utc.with_ymd_and_hms(2025, 3, 3, 13, 55, 0).unwrap()
+        );
+        assert_eq!((a.open, a.close, a.volume), (1.1000, 1.1003, 20.0));
+        assert_eq!(
+            b.datetime,
+            Utc.with_ymd_and_hms(2025, 3, 3, 14, 0, 0).unwrap()
+        );
+        assert_eq!(
+            (b.open, b.high, b.low, b.close),
+            (1.1003, 1.1010, 1.0990, 1.0995)
+        );
+        assert_eq!(b.tick_count, Some(50));
+        assert_eq!(b.spread_high, Some(0.0008));
+        assert_eq!(b.spread_low, Some(0.0001));
+        // (0.0002×10 + 0.0004×30 + 0.0002×10) / 50
+        assert!((b.spread_mean.unwrap_or(0.0) - 0.00032).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_daily_and_h4_alignment() {
+        let candles = [
+            m1(0, 1.0, 1.0, 1.0, 1.0, 0.0001, 1),
+            m1(600, 1.0, 1.0, 1.0, 1.0, 0.0001, 1),
+        ];
+        let d1 = resample(&candles, 86_400);
+        assert_eq!(d1.len(), 1);
+        assert_eq!(
+            d1[0].datetime,
+            Utc.with_ymd_and_hms(2025, 3, 3, 0, 0, 0).unwrap()
+        );
+        let h4 = resample(&candles, 14_400);
+        assert_eq!(h4.len(), 2);
+        assert_eq!(
+            h4[1].datetime,
+            Utc.with_ymd_and_hms(2025, 3, 3, 20, 0, 0).unwrap()
+        );
+        assert_eq!(timeframe_seconds("H4"), Some(14_400));
+        assert_eq!(timeframe_seconds("W1"), None);
+    }
+}
@@ -50,14 +50,22 @@ impl CleanupService {
 
         let updated_count = diesel::update(
             calendar_events
-                .filter(description.eq(target_description))
+                .filter(description.eq(&target_description))
                 .filter(symbol.eq(old_symbol))
                 .filter(calendar_import_id.eq_any(&allowed_ids)),
         )
-        .set(symbol.eq(new_symbol))
+        .set(symbol.eq(&new_symbol))
         .execute(conn)
         .map_err(|e| format!("Failed to update symbol: {}", e))?;
 
+        // L'event_id dépend de la devise : les lignes déplacées sont rattachées à nouveau
+        if updated_count > 0 {
+            crate::services::event_catalog::assign_event_ids_for(
+                conn,
+                &[(new_symbol, target_description)],
+            )?;
+        }
+
         Ok(updated_count)
     }
 
@@ -1,6 +1,7 @@
 // services/csv_cleaner.rs - Nettoyeur de CSV européens
 // Conforme .clinerules : < 150L, pas d'unwrap()
 
+use crate::services::PairDataConverter;
 use serde::{Deserialize, Serialize};
 use std::fs::{create_dir_all, File};
 use std::io::{BufRead, BufReader, Write};
@@ -29,6 +30,11 @@ pub fn clean_european_csv(input_path: &str, output_dir: &Path) -> Result<Cleanin
 
     tracing::debug!("🧹 Nettoyage: {} → {}", input_path, output_path.display());
 
+    // Exports HistData/MT5 : pas de header standard ni décimales européennes, copie telle quelle
+    if PairDataConverter::is_broker_export(input_path)? {
+        return copy_broker_export(input_path, filename, &output_path);
+    }
+
     let input_file = File::open(input_path).map_err(|e| format!("Ouverture: {}", e))?;
     let reader = BufReader::new(input_file);
     let mut output_file = File::create(&output_path).map_err(|e| format!("Création: {}", e))?;
@@ -98,6 +104,30 @@ pub fn clean_european_csv(input_path: &str, output_dir: &Path) -> Result<Cleanin
     Ok(report)
 }
 
+/// Copie un export HistData/MT5 sans le réécrire (lu ensuite par PairDataConverter)
+fn copy_broker_export(
+    input_path: &str,
+    filename: &str,
+    output_path: &Path,
+) -> Result<CleaningReport, String> {
+    let bytes = std::fs::read(input_path).map_err(|e| format!("Lecture: {}", e))?;
+    std::fs::write(output_path, &bytes).map_err(|e| format!("Création: {}", e))?;
+
+    // Compte les fins de ligne (valable aussi en UTF-16 LE)
+    let lines = bytes.iter().filter(|b| **b == b'\n').count();
+    tracing::info!("✅ Export broker copié sans nettoyage ({} lignes)", lines);
+
+    Ok(CleaningReport {
+        original_file: filename.to_string(),
+        cleaned_file: output_path.to_string_lossy().to_string(),
+        status: "success".to_string(),
+        lines_processed: lines,
+        lines_cleaned: lines,
+        errors: 0,
+        warnings: Vec::new(),
+    })
+}
+
 /// Nettoie une ligne individuelle
 fn clean_line(line: &str) -> Result<String, String> {
     // Détecter le délimiteur : point-virgule (nouveau format) ou virgule (ancien)
@@ -5,6 +5,7 @@ pub use discovery::{find_csv_file, list_available_symbols};
 pub use parser::{parse_datetime, parse_price};
 
 use crate::models::{Candle, Result, VolatilityError};
+use crate::services::source_timezone::SourceTimezone;
 use csv::ReaderBuilder;
 use std::fs;
 use std::path::{Path, PathBuf};
@@ -13,6 +14,8 @@ use tracing::{info, warn};
 /// Service de chargement des fichiers CSV
 pub struct CsvLoader {
     csv_directory: PathBuf,
+    /// Fuseau des horodatages texte (UTC pour les CSV normalisés de data/csv)
+    source_timezone: SourceTimezone,
 }
 
 impl CsvLoader {
@@ -24,16 +27,27 @@ impl CsvLoader {
 
         info!("Using user CSV directory: {:?}", csv_directory);
 
-        Self { csv_directory }
+        Self {
+            csv_directory,
+            source_timezone: SourceTimezone::Utc,
+        }
     }
 
     #[allow(dead_code)]
     pub fn with_directory<P: AsRef<Path>>(path: P) -> Self {
         Self {
             csv_directory: path.as_ref().to_path_buf(),
+            source_timezone: SourceTimezone::Utc,
         }
     }
 
+    /// Horodatages texte exprimés dans `timezone` (CSV broker non normalisé)
+    #[allow(dead_code)]
+    pub fn with_source_timezone(mut self, timezone: SourceTimezone) -> Self {
+        self.source_timezone = timezone;
+        self
+    }
+
     /// Liste tous les symboles disponibles
     pub fn list_available_symbols(&self) -> Result<Vec<String>> {
         list_available_symbols(&self.csv_directory)
@@ -95,7 +109,7 @@ impl CsvLoader {
                     continue;
                 };
 
-            let datetime = parse_datetime(&time_str).map_err(|e| {
+            let datetime = parse_datetime(&time_str, &self.source_timezone).map_err(|e| {
                 VolatilityError::InvalidCsvData(format!(
                     "Line {}: Invalid datetime '{}': {}",
                     line_number, time_str, e
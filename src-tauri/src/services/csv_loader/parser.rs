@@ -1,8 +1,10 @@
 use crate::models::{Result, VolatilityError};
+use crate::services::source_timezone::SourceTimezone;
 use chrono::{DateTime, NaiveDateTime, Utc};
 
-/// Parse une date depuis un timestamp Unix ou format "YYYY.MM.DD HH:MM:SS"
-pub fn parse_datetime(s: &str) -> Result<DateTime<Utc>> {
+/// Parse une date depuis un timestamp Unix (toujours UTC) ou le format "YYYY.MM.DD HH:MM:SS"
+/// exprimé dans le fuseau `timezone`, converti en UTC
+pub fn parse_datetime(s: &str, timezone: &SourceTimezone) -> Result<DateTime<Utc>> {
     if let Ok(timestamp) = s.parse::<i64>() {
         return DateTime::from_timestamp(timestamp, 0).ok_or_else(|| {
             VolatilityError::ChronoError(format!("Invalid Unix timestamp: {}", timestamp))
@@ -10,9 +12,10 @@ pub fn parse_datetime(s: &str) -> Result<DateTime<Utc>> {
     }
 
     let normalized = s.replace('.', "-");
-    NaiveDateTime::parse_from_str(&normalized, "%Y-%m-%d %H:%M:%S")
-        .map(|ndt| DateTime::<Utc>::from_naive_utc_and_offset(ndt, Utc))
-        .map_err(|e| VolatilityError::ChronoError(format!("Cannot parse datetime '{}': {}", s, e)))
+    let local = NaiveDateTime::parse_from_str(&normalized, "%Y-%m-%d %H:%M:%S").map_err(|e| {
+        VolatilityError::ChronoError(format!("Cannot parse datetime '{}': {}", s, e))
+    })?;
+    timezone.to_utc(local).map_err(VolatilityError::ChronoError)
 }
 
 /// Parse un prix (f64) avec gestion d'erreur
@@ -38,14 +41,14 @@ mod tests {
 
     #[test]
     fn test_parse_datetime() {
-        let result = parse_datetime("1704117600");
+        let result = parse_datetime("1704117600", &SourceTimezone::Utc);
         assert!(result.is_ok());
         let dt = result.expect("Failed to parse datetime");
         assert_eq!(dt.year(), 2024);
         assert_eq!(dt.month(), 1);
         assert_eq!(dt.day(), 1);
 
-        let result = parse_datetime("2025.01.01 00:00:00");
+        let result = parse_datetime("2025.01.01 00:00:00", &SourceTimezone::Utc);
         assert!(result.is_ok());
         let dt = result.expect("Failed to parse datetime");
         assert_eq!(dt.year(), 2025);
@@ -54,6 +57,18 @@ mod tests {
         assert_eq!(dt.hour(), 0);
     }
 
+    #[test]
+    fn test_parse_datetime_in_source_timezone() {
+        // Serveur NY-close en été (UTC+3) : 15:30 serveur = 12:30 UTC
+        let dt = parse_datetime("2024.07.05 15:30:00", &SourceTimezone::BrokerNyClose)
+            .expect("Failed to parse datetime");
+        assert_eq!((dt.hour(), dt.minute()), (12, 30));
+        // Un timestamp Unix n'est jamais décalé
+        let unix = parse_datetime("1704117600", &SourceTimezone::BrokerNyClose)
+            .expect("Failed to parse datetime");
+        assert_eq!(unix.timestamp(), 1704117600);
+    }
+
     #[test]
     fn test_parse_price() {
         assert!(parse_price("42499.0", "open", 1).is_ok());
@@ -0,0 +1,393 @@
+// services/data_quality.rs - Scanner de qualité des données M1 (candle_data)
+// Trous hors week-ends/jours fériés, flux gelé, OHLC incohérents, doublons,
+// spreads aberrants et sauts de prix, agrégés par mois.
+
+use crate::models::data_quality::{DataGap, DataQualityReport, DayCoverage, MonthlyQuality};
+use crate::models::Candle;
+use crate::services::instrument_registry;
+use chrono::{DateTime, Datelike, Duration, NaiveDate, Timelike, Utc, Weekday};
+use std::collections::BTreeMap;
+
+/// Longueur minimale d'une séquence figée pour être signalée
+pub const ZERO_RANGE_RUN_MIN: usize = 5;
+/// Spread moyen > facteur × médiane de la paire → aberrant
+pub const SPREAD_OUTLIER_FACTOR: f64 = 10.0;
+/// |open − close précédent| > facteur × range M1 médian → saut suspect
+pub const PRICE_JUMP_FACTOR: f64 = 20.0;
+/// Nombre de trous conservés dans le rapport
+pub const LARGEST_GAPS: usize = 20;
+/// Seuil de couverture journalière par défaut (%)
+pub const DEFAULT_MIN_DAY_COVERAGE_PCT: f64 = 90.0;
+
+/// Marché ouvert à la minute `t` ? Forex/indices : fermé du vendredi 21:00 au dimanche
+/// 22:00 UTC (bornes larges pour couvrir l'heure d'été), le 25/12 et le 01/01.
+/// Crypto : toujours ouvert.
+pub fn marche_ouvert(always_open: bool, t: DateTime<Utc>) -> bool {
+    if always_open {
+        return true;
+    }
+    if (t.month() == 12 && t.day() == 25) || (t.month() == 1 && t.day() == 1) {
+        return false;
+    }
+    match t.weekday() {
+        Weekday::Sat => false,
+        Weekday::Fri => t.hour() < 21,
+        Weekday::Sun => t.hour() >= 22,
+        _ => true,
+    }
+}
+
+/// Couverture de chaque jour entre la première et la dernière bougie (jours fermés exclus)
+pub fn couverture_par_jour(symbol: &str, candles: &[Candle]) -> BTreeMap<NaiveDate, DayCoverage> {
+    let always_open = is_always_open(symbol);
+    let mut present: BTreeMap<NaiveDate, u32> = BTreeMap::new();
+    for t in distinct_minutes(candles) {
+        if marche_ouvert(always_open, t) {
+            *present.entry(t.date_naive()).or_default() += 1;
+        }
+    }
+
+    let (Some(first), Some(last)) = (
+        candles.iter().map(|c| c.datetime.date_naive()).min(),
+        candles.iter().map(|c| c.datetime.date_naive()).max(),
+    ) else {
+        return BTreeMap::new();
+    };
+
+    first
+        .iter_days()
+        .take_while(|d| *d <= last)
+        .filter_map(|date| {
+            let expected = minutes_attendues(always_open, date);
+            if expected == 0 {
+                return None;
+            }
+            let present_minutes = present.get(&date).copied().unwrap_or(0);
+            Some((
+                date,
+                DayCoverage {
+                    date,
+                    expected_minutes: expected,
+                    present_minutes,
+                    coverage_pct: pct(present_minutes, expected),
+                },
+            ))
+        })
+        .collect()
+}
+
+/// Conserve les bougies des jours couverts à au moins `min_coverage_pct` %.
+/// Retourne les bougies retenues et le nombre de jours exclus.
+pub fn filtrer_jours_couverts(
+    symbol: &str,
+    candles: &[Candle],
+    min_coverage_pct: f64,
+) -> (Vec<Candle>, usize) {
+    let days = couverture_par_jour(symbol, candles);
+    let excluded = days
+        .values()
+        .filter(|d| d.coverage_pct < min_coverage_pct)
+        .count();
+    let kept = candles
+        .iter()
+        .filter(|c| {
+            days.get(&c.datetime.date_naive())
+                .map(|d| d.coverage_pct >= min_coverage_pct)
+                .unwrap_or(false)
+        })
+        .cloned()
+        .collect();
+    (kept, excluded)
+}
+
+/// Rapport de qualité complet. `min_day_coverage_pct` sélectionne les jours listés
+/// dans `low_coverage_days`.
+pub fn analyser_qualite(
+    symbol: &str,
+    candles: &[Candle],
+    min_day_coverage_pct: f64,
+) -> DataQualityReport {
+    let always_open = is_always_open(symbol);
+    let mut sorted: Vec<&Candle> = candles.iter().collect();
+    sorted.sort_by_key(|c| c.datetime);
+
+    let days = couverture_par_jour(symbol, candles);
+    let mut months: BTreeMap<String, MonthlyQuality> = BTreeMap::new();
+    for d in days.values() {
+        let m = month_entry(&mut months, d.date);
+        m.expected_minutes += d.expected_minutes;
+        m.present_minutes += d.present_minutes;
+    }
+
+    // Trous entre minutes distinctes consécutives
+    let minutes = distinct_minutes(candles);
+    let mut gaps = Vec::new();
+    for pair in minutes.windows(2) {
+        if let Some(gap) = trou_entre(always_open, pair[0], pair[1]) {
+            month_entry(&mut months, gap.start.date_naive()).gap_count += 1;
+            gaps.push(gap);
+        }
+    }
+    gaps.sort_by_key(|g| std::cmp::Reverse(g.missing_minutes));
+    gaps.truncate(LARGEST_GAPS);
+
+    let spread_limit = median(
+        sorted
+            .iter()
+            .filter_map(|c| c.spread_mean)
+            .filter(|s| *s > 0.0),
+    )
+    .map(|m| m * SPREAD_OUTLIER_FACTOR);
+    let jump_limit = median(sorted.iter().map(|c| c.high - c.low).filter(|r| *r > 0.0))
+        .map(|m| m * PRICE_JUMP_FACTOR);
+
+    let mut frozen_run = 0usize;
+    for (i, c) in sorted.iter().enumerate() {
+        let date = c.datetime.date_naive();
+        let prev = i.checked_sub(1).map(|p| sorted[p]);
+        let contiguous = prev.filter(|p| c.datetime - p.datetime == Duration::minutes(1));
+
+        if prev.is_some_and(|p| p.datetime == c.datetime) {
+            month_entry(&mut months, date).duplicate_timestamps += 1;
+        }
+        if !ohlc_coherent(c) {
+            month_entry(&mut months, date).ohlc_errors += 1;
+        }
+        if matches!((spread_limit, c.spread_mean), (Some(limit), Some(s)) if s > limit) {
+            month_entry(&mut months, date).spread_outliers += 1;
+        }
+        if matches!((jump_limit, contiguous), (Some(limit), Some(p)) if (c.open - p.close).abs() > limit)
+        {
+            month_entry(&mut months, date).price_jumps += 1;
+        }
+
+        let frozen = c.high == c.low && contiguous.is_some_and(|p| p.close == c.close);
+        frozen_run = if frozen { frozen_run + 1 } else { 0 };
+        // Comptée une seule fois, quand la séquence atteint la longueur minimale
+        if frozen_run + 1 == ZERO_RANGE_RUN_MIN {
+            month_entry(&mut months, date).zero_range_runs += 1;
+        }
+    }
+
+    let months: Vec<MonthlyQuality> = months
+        .into_values()
+        .map(|mut m| {
+            m.coverage_pct = pct(m.present_minutes, m.expected_minutes);
+            m
+        })
+        .collect();
+    let expected_minutes = months.iter().map(|m| m.expected_minutes).sum();
+    let present_minutes = months.iter().map(|m| m.present_minutes).sum();
+
+    DataQualityReport {
+        symbol: symbol.to_string(),
+        total_candles: candles.len(),
+        first_candle: sorted.first().map(|c| c.datetime),
+        last_candle: sorted.last().map(|c| c.datetime),
+        expected_minutes,
+        present_minutes,
+        coverage_pct: pct(present_minutes, expected_minutes),
+        months,
+        largest_gaps: gaps,
+        low_coverage_days: days
+            .into_values()
+            .filter(|d| d.coverage_pct < min_day_coverage_pct)
+            .collect(),
+    }
+}
+
+fn is_always_open(symbol: &str) -> bool {
+    instrument_registry::resolve_instrument(symbol).trading_hours == "24/7"
+}
+
+/// Minutes distinctes (tronquées à la minute), triées
+fn distinct_minutes(candles: &[Candle]) -> Vec<DateTime<Utc>> {
+    let mut minutes: Vec<DateTime<Utc>> = candles
+        .iter()
+        .filter_map(|c| DateTime::from_timestamp(c.datetime.timestamp() / 60 * 60, 0))
+        .collect();
+    minutes.sort();
+    minutes.dedup();
+    minutes
+}
+
+fn minutes_attendues(always_open: bool, date: NaiveDate) -> u32 {
+    let Some(midnight) = date.and_hms_opt(0, 0, 0).map(|n| n.and_utc()) else {
+        return 0;
+    };
+    (0..1440)
+        .filter(|m| marche_ouvert(always_open, midnight + Duration::minutes(*m)))
+        .count() as u32
+}
+
+/// Minutes de marché ouvert manquantes strictement entre `a` et `b`
+fn trou_entre(always_open: bool, a: DateTime<Utc>, b: DateTime<Utc>) -> Option<DataGap> {
+    let mut gap: Option<DataGap> = None;
+    let mut t = a + Duration::minutes(1);
+    while t < b {
+        if marche_ouvert(always_open, t) {
+            match gap.as_mut() {
+                Some(g) => {
+                    g.end = t;
+                    g.missing_minutes += 1;
+                }
+                None => {
+                    gap = Some(DataGap {
+                        start: t,
+                        end: t,
+                        missing_minutes: 1,
+                    })
+                }
+            }
+        }
+        t += Duration::minutes(1);
+    }
+    gap
+}
+
+fn ohlc_coherent(c: &Candle) -> bool {
+    c.open > 0.0
+        && c.close > 0.0
+        && c.low > 0.0
+        && c.high >= c.open.max(c.close)
+        && c.low <= c.open.min(c.close)
+}
+
+fn month_entry(
+    months: &mut BTreeMap<String, MonthlyQuality>,
+    date: NaiveDate,
+) -> &mut MonthlyQuality {
+    let key = format!("{:04}-{:02}", date.year(), date.month());
+    months.entry(key.clone()).or_insert_with(|| MonthlyQuality {
+        month: key,
+        ..Default::default()
+    })
+}
+
+fn pct(present: u32, expected: u32) -> f64 {
+    if expected == 0 {
+        0.0
+    } else {
+        present as f64 / expected as f64 * 100.0
+    }
+}
+
+fn median(values: impl Iterator<Item = f64>) -> Option<f64> {
+    let mut values: Vec<f64> = values.collect();
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    Some(values[values.len() / 2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    /// Mercredi 5 mars 2025
+    fn candle(minute: i64, price: f64, range: f64) -> Candle {
+        Candle {
+            symbol: "EURUSD".to_string(),
+            datetime: Utc.with_ymd_and_hms(2025, 3, 5, 0, 0, 0).unwrap()
+                + Duration::minutes(minute),
+            open: price,
+            high: price + range,
+            low: price,
+            close: price,
+            volume: 1.0,
+            spread_mean: Some(0.00001),
+            ..Default::default()
+        }
+    }
+
+    fn full_day() -> Vec<Candle> {
+        (0..1440)
+            .map(|m| candle(m, 1.1 + (m % 7) as f64 * 0.0001, 0.0002))
+            .collect()
+    }
+
+    #[test]
+    fn test_market_hours() {
+        let fri = Utc.with_ymd_and_hms(2025, 3, 7, 21, 0, 0).unwrap();
+        let sun = Utc.with_ymd_and_hms(2025, 3, 9, 22, 0, 0).unwrap();
+        assert!(!marche_ouvert(false, fri));
+        assert!(marche_ouvert(false, fri - Duration::minutes(1)));
+        assert!(marche_ouvert(false, sun));
+        assert!(!marche_ouvert(false, sun - Duration::minutes(1)));
+        assert!(!marche_ouvert(
+            false,
+            Utc.with_ymd_and_hms(2025, 12, 25, 12, 0, 0).unwrap()
+        ));
+        assert!(marche_ouvert(true, fri + Duration::days(1)));
+    }
+
+    #[test]
+    fn test_full_day_is_clean() {
+        let report = analyser_qualite("EURUSD", &full_day(), 95.0);
+        assert_eq!(report.coverage_pct, 100.0);
+        assert!(report.largest_gaps.is_empty());
+        assert!(report.low_coverage_days.is_empty());
+        let m = &report.months[0];
+        assert_eq!(m.month, "2025-03");
+        assert_eq!(
+            (
+                m.zero_range_runs,
+                m.ohlc_errors,
+                m.duplicate_timestamps,
+                m.spread_outliers,
+                m.price_jumps
+            ),
+            (0, 0, 0, 0, 0)
+        );
+    }
+
+    #[test]
+    fn test_detects_gap_and_anomalies() {
+        let mut candles: Vec<Candle> = full_day()
+            .into_iter()
+            .filter(|c| {
+                !(600..660).contains(&((c.datetime.hour() * 60 + c.datetime.minute()) as i64))
+            })
+            .collect();
+        // Flux gelé 10 minutes à partir de 02:00
+        let frozen = candles[120].open;
+        for c in &mut candles[120..130] {
+            (c.open, c.high, c.low, c.close) = (frozen, frozen, frozen, frozen);
+        }
+        candles[300].low = candles[300].open + 0.001;
+        candles[400].spread_mean = Some(0.001);
+        // Décalage de niveau à 08:20 : un seul saut
+        for c in &mut candles[500..] {
+            c.open += 0.05;
+            c.high += 0.05;
+            c.low += 0.05;
+            c.close += 0.05;
+        }
+        candles.push(candles[700].clone());
+
+        let report = analyser_qualite("EURUSD", &candles, 99.0);
+        assert_eq!(report.largest_gaps.len(), 1);
+        assert_eq!(report.largest_gaps[0].missing_minutes, 60);
+        assert_eq!(report.low_coverage_days.len(), 1);
+        let m = &report.months[0];
+        assert_eq!(m.gap_count, 1);
+        assert_eq!(m.zero_range_runs, 1);
+        assert_eq!(m.ohlc_errors, 1);
+        assert_eq!(m.duplicate_timestamps, 1);
+        assert_eq!(m.spread_outliers, 1);
+        assert_eq!(m.price_jumps, 1);
+    }
+
+    #[test]
+    fn test_filter_excludes_low_coverage_days() {
+        let mut candles = full_day();
+        // Jeudi avec 2 heures seulement
+        candles.extend((1440..1560).map(|m| candle(m, 1.1, 0.0002)));
+        let (kept, excluded) = filtrer_jours_couverts("EURUSD", &candles, 90.0);
+        assert_eq!(excluded, 1);
+        assert_eq!(kept.len(), 1440);
+        assert_eq!(couverture_par_jour("EURUSD", &candles).len(), 2);
+    }
+}
@@ -3,6 +3,7 @@
 
 use crate::db::DbPool;
 use crate::models::candle::Candle;
+use crate::services::tick_aggregator::RawTick;
 use chrono::{DateTime, Utc};
 use std::path::PathBuf;
 use tracing::{error, info, instrument};
@@ -30,6 +31,9 @@ impl std::fmt::Display for LoaderError {
 
 impl std::error::Error for LoaderError {}
 
+/// Première et dernière bougie d'une série
+pub type DateRange = (DateTime<Utc>, DateTime<Utc>);
+
 /// ============================================================================
 /// SERVICE DE CHARGEMENT DES CANDLES DEPUIS LA BD
 /// ============================================================================
@@ -105,7 +109,8 @@ impl DatabaseLoader {
         let mut stmt = conn
             .prepare(
                 "SELECT symbol, time, open, high, low, close, volume,
-                        spread_open, spread_high, spread_low, spread_close, spread_mean, tick_count
+                        spread_open, spread_high, spread_low, spread_close, spread_mean, tick_count,
+                        bid_open, bid_high, bid_low, bid_close, ask_open, ask_high, ask_low, ask_close
                  FROM candle_data
                  WHERE symbol = ? AND timeframe = ? AND time >= ? AND time <= ?
                  ORDER BY time ASC",
@@ -133,6 +138,17 @@ impl DatabaseLoader {
                         row.get::<_, Option<f64>>(10)?, // spread_close
                         row.get::<_, Option<f64>>(11)?, // spread_mean
                         row.get::<_, Option<i32>>(12)?, // tick_count
+                        // bid_open..bid_close, ask_open..ask_close
+                        [
+                            row.get::<_, Option<f64>>(13)?,
+                            row.get::<_, Option<f64>>(14)?,
+                            row.get::<_, Option<f64>>(15)?,
+                            row.get::<_, Option<f64>>(16)?,
+                            row.get::<_, Option<f64>>(17)?,
+                            row.get::<_, Option<f64>>(18)?,
+                            row.get::<_, Option<f64>>(19)?,
+                            row.get::<_, Option<f64>>(20)?,
+                        ],
                     ))
                 },
             )
@@ -144,7 +160,7 @@ impl DatabaseLoader {
         let candles: Result<Vec<_>, LoaderError> = rows
             .map(|row_result| {
                 let (sym, time_str, open, high, low, close, volume,
-                     sp_open, sp_high, sp_low, sp_close, sp_mean, t_count) =
+                     sp_open, sp_high, sp_low, sp_close, sp_mean, t_count, sides) =
                     row_result.map_err(|e| LoaderError::Query(e.to_string()))?;
 
                 let datetime = DateTime::parse_from_rfc3339(&time_str)
@@ -162,6 +178,16 @@ impl DatabaseLoader {
                 candle.spread_close = sp_close;
                 candle.spread_mean = sp_mean;
                 candle.tick_count = t_count;
+                [
+                    candle.bid_open,
+                    candle.bid_high,
+                    candle.bid_low,
+                    candle.bid_close,
+                    candle.ask_open,
+                    candle.ask_high,
+                    candle.ask_low,
+                    candle.ask_close,
+                ] = sides;
                 Ok(candle)
             })
             .collect();
@@ -236,6 +262,104 @@ impl DatabaseLoader {
 
         Ok(count)
     }
+
+    /// Première et dernière bougie stockées pour une paire/timeframe (None si aucune)
+    #[instrument(skip(self))]
+    pub fn get_date_range(
+        &self,
+        symbol: &str,
+        timeframe: &str,
+    ) -> Result<Option<DateRange>, LoaderError> {
+        let conn = self.get_rusqlite_conn()?;
+
+        let (first, last): (Option<String>, Option<String>) = conn
+            .query_row(
+                "SELECT MIN(time), MAX(time) FROM candle_data WHERE symbol = ? AND timeframe = ?",
+                rusqlite::params![symbol, timeframe],
+                |row| Ok((row.get(0)?, row.get(1)?)),
+            )
+            .map_err(|e| LoaderError::Query(e.to_string()))?;
+
+        let parse = |time_str: String| {
+            DateTime::parse_from_rfc3339(&time_str)
+                .map(|dt| dt.with_timezone(&Utc))
+                .map_err(|_| LoaderError::Parsing(format!("Invalid datetime: {}", time_str)))
+        };
+        match (first, last) {
+            (Some(first), Some(last)) => Ok(Some((parse(first)?, parse(last)?))),
+            _ => Ok(None),
+        }
+    }
+
+    /// Charge les ticks bruts (table tick_data) dans [start_time, end_time[.
+    /// Vide si aucun tick n'a été conservé à l'import pour cette fenêtre.
+    #[instrument(skip(self), fields(symbol = %symbol))]
+    pub fn load_ticks(
+        &self,
+        symbol: &str,
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+    ) -> Result<Vec<RawTick>, LoaderError> {
+        let conn = self.get_rusqlite_conn()?;
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT time_ms, bid, ask, bid_volume, ask_volume
+                 FROM tick_data
+                 WHERE symbol = ? AND time_ms >= ? AND time_ms < ?
+                 ORDER BY time_ms ASC",
+            )
+            .map_err(|e| {
+                error!("Query prepare error: {}", e);
+                LoaderError::Query(e.to_string())
+            })?;
+
+        let rows = stmt
+            .query_map(
+                rusqlite::params![
+                    symbol,
+                    start_time.timestamp_millis(),
+                    end_time.timestamp_millis()
+                ],
+                |row| {
+                    Ok((
+                        row.get::<_, i64>(0)?,
+                        row.get::<_, f64>(1)?,
+                        row.get::<_, f64>(2)?,
+                        row.get::<_, f64>(3)?,
+                        row.get::<_, f64>(4)?,
+                    ))
+                },
+            )
+            .map_err(|e| LoaderError::Query(e.to_string()))?;
+
+        let ticks = rows
+            .map(|row_result| {
+                let (time_ms, bid, ask, bid_volume, ask_volume) =
+                    row_result.map_err(|e| LoaderError::Query(e.to_string()))?;
+                let datetime_utc = DateTime::from_timestamp_millis(time_ms).ok_or_else(|| {
+                    LoaderError::Parsing(format!("Invalid tick timestamp: {}", time_ms))
+                })?;
+                Ok(RawTick {
+                    datetime_utc,
+                    bid,
+                    ask,
+                    bid_volume,
+                    ask_volume,
+                })
+            })
+            .collect::<Result<Vec<_>, LoaderError>>()?;
+
+        info!(
+            "Loaded {} ticks for {} from {} to {}",
+            ticks.len(),
+            symbol,
+            start_time,
+            end_time
+        );
+
+        Ok(ticks)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,444 @@
+// services/directional_bias.rs - Sens du mouvement après annonce
+// Relie le signe de la surprise, la position de la devise dans la paire (base / cotation)
+// et le sens réalisé à 1, 5 et 15 minutes : un stop unique dans le sens attendu ne bat
+// le straddle symétrique que si ce sens se confirme sur l'historique.
+
+use crate::models::directional_bias::{
+    CurrencySide, DirectionalBiasRow, EntryMode, HorizonHitRate, MoveDirection, SurpriseSign,
+};
+use crate::models::Candle;
+use crate::services::bootstrap::rate_interval;
+use crate::services::significance::{benjamini_hochberg, sign_test_two_sided_p_value};
+use chrono::{DateTime, Duration, Utc};
+use std::collections::BTreeMap;
+
+/// Horizons de mesure du sens réalisé
+pub const BIAS_HORIZONS_MINUTES: [u32; 3] = [1, 5, 15];
+/// Occurrences minimales par annonce et signe de surprise
+pub const DEFAULT_MIN_OCCURRENCES: usize = 5;
+/// Horizon servant au choix du type d'entrée (index dans BIAS_HORIZONS_MINUTES)
+const DECISION_HORIZON_INDEX: usize = 1;
+/// Borne basse (5%) du taux de biais au-dessus de laquelle le stop unique est conseillé
+const ONE_SIDED_MIN_LOWER_BOUND: f64 = 0.5;
+/// p-value ajustée maximale du biais pour conseiller le stop unique
+const ONE_SIDED_MAX_ADJUSTED_P: f64 = 0.05;
+/// Annonces dont un chiffre supérieur au consensus affaiblit la devise
+const INVERTED_EVENT_KEYWORDS: [&str; 3] = ["unemployment", "jobless", "claimant"];
+
+/// Occurrence d'une annonce avec son mouvement aux horizons 1 / 5 / 15 minutes
+#[derive(Debug, Clone)]
+pub struct BiasObservation {
+    pub event_id: String,
+    pub event_type: String,
+    pub currency: String,
+    pub side: CurrencySide,
+    /// false pour un indice ("US30") : pas de sens théorique déductible de la devise
+    pub has_base_currency: bool,
+    pub sign: SurpriseSign,
+    /// Pips (positif = hausse), None si la bougie de l'horizon manque
+    pub moves: [Option<f64>; 3],
+}
+
+/// Devises de base et de cotation d'un symbole (pas de base pour un indice : "US30")
+pub fn pair_currencies(pair: &str) -> (Option<String>, String) {
+    let instrument = crate::services::instrument_registry::resolve_instrument(pair);
+    let quote = instrument.quote_currency;
+    let base = instrument
+        .symbol
+        .strip_suffix(quote.as_str())
+        .filter(|b| b.len() == 3 && b.chars().all(|c| c.is_ascii_alphabetic()))
+        .map(|b| b.to_string());
+    (base, quote)
+}
+
+/// Position d'une devise dans la paire ; None si la paire n'y est pas exposée
+pub fn currency_side(base: Option<&str>, quote: &str, currency: &str) -> Option<CurrencySide> {
+    if quote.eq_ignore_ascii_case(currency) {
+        Some(CurrencySide::Quote)
+    } else if base.is_some_and(|b| b.eq_ignore_ascii_case(currency)) {
+        Some(CurrencySide::Base)
+    } else {
+        None
+    }
+}
+
+/// Chômage, inscriptions : un chiffre au-dessus du consensus est négatif pour la devise
+pub fn is_inverted_event(event_id: &str) -> bool {
+    INVERTED_EVENT_KEYWORDS
+        .iter()
+        .any(|keyword| event_id.contains(keyword))
+}
+
+/// Sens théorique de la paire : devise renforcée si la surprise est favorable
+pub fn theoretical_direction(
+    side: CurrencySide,
+    sign: SurpriseSign,
+    inverted: bool,
+) -> MoveDirection {
+    let strengthens = (sign == SurpriseSign::Positive) != inverted;
+    if strengthens == (side == CurrencySide::Base) {
+        MoveDirection::Up
+    } else {
+        MoveDirection::Down
+    }
+}
+
+/// Mouvement (pips) entre l'ouverture de T0 et la clôture de chaque horizon.
+/// None si la bougie de T0 manque ; un horizon sans bougie (1 min de tolérance) vaut None.
+pub fn mouvements_horizons(
+    candles: &[Candle],
+    event_time: DateTime<Utc>,
+    pip_value: f64,
+) -> Option<[Option<f64>; 3]> {
+    if pip_value <= 0.0 {
+        return None;
+    }
+    let first = candles
+        .iter()
+        .find(|c| c.datetime >= event_time && c.datetime < event_time + Duration::minutes(1))?;
+    let reference = first.open;
+    Some(BIAS_HORIZONS_MINUTES.map(|minutes| {
+        let end = event_time + Duration::minutes(minutes as i64);
+        candles
+            .iter()
+            .rev()
+            .find(|c| c.datetime < end && c.datetime >= end - Duration::minutes(2))
+            .map(|c| (c.close - reference) / pip_value)
+    }))
+}
+
+/// Taux par horizon ; `direction` donne le sens théorique de chaque occurrence (s'il existe)
+fn taux_horizons<F>(observations: &[&BiasObservation], direction: F) -> Vec<HorizonHitRate>
+where
+    F: Fn(&BiasObservation) -> Option<MoveDirection>,
+{
+    BIAS_HORIZONS_MINUTES
+        .iter()
+        .enumerate()
+        .map(|(i, &minutes)| {
+            let moves: Vec<(f64, Option<MoveDirection>)> = observations
+                .iter()
+                .filter_map(|o| o.moves[i].map(|m| (m, direction(o))))
+                .collect();
+            let n = moves.len();
+            let ups = moves.iter().filter(|(m, _)| *m > 0.0).count();
+            let directed: Vec<(f64, MoveDirection)> =
+                moves.iter().filter_map(|&(m, d)| Some((m, d?))).collect();
+            let hits = directed
+                .iter()
+                .filter(|(m, d)| match d {
+                    MoveDirection::Up => *m > 0.0,
+                    MoveDirection::Down => *m < 0.0,
+                })
+                .count();
+            let ratio = |count: usize| if n > 0 { count as f64 / n as f64 } else { 0.0 };
+            let has_direction = !directed.is_empty();
+            HorizonHitRate {
+                minutes,
+                sample_size: n,
+                up_rate: ratio(ups),
+                hit_rate: has_direction.then(|| hits as f64 / directed.len() as f64),
+                hit_rate_ci: has_direction.then(|| rate_interval(hits, directed.len())),
+                avg_move_pips: if n > 0 {
+                    moves.iter().map(|(m, _)| m).sum::<f64>() / n as f64
+                } else {
+                    0.0
+                },
+            }
+        })
+        .collect()
+}
+
+fn direction_theorique(o: &BiasObservation) -> Option<MoveDirection> {
+    o.has_base_currency
+        .then(|| theoretical_direction(o.side, o.sign, is_inverted_event(&o.event_id)))
+}
+
+/// Taux du sens théorique toutes annonces confondues
+pub fn taux_global(observations: &[BiasObservation]) -> Vec<HorizonHitRate> {
+    let all: Vec<&BiasObservation> = observations.iter().collect();
+    taux_horizons(&all, direction_theorique)
+}
+
+/// Tableau "sens attendu selon la surprise" : une ligne par annonce, devise, position dans
+/// la paire et signe de surprise (un id `raw:` ne porte pas la devise) ayant au moins
+/// `min_occurrences` occurrences, biais le plus marqué en premier.
+/// Le sens attendu est le sens majoritaire observé : il est testé en bilatéral. Le stop
+/// unique n'est conseillé que si le biais reste significatif une fois les p-values
+/// corrigées sur l'ensemble des lignes (sinon le meilleur de 20 groupes passe par hasard).
+pub fn tableau_biais(
+    observations: &[BiasObservation],
+    min_occurrences: usize,
+) -> Vec<DirectionalBiasRow> {
+    type GroupKey<'a> = (&'a str, &'a str, CurrencySide, SurpriseSign);
+    let mut groups: BTreeMap<GroupKey, Vec<&BiasObservation>> = BTreeMap::new();
+    for o in observations {
+        groups
+            .entry((o.event_id.as_str(), o.currency.as_str(), o.side, o.sign))
+            .or_default()
+            .push(o);
+    }
+
+    let (mut rows, p_values): (Vec<DirectionalBiasRow>, Vec<f64>) = groups
+        .into_values()
+        .filter(|members| members.len() >= min_occurrences.max(1))
+        .map(|members| {
+            let first = members[0];
+            let theoretical = direction_theorique(first);
+            let horizons = taux_horizons(&members, direction_theorique);
+            let decision = &horizons[DECISION_HORIZON_INDEX];
+            let ups = (decision.up_rate * decision.sample_size as f64).round() as usize;
+            let (expected_direction, in_direction) = if decision.up_rate >= 0.5 {
+                (MoveDirection::Up, ups)
+            } else {
+                (MoveDirection::Down, decision.sample_size - ups)
+            };
+            let bias_strength_ci = rate_interval(in_direction, decision.sample_size);
+            let p_value =
+                sign_test_two_sided_p_value(in_direction, decision.sample_size).unwrap_or(1.0);
+            let row = DirectionalBiasRow {
+                event_id: first.event_id.clone(),
+                event_type: first.event_type.clone(),
+                currency: first.currency.clone(),
+                side: first.side,
+                surprise_sign: first.sign,
+                theoretical_direction: theoretical,
+                expected_direction,
+                bias_strength: decision.up_rate.max(1.0 - decision.up_rate),
+                bias_strength_ci,
+                adjusted_p_value: p_value,
+                entry_mode: EntryMode::Straddle,
+                sample_size: members.len(),
+                horizons,
+            };
+            (row, p_value)
+        })
+        .unzip();
+
+    for (row, adjusted) in rows.iter_mut().zip(benjamini_hochberg(&p_values)) {
+        row.adjusted_p_value = adjusted;
+        let decision = &row.horizons[DECISION_HORIZON_INDEX];
+        if decision.sample_size >= min_occurrences
+            && row.bias_strength_ci.lower > ONE_SIDED_MIN_LOWER_BOUND
+            && adjusted <= ONE_SIDED_MAX_ADJUSTED_P
+        {
+            row.entry_mode = EntryMode::OneSided;
+        }
+    }
+    rows.sort_by(|a, b| {
+        b.bias_strength
+            .partial_cmp(&a.bias_strength)
+            .unwrap_or(std::cmp::Ordering::Equal)
+            .then_with(|| b.sample_size.cmp(&a.sample_size))
+    });
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::test_fixtures::{m1, release, PIP};
+
+    fn observation(
+        event_id: &str,
+        side: CurrencySide,
+        sign: SurpriseSign,
+        move_pips: f64,
+    ) -> BiasObservation {
+        BiasObservation {
+            event_id: event_id.to_string(),
+            event_type: event_id.to_string(),
+            currency: "USD".to_string(),
+            side,
+            has_base_currency: true,
+            sign,
+            moves: [Some(move_pips / 2.0), Some(move_pips), Some(move_pips)],
+        }
+    }
+
+    #[test]
+    fn test_sens_theorique_selon_devise_et_surprise() {
+        assert_eq!(
+            currency_side(Some("EUR"), "USD", "usd"),
+            Some(CurrencySide::Quote)
+        );
+        assert_eq!(
+            currency_side(Some("EUR"), "USD", "EUR"),
+            Some(CurrencySide::Base)
+        );
+        assert_eq!(currency_side(None, "USD", "EUR"), None);
+
+        // CPI US au-dessus du consensus : USD renforcé → EURUSD baisse, USDJPY monte
+        let cpi = is_inverted_event("usd_cpi_mom");
+        assert_eq!(
+            theoretical_direction(CurrencySide::Quote, SurpriseSign::Positive, cpi),
+            MoveDirection::Down
+        );
+        assert_eq!(
+            theoretical_direction(CurrencySide::Base, SurpriseSign::Positive, cpi),
+            MoveDirection::Up
+        );
+        // Chômage au-dessus du consensus : USD affaibli → EURUSD monte
+        let unemployment = is_inverted_event("usd_unemployment_rate");
+        assert!(unemployment);
+        assert_eq!(
+            theoretical_direction(CurrencySide::Quote, SurpriseSign::Positive, unemployment),
+            MoveDirection::Up
+        );
+    }
+
+    #[test]
+    fn test_mouvements_horizons() {
+        let candles: Vec<Candle> = (0..15)
+            .map(|m| {
+                let open = 1.1000 - m as f64 * PIP;
+                m1(m, open, open + PIP, open - 2.0 * PIP, open - PIP)
+            })
+            .collect();
+        let moves = mouvements_horizons(&candles, release(), PIP).unwrap();
+        let rounded = moves.map(|m| m.map(|v| v.round()));
+        assert_eq!(rounded, [Some(-1.0), Some(-5.0), Some(-15.0)]);
+
+        // Sans bougie à T0 : pas de mesure ; horizon 15 min absent : None
+        assert!(mouvements_horizons(&candles[1..], release(), PIP).is_none());
+        let moves = mouvements_horizons(&candles[..10], release(), PIP).unwrap();
+        assert_eq!(moves[2], None);
+    }
+
+    #[test]
+    fn test_tableau_biais_et_type_entree() {
+        let mut observations: Vec<BiasObservation> = (0..12)
+            .map(|_| {
+                observation(
+                    "usd_cpi_mom",
+                    CurrencySide::Quote,
+                    SurpriseSign::Positive,
+                    -20.0,
+                )
+            })
+            .collect();
+        observations.extend((0..12).map(|i| {
+            let move_pips = if i % 2 == 0 { 15.0 } else { -15.0 };
+            observation(
+                "usd_nfp",
+                CurrencySide::Quote,
+                SurpriseSign::Negative,
+                move_pips,
+            )
+        }));
+        observations.push(observation(
+            "usd_ppi_mom",
+            CurrencySide::Quote,
+            SurpriseSign::Positive,
+            5.0,
+        ));
+
+        let rows = tableau_biais(&observations, 5);
+        assert_eq!(rows.len(), 2, "groupe sous le minimum exclu");
+
+        let cpi = &rows[0];
+        assert_eq!(cpi.event_id, "usd_cpi_mom");
+        assert_eq!(cpi.theoretical_direction, Some(MoveDirection::Down));
+        assert_eq!(cpi.expected_direction, MoveDirection::Down);
+        assert_eq!(cpi.bias_strength, 1.0);
+        assert_eq!(cpi.entry_mode, EntryMode::OneSided);
+        assert!(cpi.adjusted_p_value < 0.001);
+        assert_eq!(cpi.horizons[1].hit_rate, Some(1.0));
+
+        let nfp = &rows[1];
+        assert_eq!(nfp.bias_strength, 0.5);
+        assert_eq!(nfp.entry_mode, EntryMode::Straddle);
+
+        let overall = taux_global(&observations);
+        assert_eq!(overall.len(), 3);
+        assert_eq!(overall[1].sample_size, 25);
+    }
+
+    #[test]
+    fn test_biais_corrige_sur_toutes_les_lignes() {
+        // 9 hausses sur 10 : p bilatéral = 0.021, stop unique conseillé seul
+        let biased = |observations: &mut Vec<BiasObservation>| {
+            observations.extend((0..10).map(|i| {
+                let move_pips = if i == 0 { -10.0 } else { 10.0 };
+                observation(
+                    "usd_gdp",
+                    CurrencySide::Base,
+                    SurpriseSign::Positive,
+                    move_pips,
+                )
+            }));
+        };
+        let mut alone = Vec::new();
+        biased(&mut alone);
+        assert_eq!(tableau_biais(&alone, 5)[0].entry_mode, EntryMode::OneSided);
+
+        // Parmi 10 annonces testées, le même biais ne survit pas à Benjamini-Hochberg
+        let mut many = Vec::new();
+        biased(&mut many);
+        for k in 0..9 {
+            let event_id = format!("usd_event_{}", k);
+            many.extend((0..10).map(|i| {
+                let move_pips = if i % 2 == 0 { 10.0 } else { -10.0 };
+                observation(
+                    &event_id,
+                    CurrencySide::Base,
+                    SurpriseSign::Positive,
+                    move_pips,
+                )
+            }));
+        }
+        let rows = tableau_biais(&many, 5);
+        let gdp = rows.iter().find(|r| r.event_id == "usd_gdp").unwrap();
+        assert!(gdp.adjusted_p_value > 0.05);
+        assert_eq!(gdp.entry_mode, EntryMode::Straddle);
+    }
+
+    #[test]
+    fn test_meme_id_brut_separe_par_devise() {
+        // "raw:gdp" publié pour l'EUR (base) et l'USD (cotation) : sens opposés
+        let observations: Vec<BiasObservation> = (0..6)
+            .flat_map(|_| {
+                [
+                    BiasObservation {
+                        currency: "EUR".to_string(),
+                        ..observation("raw:gdp", CurrencySide::Base, SurpriseSign::Positive, 12.0)
+                    },
+                    observation(
+                        "raw:gdp",
+                        CurrencySide::Quote,
+                        SurpriseSign::Positive,
+                        -12.0,
+                    ),
+                ]
+            })
+            .collect();
+        let rows = tableau_biais(&observations, 5);
+        assert_eq!(rows.len(), 2);
+        assert!(rows
+            .iter()
+            .all(|r| r.bias_strength == 1.0 && r.sample_size == 6));
+        let eur = rows.iter().find(|r| r.currency == "EUR").unwrap();
+        assert_eq!(eur.expected_direction, MoveDirection::Up);
+        assert_eq!(eur.horizons[1].hit_rate, Some(1.0));
+    }
+
+    #[test]
+    fn test_indice_sans_sens_theorique() {
+        let observations: Vec<BiasObservation> = (0..6)
+            .map(|_| BiasObservation {
+                has_base_currency: false,
+                ..observation(
+                    "usd_cpi_mom",
+                    CurrencySide::Quote,
+                    SurpriseSign::Positive,
+                    30.0,
+                )
+            })
+            .collect();
+        let rows = tableau_biais(&observations, 5);
+        assert_eq!(rows[0].theoretical_direction, None);
+        assert_eq!(rows[0].expected_direction, MoveDirection::Up);
+        assert_eq!(rows[0].horizons[1].hit_rate, None);
+        assert!(taux_global(&observations)[1].hit_rate_ci.is_none());
+    }
+}
@@ -1,17 +1,18 @@
 // services/dukascopy_instruments.rs
-// Liste des instruments Dukascopy supportés (CDN public).
+// Instruments supportés par le CDN public Dukascopy (fiches du registre marquées dukascopy).
 
+use crate::services::instrument_registry;
 use serde::Serialize;
 
 /// Instrument disponible sur le CDN Dukascopy
 #[derive(Debug, Clone, Serialize)]
 pub struct DukascopyInstrument {
     /// Identifiant CDN (ex: "EURUSD")
-    pub id: &'static str,
+    pub id: String,
     /// Nom affiché (ex: "EUR/USD")
-    pub display: &'static str,
+    pub display: String,
     /// Catégorie (ex: "Forex Major")
-    pub category: &'static str,
+    pub category: String,
     /// Diviseur pour décoder les prix bi5 (ex: 100_000.0 pour 5 décimales)
     pub point_value: f64,
     /// Valeur d'un pip (ex: 0.0001)
@@ -20,35 +21,16 @@ pub struct DukascopyInstrument {
 
 /// Retourne la liste complète des instruments supportés.
 pub fn get_instruments() -> Vec<DukascopyInstrument> {
-    vec![
-        // ─── Forex Majeurs ───
-        DukascopyInstrument { id: "EURUSD", display: "EUR/USD", category: "Forex Major", point_value: 100_000.0, pip_value: 0.0001 },
-        DukascopyInstrument { id: "GBPUSD", display: "GBP/USD", category: "Forex Major", point_value: 100_000.0, pip_value: 0.0001 },
-        DukascopyInstrument { id: "USDJPY", display: "USD/JPY", category: "Forex Major", point_value: 1_000.0, pip_value: 0.01 },
-        DukascopyInstrument { id: "USDCHF", display: "USD/CHF", category: "Forex Major", point_value: 100_000.0, pip_value: 0.0001 },
-        DukascopyInstrument { id: "AUDUSD", display: "AUD/USD", category: "Forex Major", point_value: 100_000.0, pip_value: 0.0001 },
-        DukascopyInstrument { id: "NZDUSD", display: "NZD/USD", category: "Forex Major", point_value: 100_000.0, pip_value: 0.0001 },
-        DukascopyInstrument { id: "USDCAD", display: "USD/CAD", category: "Forex Major", point_value: 100_000.0, pip_value: 0.0001 },
-        // ─── Forex Cross ───
-        DukascopyInstrument { id: "EURGBP", display: "EUR/GBP", category: "Forex Cross", point_value: 100_000.0, pip_value: 0.0001 },
-        DukascopyInstrument { id: "EURJPY", display: "EUR/JPY", category: "Forex Cross", point_value: 1_000.0, pip_value: 0.01 },
-        DukascopyInstrument { id: "GBPJPY", display: "GBP/JPY", category: "Forex Cross", point_value: 1_000.0, pip_value: 0.01 },
-        DukascopyInstrument { id: "EURCHF", display: "EUR/CHF", category: "Forex Cross", point_value: 100_000.0, pip_value: 0.0001 },
-        DukascopyInstrument { id: "AUDJPY", display: "AUD/JPY", category: "Forex Cross", point_value: 1_000.0, pip_value: 0.01 },
-        DukascopyInstrument { id: "CHFJPY", display: "CHF/JPY", category: "Forex Cross", point_value: 1_000.0, pip_value: 0.01 },
-        DukascopyInstrument { id: "GBPCHF", display: "GBP/CHF", category: "Forex Cross", point_value: 100_000.0, pip_value: 0.0001 },
-        DukascopyInstrument { id: "EURAUD", display: "EUR/AUD", category: "Forex Cross", point_value: 100_000.0, pip_value: 0.0001 },
-        DukascopyInstrument { id: "GBPAUD", display: "GBP/AUD", category: "Forex Cross", point_value: 100_000.0, pip_value: 0.0001 },
-        // ─── Métaux ───
-        DukascopyInstrument { id: "XAUUSD", display: "XAU/USD (Or)", category: "Métal", point_value: 1_000.0, pip_value: 0.01 },
-        DukascopyInstrument { id: "XAGUSD", display: "XAG/USD (Argent)", category: "Métal", point_value: 100_000.0, pip_value: 0.0001 },
-        // ─── Crypto ───
-        DukascopyInstrument { id: "BTCUSD", display: "BTC/USD", category: "Crypto", point_value: 100.0, pip_value: 0.01 },
-        DukascopyInstrument { id: "ETHUSD", display: "ETH/USD", category: "Crypto", point_value: 100.0, pip_value: 0.01 },
-        DukascopyInstrument { id: "LTCUSD", display: "LTC/USD", category: "Crypto", point_value: 100.0, pip_value: 0.01 },
-        DukascopyInstrument { id: "XRPUSD", display: "XRP/USD", category: "Crypto", point_value: 100_000.0, pip_value: 0.00001 },
-        DukascopyInstrument { id: "SOLUSD", display: "SOL/USD", category: "Crypto", point_value: 100.0, pip_value: 0.01 },
-    ]
+    instrument_registry::dukascopy_instruments()
+        .into_iter()
+        .map(|i| DukascopyInstrument {
+            point_value: 10f64.powi(i.digits),
+            pip_value: i.pip_size,
+            id: i.symbol,
+            display: i.display_name,
+            category: i.category,
+        })
+        .collect()
 }
 
 /// Recherche un instrument par ID (insensible à la casse).
@@ -5,9 +5,14 @@
 // occurrence historique et calcule le profit net = mouvement brut - spread réel.
 // Le spread provient des ticks importés (champ spread_mean sur Candle).
 
-use crate::models::asset_class::AssetProperties;
+use crate::models::candle::QuoteSide;
 use crate::models::entry_analysis::{EntryAnalysisConfig, EntryAnalysisResult, MinuteDetail};
+use crate::models::pre_release::PreReleaseAdvice;
 use crate::models::{Candle, Result, VolatilityError};
+use crate::models::walk_forward::WalkForwardConfig;
+use crate::services::bootstrap::{mean_interval, rate_interval};
+use crate::services::entry_point_walk_forward::{walk_forward_offsets, IssuesJour};
+use crate::services::pair_data::get_asset_properties;
 use chrono::{NaiveDate, Timelike};
 use std::collections::HashMap;
 use tracing::info;
@@ -33,7 +38,7 @@ pub fn analyze_entry_points(
     quarter: u8,
     config: &EntryAnalysisConfig,
 ) -> Result<EntryAnalysisResult> {
-    let props = AssetProperties::from_symbol(symbol);
+    let props = get_asset_properties(symbol);
     if props.pip_value <= 0.0 {
         return Err(VolatilityError::ValidationError(format!(
             "pip_value invalide pour {symbol}"
@@ -107,6 +112,15 @@ pub fn analyze_entry_points(
         props.pip_value,
     );
 
+    // Mêmes fenêtres glissantes que l'optimiseur walk-forward, en jours
+    let wf = WalkForwardConfig::default();
+    let walk_forward = walk_forward_offsets(
+        &issues_par_jour(&daily, hour, quarter_start_min, config, props.pip_value),
+        config,
+        wf.in_sample_occurrences,
+        wf.out_of_sample_occurrences,
+    );
+
     let entry_min = quarter_start_min + opt.offset;
     let label = format!("{hour:02}:{entry_min:02} UTC");
 
@@ -116,8 +130,11 @@ pub fn analyze_entry_points(
         optimal_offset_minutes: opt.offset,
         optimal_entry_time_label: label,
         real_win_rate: opt.win_rate,
+        real_win_rate_ci: opt.win_rate_ci,
         avg_net_profit_pips: opt.avg_net_profit_pips,
+        avg_net_profit_ci: opt.avg_net_profit_ci,
         avg_spread_at_entry_pips: opt.avg_spread_pips,
+        avg_spread_at_entry_ci: opt.avg_spread_ci,
         avg_movement_pips: opt.avg_net_profit_pips + 2.0 * opt.avg_spread_pips,
         peak_minute,
         movement_duration_minutes: duration,
@@ -127,9 +144,31 @@ pub fn analyze_entry_points(
         non_tradable_minutes: non_tradable,
         minute_details,
         unit: props.unit,
+        optimal_offset_seconds: None,
+        second_details: Vec::new(),
+        excluded_days: 0,
+        pre_release: None,
+        entry_advice: PreReleaseAdvice::Normal,
+        walk_forward,
     })
 }
 
+/// Répercute l'étude pré-annonce (`result.pre_release`) sur le conseil d'entrée :
+/// entrée avancée au début du mouvement anticipé, ou straddle déconseillé.
+pub fn appliquer_pre_annonce(result: &mut EntryAnalysisResult, hour: u8, quarter: u8) {
+    let Some(profile) = &result.pre_release else {
+        return;
+    };
+    result.entry_advice = profile.advice;
+    if let (PreReleaseAdvice::EnterEarlier, Some(before)) =
+        (profile.advice, profile.onset_minutes_before)
+    {
+        let start = (hour as i64 * 60 + quarter as i64 * 15 - before as i64).rem_euclid(24 * 60);
+        result.optimal_entry_time_label =
+            format!("{:02}:{:02} UTC (T-{} min)", start / 60, start % 60, before);
+    }
+}
+
 // ─── Fonctions internes ─────────────────────────────────────────────────────
 
 fn group_candles_by_date(candles: &[Candle]) -> HashMap<NaiveDate, Vec<&Candle>> {
@@ -143,6 +182,30 @@ fn group_candles_by_date(candles: &[Candle]) -> HashMap<NaiveDate, Vec<&Candle>>
     groups
 }
 
+/// (profit net, spread) de chaque offset, jour par jour dans l'ordre chronologique
+fn issues_par_jour(
+    daily: &HashMap<NaiveDate, Vec<&Candle>>,
+    hour: u8,
+    quarter_start_min: u8,
+    config: &EntryAnalysisConfig,
+    pip_value: f64,
+) -> Vec<IssuesJour> {
+    let mut dates: Vec<&NaiveDate> = daily.keys().collect();
+    dates.sort();
+    dates
+        .into_iter()
+        .map(|date| {
+            let day = &daily[date];
+            std::array::from_fn(|offset| {
+                let entry = find_candle_at(day, hour, quarter_start_min + offset as u8)?;
+                let forward = find_forward_candles(day, entry, config.forward_minutes);
+                simulate_straddle(entry, &forward, pip_value)
+                    .map(|sim| (sim.profit_net_pips, sim.spread_pips))
+            })
+        })
+        .collect()
+}
+
 fn find_candle_at<'a>(day: &[&'a Candle], hour: u8, minute: u8) -> Option<&'a Candle> {
     day.iter()
         .find(|c| c.datetime.hour() == hour as u32 && c.datetime.minute() == minute as u32)
@@ -165,8 +228,10 @@ fn find_forward_candles<'a>(
 }
 
 /// Simule un straddle réaliste avec sortie à durée fixe :
-/// - Mouvement = |close_sortie - close_entrée| à la FIN de la fenêtre (pas au meilleur moment)
-/// - Coût straddle = 2× spread (on ouvre 2 positions : long + short)
+/// - Jambe gagnante à la FIN de la fenêtre (pas au meilleur moment) :
+///   long = achat ask → vente bid, short = vente bid → rachat ask
+/// - L'autre jambe coûte un spread de plus (on ouvre 2 positions : long + short)
+/// - Sans OHLC bid/ask stocké : mid ± spread/2, soit |mouvement| − 2× spread
 /// - Win = profit net > 0 après déduction du coût total
 ///   Pas de biais look-ahead : on ne connaît pas le futur
 fn simulate_straddle(entry: &Candle, forward: &[&Candle], pip_value: f64) -> Option<StraddleSim> {
@@ -175,10 +240,16 @@ fn simulate_straddle(entry: &Candle, forward: &[&Candle], pip_value: f64) -> Opt
     }
     let spread_price = entry.spread_mean.unwrap_or(0.0);
     let spread_pips = spread_price / pip_value;
+    let hs = spread_price / 2.0;
 
     // Sortie à la fin de la fenêtre (time-based exit, pas d'optimisation ex-post)
     let exit_candle = forward.last()?;
-    let movement_pips = (exit_candle.close - entry.close).abs() / pip_value;
+    let long_pips = (exit_candle.side_ohlc(QuoteSide::Bid, hs).close
+        - entry.side_ohlc(QuoteSide::Ask, hs).close)
+        / pip_value;
+    let short_pips = (entry.side_ohlc(QuoteSide::Bid, hs).close
+        - exit_candle.side_ohlc(QuoteSide::Ask, hs).close)
+        / pip_value;
 
     // Pic = minute du plus grand mouvement (pour info uniquement, pas pour le calcul de profit)
     let mut peak_offset = 1usize;
@@ -191,9 +262,8 @@ fn simulate_straddle(entry: &Candle, forward: &[&Candle], pip_value: f64) -> Opt
         }
     }
 
-    // Straddle = 2 positions → coût = 2× spread
-    let straddle_cost = spread_pips * 2.0;
-    let profit_net = movement_pips - straddle_cost;
+    // Straddle = 2 positions → la jambe perdante coûte un spread supplémentaire
+    let profit_net = long_pips.max(short_pips) - spread_pips;
 
     Some(StraddleSim {
         profit_net_pips: profit_net,
@@ -213,9 +283,8 @@ fn analyze_offset(
 ) -> MinuteDetail {
     let target_min = quarter_start_min + offset;
     let mut wins = 0usize;
-    let mut total = 0usize;
-    let mut sum_profit = 0.0f64;
-    let mut sum_spread = 0.0f64;
+    let mut profits: Vec<f64> = Vec::new();
+    let mut spreads: Vec<f64> = Vec::new();
 
     for day_candles in daily.values() {
         let entry = match find_candle_at(day_candles, hour, target_min) {
@@ -224,20 +293,20 @@ fn analyze_offset(
         };
         let forward = find_forward_candles(day_candles, entry, config.forward_minutes);
         if let Some(sim) = simulate_straddle(entry, &forward, pip_value) {
-            total += 1;
-            sum_profit += sim.profit_net_pips;
-            sum_spread += sim.spread_pips;
+            profits.push(sim.profit_net_pips);
+            spreads.push(sim.spread_pips);
             if sim.is_win {
                 wins += 1;
             }
         }
     }
 
+    let total = profits.len();
     let (win_rate, avg_profit, avg_spread) = if total > 0 {
         (
             wins as f64 / total as f64,
-            sum_profit / total as f64,
-            sum_spread / total as f64,
+            profits.iter().sum::<f64>() / total as f64,
+            spreads.iter().sum::<f64>() / total as f64,
         )
     } else {
         (0.0, 0.0, 0.0)
@@ -246,8 +315,11 @@ fn analyze_offset(
     MinuteDetail {
         offset,
         win_rate,
+        win_rate_ci: rate_interval(wins, total),
         avg_net_profit_pips: avg_profit,
+        avg_net_profit_ci: mean_interval(&profits),
         avg_spread_pips: avg_spread,
+        avg_spread_ci: mean_interval(&spreads),
         sample_size: total,
         tradable: total == 0 || avg_spread <= config.spread_threshold_pips,
     }
@@ -420,6 +492,23 @@ mod tests {
         assert!(sim.profit_net_pips < 0.0);
     }
 
+    #[test]
+    fn test_simulate_straddle_uses_stored_sides() {
+        let mut entry = make_candle(14, 30, 1.1000, 1.1005, 1.0995, 0.0002);
+        let mut exit = make_candle(14, 31, 1.1010, 1.1012, 1.0998, 0.0002);
+        // Spread réel plus large à la sortie : bid 1.1007 au lieu de 1.1009 (mid − 1 pip)
+        (entry.ask_close, entry.bid_close) = (Some(1.1001), Some(1.0999));
+        (exit.ask_close, exit.bid_close) = (Some(1.1013), Some(1.1007));
+        for c in [&mut entry, &mut exit] {
+            (c.ask_open, c.ask_high, c.ask_low) = (c.ask_close, c.ask_close, c.ask_close);
+            (c.bid_open, c.bid_high, c.bid_low) = (c.bid_close, c.bid_close, c.bid_close);
+        }
+
+        let sim = simulate_straddle(&entry, &[&exit], 0.0001).expect("should produce result");
+        // Long : 1.1007 − 1.1001 = 6 pips, moins 2 pips pour la jambe short
+        assert!((sim.profit_net_pips - 4.0).abs() < 1e-6);
+    }
+
     #[test]
     fn test_non_tradable_zone() {
         let config = EntryAnalysisConfig {
@@ -531,6 +620,8 @@ mod tests {
             result.real_win_rate
         );
         assert_eq!(result.sample_size, 5);
+        // 5 occurrences seulement : l'intervalle bootstrap reste large
+        assert!(result.real_win_rate_ci.lower < 0.6 && result.real_win_rate_ci.upper > 0.6);
     }
 
     #[test]
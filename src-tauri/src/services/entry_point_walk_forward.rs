@@ -0,0 +1,133 @@
+// services/entry_point_walk_forward.rs
+// Offset d'entrée choisi en walk-forward.
+//
+// L'offset optimal d'`analyze_entry_points` est ajusté sur tout l'historique. Ici, le
+// meilleur offset de chaque fenêtre in-sample est appliqué tel quel aux jours suivants :
+// seule cette performance out-of-sample dit si l'offset tient hors ajustement.
+
+use crate::models::entry_analysis::{EntryAnalysisConfig, EntryWalkForward};
+use crate::services::bootstrap::mean_interval;
+use std::collections::HashMap;
+
+/// (profit net, spread) en pips pour chaque offset 0-14 d'un jour. None sans entrée simulable.
+pub type IssuesJour = [Option<(f64, f64)>; 15];
+
+/// Fenêtres glissantes sur des jours triés chronologiquement. None si aucune fenêtre
+/// n'a retenu d'offset.
+pub fn walk_forward_offsets(
+    jours: &[IssuesJour],
+    config: &EntryAnalysisConfig,
+    in_sample: usize,
+    out_of_sample: usize,
+) -> Option<EntryWalkForward> {
+    if in_sample == 0 || out_of_sample == 0 {
+        return None;
+    }
+
+    let mut choix: Vec<u8> = Vec::new();
+    let mut profits: Vec<f64> = Vec::new();
+    let mut start = 0usize;
+    while start + in_sample < jours.len() {
+        let oos_end = (start + in_sample + out_of_sample).min(jours.len());
+        if let Some(offset) = meilleur_offset(&jours[start..start + in_sample], config) {
+            choix.push(offset);
+            profits.extend(
+                jours[start + in_sample..oos_end]
+                    .iter()
+                    .filter_map(|j| j[offset as usize])
+                    .map(|(profit, _)| profit),
+            );
+        }
+        start += out_of_sample;
+    }
+
+    let mut counts: HashMap<u8, usize> = HashMap::new();
+    for offset in &choix {
+        *counts.entry(*offset).or_default() += 1;
+    }
+    // Égalité de fréquence : l'offset le plus tôt
+    let most_selected = counts
+        .into_iter()
+        .max_by_key(|(offset, n)| (*n, std::cmp::Reverse(*offset)))
+        .map(|(offset, _)| offset)?;
+
+    let wins = profits.iter().filter(|p| **p > 0.0).count();
+    let n = profits.len();
+    Some(EntryWalkForward {
+        windows: choix.len(),
+        most_selected_offset_minutes: most_selected,
+        out_of_sample_sample_size: n,
+        out_of_sample_win_rate: if n > 0 { wins as f64 / n as f64 } else { 0.0 },
+        out_of_sample_avg_net_profit_pips: if n > 0 {
+            profits.iter().sum::<f64>() / n as f64
+        } else {
+            0.0
+        },
+        out_of_sample_avg_net_profit_ci: mean_interval(&profits),
+    })
+}
+
+/// Même règle que l'offset optimal : meilleur profit net moyen parmi les offsets
+/// tradables ayant assez d'échantillons
+fn meilleur_offset(jours: &[IssuesJour], config: &EntryAnalysisConfig) -> Option<u8> {
+    (0u8..15)
+        .filter_map(|offset| {
+            let issues: Vec<(f64, f64)> = jours.iter().filter_map(|j| j[offset as usize]).collect();
+            if issues.len() < config.min_samples.max(1) {
+                return None;
+            }
+            let n = issues.len() as f64;
+            let spread = issues.iter().map(|(_, s)| s).sum::<f64>() / n;
+            if spread > config.spread_threshold_pips {
+                return None;
+            }
+            Some((offset, issues.iter().map(|(p, _)| p).sum::<f64>() / n))
+        })
+        .max_by(|a, b| a.1.partial_cmp(&b.1).unwrap_or(std::cmp::Ordering::Equal))
+        .map(|(offset, _)| offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn jour(gagnant: usize) -> IssuesJour {
+        std::array::from_fn(|o| {
+            Some(if o == gagnant {
+                (10.0, 1.0)
+            } else {
+                (-2.0, 1.0)
+            })
+        })
+    }
+
+    #[test]
+    fn test_offset_in_sample_applique_hors_echantillon() {
+        let config = EntryAnalysisConfig {
+            min_samples: 2,
+            ..Default::default()
+        };
+        // L'offset gagnant passe de 3 à 7 : la fenêtre suivante paie le retard
+        let jours: Vec<IssuesJour> = (0..8).map(|i| jour(if i < 3 { 3 } else { 7 })).collect();
+        let wf = walk_forward_offsets(&jours, &config, 4, 2).expect("fenêtres");
+
+        assert_eq!(wf.windows, 2);
+        assert_eq!(wf.most_selected_offset_minutes, 3);
+        assert_eq!(wf.out_of_sample_sample_size, 4);
+        // Fenêtre 1 : offset 3 sur jours 4-5 (-2, -2) ; fenêtre 2 : offset 7 sur 6-7 (10, 10)
+        assert!((wf.out_of_sample_avg_net_profit_pips - 4.0).abs() < 1e-9);
+        assert!((wf.out_of_sample_win_rate - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_aucun_offset_tradable() {
+        let config = EntryAnalysisConfig {
+            spread_threshold_pips: 0.5,
+            min_samples: 1,
+            ..Default::default()
+        };
+        let jours: Vec<IssuesJour> = (0..8).map(|_| jour(3)).collect();
+        assert!(walk_forward_offsets(&jours, &config, 4, 2).is_none());
+        assert!(walk_forward_offsets(&jours, &EntryAnalysisConfig::default(), 0, 2).is_none());
+    }
+}
@@ -11,6 +11,8 @@ use tracing::{debug, info};
 pub struct EntryTimingOptimizer<'a> {
     candles: &'a [Candle],
     event_time: DateTime<Utc>,
+    /// Distances SL/TP fixes en prix, prioritaires sur les multiplicateurs ATR
+    fixed_stops: Option<(f64, f64)>,
 }
 
 impl<'a> EntryTimingOptimizer<'a> {
@@ -19,9 +21,16 @@ impl<'a> EntryTimingOptimizer<'a> {
         Self {
             candles,
             event_time,
+            fixed_stops: None,
         }
     }
 
+    /// Utilise des distances SL/TP fixes en prix (ex. percentiles MAE/MFE historiques)
+    pub fn with_fixed_stops(mut self, fixed_stops: Option<(f64, f64)>) -> Self {
+        self.fixed_stops = fixed_stops;
+        self
+    }
+
     /// Analyse tous les timings possibles et trouve l'optimal
     pub fn find_optimal_timing(
         &self,
@@ -93,7 +102,8 @@ impl<'a> EntryTimingOptimizer<'a> {
         atr_multiplier_tp: f64,
         max_duration_minutes: usize,
     ) -> Result<TimingAnalysis> {
-        let calculator = WinRateCalculator::new(self.candles, self.event_time);
+        let calculator = WinRateCalculator::new(self.candles, self.event_time)
+            .with_fixed_stops(self.fixed_stops);
 
         let outcome = calculator.simulate_trade(
             minutes_before,
@@ -0,0 +1,240 @@
+// services/event_catalog/db.rs - CRUD des tables event_catalog / event_aliases (volatility.db)
+// et rattachement des lignes calendar_events (colonne event_id)
+// Conforme .clinerules : retourne Result<T, VolatilityError>
+
+use crate::db::DbPool;
+use crate::models::event_catalog::{CanonicalEvent, EventAlias, EventAliasKind, EventCategory};
+use crate::models::VolatilityError;
+use diesel::prelude::*;
+use diesel::r2d2::{ConnectionManager, PooledConnection};
+use diesel::sql_types::Text;
+use std::collections::HashMap;
+
+type PooledConn = PooledConnection<ConnectionManager<SqliteConnection>>;
+
+/// Toutes les annonces avec leurs alias, dans l'ordre d'insertion
+pub fn get_all_events(pool: &DbPool) -> Result<Vec<CanonicalEvent>, VolatilityError> {
+    let mut conn = connection(pool)?;
+    let rows: Vec<CatalogRow> = diesel::sql_query(
+        "SELECT id, name, country, currency, category, default_impact FROM event_catalog ORDER BY rowid",
+    )
+    .load(&mut conn)
+    .map_err(|e| VolatilityError::DatabaseError(format!("Query failed: {}", e)))?;
+    let alias_rows: Vec<AliasRow> =
+        diesel::sql_query("SELECT event_id, alias, match_kind FROM event_aliases ORDER BY id")
+            .load(&mut conn)
+            .map_err(|e| VolatilityError::DatabaseError(format!("Query failed: {}", e)))?;
+
+    let mut aliases: HashMap<String, Vec<EventAlias>> = HashMap::new();
+    for row in alias_rows {
+        if let Some(kind) = EventAliasKind::parse(&row.match_kind) {
+            aliases.entry(row.event_id).or_default().push(EventAlias {
+                alias: row.alias,
+                kind,
+            });
+        }
+    }
+
+    Ok(rows
+        .into_iter()
+        .map(|row| CanonicalEvent {
+            aliases: aliases.remove(&row.id).unwrap_or_default(),
+            id: row.id,
+            name: row.name,
+            country: row.country,
+            currency: row.currency,
+            category: EventCategory::parse(&row.category),
+            default_impact: row.default_impact,
+        })
+        .collect())
+}
+
+/// Insère les annonces absentes (avec leurs alias) ; retourne le nombre d'annonces ajoutées
+pub fn insert_missing(pool: &DbPool, events: &[CanonicalEvent]) -> Result<usize, VolatilityError> {
+    let mut conn = connection(pool)?;
+    conn.transaction(|conn| {
+        let mut inserted = 0;
+        for event in events {
+            let added = write_event(conn, event, "ON CONFLICT(id) DO NOTHING")?;
+            if added > 0 {
+                write_aliases(conn, event)?;
+            }
+            inserted += added;
+        }
+        Ok(inserted)
+    })
+}
+
+/// Crée ou remplace une annonce et l'ensemble de ses alias
+pub fn upsert_event(pool: &DbPool, event: &CanonicalEvent) -> Result<(), VolatilityError> {
+    let mut conn = connection(pool)?;
+    conn.transaction(|conn| {
+        write_event(
+            conn,
+            event,
+            "ON CONFLICT(id) DO UPDATE SET name = excluded.name, country = excluded.country,
+                currency = excluded.currency, category = excluded.category,
+                default_impact = excluded.default_impact, updated_at = CURRENT_TIMESTAMP",
+        )?;
+        diesel::sql_query("DELETE FROM event_aliases WHERE event_id = ?")
+            .bind::<Text, _>(&event.id)
+            .execute(conn)
+            .map_err(|e| VolatilityError::DatabaseError(format!("Delete failed: {}", e)))?;
+        write_aliases(conn, event)
+    })?;
+    tracing::info!(
+        "✅ Annonce sauvegardée: {} ({} alias)",
+        event.id,
+        event.aliases.len()
+    );
+    Ok(())
+}
+
+/// Supprime une annonce et ses alias ; retourne true si elle existait
+pub fn delete_event(pool: &DbPool, id: &str) -> Result<bool, VolatilityError> {
+    let mut conn = connection(pool)?;
+    conn.transaction(|conn| {
+        diesel::sql_query("DELETE FROM event_aliases WHERE event_id = ?")
+            .bind::<Text, _>(id)
+            .execute(conn)
+            .map_err(|e| VolatilityError::DatabaseError(format!("Delete failed: {}", e)))?;
+        let deleted = diesel::sql_query("DELETE FROM event_catalog WHERE id = ?")
+            .bind::<Text, _>(id)
+            .execute(conn)
+            .map_err(|e| VolatilityError::DatabaseError(format!("Delete failed: {}", e)))?;
+        Ok(deleted > 0)
+    })
+}
+
+/// Intitulés distincts (devise, description) du calendrier ; `only_missing` : lignes sans event_id
+pub fn distinct_descriptions(
+    pool: &DbPool,
+    only_missing: bool,
+) -> Result<Vec<(String, String)>, VolatilityError> {
+    let mut conn = connection(pool)?;
+    let filter = if only_missing {
+        "WHERE event_id IS NULL"
+    } else {
+        ""
+    };
+    let rows: Vec<DescriptionRow> = diesel::sql_query(format!(
+        "SELECT DISTINCT symbol, description FROM calendar_events {}",
+        filter
+    ))
+    .load(&mut conn)
+    .map_err(|e| VolatilityError::DatabaseError(format!("Query failed: {}", e)))?;
+    Ok(rows
+        .into_iter()
+        .map(|r| (r.symbol, r.description))
+        .collect())
+}
+
+/// Écrit les event_id calculés ; retourne le nombre de lignes modifiées
+pub fn update_event_ids(
+    pool: &DbPool,
+    assignments: &[(String, String, String)],
+) -> Result<usize, VolatilityError> {
+    let mut conn = connection(pool)?;
+    update_event_ids_on(&mut conn, assignments)
+}
+
+/// `update_event_ids` sur une connexion déjà ouverte
+pub fn update_event_ids_on(
+    conn: &mut SqliteConnection,
+    assignments: &[(String, String, String)],
+) -> Result<usize, VolatilityError> {
+    conn.transaction(|conn| {
+        let mut updated = 0;
+        for (symbol, description, event_id) in assignments {
+            updated += diesel::sql_query(
+                "UPDATE calendar_events SET event_id = ?
+                 WHERE symbol = ? AND description = ? AND event_id IS NOT ?",
+            )
+            .bind::<Text, _>(event_id)
+            .bind::<Text, _>(symbol)
+            .bind::<Text, _>(description)
+            .bind::<Text, _>(event_id)
+            .execute(conn)
+            .map_err(|e| VolatilityError::DatabaseError(format!("Update failed: {}", e)))?;
+        }
+        Ok(updated)
+    })
+}
+
+fn connection(pool: &DbPool) -> Result<PooledConn, VolatilityError> {
+    pool.get()
+        .map_err(|e| VolatilityError::DatabaseError(format!("Pool connection failed: {}", e)))
+}
+
+fn write_event(
+    conn: &mut SqliteConnection,
+    event: &CanonicalEvent,
+    on_conflict: &str,
+) -> Result<usize, VolatilityError> {
+    diesel::sql_query(format!(
+        "INSERT INTO event_catalog (id, name, country, currency, category, default_impact, updated_at)
+         VALUES (?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP) {}",
+        on_conflict
+    ))
+    .bind::<Text, _>(&event.id)
+    .bind::<Text, _>(&event.name)
+    .bind::<Text, _>(&event.country)
+    .bind::<Text, _>(&event.currency)
+    .bind::<Text, _>(event.category.as_str())
+    .bind::<Text, _>(&event.default_impact)
+    .execute(conn)
+    .map_err(|e| VolatilityError::DatabaseError(format!("Write failed: {}", e)))
+}
+
+fn write_aliases(
+    conn: &mut SqliteConnection,
+    event: &CanonicalEvent,
+) -> Result<(), VolatilityError> {
+    for alias in &event.aliases {
+        diesel::sql_query(
+            "INSERT INTO event_aliases (event_id, alias, match_kind) VALUES (?, ?, ?)
+             ON CONFLICT(event_id, alias, match_kind) DO NOTHING",
+        )
+        .bind::<Text, _>(&event.id)
+        .bind::<Text, _>(&alias.alias)
+        .bind::<Text, _>(alias.kind.as_str())
+        .execute(conn)
+        .map_err(|e| VolatilityError::DatabaseError(format!("Write failed: {}", e)))?;
+    }
+    Ok(())
+}
+
+// Helper structs pour QueryableByName avec Diesel
+#[derive(QueryableByName, Debug)]
+struct CatalogRow {
+    #[diesel(sql_type = Text)]
+    id: String,
+    #[diesel(sql_type = Text)]
+    name: String,
+    #[diesel(sql_type = Text)]
+    country: String,
+    #[diesel(sql_type = Text)]
+    currency: String,
+    #[diesel(sql_type = Text)]
+    category: String,
+    #[diesel(sql_type = Text)]
+    default_impact: String,
+}
+
+#[derive(QueryableByName, Debug)]
+struct AliasRow {
+    #[diesel(sql_type = Text)]
+    event_id: String,
+    #[diesel(sql_type = Text)]
+    alias: String,
+    #[diesel(sql_type = Text)]
+    match_kind: String,
+}
+
+#[derive(QueryableByName, Debug)]
+struct DescriptionRow {
+    #[diesel(sql_type = Text)]
+    symbol: String,
+    #[diesel(sql_type = Text)]
+    description: String,
+}
@@ -0,0 +1,186 @@
+// services/event_catalog/matcher.rs - Rattachement d'un intitulé brut à une annonce canonique
+
+use crate::models::event_catalog::{CanonicalEvent, EventAliasKind};
+use std::collections::HashSet;
+
+/// Préfixe des identifiants d'intitulés hors catalogue
+pub const UNCATALOGUED_PREFIX: &str = "raw:";
+
+/// Part minimale de mots communs (Jaccard) pour un alias approché
+const FUZZY_THRESHOLD: f64 = 0.75;
+
+/// Forme comparable d'un intitulé : minuscules, sans ponctuation ni période
+/// ("Non-Farm Employment Change (Dec)" → "nonfarm employment change",
+/// "CPI (MoM)" et "CPI m/m" → "cpi mom")
+pub fn normalize_event_name(name: &str) -> String {
+    let lower = name
+        .to_lowercase()
+        .replace("m/m", " mom ")
+        .replace("y/y", " yoy ")
+        .replace("q/q", " qoq ");
+
+    let mut kept = String::with_capacity(lower.len());
+    let mut group = String::new();
+    let mut depth = 0usize;
+    for c in lower.chars() {
+        match c {
+            '(' | '[' => {
+                depth += 1;
+                group.clear();
+            }
+            ')' | ']' if depth > 0 => {
+                depth -= 1;
+                // Variation conservée, période de référence ("Dec", "Q4", "2024") ignorée
+                let inner = group.trim();
+                if matches!(inner, "mom" | "yoy" | "qoq") {
+                    kept.push(' ');
+                    kept.push_str(inner);
+                    kept.push(' ');
+                }
+            }
+            _ if depth > 0 => group.push(c),
+            '-' | '\'' | '.' => {}
+            c if c.is_alphanumeric() => kept.push(c),
+            _ => kept.push(' '),
+        }
+    }
+    kept.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Identifiant d'un intitulé hors catalogue (regroupement sur sa forme normalisée)
+pub fn uncatalogued_id(description: &str) -> String {
+    format!(
+        "{}{}",
+        UNCATALOGUED_PREFIX,
+        normalize_event_name(description).replace(' ', "_")
+    )
+}
+
+fn tokens(normalized: &str) -> HashSet<&str> {
+    normalized.split(' ').filter(|t| !t.is_empty()).collect()
+}
+
+fn jaccard(a: &str, b: &str) -> f64 {
+    let (a, b) = (tokens(a), tokens(b));
+    let union = a.union(&b).count();
+    if union == 0 {
+        return 0.0;
+    }
+    a.intersection(&b).count() as f64 / union as f64
+}
+
+fn currency_matches(event: &CanonicalEvent, currency: &str) -> bool {
+    event.currency.is_empty()
+        || currency.is_empty()
+        || event.currency.eq_ignore_ascii_case(currency)
+}
+
+/// Annonce du catalogue correspondant à un intitulé de la devise donnée.
+/// Un alias exact (ou le nom canonique) l'emporte sur le meilleur alias approché.
+pub fn match_event<'a>(
+    catalog: &'a [CanonicalEvent],
+    description: &str,
+    currency: &str,
+) -> Option<&'a CanonicalEvent> {
+    let key = normalize_event_name(description);
+    if key.is_empty() {
+        return None;
+    }
+    let currency = currency.trim();
+    let candidates = catalog.iter().filter(|e| currency_matches(e, currency));
+
+    let mut best: Option<(&CanonicalEvent, f64)> = None;
+    for event in candidates {
+        if normalize_event_name(&event.name) == key {
+            return Some(event);
+        }
+        for alias in &event.aliases {
+            let alias_key = normalize_event_name(&alias.alias);
+            match alias.kind {
+                EventAliasKind::Exact if alias_key == key => return Some(event),
+                EventAliasKind::Fuzzy => {
+                    let score = jaccard(&alias_key, &key);
+                    if score >= FUZZY_THRESHOLD && best.is_none_or(|(_, s)| score > s) {
+                        best = Some((event, score));
+                    }
+                }
+                EventAliasKind::Exact => {}
+            }
+        }
+    }
+    best.map(|(event, _)| event)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::event_catalog::{EventAlias, EventCategory};
+
+    fn event(id: &str, currency: &str, exact: &[&str], fuzzy: &[&str]) -> CanonicalEvent {
+        let alias = |kind| {
+            move |a: &&str| EventAlias {
+                alias: a.to_string(),
+                kind,
+            }
+        };
+        CanonicalEvent {
+            id: id.to_string(),
+            name: id.to_string(),
+            country: String::new(),
+            currency: currency.to_string(),
+            category: EventCategory::Other,
+            default_impact: "H".to_string(),
+            aliases: exact
+                .iter()
+                .map(alias(EventAliasKind::Exact))
+                .chain(fuzzy.iter().map(alias(EventAliasKind::Fuzzy)))
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_normalisation_intitule() {
+        assert_eq!(
+            normalize_event_name("Non-Farm Employment Change (Dec)"),
+            "nonfarm employment change"
+        );
+        assert_eq!(normalize_event_name("CPI (MoM) (Jan)"), "cpi mom");
+        assert_eq!(normalize_event_name("CPI m/m"), "cpi mom");
+        assert_eq!(
+            normalize_event_name("  Fed Chair Powell Speaks "),
+            "fed chair powell speaks"
+        );
+        assert_eq!(uncatalogued_id("Bank Holiday"), "raw:bank_holiday");
+    }
+
+    #[test]
+    fn test_alias_exact_et_approche() {
+        let catalog = vec![
+            event(
+                "usd_nfp",
+                "USD",
+                &["Nonfarm Payrolls", "NFP", "Non-Farm Employment Change"],
+                &[],
+            ),
+            event("usd_cpi_mom", "USD", &["CPI m/m"], &[]),
+            event("usd_core_cpi_mom", "USD", &["Core CPI m/m"], &[]),
+            event("usd_fed_chair", "USD", &[], &["Fed Chair Speaks"]),
+        ];
+        let id = |d: &str, c: &str| match_event(&catalog, d, c).map(|e| e.id.as_str());
+
+        assert_eq!(
+            id("Non-Farm Employment Change (Dec)", "USD"),
+            Some("usd_nfp")
+        );
+        assert_eq!(id("NFP", "usd"), Some("usd_nfp"));
+        assert_eq!(id("CPI (MoM)", "USD"), Some("usd_cpi_mom"));
+        assert_eq!(id("Core CPI m/m", "USD"), Some("usd_core_cpi_mom"));
+        assert_eq!(id("Fed Chair Powell Speaks", "USD"), Some("usd_fed_chair"));
+        // Autre devise ou intitulé trop éloigné : hors catalogue
+        assert_eq!(id("CPI m/m", "CAD"), None);
+        assert_eq!(
+            id("Fed Vice Chair for Supervision Barr Speaks", "USD"),
+            None
+        );
+    }
+}
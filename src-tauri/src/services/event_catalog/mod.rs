@@ -0,0 +1,246 @@
+// services/event_catalog/mod.rs
+// Catalogue canonique des annonces (tables event_catalog / event_aliases de volatility.db).
+// Chaque ligne de calendar_events reçoit un event_id stable à l'import : les analyses
+// regroupent sur cet identifiant plutôt que sur l'intitulé brut propre à chaque source.
+
+pub mod db;
+pub mod matcher;
+mod seed;
+
+use crate::db::DbPool;
+use crate::models::event_catalog::{CanonicalEvent, EventResolution};
+use diesel::SqliteConnection;
+use matcher::{match_event, normalize_event_name, uncatalogued_id};
+use std::collections::HashMap;
+use std::sync::{Arc, OnceLock, RwLock};
+
+static CATALOG_POOL: OnceLock<DbPool> = OnceLock::new();
+
+/// Annonces chargées depuis la DB, rechargées après chaque modification
+static CATALOG: RwLock<Option<Arc<Vec<CanonicalEvent>>>> = RwLock::new(None);
+
+/// Enregistre le pool volatility.db (appelé une fois au démarrage dans lib.rs)
+pub fn set_catalog_pool(pool: DbPool) {
+    let _ = CATALOG_POOL.set(pool);
+}
+
+/// Insère les annonces par défaut absentes (appelé au démarrage dans lib.rs)
+pub fn seed_defaults(pool: &DbPool) -> Result<usize, String> {
+    let inserted = db::insert_missing(pool, &seed::default_events())
+        .map_err(|e| format!("Seed event catalog failed: {}", e))?;
+    if inserted > 0 {
+        tracing::info!("✅ {} annonces par défaut insérées au catalogue", inserted);
+    }
+    invalidate_catalog_cache();
+    Ok(inserted)
+}
+
+/// Force le rechargement du catalogue (après édition d'une annonce)
+pub fn invalidate_catalog_cache() {
+    if let Ok(mut guard) = CATALOG.write() {
+        *guard = None;
+    }
+}
+
+/// Toutes les annonces du catalogue (valeurs par défaut si la DB n'est pas disponible)
+pub fn list_events() -> Arc<Vec<CanonicalEvent>> {
+    if let Some(events) = CATALOG.read().ok().and_then(|guard| guard.clone()) {
+        return events;
+    }
+    // Pool pas encore enregistré (tests unitaires) : annonces par défaut, sans mise en cache
+    let Some(pool) = CATALOG_POOL.get() else {
+        return Arc::new(seed::default_events());
+    };
+    let events = match db::get_all_events(pool) {
+        Ok(events) if !events.is_empty() => events,
+        Ok(_) => seed::default_events(),
+        Err(e) => {
+            tracing::warn!("⚠️ Lecture du catalogue d'annonces impossible : {}", e);
+            return Arc::new(seed::default_events());
+        }
+    };
+    let events = Arc::new(events);
+    if let Ok(mut guard) = CATALOG.write() {
+        *guard = Some(events.clone());
+    }
+    events
+}
+
+/// Annonce par défaut (None si l'identifiant n'a pas de fiche d'origine)
+pub fn default_event(id: &str) -> Option<CanonicalEvent> {
+    seed::default_events().into_iter().find(|e| e.id == id)
+}
+
+/// Rattache un intitulé brut (et la devise de la ligne) au catalogue
+pub fn resolve_event(description: &str, currency: &str) -> EventResolution {
+    let catalog = list_events();
+    match match_event(&catalog, description, currency) {
+        Some(event) => EventResolution {
+            event_id: event.id.clone(),
+            name: event.name.clone(),
+            catalogued: true,
+            category: Some(event.category),
+        },
+        None => EventResolution {
+            event_id: uncatalogued_id(description),
+            name: description.trim().to_string(),
+            catalogued: false,
+            category: None,
+        },
+    }
+}
+
+/// Identifiant stocké dans calendar_events.event_id
+pub fn resolve_event_id(description: &str, currency: &str) -> String {
+    resolve_event(description, currency).event_id
+}
+
+/// Identifiants d'une série de lignes (description, devise), chaque intitulé résolu une seule fois
+pub fn resolve_event_ids<'a>(rows: impl IntoIterator<Item = (&'a str, &'a str)>) -> Vec<String> {
+    let mut resolved: HashMap<(&str, &str), String> = HashMap::new();
+    rows.into_iter()
+        .map(|key| {
+            resolved
+                .entry(key)
+                .or_insert_with(|| resolve_event_id(key.0, key.1))
+                .clone()
+        })
+        .collect()
+}
+
+/// Libellé d'un groupe d'analyse : nom canonique, ou intitulé brut hors catalogue
+pub fn event_label(event_id: &str, description: &str) -> String {
+    list_events()
+        .iter()
+        .find(|e| e.id == event_id)
+        .map(|e| e.name.clone())
+        .unwrap_or_else(|| description.to_string())
+}
+
+/// Identifiant désigné par un libellé d'analyse (nom canonique, alias propre à une
+/// seule annonce, ou intitulé hors catalogue) — inverse de `event_label`
+pub fn event_id_for_label(label: &str) -> String {
+    let catalog = list_events();
+    let key = normalize_event_name(label);
+    if let Some(event) = catalog
+        .iter()
+        .find(|e| normalize_event_name(&e.name) == key)
+    {
+        return event.id.clone();
+    }
+    let mut owners = catalog.iter().filter(|e| {
+        e.aliases
+            .iter()
+            .any(|a| normalize_event_name(&a.alias) == key)
+    });
+    match (owners.next(), owners.next()) {
+        (Some(event), None) => event.id.clone(),
+        _ => uncatalogued_id(label),
+    }
+}
+
+/// Recalcule calendar_events.event_id (toutes les lignes, ou celles qui n'en ont pas)
+pub fn assign_event_ids(pool: &DbPool, only_missing: bool) -> Result<usize, String> {
+    let descriptions = db::distinct_descriptions(pool, only_missing)
+        .map_err(|e| format!("Lecture des intitulés impossible : {}", e))?;
+    if descriptions.is_empty() {
+        return Ok(0);
+    }
+    let assignments: Vec<(String, String, String)> = descriptions
+        .into_iter()
+        .map(|(symbol, description)| {
+            let event_id = resolve_event_id(&description, &symbol);
+            (symbol, description, event_id)
+        })
+        .collect();
+    let updated = db::update_event_ids(pool, &assignments)
+        .map_err(|e| format!("Rattachement des événements impossible : {}", e))?;
+    if updated > 0 {
+        tracing::info!(
+            "✅ {} événements rattachés au catalogue ({} intitulés)",
+            updated,
+            assignments.len()
+        );
+    }
+    Ok(updated)
+}
+
+/// Recalcule l'event_id des lignes (devise, intitulé) données, après un changement de devise :
+/// l'identifiant en dépend ("CPI m/m" USD ≠ CAD)
+pub fn assign_event_ids_for(
+    conn: &mut SqliteConnection,
+    rows: &[(String, String)],
+) -> Result<usize, String> {
+    let assignments: Vec<(String, String, String)> = rows
+        .iter()
+        .map(|(symbol, description)| {
+            let event_id = resolve_event_id(description, symbol);
+            (symbol.clone(), description.clone(), event_id)
+        })
+        .collect();
+    db::update_event_ids_on(conn, &assignments)
+        .map_err(|e| format!("Rattachement des événements impossible : {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_variantes_nfp_meme_identifiant() {
+        for description in [
+            "Non-Farm Employment Change",
+            "Nonfarm Payrolls",
+            "NFP",
+            "Non-Farm Employment Change (Dec)",
+        ] {
+            assert_eq!(
+                resolve_event_id(description, "USD"),
+                "usd_nfp",
+                "{}",
+                description
+            );
+        }
+    }
+
+    #[test]
+    fn test_meme_intitule_devises_differentes() {
+        assert_eq!(resolve_event_id("CPI m/m", "USD"), "usd_cpi_mom");
+        assert_eq!(resolve_event_id("CPI m/m", "CAD"), "cad_cpi_mom");
+        assert_eq!(resolve_event_id("CPI m/m", "CNY"), "raw:cpi_mom");
+    }
+
+    #[test]
+    fn test_libelle_aller_retour() {
+        let nfp = resolve_event("Nonfarm Payrolls", "USD");
+        assert!(nfp.catalogued);
+        assert_eq!(
+            event_id_for_label(&event_label(&nfp.event_id, "NFP")),
+            "usd_nfp"
+        );
+
+        let holiday = resolve_event("Bank Holiday", "JPY");
+        assert!(!holiday.catalogued);
+        let label = event_label(&holiday.event_id, "Bank Holiday");
+        assert_eq!(label, "Bank Holiday");
+        assert_eq!(event_id_for_label(&label), holiday.event_id);
+
+        // Alias partagé par plusieurs devises : pas de rattachement implicite
+        assert_eq!(
+            event_id_for_label("Employment Change"),
+            "raw:employment_change"
+        );
+    }
+
+    #[test]
+    fn test_catalogue_par_defaut_coherent() {
+        let events = list_events();
+        let mut ids: Vec<&str> = events.iter().map(|e| e.id.as_str()).collect();
+        ids.sort_unstable();
+        ids.dedup();
+        assert_eq!(ids.len(), events.len(), "identifiants uniques");
+        assert!(events
+            .iter()
+            .all(|e| matches!(e.default_impact.as_str(), "H" | "M" | "L")));
+    }
+}
@@ -0,0 +1,525 @@
+// services/event_catalog/seed.rs
+// Annonces par défaut du catalogue : principales publications des 8 devises majeures,
+// avec les intitulés Forex Factory, Investing.com et TradingEconomics connus.
+
+use crate::models::event_catalog::{CanonicalEvent, EventAlias, EventAliasKind, EventCategory};
+use EventCategory::*;
+
+struct Country {
+    currency: &'static str,
+    name: &'static str,
+}
+
+const US: Country = Country {
+    currency: "USD",
+    name: "United States",
+};
+const EZ: Country = Country {
+    currency: "EUR",
+    name: "Euro Area",
+};
+const UK: Country = Country {
+    currency: "GBP",
+    name: "United Kingdom",
+};
+const JP: Country = Country {
+    currency: "JPY",
+    name: "Japan",
+};
+const CA: Country = Country {
+    currency: "CAD",
+    name: "Canada",
+};
+const AU: Country = Country {
+    currency: "AUD",
+    name: "Australia",
+};
+const NZ: Country = Country {
+    currency: "NZD",
+    name: "New Zealand",
+};
+const CH: Country = Country {
+    currency: "CHF",
+    name: "Switzerland",
+};
+
+/// Annonce identifiée par `{devise}_{slug}`, nom affiché préfixé de la devise
+fn event(
+    country: &Country,
+    slug: &str,
+    title: &str,
+    category: EventCategory,
+    impact: &str,
+    exact: &[&str],
+    fuzzy: &[&str],
+) -> CanonicalEvent {
+    let alias = |kind: EventAliasKind| {
+        move |a: &&str| EventAlias {
+            alias: a.to_string(),
+            kind,
+        }
+    };
+    CanonicalEvent {
+        id: format!("{}_{}", country.currency.to_lowercase(), slug),
+        name: format!("{} {}", country.currency, title),
+        country: country.name.to_string(),
+        currency: country.currency.to_string(),
+        category,
+        default_impact: impact.to_string(),
+        aliases: exact
+            .iter()
+            .map(alias(EventAliasKind::Exact))
+            .chain(fuzzy.iter().map(alias(EventAliasKind::Fuzzy)))
+            .collect(),
+    }
+}
+
+/// Annonces insérées au démarrage si absentes
+pub(super) fn default_events() -> Vec<CanonicalEvent> {
+    vec![
+        // ─── États-Unis ───
+        event(
+            &US,
+            "nfp",
+            "Non-Farm Payrolls",
+            Employment,
+            "H",
+            &[
+                "Non-Farm Employment Change",
+                "Nonfarm Payrolls",
+                "Non Farm Payrolls",
+                "NFP",
+            ],
+            &[],
+        ),
+        event(
+            &US,
+            "unemployment_rate",
+            "Unemployment Rate",
+            Employment,
+            "H",
+            &["Unemployment Rate"],
+            &[],
+        ),
+        event(
+            &US,
+            "avg_hourly_earnings",
+            "Average Hourly Earnings m/m",
+            Employment,
+            "H",
+            &["Average Hourly Earnings m/m", "Average Hourly Earnings"],
+            &[],
+        ),
+        event(
+            &US,
+            "adp",
+            "ADP Employment Change",
+            Employment,
+            "M",
+            &[
+                "ADP Non-Farm Employment Change",
+                "ADP Nonfarm Employment Change",
+            ],
+            &[],
+        ),
+        event(
+            &US,
+            "jobless_claims",
+            "Initial Jobless Claims",
+            Employment,
+            "M",
+            &["Unemployment Claims", "Jobless Claims"],
+            &[],
+        ),
+        event(
+            &US,
+            "cpi_mom",
+            "CPI m/m",
+            Inflation,
+            "H",
+            &["CPI m/m", "Inflation Rate MoM"],
+            &[],
+        ),
+        event(
+            &US,
+            "cpi_yoy",
+            "CPI y/y",
+            Inflation,
+            "H",
+            &["CPI y/y", "Inflation Rate YoY"],
+            &[],
+        ),
+        event(
+            &US,
+            "core_cpi_mom",
+            "Core CPI m/m",
+            Inflation,
+            "H",
+            &["Core CPI m/m", "Core Inflation Rate MoM"],
+            &[],
+        ),
+        event(
+            &US,
+            "core_pce_mom",
+            "Core PCE Price Index m/m",
+            Inflation,
+            "H",
+            &["Core PCE Price Index m/m", "Core PCE Price Index MoM"],
+            &[],
+        ),
+        event(
+            &US,
+            "ppi_mom",
+            "PPI m/m",
+            Inflation,
+            "M",
+            &["PPI m/m", "PPI MoM"],
+            &[],
+        ),
+        event(
+            &US,
+            "fed_rate",
+            "Fed Interest Rate Decision",
+            Rates,
+            "H",
+            &[
+                "Federal Funds Rate",
+                "Fed Interest Rate Decision",
+                "Interest Rate Decision",
+            ],
+            &[],
+        ),
+        event(
+            &US,
+            "fomc_statement",
+            "FOMC Statement",
+            Rates,
+            "H",
+            &["FOMC Statement"],
+            &[],
+        ),
+        event(
+            &US,
+            "fomc_press_conference",
+            "FOMC Press Conference",
+            Rates,
+            "H",
+            &["FOMC Press Conference"],
+            &[],
+        ),
+        event(
+            &US,
+            "fomc_minutes",
+            "FOMC Meeting Minutes",
+            Rates,
+            "H",
+            &["FOMC Meeting Minutes", "FOMC Minutes"],
+            &[],
+        ),
+        event(
+            &US,
+            "fed_chair_speech",
+            "Fed Chair Speaks",
+            Speech,
+            "H",
+            &["Powell Speaks"],
+            &["Fed Chair Speaks", "Fed Chair Powell Speaks"],
+        ),
+        event(
+            &US,
+            "ism_manufacturing",
+            "ISM Manufacturing PMI",
+            Pmi,
+            "H",
+            &["ISM Manufacturing PMI"],
+            &[],
+        ),
+        event(
+            &US,
+            "ism_services",
+            "ISM Services PMI",
+            Pmi,
+            "H",
+            &["ISM Services PMI", "ISM Non-Manufacturing PMI"],
+            &[],
+        ),
+        event(
+            &US,
+            "gdp_qoq",
+            "GDP q/q",
+            Growth,
+            "H",
+            &[
+                "Advance GDP q/q",
+                "Prelim GDP q/q",
+                "Final GDP q/q",
+                "GDP Growth Rate QoQ",
+            ],
+            &[],
+        ),
+        event(
+            &US,
+            "retail_sales_mom",
+            "Retail Sales m/m",
+            Consumption,
+            "H",
+            &["Retail Sales m/m", "Retail Sales MoM"],
+            &[],
+        ),
+        event(
+            &US,
+            "core_retail_sales_mom",
+            "Core Retail Sales m/m",
+            Consumption,
+            "H",
+            &["Core Retail Sales m/m", "Retail Sales Ex Autos MoM"],
+            &[],
+        ),
+        // ─── Zone euro ───
+        event(
+            &EZ,
+            "ecb_rate",
+            "ECB Interest Rate Decision",
+            Rates,
+            "H",
+            &[
+                "Main Refinancing Rate",
+                "ECB Interest Rate Decision",
+                "Deposit Facility Rate",
+            ],
+            &[],
+        ),
+        event(
+            &EZ,
+            "ecb_press_conference",
+            "ECB Press Conference",
+            Rates,
+            "H",
+            &["ECB Press Conference", "Monetary Policy Press Conference"],
+            &[],
+        ),
+        event(
+            &EZ,
+            "ecb_president_speech",
+            "ECB President Speaks",
+            Speech,
+            "H",
+            &["Lagarde Speaks"],
+            &["ECB President Lagarde Speaks"],
+        ),
+        event(
+            &EZ,
+            "cpi_flash_yoy",
+            "CPI Flash Estimate y/y",
+            Inflation,
+            "H",
+            &["CPI Flash Estimate y/y", "Inflation Rate YoY Flash"],
+            &[],
+        ),
+        event(
+            &EZ,
+            "core_cpi_flash_yoy",
+            "Core CPI Flash Estimate y/y",
+            Inflation,
+            "H",
+            &[
+                "Core CPI Flash Estimate y/y",
+                "Core Inflation Rate YoY Flash",
+            ],
+            &[],
+        ),
+        event(
+            &EZ,
+            "german_manufacturing_pmi",
+            "German Flash Manufacturing PMI",
+            Pmi,
+            "H",
+            &[
+                "German Flash Manufacturing PMI",
+                "HCOB Manufacturing PMI Flash",
+            ],
+            &[],
+        ),
+        event(
+            &EZ,
+            "gdp_qoq",
+            "Flash GDP q/q",
+            Growth,
+            "M",
+            &[
+                "Flash GDP q/q",
+                "Prelim Flash GDP q/q",
+                "GDP Growth Rate QoQ Flash",
+            ],
+            &[],
+        ),
+        // ─── Royaume-Uni ───
+        event(
+            &UK,
+            "boe_rate",
+            "BOE Interest Rate Decision",
+            Rates,
+            "H",
+            &["Official Bank Rate", "BoE Interest Rate Decision"],
+            &[],
+        ),
+        event(
+            &UK,
+            "cpi_yoy",
+            "CPI y/y",
+            Inflation,
+            "H",
+            &["CPI y/y", "Inflation Rate YoY"],
+            &[],
+        ),
+        event(
+            &UK,
+            "claimant_count",
+            "Claimant Count Change",
+            Employment,
+            "H",
+            &["Claimant Count Change"],
+            &[],
+        ),
+        event(
+            &UK,
+            "gdp_mom",
+            "GDP m/m",
+            Growth,
+            "H",
+            &["GDP m/m", "GDP MoM"],
+            &[],
+        ),
+        event(
+            &UK,
+            "services_pmi",
+            "Flash Services PMI",
+            Pmi,
+            "H",
+            &["Flash Services PMI", "S&P Global Services PMI Flash"],
+            &[],
+        ),
+        // ─── Japon ───
+        event(
+            &JP,
+            "boj_rate",
+            "BOJ Policy Rate",
+            Rates,
+            "H",
+            &["BOJ Policy Rate", "BoJ Interest Rate Decision"],
+            &[],
+        ),
+        event(
+            &JP,
+            "boj_press_conference",
+            "BOJ Press Conference",
+            Rates,
+            "H",
+            &["BOJ Press Conference"],
+            &[],
+        ),
+        event(
+            &JP,
+            "tokyo_core_cpi",
+            "Tokyo Core CPI y/y",
+            Inflation,
+            "M",
+            &["Tokyo Core CPI y/y", "Tokyo Core CPI YoY"],
+            &[],
+        ),
+        // ─── Canada ───
+        event(
+            &CA,
+            "boc_rate",
+            "BOC Interest Rate Decision",
+            Rates,
+            "H",
+            &["Overnight Rate", "BoC Interest Rate Decision"],
+            &[],
+        ),
+        event(
+            &CA,
+            "employment_change",
+            "Employment Change",
+            Employment,
+            "H",
+            &["Employment Change"],
+            &[],
+        ),
+        event(
+            &CA,
+            "unemployment_rate",
+            "Unemployment Rate",
+            Employment,
+            "H",
+            &["Unemployment Rate"],
+            &[],
+        ),
+        event(
+            &CA,
+            "cpi_mom",
+            "CPI m/m",
+            Inflation,
+            "H",
+            &["CPI m/m", "Inflation Rate MoM"],
+            &[],
+        ),
+        // ─── Australie ───
+        event(
+            &AU,
+            "rba_rate",
+            "RBA Interest Rate Decision",
+            Rates,
+            "H",
+            &["Cash Rate", "RBA Interest Rate Decision"],
+            &[],
+        ),
+        event(
+            &AU,
+            "employment_change",
+            "Employment Change",
+            Employment,
+            "H",
+            &["Employment Change"],
+            &[],
+        ),
+        event(
+            &AU,
+            "cpi_qoq",
+            "CPI q/q",
+            Inflation,
+            "H",
+            &["CPI q/q", "Inflation Rate QoQ"],
+            &[],
+        ),
+        // ─── Nouvelle-Zélande ───
+        event(
+            &NZ,
+            "rbnz_rate",
+            "RBNZ Interest Rate Decision",
+            Rates,
+            "H",
+            &["Official Cash Rate", "RBNZ Interest Rate Decision"],
+            &[],
+        ),
+        event(
+            &NZ,
+            "cpi_qoq",
+            "CPI q/q",
+            Inflation,
+            "H",
+            &["CPI q/q", "Inflation Rate QoQ"],
+            &[],
+        ),
+        // ─── Suisse ───
+        event(
+            &CH,
+            "snb_rate",
+            "SNB Interest Rate Decision",
+            Rates,
+            "H",
+            &["SNB Policy Rate", "SNB Interest Rate Decision"],
+            &[],
+        ),
+    ]
+}
@@ -1,10 +1,17 @@
 // services/event_correlation.rs - Service de corrélation événements/volatilité
 use crate::db::DbPool;
 use crate::models::{CalendarEvent, Candle, CorrelatedEvent, VolatilityError};
+use crate::services::candle_index::CandleIndex;
+use crate::services::significance::{benjamini_hochberg, compare_samples};
 use chrono::{Duration, NaiveDateTime, Timelike};
 use diesel::prelude::*;
 use diesel::SelectableHelper;
 
+/// Jours précédents utilisés comme baseline (même heure, cf. CandleIndex::get_baseline_candles)
+const BASELINE_DAYS_BACK: i64 = 7;
+/// Augmentation mean vs mean (%) à partir de laquelle un événement est retenu
+const MIN_INCREASE_PCT: f64 = 50.0;
+
 /// Service pour analyser la corrélation entre événements économiques et volatilité
 pub struct EventCorrelationService {
     pool: DbPool,
@@ -44,7 +51,21 @@ impl EventCorrelationService {
     pub fn correlate_event_with_volatility(
         &self,
         event: &CalendarEvent,
+        symbol: &str,
+        candles: &[Candle],
+        index: &CandleIndex,
+    ) -> Option<CorrelatedEvent> {
+        self.evaluer_evenement(event, symbol, candles, index)
+            .filter(|c| c.volatility_increase > MIN_INCREASE_PCT)
+    }
+
+    /// Hausse de volatilité et test d'hypothèse d'un événement, sans seuil
+    fn evaluer_evenement(
+        &self,
+        event: &CalendarEvent,
+        symbol: &str,
         candles: &[Candle],
+        index: &CandleIndex,
     ) -> Option<CorrelatedEvent> {
         // ✅ CORRECTION: Ignorer les événements LOW-impact (bruit)
         if event.impact == "LOW" {
@@ -56,47 +77,56 @@ impl EventCorrelationService {
 
         // Calcule la volatilité (Max True Range) 1h avant l'événement (Baseline)
         // Fenêtre Baseline : -60 min à -10 min
-        let before_metrics = self.calculer_metriques_volatilite(
+        let (before_mean, _) = self.calculer_metriques_volatilite(
             event.event_time - Duration::minutes(60),
             event.event_time - Duration::minutes(10),
             candles,
-        );
+        )?;
 
         // Calcule la volatilité (Max True Range) autour de l'événement (Impact)
         // Fenêtre Impact : -10 min à +30 min (FIX-03)
-        let impact_metrics = self.calculer_metriques_volatilite(
+        let (impact_mean, _) = self.calculer_metriques_volatilite(
             event.event_time - Duration::minutes(10),
             event.event_time + Duration::minutes(30),
             candles,
-        );
+        )?;
 
-        // Si on a des données valides
-        if let (Some((before_mean, _)), Some((impact_mean, _))) = (before_metrics, impact_metrics) {
-            if before_mean > 0.0 {
-                // On compare la MOYENNE de l'événement (Mean TR) à la MOYENNE avant (Mean TR)
-                // Cela donne le ratio d'augmentation non biaisé (Mean vs Mean)
-                let increase_pct = ((impact_mean - before_mean) / before_mean) * 100.0;
-
-                // Score de corrélation basé sur :
-                // - Impact de l'événement (HIGH = bonus)
-                // - Magnitude de l'augmentation
-                // - Cohérence temporelle
-                let impact_multiplier = if event.impact == "HIGH" { 1.5 } else { 1.0 };
-                let correlation_score = (increase_pct.abs() * impact_multiplier).min(100.0);
-
-                // Ne retourne que les corrélations significatives (> 50% d'augmentation mean vs mean)
-                if increase_pct > 50.0 {
-                    return Some(CorrelatedEvent {
-                        event: event.clone(),
-                        volatility_hour: event_hour,
-                        volatility_increase: increase_pct,
-                        correlation_score,
-                    });
-                }
-            }
+        if before_mean <= 0.0 {
+            return None;
         }
 
-        None
+        // On compare la MOYENNE de l'événement (Mean TR) à la MOYENNE avant (Mean TR)
+        // Cela donne le ratio d'augmentation non biaisé (Mean vs Mean)
+        let increase_pct = ((impact_mean - before_mean) / before_mean) * 100.0;
+
+        // Score de corrélation basé sur :
+        // - Impact de l'événement (HIGH = bonus)
+        // - Magnitude de l'augmentation
+        // - Cohérence temporelle
+        let impact_multiplier = if event.impact == "HIGH" { 1.5 } else { 1.0 };
+        let correlation_score = (increase_pct.abs() * impact_multiplier).min(100.0);
+
+        // Test d'hypothèse : ranges de la fenêtre d'impact vs même heure des jours précédents.
+        // Calculé pour chaque événement, avant tout filtrage sur l'augmentation.
+        let impact_ranges = self.collecter_ranges(
+            event.event_time - Duration::minutes(10),
+            event.event_time + Duration::minutes(30),
+            candles,
+        );
+        let baseline_ranges: Vec<f64> = index
+            .get_baseline_candles(symbol, event.event_time.and_utc(), BASELINE_DAYS_BACK)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(_, high, low)| high - low)
+            .collect();
+
+        Some(CorrelatedEvent {
+            event: event.clone(),
+            volatility_hour: event_hour,
+            volatility_increase: increase_pct,
+            correlation_score,
+            significance: compare_samples(&impact_ranges, &baseline_ranges),
+        })
     }
 
     /// Calcule les métriques de volatilité (Mean TR, Max TR) dans une fenêtre temporelle
@@ -137,6 +167,23 @@ impl EventCorrelationService {
         Some((mean_tr, max_tr))
     }
 
+    /// Ranges H-L des bougies dans une fenêtre temporelle
+    fn collecter_ranges(
+        &self,
+        start: NaiveDateTime,
+        end: NaiveDateTime,
+        candles: &[Candle],
+    ) -> Vec<f64> {
+        candles
+            .iter()
+            .filter(|c| {
+                let naive_time = c.datetime.naive_utc();
+                naive_time >= start && naive_time <= end
+            })
+            .map(|c| c.high - c.low)
+            .collect()
+    }
+
     /// Analyse complète : trouve tous les événements corrélés avec des pics de volatilité
     /// NOTE: Méthode conservée pour usage futur (Phase 2 - analyses avancées)
     #[allow(dead_code)]
@@ -164,10 +211,19 @@ impl EventCorrelationService {
         // Récupère les événements économiques dans cette période
         let events = self.get_events_for_period(symbol, start_time, end_time)?;
 
-        // Corrèle chaque événement avec la volatilité
-        let mut correlations: Vec<CorrelatedEvent> = events
+        // Évalue et teste chaque événement, puis corrige les p-values sur l'ensemble des tests
+        // avant de filtrer : filtrer d'abord sur l'augmentation biaiserait la correction.
+        let index = CandleIndex::from_candles(symbol, candles.to_vec());
+        let mut evaluated: Vec<CorrelatedEvent> = events
             .iter()
-            .filter_map(|event| self.correlate_event_with_volatility(event, candles))
+            .filter_map(|event| self.evaluer_evenement(event, symbol, candles, &index))
+            .collect();
+        appliquer_benjamini_hochberg(&mut evaluated);
+
+        // Ne retient que les corrélations significatives (> 50% d'augmentation mean vs mean)
+        let mut correlations: Vec<CorrelatedEvent> = evaluated
+            .into_iter()
+            .filter(|c| c.volatility_increase > MIN_INCREASE_PCT)
             .collect();
 
         // Trie par score de corrélation décroissant
@@ -181,6 +237,18 @@ impl EventCorrelationService {
     }
 }
 
+/// Correction Benjamini-Hochberg sur tous les événements testés
+fn appliquer_benjamini_hochberg(correlations: &mut [CorrelatedEvent]) {
+    let mut tested: Vec<&mut crate::models::significance::SignificanceResult> = correlations
+        .iter_mut()
+        .filter_map(|c| c.significance.as_mut())
+        .collect();
+    let p_values: Vec<f64> = tested.iter().map(|t| t.mann_whitney_p_value).collect();
+    for (test, adjusted) in tested.iter_mut().zip(benjamini_hochberg(&p_values)) {
+        test.adjusted_p_value = Some(adjusted);
+    }
+}
+
 /// Statistiques de corrélation globales
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct CorrelationStats {
@@ -324,4 +392,40 @@ mod tests {
         assert!((pct_max - 400.0).abs() < 1e-10, "l'ancien bug aurait donné 400%");
         assert!(pct_mean < pct_max, "mean < max toujours");
     }
+
+    #[test]
+    fn test_benjamini_hochberg_sur_tous_les_evenements() {
+        let calm = [2.0, 2.1, 1.9, 2.0, 2.2, 1.8, 2.1, 2.0];
+        let spike = [6.0, 7.0, 6.5, 8.0, 7.5, 6.2, 7.1, 6.8];
+        let correlated = |increase: f64, impact: &[f64]| CorrelatedEvent {
+            event: make_event(14, 30, "HIGH"),
+            volatility_hour: 14,
+            volatility_increase: increase,
+            correlation_score: 0.0,
+            significance: compare_samples(impact, &calm),
+        };
+        let mut evaluated = vec![
+            correlated(250.0, &spike),
+            correlated(10.0, &calm),
+            correlated(5.0, &calm),
+        ];
+        appliquer_benjamini_hochberg(&mut evaluated);
+
+        // La p-value corrigée tient compte des 3 tests, y compris ceux sous le seuil de 50%
+        let raw = evaluated[0]
+            .significance
+            .as_ref()
+            .map(|s| s.mann_whitney_p_value)
+            .unwrap();
+        let adjusted = evaluated[0]
+            .significance
+            .as_ref()
+            .and_then(|s| s.adjusted_p_value)
+            .unwrap();
+        assert!((adjusted - raw * 3.0).abs() < 1e-12);
+        assert!(evaluated.iter().all(|c| c
+            .significance
+            .as_ref()
+            .is_some_and(|s| s.adjusted_p_value.is_some())));
+    }
 }
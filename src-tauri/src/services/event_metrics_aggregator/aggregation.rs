@@ -1,11 +1,14 @@
 use super::config::MetricsConfig;
 use crate::models::{Candle, EventMetrics, Result, VolatilityError};
+use crate::services::bootstrap::proportion_interval;
+use crate::services::excursion_analyzer::stops_depuis_occurrences;
+use crate::services::pair_data::get_asset_properties;
 use chrono::{DateTime, Utc};
 use tracing::warn;
 
 /// Calcule les métriques agrégées pour plusieurs occurrences du même événement
 pub fn calculer_metriques_agregees(
-    events: Vec<(Vec<Candle>, DateTime<Utc>)>,
+    mut events: Vec<(Vec<Candle>, DateTime<Utc>)>,
     event_name: String,
     config: MetricsConfig,
 ) -> Result<EventMetrics> {
@@ -21,13 +24,30 @@ pub fn calculer_metriques_agregees(
         event_name
     );
 
+    events.sort_by_key(|(_, event_time)| *event_time);
+    let pip_value = events
+        .iter()
+        .find_map(|(candles, _)| candles.first())
+        .map(|c| get_asset_properties(&c.symbol).pip_value)
+        .unwrap_or(0.0);
+
     let mut all_metrics = Vec::new();
 
-    for (candles, event_time) in &events {
+    for (i, (candles, event_time)) in events.iter().enumerate() {
+        // SL/TP tirés des seules occurrences strictement antérieures (hors échantillon) ;
+        // ATR tant que l'historique est trop court
+        let stops = stops_depuis_occurrences(
+            events[..i]
+                .iter()
+                .filter(|(_, t)| t < event_time)
+                .map(|(candles, t)| (candles.as_slice(), *t)),
+            config.max_trade_duration_minutes as u32,
+            pip_value,
+        );
         let aggregator =
             super::EventMetricsAggregator::new(candles, *event_time, event_name.clone());
 
-        match aggregator.calculer_toutes_metriques(config.clone()) {
+        match aggregator.calculer_toutes_metriques(config.clone().avec_stops_excursions(stops)) {
             Ok(metrics) => all_metrics.push(metrics),
             Err(e) => {
                 warn!(
@@ -46,6 +66,7 @@ pub fn calculer_metriques_agregees(
     }
 
     let n = all_metrics.len() as f64;
+    let rates = |f: fn(&EventMetrics) -> f64| all_metrics.iter().map(f).collect::<Vec<f64>>();
 
     let aggregated = EventMetrics {
         id: None,
@@ -71,6 +92,9 @@ pub fn calculer_metriques_agregees(
         win_rate: all_metrics.iter().map(|m| m.win_rate).sum::<f64>() / n,
         loss_rate: all_metrics.iter().map(|m| m.loss_rate).sum::<f64>() / n,
         whipsaw_rate: all_metrics.iter().map(|m| m.whipsaw_rate).sum::<f64>() / n,
+        win_rate_ci: proportion_interval(&rates(|m| m.win_rate)),
+        loss_rate_ci: proportion_interval(&rates(|m| m.loss_rate)),
+        whipsaw_rate_ci: proportion_interval(&rates(|m| m.whipsaw_rate)),
         risk_reward_ratio: all_metrics.iter().map(|m| m.risk_reward_ratio).sum::<f64>() / n,
         best_entry_minutes_before: (all_metrics
             .iter()
@@ -1,3 +1,4 @@
+use crate::models::excursion::ExcursionStops;
 use crate::services::contextual_atr_analyzer::VolatilityLevel;
 
 /// Configuration pour le calcul des métriques
@@ -7,6 +8,10 @@ pub struct MetricsConfig {
     pub atr_multiplier_sl: f64,
     pub atr_multiplier_tp: f64,
     pub max_trade_duration_minutes: usize,
+    /// SL issu d'un percentile de MAE (étude d'excursions), en pips
+    pub stop_loss_pips: Option<f64>,
+    /// TP issu d'un percentile de MFE (étude d'excursions), en pips
+    pub take_profit_pips: Option<f64>,
 }
 
 impl Default for MetricsConfig {
@@ -16,6 +21,30 @@ impl Default for MetricsConfig {
             atr_multiplier_sl: 2.0,
             atr_multiplier_tp: 3.0,
             max_trade_duration_minutes: 120,
+            stop_loss_pips: None,
+            take_profit_pips: None,
+        }
+    }
+}
+
+impl MetricsConfig {
+    /// Complète SL/TP depuis l'étude d'excursions quand l'appelant n'en fournit pas
+    pub fn avec_stops_excursions(mut self, stops: Option<ExcursionStops>) -> Self {
+        if let (None, None, Some(stops)) = (self.stop_loss_pips, self.take_profit_pips, stops) {
+            self.stop_loss_pips = Some(stops.stop_loss_pips);
+            self.take_profit_pips = Some(stops.take_profit_pips);
+        }
+        self
+    }
+
+    /// Distances SL/TP en prix tirées des excursions historiques.
+    /// None (retour aux multiplicateurs ATR) si l'une des deux manque ou n'est pas positive.
+    pub fn fixed_stops(&self, pip_value: f64) -> Option<(f64, f64)> {
+        match (self.stop_loss_pips, self.take_profit_pips) {
+            (Some(sl), Some(tp)) if sl > 0.0 && tp > 0.0 && pip_value > 0.0 => {
+                Some((sl * pip_value, tp * pip_value))
+            }
+            _ => None,
         }
     }
 }
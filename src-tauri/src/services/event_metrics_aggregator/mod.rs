@@ -6,8 +6,9 @@ pub use config::{volatility_level_to_string, MetricsConfig};
 
 use crate::models::{Candle, EventMetrics, Result};
 use crate::services::{
-    contextual_atr_analyzer::ContextualAtrAnalyzer, entry_timing_optimizer::EntryTimingOptimizer,
-    event_duration_analyzer::EventDurationAnalyzer, win_rate_calculator::WinRateCalculator,
+    bootstrap::rate_interval, contextual_atr_analyzer::ContextualAtrAnalyzer,
+    entry_timing_optimizer::EntryTimingOptimizer, event_duration_analyzer::EventDurationAnalyzer,
+    pair_data::get_asset_properties, win_rate_calculator::WinRateCalculator,
 };
 use chrono::{DateTime, Utc};
 use tracing::info;
@@ -44,7 +45,12 @@ impl<'a> EventMetricsAggregator<'a> {
             duration_metrics.peak_duration_minutes, duration_metrics.return_to_normal_minutes
         );
 
-        let win_rate_calculator = WinRateCalculator::new(self.candles, self.event_time);
+        let fixed_stops = self
+            .candles
+            .first()
+            .and_then(|c| config.fixed_stops(get_asset_properties(&c.symbol).pip_value));
+        let win_rate_calculator =
+            WinRateCalculator::new(self.candles, self.event_time).with_fixed_stops(fixed_stops);
         let win_rate_metrics = win_rate_calculator.calculer_taux_reussite(
             15,
             config.atr_multiplier_sl,
@@ -60,7 +66,8 @@ impl<'a> EventMetricsAggregator<'a> {
             win_rate_metrics.whipsaws
         );
 
-        let timing_optimizer = EntryTimingOptimizer::new(self.candles, self.event_time);
+        let timing_optimizer =
+            EntryTimingOptimizer::new(self.candles, self.event_time).with_fixed_stops(fixed_stops);
         let timing_result = timing_optimizer.find_optimal_timing(
             config.atr_multiplier_sl,
             config.atr_multiplier_tp,
@@ -99,6 +106,12 @@ impl<'a> EventMetricsAggregator<'a> {
                 / (win_rate_metrics.wins + win_rate_metrics.losses + win_rate_metrics.whipsaws)
                     as f64,
             whipsaw_rate: win_rate_metrics.whipsaw_rate,
+            win_rate_ci: win_rate_metrics.win_rate_ci,
+            loss_rate_ci: rate_interval(
+                win_rate_metrics.losses,
+                win_rate_metrics.wins + win_rate_metrics.losses + win_rate_metrics.whipsaws,
+            ),
+            whipsaw_rate_ci: win_rate_metrics.whipsaw_rate_ci,
             risk_reward_ratio: win_rate_metrics.risk_reward_ratio,
             best_entry_minutes_before: timing_result.best_entry_minutes_before,
             best_entry_win_rate: timing_result.best_win_rate,
@@ -177,4 +190,30 @@ mod tests {
         assert!(metrics.win_rate >= 0.0 && metrics.win_rate <= 1.0);
         assert!(metrics.atr_ratio >= 0.0);
     }
+
+    #[test]
+    fn test_stops_excursions_prioritaires_sur_atr() {
+        let stops = crate::models::excursion::ExcursionStops {
+            stop_loss_pips: 12.0,
+            take_profit_pips: 30.0,
+            sl_survival_rate: 0.75,
+            tp_reach_rate: 0.5,
+        };
+        let config = MetricsConfig::default().avec_stops_excursions(Some(stops));
+        let (sl, tp) = config.fixed_stops(0.0001).expect("stops d'excursion");
+        assert!((sl - 0.0012).abs() < 1e-12 && (tp - 0.0030).abs() < 1e-12);
+
+        // Échantillon insuffisant : multiplicateurs ATR
+        let config = MetricsConfig::default().avec_stops_excursions(None);
+        assert_eq!(config.fixed_stops(0.0001), None);
+
+        // SL/TP imposés par l'appelant conservés
+        let config = MetricsConfig {
+            stop_loss_pips: Some(8.0),
+            take_profit_pips: Some(20.0),
+            ..Default::default()
+        }
+        .avec_stops_excursions(Some(stops));
+        assert_eq!(config.stop_loss_pips, Some(8.0));
+    }
 }
@@ -0,0 +1,400 @@
+// services/excursion_analyzer.rs - Étude MFE/MAE des deux jambes du straddle
+// Pour chaque occurrence, buy-stop et sell-stop sont placés à ± distance de la dernière
+// clôture avant T0. Chaque jambe est suivie indépendamment (sans OCO) depuis son
+// déclenchement : la bougie de déclenchement compte pour la MFE et la MAE (hypothèse
+// prudente, l'ordre intra-bougie étant inconnu). Côtés du backtest : le buy-stop se déclenche
+// sur l'ask et se mesure sur le bid de sortie, le sell-stop l'inverse ; sans OHLC bid/ask stocké,
+// mid ± demi-spread de la bougie.
+
+use crate::models::candle::QuoteSide;
+use crate::models::excursion::{
+    ExcursionDistribution, ExcursionStops, HistogramBin, HorizonDistribution, HorizonExcursion,
+    LegDistribution, LegExcursion, OccurrenceExcursion,
+};
+use crate::models::Candle;
+use crate::services::win_rate_calculator::half_spread;
+use chrono::{DateTime, Duration, Utc};
+
+/// Horizons de mesure après T0
+pub const DEFAULT_HORIZONS_MINUTES: [u32; 4] = [5, 15, 30, 60];
+/// Distance des ordres stop, alignée sur le backtest straddle
+pub const DEFAULT_STRADDLE_DISTANCE_PIPS: f64 = 10.0;
+/// Percentile de MAE retenu pour le SL
+pub const DEFAULT_SL_PERCENTILE: f64 = 75.0;
+/// Percentile de MFE retenu pour le TP
+pub const DEFAULT_TP_PERCENTILE: f64 = 50.0;
+/// Classes de l'histogramme, de 0 à la valeur max
+const HISTOGRAM_BINS: usize = 10;
+/// Déclenchements minimaux pour proposer un SL/TP
+const MIN_STOP_SAMPLES: usize = 5;
+/// Écart max entre la dernière clôture de référence et T0
+const MAX_REFERENCE_GAP_MINUTES: i64 = 5;
+
+/// Excursions d'une occurrence sur bougies M1 triées couvrant [T0 - 5 min, T0 + horizon max].
+/// None sans clôture de référence, sans bougie à T0 ou si moins de la moitié de l'horizon
+/// max est couverte.
+pub fn mesurer_excursions(
+    candles: &[Candle],
+    event_time: DateTime<Utc>,
+    horizons: &[u32],
+    distance_pips: f64,
+    pip_value: f64,
+) -> Option<Vec<HorizonExcursion>> {
+    let max_horizon = horizons.iter().copied().max()?;
+    if pip_value <= 0.0 || max_horizon == 0 {
+        return None;
+    }
+
+    let t0_idx = candles.partition_point(|c| c.datetime < event_time);
+    let reference = candles[..t0_idx].last()?;
+    if event_time - reference.datetime > Duration::minutes(MAX_REFERENCE_GAP_MINUTES) {
+        return None;
+    }
+    let end = event_time + Duration::minutes(max_horizon as i64);
+    let post = &candles[t0_idx..t0_idx + candles[t0_idx..].partition_point(|c| c.datetime < end)];
+    let first = post.first()?;
+    if first.datetime - event_time >= Duration::minutes(1) || post.len() * 2 < max_horizon as usize
+    {
+        return None;
+    }
+
+    let distance = distance_pips.max(0.0) * pip_value;
+    let buy_stop = reference.close + distance;
+    let sell_stop = reference.close - distance;
+
+    Some(
+        horizons
+            .iter()
+            .map(|&horizon| {
+                let limit = event_time + Duration::minutes(horizon as i64);
+                let window = &post[..post.partition_point(|c| c.datetime < limit)];
+                HorizonExcursion {
+                    horizon_minutes: horizon,
+                    long: suivre_jambe(window, event_time, buy_stop, pip_value, true),
+                    short: suivre_jambe(window, event_time, sell_stop, pip_value, false),
+                }
+            })
+            .collect(),
+    )
+}
+
+/// MFE/MAE d'une jambe depuis sa bougie de déclenchement, sur le côté de sortie
+fn suivre_jambe(
+    window: &[Candle],
+    event_time: DateTime<Utc>,
+    entry: f64,
+    pip_value: f64,
+    is_long: bool,
+) -> Option<LegExcursion> {
+    let (entry_side, exit_side) = if is_long {
+        (QuoteSide::Ask, QuoteSide::Bid)
+    } else {
+        (QuoteSide::Bid, QuoteSide::Ask)
+    };
+    let trigger = window.iter().position(|c| {
+        let quote = c.side_ohlc(entry_side, half_spread(c));
+        if is_long {
+            quote.high >= entry
+        } else {
+            quote.low <= entry
+        }
+    })?;
+    let exits: Vec<_> = window[trigger..]
+        .iter()
+        .map(|c| c.side_ohlc(exit_side, half_spread(c)))
+        .collect();
+    let high = exits
+        .iter()
+        .map(|q| q.high)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let low = exits.iter().map(|q| q.low).fold(f64::INFINITY, f64::min);
+    let (favourable, adverse) = if is_long {
+        (high - entry, entry - low)
+    } else {
+        (entry - low, high - entry)
+    };
+    Some(LegExcursion {
+        trigger_minute: (window[trigger].datetime - event_time).num_minutes().max(0) as u32,
+        mfe_pips: favourable.max(0.0) / pip_value,
+        mae_pips: adverse.max(0.0) / pip_value,
+    })
+}
+
+/// Percentile (0-100) par interpolation linéaire sur des valeurs triées
+pub fn percentile(sorted: &[f64], pct: f64) -> f64 {
+    match sorted.len() {
+        0 => 0.0,
+        1 => sorted[0],
+        n => {
+            let rank = pct.clamp(0.0, 100.0) / 100.0 * (n - 1) as f64;
+            let lower = rank.floor() as usize;
+            let upper = rank.ceil() as usize;
+            sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+        }
+    }
+}
+
+/// Percentiles et histogramme (classes égales de 0 au max). None sans valeur
+pub fn distribution(values: &[f64]) -> Option<ExcursionDistribution> {
+    if values.is_empty() {
+        return None;
+    }
+    let mut sorted = values.to_vec();
+    sorted.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let max = sorted[sorted.len() - 1];
+
+    let bins = if max > 0.0 { HISTOGRAM_BINS } else { 1 };
+    let width = max / bins as f64;
+    let mut counts = vec![0usize; bins];
+    for v in &sorted {
+        let idx = if width > 0.0 {
+            ((v / width) as usize).min(bins - 1)
+        } else {
+            0
+        };
+        counts[idx] += 1;
+    }
+
+    Some(ExcursionDistribution {
+        count: sorted.len(),
+        mean: sorted.iter().sum::<f64>() / sorted.len() as f64,
+        p25: percentile(&sorted, 25.0),
+        p50: percentile(&sorted, 50.0),
+        p75: percentile(&sorted, 75.0),
+        p90: percentile(&sorted, 90.0),
+        p95: percentile(&sorted, 95.0),
+        max,
+        histogram: counts
+            .into_iter()
+            .enumerate()
+            .map(|(i, count)| HistogramBin {
+                lower_pips: width * i as f64,
+                upper_pips: width * (i + 1) as f64,
+                count,
+            })
+            .collect(),
+    })
+}
+
+/// SL au percentile de MAE, TP au percentile de MFE, jambes réunies
+pub fn niveaux_stop(
+    legs: &[LegExcursion],
+    sl_percentile: f64,
+    tp_percentile: f64,
+) -> Option<ExcursionStops> {
+    if legs.len() < MIN_STOP_SAMPLES {
+        return None;
+    }
+    let mut maes: Vec<f64> = legs.iter().map(|l| l.mae_pips).collect();
+    let mut mfes: Vec<f64> = legs.iter().map(|l| l.mfe_pips).collect();
+    maes.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    mfes.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    let stop_loss = percentile(&maes, sl_percentile);
+    let take_profit = percentile(&mfes, tp_percentile);
+    if stop_loss <= 0.0 || take_profit <= 0.0 {
+        return None;
+    }
+
+    let n = legs.len() as f64;
+    Some(ExcursionStops {
+        stop_loss_pips: stop_loss,
+        take_profit_pips: take_profit,
+        sl_survival_rate: maes.iter().filter(|m| **m < stop_loss).count() as f64 / n,
+        tp_reach_rate: mfes.iter().filter(|m| **m >= take_profit).count() as f64 / n,
+    })
+}
+
+/// SL/TP d'un événement tirés de ses occurrences (bougies M1 triées, heure de publication),
+/// mesurés à l'horizon de détention avec la distance et les percentiles par défaut.
+/// None sous `MIN_STOP_SAMPLES` jambes déclenchées : l'appelant garde l'ATR
+pub fn stops_depuis_occurrences<'a>(
+    occurrences: impl IntoIterator<Item = (&'a [Candle], DateTime<Utc>)>,
+    horizon_minutes: u32,
+    pip_value: f64,
+) -> Option<ExcursionStops> {
+    let horizons = [horizon_minutes];
+    let legs: Vec<LegExcursion> = occurrences
+        .into_iter()
+        .filter_map(|(candles, event_time)| {
+            mesurer_excursions(
+                candles,
+                event_time,
+                &horizons,
+                DEFAULT_STRADDLE_DISTANCE_PIPS,
+                pip_value,
+            )
+        })
+        .flatten()
+        .flat_map(|h| [h.long, h.short].into_iter().flatten())
+        .collect();
+    niveaux_stop(&legs, DEFAULT_SL_PERCENTILE, DEFAULT_TP_PERCENTILE)
+}
+
+/// Distributions MFE/MAE par horizon sur l'ensemble des occurrences
+pub fn distributions_par_horizon(
+    occurrences: &[OccurrenceExcursion],
+    horizons: &[u32],
+    sl_percentile: f64,
+    tp_percentile: f64,
+) -> Vec<HorizonDistribution> {
+    horizons
+        .iter()
+        .enumerate()
+        .map(|(i, &horizon)| {
+            let at_horizon = occurrences.iter().filter_map(|o| o.horizons.get(i));
+            let longs: Vec<LegExcursion> = at_horizon.clone().filter_map(|h| h.long).collect();
+            let shorts: Vec<LegExcursion> = at_horizon.filter_map(|h| h.short).collect();
+            let all: Vec<LegExcursion> = longs.iter().chain(shorts.iter()).copied().collect();
+            HorizonDistribution {
+                horizon_minutes: horizon,
+                long: distribution_jambe(&longs),
+                short: distribution_jambe(&shorts),
+                stops: niveaux_stop(&all, sl_percentile, tp_percentile),
+            }
+        })
+        .collect()
+}
+
+fn distribution_jambe(legs: &[LegExcursion]) -> Option<LegDistribution> {
+    let mfes: Vec<f64> = legs.iter().map(|l| l.mfe_pips).collect();
+    let maes: Vec<f64> = legs.iter().map(|l| l.mae_pips).collect();
+    Some(LegDistribution {
+        triggered_count: legs.len(),
+        mfe: distribution(&mfes)?,
+        mae: distribution(&maes)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::test_fixtures::{m1_at, release, release_on, PIP};
+
+    /// Référence 1.1000 à T-1, hausse jusqu'à +24 pips après un creux à -3 pips
+    fn hausse(t0: DateTime<Utc>) -> Vec<Candle> {
+        let mut candles = vec![m1_at(t0, -1, 1.1000, 1.1001, 1.0999, 1.1000)];
+        candles.push(m1_at(t0, 0, 1.1000, 1.1012, 1.0997, 1.1010));
+        candles.extend((1..15).map(|m| {
+            let top = 1.1010 + m as f64 * PIP;
+            m1_at(t0, m, top - PIP, top, top - 2.0 * PIP, top - PIP)
+        }));
+        candles
+    }
+
+    fn leg(mfe: f64, mae: f64) -> LegExcursion {
+        LegExcursion {
+            trigger_minute: 0,
+            mfe_pips: mfe,
+            mae_pips: mae,
+        }
+    }
+
+    #[test]
+    fn test_percentiles_et_histogramme() {
+        let values: Vec<f64> = (0..=10).map(|v| v as f64).collect();
+        assert_eq!(percentile(&values, 50.0), 5.0);
+        assert!((percentile(&values, 95.0) - 9.5).abs() < 1e-9);
+        assert_eq!(percentile(&[3.0], 90.0), 3.0);
+
+        let d = distribution(&values).unwrap();
+        assert_eq!(d.count, 11);
+        assert_eq!(d.max, 10.0);
+        assert_eq!(d.histogram.len(), 10);
+        assert_eq!(d.histogram.iter().map(|b| b.count).sum::<usize>(), 11);
+        // Le max tombe dans la dernière classe
+        assert_eq!(d.histogram[9].count, 2);
+
+        let flat = distribution(&[0.0, 0.0]).unwrap();
+        assert_eq!(flat.histogram.len(), 1);
+        assert!(distribution(&[]).is_none());
+    }
+
+    #[test]
+    fn test_excursions_jambes() {
+        let candles = hausse(release());
+        let horizons = [1, 15];
+        let result = mesurer_excursions(&candles, release(), &horizons, 10.0, PIP).unwrap();
+        assert_eq!(result.len(), 2);
+
+        // Buy-stop à 1.1010 déclenché sur la bougie de T0 : son creux compte pour la MAE
+        let long = result[1].long.unwrap();
+        assert_eq!(long.trigger_minute, 0);
+        assert!((long.mfe_pips - 14.0).abs() < 1e-6);
+        assert!((long.mae_pips - 13.0).abs() < 1e-6);
+        assert!((result[0].long.unwrap().mfe_pips - 2.0).abs() < 1e-6);
+        // Sell-stop à 1.0990 jamais atteint
+        assert!(result[1].short.is_none());
+
+        // Sans clôture de référence ni bougie à T0 : pas de mesure
+        assert!(mesurer_excursions(&candles[1..], release(), &horizons, 10.0, PIP).is_none());
+        assert!(mesurer_excursions(&candles[..1], release(), &horizons, 10.0, PIP).is_none());
+    }
+
+    #[test]
+    fn test_excursions_cote_bid_ask() {
+        // 1 pip de spread : déclenchement sur l'ask, MFE/MAE sur le bid de sortie
+        let mut candles = hausse(release());
+        for c in &mut candles {
+            c.spread_mean = Some(PIP);
+        }
+        let result = mesurer_excursions(&candles, release(), &[15], 10.0, PIP).unwrap();
+        let long = result[0].long.unwrap();
+        assert_eq!(long.trigger_minute, 0);
+        assert!((long.mfe_pips - 13.5).abs() < 1e-6);
+        assert!((long.mae_pips - 13.5).abs() < 1e-6);
+
+        // OHLC bid/ask stocké prioritaire : ask haut à T0 sous le buy-stop, déclenchement à T+1
+        candles[1].ask_open = Some(1.1000);
+        candles[1].ask_high = Some(1.1005);
+        candles[1].ask_low = Some(1.0998);
+        candles[1].ask_close = Some(1.1004);
+        let result = mesurer_excursions(&candles, release(), &[15], 10.0, PIP).unwrap();
+        assert_eq!(result[0].long.unwrap().trigger_minute, 1);
+    }
+
+    #[test]
+    fn test_stops_depuis_occurrences() {
+        let series: Vec<(DateTime<Utc>, Vec<Candle>)> = (2..7)
+            .map(|d| (release_on(d), hausse(release_on(d))))
+            .collect();
+        let occurrences = || series.iter().map(|(t, c)| (c.as_slice(), *t));
+
+        // 4 jambes déclenchées : trop peu, retour à l'ATR
+        assert!(stops_depuis_occurrences(occurrences().take(4), 15, PIP).is_none());
+
+        let stops = stops_depuis_occurrences(occurrences(), 15, PIP).unwrap();
+        assert!((stops.stop_loss_pips - 13.0).abs() < 1e-6);
+        assert!((stops.take_profit_pips - 14.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_niveaux_stop_depuis_percentiles() {
+        let legs: Vec<LegExcursion> = (1..=8)
+            .map(|i| leg(i as f64 * 10.0, i as f64 * 2.0))
+            .collect();
+        assert!(niveaux_stop(&legs[..4], 75.0, 50.0).is_none());
+
+        let stops = niveaux_stop(&legs, 75.0, 50.0).unwrap();
+        assert!((stops.stop_loss_pips - 12.5).abs() < 1e-9);
+        assert!((stops.take_profit_pips - 45.0).abs() < 1e-9);
+        assert_eq!(stops.sl_survival_rate, 0.75);
+        assert_eq!(stops.tp_reach_rate, 0.5);
+
+        let occurrences: Vec<OccurrenceExcursion> = legs
+            .iter()
+            .map(|l| OccurrenceExcursion {
+                event_time: release().to_rfc3339(),
+                horizons: vec![HorizonExcursion {
+                    horizon_minutes: 15,
+                    long: Some(*l),
+                    short: None,
+                }],
+            })
+            .collect();
+        let dists = distributions_par_horizon(&occurrences, &[15], 75.0, 50.0);
+        assert_eq!(dists.len(), 1);
+        assert_eq!(dists[0].long.as_ref().unwrap().triggered_count, 8);
+        assert!(dists[0].short.is_none());
+        assert_eq!(dists[0].stops, Some(stops));
+    }
+}
@@ -0,0 +1,58 @@
+// services/global_analyzer_confidence.rs - Intervalles bootstrap des classements globaux
+// (BestPair, GoldenHour) : l'archive est l'unité rééchantillonnée.
+
+use super::global_analyzer_types::WeightedArchiveData;
+use crate::models::ConfidenceInterval;
+use crate::services::bootstrap::{resample_interval, weighted_mean_interval};
+
+/// Bornes d'une paire : (volatilité moyenne, win rate, score)
+pub fn best_pair_intervals(
+    samples: &[(f64, f64, f64)], // (volatilité, confiance, poids)
+) -> (ConfidenceInterval, ConfidenceInterval, ConfidenceInterval) {
+    let vol: Vec<(f64, f64)> = samples.iter().map(|s| (s.0, s.2)).collect();
+    let conf: Vec<(f64, f64)> = samples.iter().map(|s| (s.1, s.2)).collect();
+    let score = resample_interval(samples.len(), |idx| {
+        let (v, c, w) = idx.iter().fold((0.0, 0.0, 0.0), |acc, &i| {
+            let (vol, conf, weight) = samples[i];
+            (acc.0 + vol * weight, acc.1 + conf * weight, acc.2 + weight)
+        });
+        (w > 0.0).then(|| (v / w) * (c / w) * 100.0)
+    });
+    (
+        weighted_mean_interval(&vol),
+        weighted_mean_interval(&conf),
+        score,
+    )
+}
+
+/// Bornes d'une heure : (volatilité moyenne, fiabilité en %)
+pub fn golden_hour_intervals(
+    results: &[WeightedArchiveData],
+    hour: u8,
+) -> (ConfidenceInterval, ConfidenceInterval) {
+    let vol: Vec<(f64, f64)> = results
+        .iter()
+        .filter(|r| r.data.best_hours.contains(&hour))
+        .map(|r| (archive_volatility(r), r.weight))
+        .collect();
+    let reliability = resample_interval(results.len(), |idx| {
+        let (hour_weight, total) = idx.iter().fold((0.0, 0.0), |acc, &i| {
+            let r = &results[i];
+            let hits = r.data.best_hours.iter().filter(|&&h| h == hour).count() as f64;
+            (
+                acc.0 + r.weight * hits,
+                acc.1 + r.weight * r.data.best_hours.len() as f64,
+            )
+        });
+        (total > 0.0).then(|| hour_weight / total * 100.0)
+    });
+    (weighted_mean_interval(&vol), reliability)
+}
+
+pub fn archive_volatility(r: &WeightedArchiveData) -> f64 {
+    r.data
+        .global_metrics
+        .as_ref()
+        .map(|m| m.mean_volatility)
+        .unwrap_or(0.0)
+}
@@ -1,6 +1,9 @@
 // services/global_analyzer_metrics.rs
 // Calcul des métriques globales (extracted compute functions)
 
+use super::global_analyzer_confidence::{
+    archive_volatility, best_pair_intervals, golden_hour_intervals,
+};
 use super::global_analyzer_event_analysis;
 use super::global_analyzer_helpers::*;
 use super::global_analyzer_types::*;
@@ -81,27 +84,23 @@ pub fn compute_global_stats(results: &[WeightedArchiveData]) -> GlobalStats {
 }
 
 pub fn compute_best_pairs(results: &[WeightedArchiveData]) -> Vec<BestPair> {
-    let mut pair_stats: HashMap<String, (f64, f64, f64, usize)> = HashMap::new();
+    // (volatilité, confiance, poids) par archive, conservés pour le bootstrap
+    let mut pair_samples: HashMap<String, Vec<(f64, f64, f64)>> = HashMap::new();
 
     for r in results {
-        let vol = r
-            .data
-            .global_metrics
-            .as_ref()
-            .map(|m| m.mean_volatility)
-            .unwrap_or(0.0);
-        let entry = pair_stats
+        pair_samples
             .entry(r.data.symbol.clone())
-            .or_insert((0.0, 0.0, 0.0, 0));
-        entry.0 += vol * r.weight;
-        entry.1 += r.data.confidence_score * r.weight;
-        entry.2 += r.weight;
-        entry.3 += 1;
+            .or_default()
+            .push((archive_volatility(r), r.data.confidence_score, r.weight));
     }
 
-    let mut best_pairs: Vec<BestPair> = pair_stats
+    let mut best_pairs: Vec<BestPair> = pair_samples
         .into_iter()
-        .map(|(symbol, (weighted_vol, weighted_conf, total_weight, count))| {
+        .map(|(symbol, samples)| {
+            let (weighted_vol, weighted_conf, total_weight) =
+                samples.iter().fold((0.0, 0.0, 0.0), |acc, (vol, conf, w)| {
+                    (acc.0 + vol * w, acc.1 + conf * w, acc.2 + w)
+                });
             let avg_vol = if total_weight > 0.0 {
                 weighted_vol / total_weight
             } else {
@@ -112,20 +111,23 @@ pub fn compute_best_pairs(results: &[WeightedArchiveData]) -> Vec<BestPair> {
             } else {
                 0.0
             };
+            let (avg_volatility_ci, win_rate_ci, score_ci) = best_pair_intervals(&samples);
             BestPair {
                 symbol,
                 score: avg_vol * avg_conf * 100.0,
+                score_ci,
                 avg_volatility: avg_vol,
+                avg_volatility_ci,
                 win_rate: avg_conf,
-                analysis_count: count,
+                win_rate_ci,
+                analysis_count: samples.len(),
             }
         })
         .collect();
 
+    // Classement prudent : borne basse 5% du score, un seul bon archivage ne suffit pas
     best_pairs.sort_by(|a, b| {
-        b.score
-            .partial_cmp(&a.score)
-            .unwrap_or(std::cmp::Ordering::Equal)
+        par_borne_basse((a.score_ci.lower, a.score), (b.score_ci.lower, b.score))
     });
     best_pairs
 }
@@ -136,12 +138,7 @@ pub fn compute_golden_hours(results: &[WeightedArchiveData]) -> Vec<GoldenHour>
     let mut total_weight = 0.0;
 
     for r in results {
-        let vol = r
-            .data
-            .global_metrics
-            .as_ref()
-            .map(|m| m.mean_volatility)
-            .unwrap_or(0.0);
+        let vol = archive_volatility(r);
         for &hour in &r.data.best_hours {
             *hour_weights.entry(hour).or_insert(0.0) += r.weight;
             let entry = hour_volatilities.entry(hour).or_insert((0.0, 0.0));
@@ -158,28 +155,39 @@ pub fn compute_golden_hours(results: &[WeightedArchiveData]) -> Vec<GoldenHour>
                 .get(&hour)
                 .map(|(sum, w)| if *w > 0.0 { sum / w } else { 0.0 })
                 .unwrap_or(0.0);
+            let (avg_volatility_ci, reliability_ci) = golden_hour_intervals(results, hour);
             GoldenHour {
                 hour,
                 score: weight,
                 avg_volatility: avg_vol,
+                avg_volatility_ci,
                 reliability: if total_weight > 0.0 {
                     (weight / total_weight) * 100.0
                 } else {
                     0.0
                 },
+                reliability_ci,
             }
         })
         .collect();
 
     golden_hours.sort_by(|a, b| {
-        b.reliability
-            .partial_cmp(&a.reliability)
-            .unwrap_or(std::cmp::Ordering::Equal)
+        par_borne_basse(
+            (a.reliability_ci.lower, a.reliability),
+            (b.reliability_ci.lower, b.reliability),
+        )
     });
     golden_hours.truncate(24);
     golden_hours
 }
 
+/// Ordre décroissant sur (borne basse, estimation ponctuelle) : la borne basse prime
+fn par_borne_basse(a: (f64, f64), b: (f64, f64)) -> std::cmp::Ordering {
+    b.0.partial_cmp(&a.0)
+        .unwrap_or(std::cmp::Ordering::Equal)
+        .then_with(|| b.1.partial_cmp(&a.1).unwrap_or(std::cmp::Ordering::Equal))
+}
+
 pub fn compute_tradable_events(
     archives: &[crate::models::Archive],
 ) -> Vec<crate::models::TradableEventType> {
@@ -1,9 +1,16 @@
 // services/import_processor.rs - Logique de traitement pour l'import unifié
 // Conforme .clinerules : < 300L, pas d'unwrap()
 
+use crate::services::import_stream::ImportControl;
+use crate::services::pair_data::{NormalizedCandle, PairMetadata};
+use crate::services::source_timezone::SourceTimezone;
 use crate::services::{clean_european_csv, PairDataConverter};
 use std::path::{Path, PathBuf};
 
+/// Reçoit chaque lot de bougies normalisées avec la paire/timeframe du fichier
+pub type CandleBatchSink<'a> =
+    dyn FnMut(&PairMetadata, &[NormalizedCandle]) -> Result<(), String> + 'a;
+
 /// Résultat du traitement d'un fichier
 pub struct ProcessResult {
     pub pair: String,
@@ -12,6 +19,8 @@ pub struct ProcessResult {
     pub errors: usize,
     pub error_rate: f64,
     pub cleaned_file_path: String, // Chemin du fichier nettoyé
+    pub source_timezone: Option<SourceTimezone>, // Fuseau connu du fichier (None = supposé UTC)
+    pub late_ticks: usize,         // Ticks hors ordre ignorés (exports tick HistData/MT5)
 }
 
 /// Gère les doublons en ajoutant un suffixe de version (fonction utilitaire réutilisable)
@@ -35,11 +44,17 @@ pub fn handle_duplicate(output_dir: &Path, filename: &str) -> Result<PathBuf, St
     Err("Trop de versions du même fichier".to_string())
 }
 
-/// Traite un fichier : nettoyage + import + suppression du temporaire
+/// Traite un fichier : nettoyage + import + suppression du temporaire.
+/// `source_timezone` : fuseau déclaré (None = défaut du format détecté).
+/// Lecture en continu : chaque lot est passé à `on_batch` (écriture BD), `ctrl`
+/// remonte la progression et interrompt l'import s'il est annulé.
 pub fn process_file_with_cleaning(
     source_path: &str,
     temp_dir: &Path,
     data_dir: &Path,
+    source_timezone: Option<&SourceTimezone>,
+    ctrl: &mut ImportControl,
+    on_batch: &mut CandleBatchSink<'_>,
 ) -> Result<ProcessResult, String> {
     tracing::info!("🧹 Début du nettoyage du fichier");
     let cleaning_report = clean_european_csv(source_path, temp_dir)?;
@@ -68,13 +83,23 @@ pub fn process_file_with_cleaning(
         error_rate
     );
 
-    tracing::info!("📥 Début de l'import du fichier");
-    let (pair, timeframe) = import_cleaned_file(cleaned_path, data_dir)?;
+    let known_timezone = PairDataConverter::known_source_timezone(cleaned_path, source_timezone)?;
+    let source_timezone = known_timezone.unwrap_or_default();
+    tracing::info!(
+        "📥 Début de l'import du fichier (fuseau source: {})",
+        source_timezone
+    );
+    let imported = import_cleaned_file(cleaned_path, data_dir, &source_timezone, ctrl, on_batch);
+    if imported.is_err() {
+        // Import interrompu : le fichier nettoyé temporaire ne sera pas repris
+        let _ = std::fs::remove_file(cleaned_path);
+    }
+    let (pair, timeframe, late_ticks) = imported?;
 
     tracing::info!("✅ Importé: {} ({})", pair, timeframe);
 
     // NOTE: Ne pas supprimer le fichier nettoyé ici
-    // Il sera inséré en BD par import_clean_commands.rs, puis supprimé après
+    // Il est supprimé par import_clean une fois l'import publié en BD
 
     Ok(ProcessResult {
         pair,
@@ -83,38 +108,94 @@ pub fn process_file_with_cleaning(
         errors: cleaning_report.errors,
         error_rate,
         cleaned_file_path: cleaned_path.to_string(), // Retourner le chemin du fichier nettoyé
+        source_timezone: known_timezone,
+        late_ticks,
     })
 }
 
-/// Importe un fichier CSV nettoyé (réutilise la logique de pair_data_commands)
-fn import_cleaned_file(cleaned_path: &str, output_dir: &Path) -> Result<(String, String), String> {
-    // 1. Lire et normaliser le CSV
-    let candles = PairDataConverter::read_and_normalize(cleaned_path)?;
-
-    if candles.is_empty() {
-        return Err("Aucune donnée valide trouvée".to_string());
-    }
-
-    // 2. Extraire les métadonnées
+/// Importe un fichier CSV nettoyé en continu : chaque lot est ajouté au CSV normalisé
+/// (data/csv) puis transmis à `on_batch`. Le CSV est écrit sous un nom provisoire,
+/// renommé à la fin (les dates du nom ne sont connues qu'en fin de lecture).
+fn import_cleaned_file(
+    cleaned_path: &str,
+    output_dir: &Path,
+    source_timezone: &SourceTimezone,
+    ctrl: &mut ImportControl,
+    on_batch: &mut CandleBatchSink<'_>,
+) -> Result<(String, String, usize), String> {
     let filename = Path::new(cleaned_path)
         .file_name()
         .ok_or("Nom de fichier invalide")?
         .to_str()
         .ok_or("Nom de fichier non-UTF8")?;
+    let partial_path = output_dir.join(format!("{}.partial", filename));
+
+    // 1. Lire et normaliser le CSV par lots (horodatages convertis en UTC)
+    let mut metadata: Option<PairMetadata> = None;
+    let mut writer: Option<csv::Writer<std::fs::File>> = None;
+    let mut range: Option<(i64, i64)> = None;
+    let streamed = PairDataConverter::stream_normalized(
+        cleaned_path,
+        Some(source_timezone),
+        ctrl,
+        &mut |batch| {
+            // 2. Métadonnées (paire, timeframe) extraites du premier lot
+            let meta = if let Some(meta) = metadata.as_ref() {
+                meta
+            } else {
+                metadata.insert(PairDataConverter::extract_metadata(&batch, filename)?)
+            };
+            let out = if let Some(out) = writer.as_mut() {
+                out
+            } else {
+                writer.insert(PairDataConverter::create_normalized_csv(&partial_path)?)
+            };
+            PairDataConverter::append_normalized_csv(out, &batch)?;
+            for candle in &batch {
+                range = Some(match range {
+                    Some((first, last)) => {
+                        (first.min(candle.timestamp), last.max(candle.timestamp))
+                    }
+                    None => (candle.timestamp, candle.timestamp),
+                });
+            }
+            on_batch(meta, &batch)
+        },
+    );
 
-    let metadata = PairDataConverter::extract_metadata(&candles, filename)?;
+    let finished = streamed.and_then(|streamed| {
+        let mut metadata = metadata.ok_or("Aucune donnée valide trouvée")?;
+        let mut writer = writer.ok_or("Aucune donnée valide trouvée")?;
+        writer.flush().map_err(|e| format!("Erreur flush: {}", e))?;
+        drop(writer);
+
+        // 3. Générer le nom du fichier normalisé (période complète)
+        if let Some((first, last)) = range {
+            let day = |t: i64| {
+                chrono::DateTime::from_timestamp(t, 0)
+                    .map(|d| d.format("%Y-%m-%d").to_string())
+                    .ok_or("Timestamp invalide")
+            };
+            metadata.start_date = day(first)?;
+            metadata.end_date = day(last)?;
+        }
+        let output_filename = PairDataConverter::generate_filename(&metadata);
+        let mut output_path = output_dir.join(&output_filename);
 
-    // 3. Générer le nom du fichier normalisé
-    let output_filename = PairDataConverter::generate_filename(&metadata);
-    let mut output_path = output_dir.join(&output_filename);
+        // 4. Gérer les doublons (versioning)
+        if output_path.exists() {
+            output_path = handle_duplicate(output_dir, &output_filename)?;
+        }
 
-    // 4. Gérer les doublons (versioning)
-    if output_path.exists() {
-        output_path = handle_duplicate(output_dir, &output_filename)?;
-    }
+        // 5. Conserver le CSV normalisé
+        std::fs::rename(&partial_path, &output_path)
+            .map_err(|e| format!("Erreur renommage CSV normalisé: {}", e))?;
 
-    // 5. Sauvegarder le CSV normalisé
-    PairDataConverter::save_normalized_csv(&candles, &output_path)?;
+        Ok((metadata.pair, metadata.timeframe, streamed.late_ticks))
+    });
 
-    Ok((metadata.pair, metadata.timeframe))
+    if finished.is_err() && partial_path.exists() {
+        let _ = std::fs::remove_file(&partial_path);
+    }
+    finished
 }
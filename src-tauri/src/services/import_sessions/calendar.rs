@@ -0,0 +1,198 @@
+// services/import_sessions/calendar.rs - Sessions d'import de calendrier (volatility.db)
+// Un remplacement de calendrier n'est pas réversible : seule la dernière session s'annule.
+
+use crate::models::import_session::{
+    ImportKind, ImportSession, ImportSessionDetail, ReimportMode, RollbackResult,
+};
+use chrono::Utc;
+use rusqlite::{params, Connection, OptionalExtension};
+use tracing::info;
+
+/// Ouvre une session d'import de calendrier et retourne son id
+pub fn ouvrir_session_calendrier(
+    conn: &Connection,
+    calendar_id: i64,
+    filename: &str,
+    mode: Option<ReimportMode>,
+) -> Result<i64, String> {
+    conn.query_row(
+        "INSERT INTO calendar_import_log (calendar_import_id, filename, import_mode, status, imported_at)
+         VALUES (?, ?, ?, 'success', ?)
+         RETURNING id",
+        params![
+            calendar_id,
+            filename,
+            mode.map_or("new", |m| m.as_str()),
+            Utc::now().to_rfc3339()
+        ],
+        |row| row.get(0),
+    )
+    .map_err(|e| format!("Erreur calendar_import_log : {}", e))
+}
+
+/// Enregistre le nombre d'événements insérés par une session de calendrier
+pub fn clore_session_calendrier(conn: &Connection, id: i64, inserted: usize) -> Result<(), String> {
+    conn.execute(
+        "UPDATE calendar_import_log SET event_count = ? WHERE id = ?",
+        params![inserted as i64, id],
+    )
+    .map(|_| ())
+    .map_err(|e| format!("Erreur calendar_import_log : {}", e))
+}
+
+/// Sessions d'un calendrier supprimé ou remplacé : plus annulables
+pub fn marquer_calendrier_remplace(conn: &Connection, calendar_id: i64) -> Result<(), String> {
+    conn.execute(
+        "UPDATE calendar_import_log SET status = 'replaced'
+         WHERE calendar_import_id = ? AND status = 'success'",
+        [calendar_id],
+    )
+    .map(|_| ())
+    .map_err(|e| format!("Erreur calendar_import_log : {}", e))
+}
+
+/// Recalcule event_count et la plage de dates d'un calendrier ; retourne le nombre d'événements
+pub fn rafraichir_calendrier(conn: &Connection, calendar_id: i64) -> Result<i64, String> {
+    conn.query_row(
+        "UPDATE calendar_imports SET
+            event_count = (SELECT COUNT(*) FROM calendar_events WHERE calendar_import_id = ?1),
+            oldest_event_date = (SELECT MIN(event_time) FROM calendar_events WHERE calendar_import_id = ?1),
+            newest_event_date = (SELECT MAX(event_time) FROM calendar_events WHERE calendar_import_id = ?1)
+         WHERE id = ?1
+         RETURNING event_count",
+        [calendar_id],
+        |row| row.get(0),
+    )
+    .optional()
+    .map(|count| count.unwrap_or(0))
+    .map_err(|e| format!("Erreur calendar_imports : {}", e))
+}
+
+fn session_calendrier(row: &rusqlite::Row) -> rusqlite::Result<ImportSession> {
+    Ok(ImportSession {
+        kind: ImportKind::Calendar,
+        id: row.get(0)?,
+        filename: row.get(1)?,
+        target: row.get(2)?,
+        timeframe: None,
+        imported_at: row.get(3)?,
+        mode: row.get(4)?,
+        status: row.get(5)?,
+        row_count: row.get(6)?,
+        source_timezone: None,
+        live_rows: row.get(7)?,
+    })
+}
+
+const SESSION_CALENDRIER_SQL: &str = "SELECT l.id, l.filename, COALESCE(c.name, l.filename),
+        l.imported_at, l.import_mode, l.status, l.event_count,
+        (SELECT COUNT(*) FROM calendar_events e WHERE e.import_session_id = l.id)
+     FROM calendar_import_log l
+     LEFT JOIN calendar_imports c ON c.id = l.calendar_import_id";
+
+/// Sessions de calendrier, plus récentes d'abord
+pub fn lister_sessions_calendrier(
+    conn: &Connection,
+    limit: usize,
+) -> Result<Vec<ImportSession>, String> {
+    let mut stmt = conn
+        .prepare(&format!(
+            "{} ORDER BY l.id DESC LIMIT ?",
+            SESSION_CALENDRIER_SQL
+        ))
+        .map_err(|e| format!("Erreur prepare : {}", e))?;
+    let sessions = stmt
+        .query_map([limit as i64], session_calendrier)
+        .map_err(|e| format!("Erreur lecture calendar_import_log : {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Erreur lecture calendar_import_log : {}", e))?;
+    Ok(sessions)
+}
+
+/// Détail d'une session de calendrier
+pub fn detail_session_calendrier(
+    conn: &Connection,
+    id: i64,
+) -> Result<ImportSessionDetail, String> {
+    let sql_err = |e: rusqlite::Error| format!("Erreur lecture session calendrier #{} : {}", id, e);
+    let session = conn
+        .query_row(
+            &format!("{} WHERE l.id = ?", SESSION_CALENDRIER_SQL),
+            [id],
+            session_calendrier,
+        )
+        .optional()
+        .map_err(sql_err)?
+        .ok_or_else(|| format!("Session calendrier #{} introuvable", id))?;
+    let (first_time, last_time) = conn
+        .query_row(
+            "SELECT MIN(event_time), MAX(event_time) FROM calendar_events WHERE import_session_id = ?",
+            [id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .map_err(sql_err)?;
+
+    Ok(ImportSessionDetail {
+        can_rollback: session.status == "success",
+        session,
+        first_time,
+        last_time,
+        tick_rows: 0,
+        displaced_rows: 0,
+        displaced_ticks: 0,
+    })
+}
+
+/// Annule une session de calendrier : ses événements sont supprimés, le
+/// calendrier est supprimé s'il n'en reste aucun. Un remplacement n'est pas
+/// réversible : le calendrier précédent n'est pas restauré.
+pub fn annuler_session_calendrier(conn: &Connection, id: i64) -> Result<RollbackResult, String> {
+    let (calendar_id, status): (i64, String) = conn
+        .query_row(
+            "SELECT calendar_import_id, status FROM calendar_import_log WHERE id = ?",
+            [id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .optional()
+        .map_err(|e| format!("Erreur lecture calendar_import_log : {}", e))?
+        .ok_or_else(|| format!("Session calendrier #{} introuvable", id))?;
+    if status != "success" {
+        return Err(format!(
+            "Session calendrier #{} non annulable (statut {})",
+            id, status
+        ));
+    }
+
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| format!("Erreur début transaction : {}", e))?;
+    let rows_deleted = tx
+        .execute(
+            "DELETE FROM calendar_events WHERE import_session_id = ?",
+            [id],
+        )
+        .map_err(|e| format!("Erreur suppression événements : {}", e))?;
+    tx.execute(
+        "UPDATE calendar_import_log SET status = 'rolled_back' WHERE id = ?",
+        [id],
+    )
+    .map_err(|e| format!("Erreur calendar_import_log : {}", e))?;
+    if rafraichir_calendrier(&tx, calendar_id)? == 0 {
+        tx.execute("DELETE FROM calendar_imports WHERE id = ?", [calendar_id])
+            .map_err(|e| format!("Erreur suppression calendrier : {}", e))?;
+    }
+    tx.commit().map_err(|e| format!("Erreur commit : {}", e))?;
+
+    info!(
+        "↩️ Session calendrier #{} annulée : {} événements retirés",
+        id, rows_deleted
+    );
+    Ok(RollbackResult {
+        kind: ImportKind::Calendar,
+        id,
+        rows_deleted,
+        rows_restored: 0,
+        ticks_deleted: 0,
+        ticks_restored: 0,
+    })
+}
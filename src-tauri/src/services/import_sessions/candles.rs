@@ -0,0 +1,262 @@
+// services/import_sessions/candles.rs - Annulation des sessions de bougies
+// Les bougies écrasées par une session sont remises en place depuis candle_displaced.
+
+use super::sessions::{marquer_session, purger_sauvegardes};
+use super::ticks::retirer_ticks;
+use crate::models::import_session::{ImportKind, RollbackResult};
+use crate::services::candle_resampler;
+use chrono::{DateTime, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use tracing::info;
+
+/// Colonnes de valeur communes à candle_data et candle_displaced
+const CANDLE_COLUMNS: &str = "symbol, timeframe, time, open, high, low, close, volume,
+    spread_open, spread_high, spread_low, spread_close, spread_mean, tick_count,
+    bid_open, bid_high, bid_low, bid_close, ask_open, ask_high, ask_low, ask_close,
+    imported_at, source_file, import_id";
+
+/// Première et dernière bougie couvertes
+type PlageUtc = (DateTime<Utc>, DateTime<Utc>);
+
+/// Effet du retrait d'une session de candle_data/tick_data
+struct Retrait {
+    rows_deleted: usize,
+    rows_restored: usize,
+    ticks_deleted: usize,
+    ticks_restored: usize,
+    range: Option<(String, String)>,
+}
+
+/// Copie dans candle_displaced les bougies en place que l'import en transit va écraser
+pub fn sauvegarder_bougies_ecrasees(
+    conn: &Connection,
+    import_id: i64,
+    symbol: &str,
+    timeframe: &str,
+    staging_id: &str,
+) -> Result<usize, String> {
+    conn.execute(
+        &format!(
+            "INSERT INTO candle_displaced (displaced_by, {cols})
+             SELECT ?1, {cols} FROM candle_data
+             WHERE symbol = ?2 AND timeframe = ?3
+               AND time IN (SELECT time FROM candle_staging WHERE import_id = ?4)",
+            cols = CANDLE_COLUMNS
+        ),
+        params![import_id, symbol, timeframe, staging_id],
+    )
+    .map_err(|e| format!("Erreur sauvegarde bougies écrasées : {}", e))
+}
+
+/// Retire une session remplacée par un nouvel import du même fichier ;
+/// retourne la plage qu'elle couvrait (timeframes dérivés à recalculer).
+pub fn remplacer_session(conn: &Connection, id: i64) -> Result<Option<PlageUtc>, String> {
+    let retrait = retirer_session(conn, id)?;
+    marquer_session(conn, id, "replaced")?;
+    info!(
+        "♻️ Import #{} remplacé : {} bougies retirées, {} restaurées",
+        id, retrait.rows_deleted, retrait.rows_restored
+    );
+    match retrait.range {
+        Some((first, last)) => Ok(Some((parse_time(&first)?, parse_time(&last)?))),
+        None => Ok(None),
+    }
+}
+
+/// Retire de candle_data/tick_data les lignes d'une session et remet en place
+/// les bougies qu'elle avait écrasées.
+fn retirer_session(conn: &Connection, id: i64) -> Result<Retrait, String> {
+    let sql_err = |e: rusqlite::Error| format!("Erreur retrait import #{} : {}", id, e);
+
+    let (first, last): (Option<String>, Option<String>) = conn
+        .query_row(
+            "SELECT MIN(time), MAX(time) FROM candle_data WHERE import_id = ?",
+            [id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .map_err(sql_err)?;
+
+    // Sauvegardes détenues par cette session sur des clés dont sa propre bougie a
+    // ensuite été écrasée : confiées à la session qui l'a écrasée
+    conn.execute(
+        "UPDATE candle_displaced SET displaced_by = (
+            SELECT w.displaced_by FROM candle_displaced w
+            WHERE w.import_id = ?1 AND w.symbol = candle_displaced.symbol
+              AND w.timeframe = candle_displaced.timeframe AND w.time = candle_displaced.time)
+         WHERE displaced_by = ?1 AND EXISTS (
+            SELECT 1 FROM candle_displaced w
+            WHERE w.import_id = ?1 AND w.symbol = candle_displaced.symbol
+              AND w.timeframe = candle_displaced.timeframe AND w.time = candle_displaced.time)",
+        [id],
+    )
+    .map_err(sql_err)?;
+    conn.execute("DELETE FROM candle_displaced WHERE import_id = ?", [id])
+        .map_err(sql_err)?;
+
+    let rows_deleted = conn
+        .execute("DELETE FROM candle_data WHERE import_id = ?", [id])
+        .map_err(sql_err)?;
+    let rows_restored = conn
+        .execute(
+            &format!(
+                "INSERT INTO candle_data ({cols})
+                 SELECT {cols} FROM candle_displaced WHERE displaced_by = ?
+                 ON CONFLICT(symbol, timeframe, time) DO NOTHING",
+                cols = CANDLE_COLUMNS
+            ),
+            [id],
+        )
+        .map_err(sql_err)?;
+    conn.execute("DELETE FROM candle_displaced WHERE displaced_by = ?", [id])
+        .map_err(sql_err)?;
+
+    let (ticks_deleted, ticks_restored) = retirer_ticks(conn, id).map_err(sql_err)?;
+
+    let mut retrait = Retrait {
+        rows_deleted,
+        rows_restored,
+        ticks_deleted,
+        ticks_restored,
+        range: first.zip(last),
+    };
+    if rows_deleted == 0 {
+        retirer_session_historique(conn, id, &mut retrait)?;
+    }
+    // Sauvegardes confiées à une session déjà validée : plus rien ne les restaurera
+    purger_sauvegardes(conn)?;
+    Ok(retrait)
+}
+
+/// Import antérieur aux sessions (bougies sans import_id) : retrait par source_file
+fn retirer_session_historique(
+    conn: &Connection,
+    id: i64,
+    retrait: &mut Retrait,
+) -> Result<(), String> {
+    let sql_err = |e: rusqlite::Error| format!("Erreur retrait import #{} : {}", id, e);
+    let Some((filename, symbol, timeframe)) = conn
+        .query_row(
+            "SELECT filename, symbol, timeframe FROM import_log
+             WHERE id = ? AND import_mode IS NULL",
+            [id],
+            |row| {
+                Ok((
+                    row.get::<_, String>(0)?,
+                    row.get::<_, String>(1)?,
+                    row.get::<_, String>(2)?,
+                ))
+            },
+        )
+        .optional()
+        .map_err(sql_err)?
+    else {
+        return Ok(());
+    };
+
+    let (first, last): (Option<String>, Option<String>) = conn
+        .query_row(
+            "SELECT MIN(time), MAX(time) FROM candle_data
+             WHERE import_id IS NULL AND symbol = ? AND timeframe = ? AND source_file = ?",
+            params![&symbol, &timeframe, &filename],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .map_err(sql_err)?;
+    retrait.rows_deleted = conn
+        .execute(
+            "DELETE FROM candle_data
+             WHERE import_id IS NULL AND symbol = ? AND timeframe = ? AND source_file = ?",
+            params![&symbol, &timeframe, &filename],
+        )
+        .map_err(sql_err)?;
+    retrait.range = first.zip(last);
+    Ok(())
+}
+
+/// Annule une session de bougies : lignes retirées, bougies et ticks écrasés restaurés,
+/// pair_metadata et timeframes dérivés recalculés.
+pub fn annuler_session_bougies(conn: &Connection, id: i64) -> Result<RollbackResult, String> {
+    let (symbol, timeframe, status): (String, String, String) = conn
+        .query_row(
+            "SELECT symbol, timeframe, status FROM import_log WHERE id = ?",
+            [id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?)),
+        )
+        .optional()
+        .map_err(|e| format!("Erreur lecture import_log : {}", e))?
+        .ok_or_else(|| format!("Import #{} introuvable", id))?;
+    if status != "success" {
+        return Err(format!("Import #{} non annulable (statut {})", id, status));
+    }
+
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| format!("Erreur début transaction : {}", e))?;
+    let retrait = retirer_session(&tx, id)?;
+    marquer_session(&tx, id, "rolled_back")?;
+    rafraichir_pair_metadata(&tx, &symbol, &timeframe)?;
+    tx.commit().map_err(|e| format!("Erreur commit : {}", e))?;
+
+    // Timeframes dérivés recalculés sur les jours touchés
+    if let Some((first, last)) = retrait.range.as_ref().filter(|_| timeframe == "M1") {
+        candle_resampler::sync_derived_timeframes(
+            conn,
+            &symbol,
+            parse_time(first)?,
+            parse_time(last)?,
+        )?;
+    }
+
+    info!(
+        "↩️ Import #{} annulé ({}/{}) : {} bougies retirées, {} restaurées, {} ticks retirés, {} restaurés",
+        id,
+        symbol,
+        timeframe,
+        retrait.rows_deleted,
+        retrait.rows_restored,
+        retrait.ticks_deleted,
+        retrait.ticks_restored
+    );
+    Ok(RollbackResult {
+        kind: ImportKind::Candles,
+        id,
+        rows_deleted: retrait.rows_deleted,
+        rows_restored: retrait.rows_restored,
+        ticks_deleted: retrait.ticks_deleted,
+        ticks_restored: retrait.ticks_restored,
+    })
+}
+
+fn parse_time(time: &str) -> Result<DateTime<Utc>, String> {
+    DateTime::parse_from_rfc3339(time)
+        .map(|t| t.with_timezone(&Utc))
+        .map_err(|e| format!("Horodatage invalide {} : {}", time, e))
+}
+
+/// Recompte les bougies d'une paire/timeframe (entrée supprimée s'il n'en reste aucune)
+fn rafraichir_pair_metadata(
+    conn: &Connection,
+    symbol: &str,
+    timeframe: &str,
+) -> Result<(), String> {
+    let count: i64 = conn
+        .query_row(
+            "SELECT COUNT(*) FROM candle_data WHERE symbol = ? AND timeframe = ?",
+            params![symbol, timeframe],
+            |row| row.get(0),
+        )
+        .map_err(|e| format!("Erreur COUNT : {}", e))?;
+    let updated = if count == 0 {
+        conn.execute(
+            "DELETE FROM pair_metadata WHERE symbol = ? AND timeframe = ?",
+            params![symbol, timeframe],
+        )
+    } else {
+        conn.execute(
+            "UPDATE pair_metadata SET row_count = ? WHERE symbol = ? AND timeframe = ?",
+            params![count, symbol, timeframe],
+        )
+    };
+    updated
+        .map(|_| ())
+        .map_err(|e| format!("Erreur pair_metadata : {}", e))
+}
@@ -0,0 +1,31 @@
+// services/import_sessions/mod.rs - Sessions d'import : historique, inspection, annulation
+// Bougies (pairs.db) : chaque ligne publiée porte import_log.id dans candle_data.import_id,
+// les bougies écrasées sont gardées dans candle_displaced pour être remises à l'annulation,
+// jusqu'à validation de la session (manuelle ou après DEFAULT_BACKUP_RETENTION_DAYS jours).
+// Calendriers (volatility.db) : calendar_events.import_session_id = calendar_import_log.id.
+
+mod calendar;
+mod candles;
+mod sessions;
+mod ticks;
+
+pub use calendar::{
+    annuler_session_calendrier, clore_session_calendrier, detail_session_calendrier,
+    lister_sessions_calendrier, marquer_calendrier_remplace, ouvrir_session_calendrier,
+    rafraichir_calendrier,
+};
+pub use candles::{annuler_session_bougies, remplacer_session, sauvegarder_bougies_ecrasees};
+pub use sessions::{
+    confirmer_session_bougies, detail_session_bougies, imports_actifs, lister_sessions_bougies,
+    ouvrir_session, purger_sauvegardes_anciennes, verifier_reimport,
+};
+pub use ticks::sauvegarder_ticks_ecrases;
+
+/// Préfixe de l'erreur renvoyée quand un fichier déjà importé arrive sans mode choisi
+pub const ALREADY_IMPORTED: &str = "Fichier déjà importé";
+
+/// Sessions validées d'office (sauvegardes purgées) passé ce délai, à chaque import
+pub const DEFAULT_BACKUP_RETENTION_DAYS: i64 = 30;
+
+#[cfg(test)]
+mod tests;
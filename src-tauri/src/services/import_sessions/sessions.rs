@@ -0,0 +1,263 @@
+// services/import_sessions/sessions.rs - Tenue des sessions de bougies dans import_log
+// Ouverture, détection des ré-imports, historique, validation et rétention des sauvegardes.
+
+use super::ALREADY_IMPORTED;
+use crate::models::import_session::{
+    BackupPurgeResult, ImportKind, ImportSession, ImportSessionDetail, ReimportMode,
+};
+use chrono::{Duration, Utc};
+use rusqlite::{params, Connection, OptionalExtension};
+use tracing::info;
+
+/// Sessions encore en base d'un fichier pour une paire/timeframe (plus récente d'abord)
+pub fn imports_actifs(
+    conn: &Connection,
+    filename: &str,
+    symbol: &str,
+    timeframe: &str,
+) -> Result<Vec<i64>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id FROM import_log
+             WHERE filename = ? AND symbol = ? AND timeframe = ?
+               AND status IN ('success', 'confirmed')
+             ORDER BY id DESC",
+        )
+        .map_err(|e| format!("Erreur prepare : {}", e))?;
+    let ids = stmt
+        .query_map(params![filename, symbol, timeframe], |row| row.get(0))
+        .map_err(|e| format!("Erreur lecture import_log : {}", e))?
+        .collect::<Result<Vec<i64>, _>>()
+        .map_err(|e| format!("Erreur lecture import_log : {}", e))?;
+    Ok(ids)
+}
+
+/// Sessions à remplacer avant un ré-import ; erreur `ALREADY_IMPORTED` si le
+/// fichier a déjà été importé et qu'aucun mode n'a été choisi.
+pub fn verifier_reimport(
+    conn: &Connection,
+    filename: &str,
+    symbol: &str,
+    timeframe: &str,
+    mode: Option<ReimportMode>,
+) -> Result<Vec<i64>, String> {
+    let actifs = imports_actifs(conn, filename, symbol, timeframe)?;
+    match (actifs.first(), mode) {
+        (Some(id), None) => Err(format!(
+            "{} (import #{}, {}/{}) : choisir remplacer ou fusionner",
+            ALREADY_IMPORTED, id, symbol, timeframe
+        )),
+        _ => Ok(actifs),
+    }
+}
+
+/// Ouvre la session dans import_log (row_count complété au commit) et retourne son id
+pub fn ouvrir_session(
+    conn: &Connection,
+    filename: &str,
+    symbol: &str,
+    timeframe: &str,
+    imported_at: &str,
+    source_timezone: Option<&str>,
+    mode: Option<ReimportMode>,
+) -> Result<i64, String> {
+    conn.query_row(
+        "INSERT INTO import_log (filename, symbol, timeframe, row_count, status, imported_at, source_timezone, import_mode)
+         VALUES (?, ?, ?, 0, 'success', ?, ?, ?)
+         RETURNING id",
+        params![
+            filename,
+            symbol,
+            timeframe,
+            imported_at,
+            source_timezone,
+            mode.map_or("new", |m| m.as_str())
+        ],
+        |row| row.get(0),
+    )
+    .map_err(|e| format!("Erreur import_log : {}", e))
+}
+
+pub(super) fn marquer_session(conn: &Connection, id: i64, status: &str) -> Result<(), String> {
+    conn.execute(
+        "UPDATE import_log SET status = ? WHERE id = ?",
+        params![status, id],
+    )
+    .map(|_| ())
+    .map_err(|e| format!("Erreur mise à jour import_log : {}", e))
+}
+
+/// Valide une session : ses sauvegardes sont purgées et elle n'est plus annulable
+/// (un ré-import du même fichier peut toujours la remplacer).
+pub fn confirmer_session_bougies(conn: &Connection, id: i64) -> Result<BackupPurgeResult, String> {
+    let status: String = conn
+        .query_row("SELECT status FROM import_log WHERE id = ?", [id], |row| {
+            row.get(0)
+        })
+        .optional()
+        .map_err(|e| format!("Erreur lecture import_log : {}", e))?
+        .ok_or_else(|| format!("Import #{} introuvable", id))?;
+    if status != "success" {
+        return Err(format!("Import #{} non validable (statut {})", id, status));
+    }
+
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| format!("Erreur début transaction : {}", e))?;
+    marquer_session(&tx, id, "confirmed")?;
+    let purge = purger_sauvegardes(&tx)?;
+    tx.commit().map_err(|e| format!("Erreur commit : {}", e))?;
+    info!(
+        "✔️ Import #{} validé : {} bougies et {} ticks de sauvegarde purgés",
+        id, purge.rows_purged, purge.ticks_purged
+    );
+    Ok(BackupPurgeResult {
+        sessions_confirmed: 1,
+        ..purge
+    })
+}
+
+/// Règle de rétention : valide les sessions importées il y a plus de `older_than_days`
+/// jours et purge leurs sauvegardes.
+pub fn purger_sauvegardes_anciennes(
+    conn: &Connection,
+    older_than_days: i64,
+) -> Result<BackupPurgeResult, String> {
+    let cutoff = (Utc::now() - Duration::days(older_than_days.max(0))).to_rfc3339();
+    let tx = conn
+        .unchecked_transaction()
+        .map_err(|e| format!("Erreur début transaction : {}", e))?;
+    let sessions_confirmed = tx
+        .execute(
+            "UPDATE import_log SET status = 'confirmed'
+             WHERE status = 'success' AND imported_at < ?",
+            [&cutoff],
+        )
+        .map_err(|e| format!("Erreur mise à jour import_log : {}", e))?;
+    let purge = purger_sauvegardes(&tx)?;
+    tx.commit().map_err(|e| format!("Erreur commit : {}", e))?;
+    if sessions_confirmed > 0 {
+        info!(
+            "✔️ {} imports de plus de {} jours validés : {} bougies et {} ticks de sauvegarde purgés",
+            sessions_confirmed, older_than_days, purge.rows_purged, purge.ticks_purged
+        );
+    }
+    Ok(BackupPurgeResult {
+        sessions_confirmed,
+        ..purge
+    })
+}
+
+/// Supprime les sauvegardes détenues par des sessions validées
+pub(super) fn purger_sauvegardes(conn: &Connection) -> Result<BackupPurgeResult, String> {
+    let purge = |table: &str| {
+        conn.execute(
+            &format!(
+                "DELETE FROM {} WHERE displaced_by IN (
+                    SELECT id FROM import_log WHERE status = 'confirmed')",
+                table
+            ),
+            [],
+        )
+        .map_err(|e| format!("Erreur purge {} : {}", table, e))
+    };
+    Ok(BackupPurgeResult {
+        sessions_confirmed: 0,
+        rows_purged: purge("candle_displaced")?,
+        ticks_purged: purge("tick_displaced")?,
+    })
+}
+
+fn session_bougies(row: &rusqlite::Row) -> rusqlite::Result<ImportSession> {
+    Ok(ImportSession {
+        kind: ImportKind::Candles,
+        id: row.get(0)?,
+        filename: row.get(1)?,
+        target: row.get(2)?,
+        timeframe: row.get(3)?,
+        imported_at: row.get(4)?,
+        mode: row.get(5)?,
+        status: row.get(6)?,
+        row_count: row.get(7)?,
+        source_timezone: row.get(8)?,
+        live_rows: row.get(9)?,
+    })
+}
+
+const SESSION_BOUGIES_SQL: &str = "SELECT l.id, l.filename, l.symbol, l.timeframe, l.imported_at,
+        l.import_mode, l.status, l.row_count, l.source_timezone,
+        (SELECT COUNT(*) FROM candle_data c WHERE c.import_id = l.id)
+     FROM import_log l";
+
+/// Sessions de bougies, plus récentes d'abord (filtrables par paire)
+pub fn lister_sessions_bougies(
+    conn: &Connection,
+    symbol: Option<&str>,
+    limit: usize,
+) -> Result<Vec<ImportSession>, String> {
+    let mut stmt = conn
+        .prepare(&format!(
+            "{} WHERE ?1 IS NULL OR l.symbol = ?1 ORDER BY l.id DESC LIMIT ?2",
+            SESSION_BOUGIES_SQL
+        ))
+        .map_err(|e| format!("Erreur prepare : {}", e))?;
+    let sessions = stmt
+        .query_map(params![symbol, limit as i64], session_bougies)
+        .map_err(|e| format!("Erreur lecture import_log : {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Erreur lecture import_log : {}", e))?;
+    Ok(sessions)
+}
+
+/// Détail d'une session de bougies
+pub fn detail_session_bougies(conn: &Connection, id: i64) -> Result<ImportSessionDetail, String> {
+    let sql_err = |e: rusqlite::Error| format!("Erreur lecture import #{} : {}", id, e);
+    let session = conn
+        .query_row(
+            &format!("{} WHERE l.id = ?", SESSION_BOUGIES_SQL),
+            [id],
+            session_bougies,
+        )
+        .optional()
+        .map_err(sql_err)?
+        .ok_or_else(|| format!("Import #{} introuvable", id))?;
+
+    let (first_time, last_time) = conn
+        .query_row(
+            "SELECT MIN(time), MAX(time) FROM candle_data WHERE import_id = ?",
+            [id],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .map_err(sql_err)?;
+    let tick_rows = conn
+        .query_row(
+            "SELECT COUNT(*) FROM tick_data WHERE import_id = ?",
+            [id],
+            |row| row.get(0),
+        )
+        .map_err(sql_err)?;
+    let displaced_rows = conn
+        .query_row(
+            "SELECT COUNT(*) FROM candle_displaced WHERE displaced_by = ?",
+            [id],
+            |row| row.get(0),
+        )
+        .map_err(sql_err)?;
+    let displaced_ticks = conn
+        .query_row(
+            "SELECT COUNT(*) FROM tick_displaced WHERE displaced_by = ?",
+            [id],
+            |row| row.get(0),
+        )
+        .map_err(sql_err)?;
+
+    Ok(ImportSessionDetail {
+        can_rollback: session.status == "success",
+        session,
+        first_time,
+        last_time,
+        tick_rows,
+        displaced_rows,
+        displaced_ticks,
+    })
+}
@@ -0,0 +1,197 @@
+// services/import_sessions/tests.rs - Remplacement, annulation et rétention des sessions
+
+use super::*;
+use crate::services::staged_import::StagedImport;
+use crate::models::import_session::ReimportMode;
+use crate::models::Candle;
+use crate::services::tick_aggregator::RawTick;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+use rusqlite::Connection;
+
+fn minute(m: i64) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2024, 1, 5, 13, 0, 0).unwrap() + Duration::minutes(m)
+}
+
+/// Importe `file` : une bougie M1 et un tick par (minute, prix) ; retourne l'id de session
+fn importer(conn: &Connection, file: &str, bars: &[(i64, f64)], mode: Option<ReimportMode>) -> i64 {
+    let candles: Vec<Candle> = bars
+        .iter()
+        .map(|&(m, price)| Candle {
+            symbol: "EURUSD".to_string(),
+            datetime: minute(m),
+            open: price,
+            high: price,
+            low: price,
+            close: price,
+            volume: 1.0,
+            ..Default::default()
+        })
+        .collect();
+    let ticks: Vec<RawTick> = bars
+        .iter()
+        .map(|&(m, price)| RawTick {
+            datetime_utc: minute(m) + Duration::seconds(10),
+            bid: price,
+            ask: price + 0.0001,
+            bid_volume: 1.0,
+            ask_volume: 1.0,
+        })
+        .collect();
+    let mut staged = StagedImport::begin(conn, "EURUSD", "M1", file, mode).unwrap();
+    staged.stage_candles(&candles).unwrap();
+    staged.stage_ticks(&ticks).unwrap();
+    staged.commit(Some("UTC")).unwrap();
+    conn.query_row("SELECT MAX(id) FROM import_log", [], |row| row.get(0))
+        .unwrap()
+}
+
+/// Prix de clôture M1 et bid des ticks, par ordre chronologique
+fn etat(conn: &Connection) -> (Vec<f64>, Vec<f64>) {
+    let read = |sql: &str| -> Vec<f64> {
+        let mut stmt = conn.prepare(sql).unwrap();
+        let values = stmt
+            .query_map([], |row| row.get(0))
+            .unwrap()
+            .collect::<Result<Vec<f64>, _>>()
+            .unwrap();
+        values
+    };
+    (
+        read("SELECT close FROM candle_data WHERE timeframe = 'M1' ORDER BY time"),
+        read("SELECT bid FROM tick_data ORDER BY time_ms"),
+    )
+}
+
+#[test]
+fn test_replace_then_rollback_restores_overwritten_rows() {
+    let (_dir, conn) = crate::db::temp_pairs_db();
+    importer(&conn, "base.csv", &[(0, 1.0), (1, 1.0), (2, 1.0)], None);
+    importer(&conn, "a.csv", &[(1, 2.0), (2, 2.0)], None);
+    let replacing = importer(
+        &conn,
+        "a.csv",
+        &[(1, 3.0), (2, 3.0)],
+        Some(ReimportMode::Replace),
+    );
+    assert_eq!(etat(&conn), (vec![1.0, 3.0, 3.0], vec![1.0, 3.0, 3.0]));
+
+    let result = annuler_session_bougies(&conn, replacing).unwrap();
+    assert_eq!((result.rows_deleted, result.rows_restored), (2, 2));
+    assert_eq!((result.ticks_deleted, result.ticks_restored), (2, 2));
+    assert_eq!(etat(&conn), (vec![1.0; 3], vec![1.0; 3]));
+}
+
+#[test]
+fn test_merge_then_rollback_keeps_earlier_rows() {
+    let (_dir, conn) = crate::db::temp_pairs_db();
+    importer(&conn, "a.csv", &[(0, 1.0), (1, 1.0)], None);
+    let merged = importer(
+        &conn,
+        "a.csv",
+        &[(1, 2.0), (2, 2.0)],
+        Some(ReimportMode::Merge),
+    );
+    // Fusion : seule la minute absente est ajoutée
+    assert_eq!(etat(&conn), (vec![1.0, 1.0, 2.0], vec![1.0, 1.0, 2.0]));
+
+    let result = annuler_session_bougies(&conn, merged).unwrap();
+    assert_eq!((result.rows_deleted, result.ticks_deleted), (1, 1));
+    assert_eq!(etat(&conn), (vec![1.0, 1.0], vec![1.0, 1.0]));
+}
+
+#[test]
+fn test_rollback_hands_backups_to_later_import() {
+    let (_dir, conn) = crate::db::temp_pairs_db();
+    importer(&conn, "base.csv", &[(0, 1.0), (1, 1.0), (2, 1.0)], None);
+    let a = importer(&conn, "a.csv", &[(0, 2.0), (1, 2.0), (2, 2.0)], None);
+    let b = importer(&conn, "b.csv", &[(1, 3.0)], None);
+
+    // A annulé : ses sauvegardes sous B passent à B, le reste revient à la base
+    annuler_session_bougies(&conn, a).unwrap();
+    assert_eq!(etat(&conn), (vec![1.0, 3.0, 1.0], vec![1.0, 3.0, 1.0]));
+
+    // B annulé : la base est entièrement restaurée
+    annuler_session_bougies(&conn, b).unwrap();
+    assert_eq!(etat(&conn), (vec![1.0; 3], vec![1.0; 3]));
+    assert_eq!(sauvegardes(&conn), 0);
+}
+
+/// Bougies et ticks de sauvegarde encore en base
+fn sauvegardes(conn: &Connection) -> i64 {
+    conn.query_row(
+        "SELECT (SELECT COUNT(*) FROM candle_displaced) + (SELECT COUNT(*) FROM tick_displaced)",
+        [],
+        |row| row.get(0),
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_confirm_purges_backups_and_blocks_rollback() {
+    let (_dir, conn) = crate::db::temp_pairs_db();
+    importer(&conn, "base.csv", &[(0, 1.0), (1, 1.0)], None);
+    let a = importer(&conn, "a.csv", &[(0, 2.0), (1, 2.0)], None);
+    assert_eq!(sauvegardes(&conn), 4);
+
+    let purge = confirmer_session_bougies(&conn, a).unwrap();
+    assert_eq!((purge.rows_purged, purge.ticks_purged), (2, 2));
+    assert_eq!(sauvegardes(&conn), 0);
+    assert!(annuler_session_bougies(&conn, a).is_err());
+    assert!(!detail_session_bougies(&conn, a).unwrap().can_rollback);
+
+    // Toujours remplaçable par un ré-import du même fichier
+    assert_eq!(
+        imports_actifs(&conn, "a.csv", "EURUSD", "M1").unwrap(),
+        vec![a]
+    );
+}
+
+#[test]
+fn test_retention_confirms_old_sessions_only() {
+    let (_dir, conn) = crate::db::temp_pairs_db();
+    importer(&conn, "base.csv", &[(0, 1.0), (1, 1.0)], None);
+    let old = importer(&conn, "old.csv", &[(0, 2.0)], None);
+    let recent = importer(&conn, "recent.csv", &[(1, 3.0)], None);
+    conn.execute(
+        "UPDATE import_log SET imported_at = '2020-01-01T00:00:00+00:00' WHERE id = ?",
+        [old],
+    )
+    .unwrap();
+
+    let purge = purger_sauvegardes_anciennes(&conn, DEFAULT_BACKUP_RETENTION_DAYS).unwrap();
+    assert_eq!(purge.sessions_confirmed, 1);
+    assert_eq!((purge.rows_purged, purge.ticks_purged), (1, 1));
+    // La session récente reste annulable et restaure la base
+    annuler_session_bougies(&conn, recent).unwrap();
+    assert_eq!(etat(&conn), (vec![2.0, 1.0], vec![2.0, 1.0]));
+}
+
+#[test]
+fn test_rollback_legacy_import_without_session_rows() {
+    let (_dir, conn) = crate::db::temp_pairs_db();
+    conn.execute(
+        "INSERT INTO import_log (filename, symbol, timeframe, row_count, status)
+         VALUES ('legacy.csv', 'EURUSD', 'M1', 2, 'success')",
+        [],
+    )
+    .unwrap();
+    let id = conn.last_insert_rowid();
+    for m in 0..2 {
+        conn.execute(
+            "INSERT INTO candle_data (symbol, timeframe, time, open, high, low, close, volume, source_file)
+             VALUES ('EURUSD', 'M1', ?, 1.0, 1.0, 1.0, 1.0, 1.0, 'legacy.csv')",
+            [minute(m).to_rfc3339()],
+        )
+        .unwrap();
+    }
+
+    let result = annuler_session_bougies(&conn, id).unwrap();
+    assert_eq!(result.rows_deleted, 2);
+    assert_eq!(etat(&conn), (vec![], vec![]));
+    let status: String = conn
+        .query_row("SELECT status FROM import_log WHERE id = ?", [id], |row| {
+            row.get(0)
+        })
+        .unwrap();
+    assert_eq!(status, "rolled_back");
+}
@@ -0,0 +1,69 @@
+// services/import_sessions/ticks.rs - Sauvegarde et annulation des ticks d'une session
+// Les ticks purgés par un import sont gardés dans tick_displaced jusqu'à sa validation.
+
+use rusqlite::{params, Connection};
+
+/// Colonnes communes à tick_data et tick_displaced
+const TICK_COLUMNS: &str = "symbol, time_ms, bid, ask, bid_volume, ask_volume, import_id";
+
+/// Copie dans tick_displaced les ticks en place que l'import va purger sur sa plage
+pub fn sauvegarder_ticks_ecrases(
+    conn: &Connection,
+    import_id: i64,
+    symbol: &str,
+    first_ms: i64,
+    last_ms: i64,
+) -> Result<usize, String> {
+    conn.execute(
+        &format!(
+            "INSERT INTO tick_displaced (displaced_by, {cols})
+             SELECT ?1, {cols} FROM tick_data
+             WHERE symbol = ?2 AND time_ms >= ?3 AND time_ms <= ?4",
+            cols = TICK_COLUMNS
+        ),
+        params![import_id, symbol, first_ms, last_ms],
+    )
+    .map_err(|e| format!("Erreur sauvegarde ticks écrasés : {}", e))
+}
+
+/// Retire les ticks d'une session et remet en place ceux qu'elle avait purgés.
+/// Retourne (ticks retirés, ticks restaurés).
+pub(super) fn retirer_ticks(conn: &Connection, id: i64) -> rusqlite::Result<(usize, usize)> {
+    // Sauvegardes détenues par cette session sur la plage d'un import qui a ensuite
+    // purgé ses propres ticks : confiées à cet import (il les aurait aussi purgées)
+    let successeurs: Vec<i64> = conn
+        .prepare("SELECT DISTINCT displaced_by FROM tick_displaced WHERE import_id = ?")?
+        .query_map([id], |row| row.get(0))?
+        .collect::<Result<_, _>>()?;
+    for successeur in successeurs {
+        let (first_ms, last_ms): (Option<i64>, Option<i64>) = conn.query_row(
+            "SELECT MIN(time_ms), MAX(time_ms) FROM tick_data WHERE import_id = ?",
+            [successeur],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )?;
+        if let (Some(first_ms), Some(last_ms)) = (first_ms, last_ms) {
+            conn.execute(
+                "UPDATE tick_displaced SET displaced_by = ?2
+                 WHERE displaced_by = ?1 AND time_ms >= ?3 AND time_ms <= ?4",
+                params![id, successeur, first_ms, last_ms],
+            )?;
+        }
+    }
+    conn.execute("DELETE FROM tick_displaced WHERE import_id = ?", [id])?;
+
+    let deleted = conn.execute("DELETE FROM tick_data WHERE import_id = ?", [id])?;
+    let restored = conn.execute(
+        &format!(
+            "INSERT INTO tick_data ({cols})
+             SELECT {cols} FROM tick_displaced d WHERE d.displaced_by = ?1
+               AND NOT EXISTS (
+                SELECT 1 FROM tick_data t WHERE t.symbol = d.symbol AND t.time_ms = d.time_ms
+                  AND t.import_id IS NOT d.import_id)
+             ORDER BY d.rowid",
+            cols = TICK_COLUMNS
+        ),
+        [id],
+    )?;
+    conn.execute("DELETE FROM tick_displaced WHERE displaced_by = ?", [id])?;
+    Ok((deleted, restored))
+}
@@ -0,0 +1,159 @@
+// services/import_stream.rs - Contrôle des imports en continu (fichiers de plusieurs Go)
+// Lecture par flux, écriture par lots : la progression est remontée à l'appelant
+// (événement "import-progress" côté commande) et l'annulation est vérifiée entre deux lots.
+
+use serde::{Deserialize, Serialize};
+use std::cell::Cell;
+use std::io::{Read, Seek, SeekFrom};
+use std::rc::Rc;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Bougies (ou ticks) écrites par transaction
+pub const BATCH_SIZE: usize = 20_000;
+/// Message d'erreur d'un import annulé (reconnu par le frontend)
+pub const IMPORT_CANCELLED: &str = "Import annulé";
+
+/// Étape en cours
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ImportStage {
+    /// Lecture, agrégation et écriture des lots en zone de transit
+    Reading,
+    /// Publication en une transaction (candle_data, tick_data, dérivés)
+    Committing,
+    Done,
+    Cancelled,
+}
+
+/// Progression d'un fichier
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ImportProgress {
+    pub file: String,
+    pub stage: ImportStage,
+    pub bytes_read: u64,
+    pub total_bytes: u64,
+    /// 0-100, sur les octets lus
+    pub percent: f64,
+    /// Lignes (ticks ou bougies) lues
+    pub rows_read: usize,
+    /// Bougies écrites en zone de transit
+    pub rows_written: usize,
+}
+
+/// Annulation + remontée de progression d'un import
+pub struct ImportControl<'a> {
+    cancelled: &'a AtomicBool,
+    on_progress: &'a mut dyn FnMut(&ImportProgress),
+    progress: ImportProgress,
+    bytes_read: Rc<Cell<u64>>,
+}
+
+impl<'a> ImportControl<'a> {
+    pub fn new(
+        file: &str,
+        total_bytes: u64,
+        cancelled: &'a AtomicBool,
+        on_progress: &'a mut dyn FnMut(&ImportProgress),
+    ) -> Self {
+        Self {
+            cancelled,
+            on_progress,
+            progress: ImportProgress {
+                file: file.to_string(),
+                stage: ImportStage::Reading,
+                bytes_read: 0,
+                total_bytes,
+                percent: 0.0,
+                rows_read: 0,
+                rows_written: 0,
+            },
+            bytes_read: Rc::new(Cell::new(0)),
+        }
+    }
+
+    /// Enveloppe un lecteur pour compter les octets lus (progression)
+    pub fn counting<R: Read>(&self, inner: R) -> CountingReader<R> {
+        CountingReader {
+            inner,
+            count: Rc::clone(&self.bytes_read),
+        }
+    }
+
+    /// Erreur `IMPORT_CANCELLED` si l'annulation a été demandée
+    pub fn check(&self) -> Result<(), String> {
+        if self.cancelled.load(Ordering::Relaxed) {
+            Err(IMPORT_CANCELLED.to_string())
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Remonte la progression après un lot
+    pub fn report(&mut self, stage: ImportStage, rows_read: usize, rows_written: usize) {
+        let p = &mut self.progress;
+        p.stage = stage;
+        p.bytes_read = self.bytes_read.get();
+        p.percent = if stage == ImportStage::Done {
+            100.0
+        } else if p.total_bytes > 0 {
+            (p.bytes_read as f64 / p.total_bytes as f64 * 100.0).min(100.0)
+        } else {
+            0.0
+        };
+        p.rows_read = rows_read;
+        p.rows_written = rows_written;
+        (self.on_progress)(p);
+    }
+
+    /// Remonte un changement d'étape (compteurs inchangés)
+    pub fn stage(&mut self, stage: ImportStage) {
+        let (rows_read, rows_written) = (self.progress.rows_read, self.progress.rows_written);
+        self.report(stage, rows_read, rows_written);
+    }
+}
+
+/// Lecteur qui compte les octets consommés
+pub struct CountingReader<R> {
+    inner: R,
+    count: Rc<Cell<u64>>,
+}
+
+impl<R: Read> Read for CountingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let n = self.inner.read(buf)?;
+        self.count.set(self.count.get() + n as u64);
+        Ok(n)
+    }
+}
+
+impl<R: Seek> Seek for CountingReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> std::io::Result<u64> {
+        let position = self.inner.seek(pos)?;
+        self.count.set(position);
+        Ok(position)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_progress_and_cancellation() {
+        let cancelled = AtomicBool::new(false);
+        let mut seen = Vec::new();
+        let mut on_progress = |p: &ImportProgress| seen.push((p.percent, p.rows_read));
+        let mut ctrl = ImportControl::new("ticks.csv", 10, &cancelled, &mut on_progress);
+
+        let mut reader = ctrl.counting(&b"0123"[..]);
+        let mut buf = String::new();
+        reader.read_to_string(&mut buf).unwrap();
+        ctrl.report(ImportStage::Reading, 4, 0);
+        assert!(ctrl.check().is_ok());
+
+        cancelled.store(true, Ordering::Relaxed);
+        assert_eq!(ctrl.check().unwrap_err(), IMPORT_CANCELLED);
+        drop(ctrl);
+        assert_eq!(seen, vec![(40.0, 4)]);
+    }
+}
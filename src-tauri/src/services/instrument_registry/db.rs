@@ -0,0 +1,193 @@
+// services/instrument_registry/db.rs - CRUD de la table instruments (pairs.db)
+// Conforme .clinerules : retourne Result<T, VolatilityError>
+
+use crate::db::DbPool;
+use crate::models::asset_class::AssetType;
+use crate::models::instrument::Instrument;
+use crate::models::trading_costs::TradingCostProfile;
+use crate::models::VolatilityError;
+use diesel::prelude::*;
+use diesel::sql_types::{Bool, Double, Integer, Text};
+
+const SELECT_INSTRUMENTS: &str = "SELECT symbol, display_name, category, asset_class, digits, pip_size, pip_unit,
+        display_digits, contract_size, point_value, quote_currency, trading_hours, dukascopy,
+        spread_min, spread_max, spread_avg, slippage, spread_multiplier_event, entry_slippage_multiplier
+     FROM instruments";
+
+const INSERT_INSTRUMENT: &str = "INSERT INTO instruments (symbol, display_name, category, asset_class, digits,
+        pip_size, pip_unit, display_digits, contract_size, point_value, quote_currency, trading_hours,
+        dukascopy, spread_min, spread_max, spread_avg, slippage, spread_multiplier_event,
+        entry_slippage_multiplier, updated_at)
+     VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, CURRENT_TIMESTAMP)";
+
+/// Fiches par défaut : une fiche existante (éventuellement éditée) est conservée
+const ON_CONFLICT_KEEP: &str = "ON CONFLICT(symbol) DO NOTHING";
+
+/// Édition : mise à jour en place (l'ordre d'insertion est conservé)
+const ON_CONFLICT_UPDATE: &str = "ON CONFLICT(symbol) DO UPDATE SET
+        display_name = excluded.display_name, category = excluded.category,
+        asset_class = excluded.asset_class, digits = excluded.digits, pip_size = excluded.pip_size,
+        pip_unit = excluded.pip_unit, display_digits = excluded.display_digits,
+        contract_size = excluded.contract_size, point_value = excluded.point_value,
+        quote_currency = excluded.quote_currency, trading_hours = excluded.trading_hours,
+        dukascopy = excluded.dukascopy, spread_min = excluded.spread_min,
+        spread_max = excluded.spread_max, spread_avg = excluded.spread_avg,
+        slippage = excluded.slippage, spread_multiplier_event = excluded.spread_multiplier_event,
+        entry_slippage_multiplier = excluded.entry_slippage_multiplier,
+        updated_at = CURRENT_TIMESTAMP";
+
+/// Toutes les fiches, dans l'ordre d'insertion
+pub fn get_all_instruments(pool: &DbPool) -> Result<Vec<Instrument>, VolatilityError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| VolatilityError::DatabaseError(format!("Pool connection failed: {}", e)))?;
+    let rows: Vec<InstrumentRow> =
+        diesel::sql_query(format!("{} ORDER BY rowid", SELECT_INSTRUMENTS))
+            .load::<InstrumentRow>(&mut conn)
+            .map_err(|e| VolatilityError::DatabaseError(format!("Query failed: {}", e)))?;
+    Ok(rows
+        .into_iter()
+        .map(InstrumentRow::into_instrument)
+        .collect())
+}
+
+/// Insère les fiches absentes ; retourne le nombre de fiches ajoutées
+pub fn insert_missing(pool: &DbPool, instruments: &[Instrument]) -> Result<usize, VolatilityError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| VolatilityError::DatabaseError(format!("Pool connection failed: {}", e)))?;
+    let mut inserted = 0;
+    for instrument in instruments {
+        inserted += write(&mut conn, ON_CONFLICT_KEEP, instrument)?;
+    }
+    Ok(inserted)
+}
+
+/// Crée ou remplace une fiche
+pub fn upsert_instrument(pool: &DbPool, instrument: &Instrument) -> Result<(), VolatilityError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| VolatilityError::DatabaseError(format!("Pool connection failed: {}", e)))?;
+    write(&mut conn, ON_CONFLICT_UPDATE, instrument)?;
+    tracing::info!(
+        "✅ Instrument sauvegardé: {} (pip={}, contrat={}, {})",
+        instrument.symbol,
+        instrument.pip_size,
+        instrument.contract_size,
+        instrument.asset_class.as_str()
+    );
+    Ok(())
+}
+
+/// Supprime une fiche ; retourne true si elle existait
+pub fn delete_instrument(pool: &DbPool, symbol: &str) -> Result<bool, VolatilityError> {
+    let mut conn = pool
+        .get()
+        .map_err(|e| VolatilityError::DatabaseError(format!("Pool connection failed: {}", e)))?;
+    let deleted = diesel::sql_query("DELETE FROM instruments WHERE symbol = ?")
+        .bind::<Text, _>(symbol)
+        .execute(&mut conn)
+        .map_err(|e| VolatilityError::DatabaseError(format!("Delete failed: {}", e)))?;
+    Ok(deleted > 0)
+}
+
+fn write(
+    conn: &mut SqliteConnection,
+    on_conflict: &str,
+    instrument: &Instrument,
+) -> Result<usize, VolatilityError> {
+    let costs = &instrument.costs;
+    diesel::sql_query(format!("{} {}", INSERT_INSTRUMENT, on_conflict))
+        .bind::<Text, _>(&instrument.symbol)
+        .bind::<Text, _>(&instrument.display_name)
+        .bind::<Text, _>(&instrument.category)
+        .bind::<Text, _>(instrument.asset_class.as_str())
+        .bind::<Integer, _>(instrument.digits)
+        .bind::<Double, _>(instrument.pip_size)
+        .bind::<Text, _>(&instrument.pip_unit)
+        .bind::<Integer, _>(instrument.display_digits)
+        .bind::<Double, _>(instrument.contract_size)
+        .bind::<Double, _>(instrument.point_value)
+        .bind::<Text, _>(&instrument.quote_currency)
+        .bind::<Text, _>(&instrument.trading_hours)
+        .bind::<Bool, _>(instrument.dukascopy)
+        .bind::<Double, _>(costs.spread_min)
+        .bind::<Double, _>(costs.spread_max)
+        .bind::<Double, _>(costs.spread_avg)
+        .bind::<Double, _>(costs.slippage)
+        .bind::<Double, _>(costs.spread_multiplier_event)
+        .bind::<Double, _>(costs.entry_slippage_multiplier)
+        .execute(conn)
+        .map_err(|e| VolatilityError::DatabaseError(format!("Write failed: {}", e)))
+}
+
+// Helper struct pour QueryableByName avec Diesel
+#[derive(QueryableByName, Debug)]
+struct InstrumentRow {
+    #[diesel(sql_type = Text)]
+    symbol: String,
+    #[diesel(sql_type = Text)]
+    display_name: String,
+    #[diesel(sql_type = Text)]
+    category: String,
+    #[diesel(sql_type = Text)]
+    asset_class: String,
+    #[diesel(sql_type = Integer)]
+    digits: i32,
+    #[diesel(sql_type = Double)]
+    pip_size: f64,
+    #[diesel(sql_type = Text)]
+    pip_unit: String,
+    #[diesel(sql_type = Integer)]
+    display_digits: i32,
+    #[diesel(sql_type = Double)]
+    contract_size: f64,
+    #[diesel(sql_type = Double)]
+    point_value: f64,
+    #[diesel(sql_type = Text)]
+    quote_currency: String,
+    #[diesel(sql_type = Text)]
+    trading_hours: String,
+    #[diesel(sql_type = Bool)]
+    dukascopy: bool,
+    #[diesel(sql_type = Double)]
+    spread_min: f64,
+    #[diesel(sql_type = Double)]
+    spread_max: f64,
+    #[diesel(sql_type = Double)]
+    spread_avg: f64,
+    #[diesel(sql_type = Double)]
+    slippage: f64,
+    #[diesel(sql_type = Double)]
+    spread_multiplier_event: f64,
+    #[diesel(sql_type = Double)]
+    entry_slippage_multiplier: f64,
+}
+
+impl InstrumentRow {
+    fn into_instrument(self) -> Instrument {
+        Instrument {
+            symbol: self.symbol,
+            display_name: self.display_name,
+            category: self.category,
+            asset_class: AssetType::parse(&self.asset_class),
+            digits: self.digits,
+            pip_size: self.pip_size,
+            pip_unit: self.pip_unit,
+            display_digits: self.display_digits,
+            contract_size: self.contract_size,
+            point_value: self.point_value,
+            quote_currency: self.quote_currency,
+            trading_hours: self.trading_hours,
+            dukascopy: self.dukascopy,
+            costs: TradingCostProfile {
+                spread_min: self.spread_min,
+                spread_max: self.spread_max,
+                spread_avg: self.spread_avg,
+                slippage: self.slippage,
+                spread_multiplier_event: self.spread_multiplier_event,
+                entry_slippage_multiplier: self.entry_slippage_multiplier,
+            },
+        }
+    }
+}
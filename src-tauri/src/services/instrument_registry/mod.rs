@@ -0,0 +1,178 @@
+// services/instrument_registry/mod.rs
+// Registre des instruments (table instruments de pairs.db) : digits, pip, valeur du
+// point, taille de contrat, devise de cotation, horaires, classe d'actif et coûts.
+// Source unique pour les analyseurs, la liste Dukascopy et les conversions.
+
+pub mod db;
+mod seed;
+
+use crate::db::DbPool;
+use crate::models::asset_class::{AssetProperties, AssetType};
+use crate::models::instrument::Instrument;
+use crate::models::trading_costs::TradingCostProfile;
+use crate::services::pair_data::symbol_aliases::canonical_symbol;
+use crate::services::pair_data::symbol_properties::pairs_pool;
+use std::sync::{Arc, RwLock};
+
+/// Fiches chargées depuis la DB, rechargées après chaque modification
+static REGISTRY: RwLock<Option<Arc<Vec<Instrument>>>> = RwLock::new(None);
+
+/// Insère les fiches par défaut absentes (appelé au démarrage dans lib.rs)
+pub fn seed_defaults(pool: &DbPool) -> Result<usize, String> {
+    let inserted = db::insert_missing(pool, &seed::default_instruments())
+        .map_err(|e| format!("Seed instruments failed: {}", e))?;
+    if inserted > 0 {
+        tracing::info!("✅ {} fiches instruments par défaut insérées", inserted);
+    }
+    invalidate_registry_cache();
+    Ok(inserted)
+}
+
+/// Force le rechargement du registre (après édition d'une fiche)
+pub fn invalidate_registry_cache() {
+    if let Ok(mut guard) = REGISTRY.write() {
+        *guard = None;
+    }
+}
+
+/// Toutes les fiches du registre (valeurs par défaut si la DB n'est pas disponible)
+pub fn list_instruments() -> Arc<Vec<Instrument>> {
+    if let Some(instruments) = REGISTRY.read().ok().and_then(|guard| guard.clone()) {
+        return instruments;
+    }
+    // Pool pas encore enregistré (tests unitaires) : fiches par défaut, sans mise en cache
+    let Some(pool) = pairs_pool() else {
+        return Arc::new(seed::default_instruments());
+    };
+    let instruments = match db::get_all_instruments(pool) {
+        Ok(instruments) if !instruments.is_empty() => instruments,
+        Ok(_) => seed::default_instruments(),
+        Err(e) => {
+            tracing::warn!("⚠️ Lecture du registre instruments impossible : {}", e);
+            return Arc::new(seed::default_instruments());
+        }
+    };
+    let instruments = Arc::new(instruments);
+    if let Ok(mut guard) = REGISTRY.write() {
+        *guard = Some(instruments.clone());
+    }
+    instruments
+}
+
+/// Fiche du registre pour un symbole (alias broker résolus), si elle existe
+pub fn find_instrument(symbol: &str) -> Option<Instrument> {
+    let canonical = canonical_symbol(symbol);
+    list_instruments()
+        .iter()
+        .find(|i| i.symbol == canonical)
+        .cloned()
+}
+
+/// Fiche d'un symbole ; un symbole absent du registre reçoit une fiche déduite
+/// de son nom (heuristique `AssetProperties::from_symbol`)
+pub fn resolve_instrument(symbol: &str) -> Instrument {
+    find_instrument(symbol).unwrap_or_else(|| fallback_instrument(&canonical_symbol(symbol)))
+}
+
+/// Instruments téléchargeables sur le CDN Dukascopy
+pub fn dukascopy_instruments() -> Vec<Instrument> {
+    list_instruments()
+        .iter()
+        .filter(|i| i.dukascopy)
+        .cloned()
+        .collect()
+}
+
+/// Coûts de trading de repli (M1 sans données tick)
+pub fn trading_costs(symbol: &str) -> TradingCostProfile {
+    resolve_instrument(symbol).costs
+}
+
+/// Fiche par défaut d'un instrument (None si le symbole n'a pas de fiche d'origine)
+pub fn default_instrument(symbol: &str) -> Option<Instrument> {
+    seed::default_instruments()
+        .into_iter()
+        .find(|i| i.symbol == symbol)
+}
+
+fn fallback_instrument(symbol: &str) -> Instrument {
+    let props = AssetProperties::from_symbol(symbol);
+    let forex = matches!(
+        props.asset_type,
+        AssetType::ForexMajor | AssetType::ForexJpy
+    );
+    let contract_size = if forex { 100_000.0 } else { 1.0 };
+    Instrument {
+        symbol: symbol.to_string(),
+        display_name: symbol.to_string(),
+        category: "Custom / Others".to_string(),
+        asset_class: props.asset_type,
+        digits: props.display_digits as i32 + 1,
+        pip_size: props.pip_value,
+        pip_unit: props.unit,
+        display_digits: props.display_digits as i32,
+        contract_size,
+        point_value: props.pip_value * contract_size,
+        quote_currency: symbol
+            .get(symbol.len().saturating_sub(3)..)
+            .filter(|q| forex && q.chars().all(|c| c.is_ascii_alphabetic()))
+            .unwrap_or("USD")
+            .to_string(),
+        trading_hours: if props.asset_type == AssetType::Crypto {
+            "24/7".to_string()
+        } else {
+            "24/5".to_string()
+        },
+        dukascopy: false,
+        costs: seed::COST_MAJOR,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fiches_par_defaut_coherentes_avec_heuristique() {
+        // Les propriétés d'analyse du registre reprennent les anciennes valeurs hardcodées
+        for symbol in [
+            "EURUSD", "USDJPY", "XAUUSD", "XAGUSD", "BTCUSD", "US30", "NGAS",
+        ] {
+            let from_registry = resolve_instrument(symbol).asset_properties();
+            let hardcoded = AssetProperties::from_symbol(symbol);
+            assert_eq!(from_registry.asset_type, hardcoded.asset_type, "{}", symbol);
+            assert_eq!(from_registry.pip_value, hardcoded.pip_value, "{}", symbol);
+            assert_eq!(from_registry.unit, hardcoded.unit, "{}", symbol);
+        }
+    }
+
+    #[test]
+    fn test_resolution_alias_broker() {
+        let instrument = resolve_instrument("GBPJPY.m");
+        assert_eq!(instrument.symbol, "GBPJPY");
+        assert_eq!(instrument.quote_currency, "JPY");
+        assert_eq!(instrument.digits, 3);
+        assert_eq!(instrument.costs.spread_avg, 6.5);
+    }
+
+    #[test]
+    fn test_symbole_inconnu_fiche_deduite() {
+        let instrument = resolve_instrument("USDMXN");
+        assert_eq!(instrument.asset_class, AssetType::ForexMajor);
+        assert_eq!(instrument.quote_currency, "MXN");
+        assert_eq!(instrument.contract_size, 100_000.0);
+        assert!(!instrument.dukascopy);
+    }
+
+    #[test]
+    fn test_liste_dukascopy() {
+        let ids: Vec<String> = dukascopy_instruments()
+            .into_iter()
+            .map(|i| i.symbol)
+            .collect();
+        assert!(ids.contains(&"EURUSD".to_string()));
+        assert!(ids.contains(&"SOLUSD".to_string()));
+        assert!(!ids.contains(&"US30".to_string()));
+        assert_eq!(ids.len(), 23);
+    }
+}
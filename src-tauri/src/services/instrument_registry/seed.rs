@@ -0,0 +1,290 @@
+// services/instrument_registry/seed.rs
+// Fiches par défaut du registre : valeurs reprises des anciennes heuristiques
+// (AssetProperties::from_symbol, liste Dukascopy, TradingCostProfile::get_profile).
+
+use crate::models::asset_class::AssetType;
+use crate::models::instrument::Instrument;
+use crate::models::trading_costs::TradingCostProfile;
+
+/// Majors liquides (EURUSD, USDJPY) — spread ×3, slippage entrée ×2
+pub(super) const COST_MAJOR: TradingCostProfile = TradingCostProfile {
+    spread_min: 1.0,
+    spread_max: 4.0,
+    spread_avg: 2.5,
+    slippage: 1.0,
+    spread_multiplier_event: 3.0,
+    entry_slippage_multiplier: 2.0,
+};
+/// Crosses volatils (GBPJPY, EURJPY) — spread ×4, slippage entrée ×3
+const COST_JPY_CROSS: TradingCostProfile = TradingCostProfile {
+    spread_min: 4.0,
+    spread_max: 8.0,
+    spread_avg: 6.5,
+    slippage: 3.0,
+    spread_multiplier_event: 4.0,
+    entry_slippage_multiplier: 3.0,
+};
+/// Paires GBP/AUD — spread ×3, slippage entrée ×2.5
+const COST_GBP_AUD: TradingCostProfile = TradingCostProfile {
+    spread_min: 2.0,
+    spread_max: 6.0,
+    spread_avg: 4.0,
+    slippage: 2.0,
+    spread_multiplier_event: 3.0,
+    entry_slippage_multiplier: 2.5,
+};
+/// Paires NZD — spread plus large que les majors
+const COST_NZD: TradingCostProfile = TradingCostProfile {
+    spread_min: 2.5,
+    spread_max: 6.0,
+    spread_avg: 4.5,
+    slippage: 2.0,
+    spread_multiplier_event: 3.0,
+    entry_slippage_multiplier: 2.5,
+};
+/// Paires CAD — liquidité correcte
+const COST_CAD: TradingCostProfile = TradingCostProfile {
+    spread_min: 2.0,
+    spread_max: 5.0,
+    spread_avg: 3.5,
+    slippage: 1.5,
+    spread_multiplier_event: 3.0,
+    entry_slippage_multiplier: 2.0,
+};
+/// Paires CHF — liquidité moyenne, risque SNB
+const COST_CHF: TradingCostProfile = TradingCostProfile {
+    spread_min: 1.5,
+    spread_max: 5.0,
+    spread_avg: 3.0,
+    slippage: 1.5,
+    spread_multiplier_event: 3.5,
+    entry_slippage_multiplier: 2.5,
+};
+/// Or — spread ×4, slippage entrée ×3
+const COST_GOLD: TradingCostProfile = TradingCostProfile {
+    spread_min: 3.0,
+    spread_max: 6.0,
+    spread_avg: 4.0,
+    slippage: 2.0,
+    spread_multiplier_event: 4.0,
+    entry_slippage_multiplier: 3.0,
+};
+/// BTC — spread ×5, slippage entrée ×3
+const COST_BTC: TradingCostProfile = TradingCostProfile {
+    spread_min: 30.0,
+    spread_max: 60.0,
+    spread_avg: 40.0,
+    slippage: 20.0,
+    spread_multiplier_event: 5.0,
+    entry_slippage_multiplier: 3.0,
+};
+/// DAX — spread ×3, slippage entrée ×2.5
+const COST_DAX: TradingCostProfile = TradingCostProfile {
+    spread_min: 4.0,
+    spread_max: 8.0,
+    spread_avg: 6.0,
+    slippage: 3.0,
+    spread_multiplier_event: 3.0,
+    entry_slippage_multiplier: 2.5,
+};
+/// Indices US (Dow, Nasdaq) — spread ×3, slippage entrée ×2.5
+const COST_US_INDEX: TradingCostProfile = TradingCostProfile {
+    spread_min: 5.0,
+    spread_max: 10.0,
+    spread_avg: 7.5,
+    slippage: 5.0,
+    spread_multiplier_event: 3.0,
+    entry_slippage_multiplier: 2.5,
+};
+
+/// Paire forex : 5 décimales (3 contre le JPY), lot de 100 000
+fn forex(symbol: &str, display: &str, category: &str, costs: TradingCostProfile) -> Instrument {
+    let quote = symbol.get(3..6).unwrap_or("USD");
+    let jpy = quote == "JPY";
+    let pip_size = if jpy { 0.01 } else { 0.0001 };
+    Instrument {
+        symbol: symbol.to_string(),
+        display_name: display.to_string(),
+        category: category.to_string(),
+        asset_class: if jpy {
+            AssetType::ForexJpy
+        } else {
+            AssetType::ForexMajor
+        },
+        digits: if jpy { 3 } else { 5 },
+        pip_size,
+        pip_unit: "pips".to_string(),
+        display_digits: if jpy { 2 } else { 4 },
+        contract_size: 100_000.0,
+        point_value: pip_size * 100_000.0,
+        quote_currency: quote.to_string(),
+        trading_hours: "24/5".to_string(),
+        dukascopy: true,
+        costs,
+    }
+}
+
+/// Métal coté en dollars (pip de 0.01 $)
+fn metal(
+    symbol: &str,
+    display: &str,
+    asset_class: AssetType,
+    digits: i32,
+    contract_size: f64,
+    costs: TradingCostProfile,
+) -> Instrument {
+    Instrument {
+        symbol: symbol.to_string(),
+        display_name: display.to_string(),
+        category: "Métal".to_string(),
+        asset_class,
+        digits,
+        pip_size: 0.01,
+        pip_unit: "$".to_string(),
+        display_digits: 2,
+        contract_size,
+        point_value: 0.01 * contract_size,
+        quote_currency: "USD".to_string(),
+        trading_hours: "24/5".to_string(),
+        dukascopy: true,
+        costs,
+    }
+}
+
+/// Crypto : 1 pip = 1 $, cotation continue
+fn crypto(
+    symbol: &str,
+    display: &str,
+    digits: i32,
+    dukascopy: bool,
+    costs: TradingCostProfile,
+) -> Instrument {
+    Instrument {
+        symbol: symbol.to_string(),
+        display_name: display.to_string(),
+        category: "Crypto".to_string(),
+        asset_class: AssetType::Crypto,
+        digits,
+        pip_size: 1.0,
+        pip_unit: "$".to_string(),
+        display_digits: 0,
+        contract_size: 1.0,
+        point_value: 1.0,
+        quote_currency: "USD".to_string(),
+        trading_hours: "24/7".to_string(),
+        dukascopy,
+        costs,
+    }
+}
+
+/// Indice CFD : analyse en points, un contrat = 1 × l'indice
+fn index(symbol: &str, display: &str, quote: &str, costs: TradingCostProfile) -> Instrument {
+    Instrument {
+        symbol: symbol.to_string(),
+        display_name: display.to_string(),
+        category: "Indice".to_string(),
+        asset_class: AssetType::Index,
+        digits: 2,
+        pip_size: 1.0,
+        pip_unit: "points".to_string(),
+        display_digits: 0,
+        contract_size: 1.0,
+        point_value: 1.0,
+        quote_currency: quote.to_string(),
+        trading_hours: "23/5".to_string(),
+        dukascopy: false,
+        costs,
+    }
+}
+
+/// Matière première cotée en dollars
+fn commodity(
+    symbol: &str,
+    display: &str,
+    digits: i32,
+    pip_size: f64,
+    contract_size: f64,
+) -> Instrument {
+    Instrument {
+        symbol: symbol.to_string(),
+        display_name: display.to_string(),
+        category: "Matière première".to_string(),
+        asset_class: AssetType::Commodity,
+        digits,
+        pip_size,
+        pip_unit: "$".to_string(),
+        display_digits: digits,
+        contract_size,
+        point_value: pip_size * contract_size,
+        quote_currency: "USD".to_string(),
+        trading_hours: "23/5".to_string(),
+        dukascopy: false,
+        costs: COST_MAJOR,
+    }
+}
+
+/// Fiches insérées au démarrage si absentes (l'ordre est celui des listes déroulantes)
+pub(super) fn default_instruments() -> Vec<Instrument> {
+    vec![
+        // ─── Forex Majeurs ───
+        forex("EURUSD", "EUR/USD", "Forex Major", COST_MAJOR),
+        forex("GBPUSD", "GBP/USD", "Forex Major", COST_GBP_AUD),
+        forex("USDJPY", "USD/JPY", "Forex Major", COST_MAJOR),
+        forex("USDCHF", "USD/CHF", "Forex Major", COST_CHF),
+        forex("AUDUSD", "AUD/USD", "Forex Major", COST_GBP_AUD),
+        forex("NZDUSD", "NZD/USD", "Forex Major", COST_NZD),
+        forex("USDCAD", "USD/CAD", "Forex Major", COST_CAD),
+        // ─── Forex Cross ───
+        forex("EURGBP", "EUR/GBP", "Forex Cross", COST_GBP_AUD),
+        forex("EURJPY", "EUR/JPY", "Forex Cross", COST_JPY_CROSS),
+        forex("GBPJPY", "GBP/JPY", "Forex Cross", COST_JPY_CROSS),
+        forex("EURCHF", "EUR/CHF", "Forex Cross", COST_CHF),
+        forex("AUDJPY", "AUD/JPY", "Forex Cross", COST_GBP_AUD),
+        forex("CHFJPY", "CHF/JPY", "Forex Cross", COST_CHF),
+        forex("GBPCHF", "GBP/CHF", "Forex Cross", COST_GBP_AUD),
+        forex("EURAUD", "EUR/AUD", "Forex Cross", COST_GBP_AUD),
+        forex("GBPAUD", "GBP/AUD", "Forex Cross", COST_GBP_AUD),
+        Instrument {
+            dukascopy: false,
+            ..forex("CADJPY", "CAD/JPY", "Forex Cross", COST_CAD)
+        },
+        // ─── Métaux (diviseur Dukascopy : XAU 3 décimales, XAG 5) ───
+        metal(
+            "XAUUSD",
+            "XAU/USD (Or)",
+            AssetType::Gold,
+            3,
+            100.0,
+            COST_GOLD,
+        ),
+        metal(
+            "XAGUSD",
+            "XAG/USD (Argent)",
+            AssetType::Silver,
+            5,
+            5_000.0,
+            COST_MAJOR,
+        ),
+        // ─── Crypto ───
+        crypto("BTCUSD", "BTC/USD", 2, true, COST_BTC),
+        crypto("ETHUSD", "ETH/USD", 2, true, COST_MAJOR),
+        crypto("LTCUSD", "LTC/USD", 2, true, COST_MAJOR),
+        crypto("XRPUSD", "XRP/USD", 5, true, COST_MAJOR),
+        crypto("SOLUSD", "SOL/USD", 2, true, COST_MAJOR),
+        crypto("ETHUS0", "ETH/USD (ETHUS0)", 2, false, COST_MAJOR),
+        // ─── Indices ───
+        index("US30", "Dow Jones (US30)", "USD", COST_US_INDEX),
+        index("US39", "US39", "USD", COST_MAJOR),
+        index("USATEC", "Nasdaq 100 (USATEC)", "USD", COST_MAJOR),
+        index("NAS100", "Nasdaq 100 (NAS100)", "USD", COST_US_INDEX),
+        index("SPX500", "S&P 500", "USD", COST_MAJOR),
+        index("VIX", "VIX", "USD", COST_MAJOR),
+        index("GER40", "DAX (GER40)", "EUR", COST_DAX),
+        index("DEUIDX", "DAX (DEUIDX)", "EUR", COST_MAJOR),
+        index("DEUIDS", "DEUIDS", "EUR", COST_MAJOR),
+        // ─── Matières premières ───
+        commodity("WTI", "Pétrole WTI", 2, 0.01, 1_000.0),
+        commodity("BRENT", "Pétrole Brent", 2, 0.01, 1_000.0),
+        commodity("NGAS", "Gaz naturel", 3, 0.001, 10_000.0),
+    ]
+}
@@ -2,7 +2,8 @@
 // Conforme .clinerules : < 200L, pas d'unwrap()
 
 use super::distribution::TrueRangeDistribution;
-use crate::models::{Candle, Result, VolatilityError};
+use super::range_estimators;
+use crate::models::{Candle, RangeVolatility, Result, VolatilityError};
 use tracing::{debug, info};
 
 /// Calculateur de métriques de volatilité
@@ -172,6 +173,11 @@ impl<'a> MetricsCalculator<'a> {
         noise_ratios
     }
 
+    /// Volatilité Parkinson / Garman-Klass / Rogers-Satchell / Yang-Zhang (% par bougie)
+    pub fn calculer_volatilite_range(&self) -> RangeVolatility {
+        range_estimators::estimer(self.candles)
+    }
+
     /// Calcule la distribution du True Range (détecte les breakouts)
     pub fn calculer_distribution_true_range(&self) -> Result<TrueRangeDistribution> {
         TrueRangeDistribution::calculer(self.candles)
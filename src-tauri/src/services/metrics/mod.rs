@@ -3,5 +3,7 @@
 
 mod calculator;
 mod distribution;
+mod range_estimators;
 
 pub use calculator::MetricsCalculator;
+pub use range_estimators::selectionner_volatilite;
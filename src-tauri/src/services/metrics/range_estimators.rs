@@ -0,0 +1,198 @@
+// services/metrics/range_estimators.rs - Estimateurs de volatilité basés sur le range
+// Parkinson, Garman-Klass, Rogers-Satchell et Yang-Zhang calculés sur OHLC M1.
+// Contrairement à l'ATR, les trois premiers n'utilisent que l'intérieur de la bougie
+// et ne sont donc pas biaisés par les minutes manquantes.
+
+use crate::models::range_volatility::{RangeVolatility, VolatilityEstimator};
+use crate::models::Candle;
+use crate::services::stats::mean;
+
+/// Calcule les quatre estimateurs (en % par bougie). 0.0 si données insuffisantes.
+pub fn estimer(candles: &[Candle]) -> RangeVolatility {
+    let bars: Vec<LogBar> = candles.iter().filter_map(LogBar::from_candle).collect();
+    if bars.is_empty() {
+        return RangeVolatility::default();
+    }
+
+    RangeVolatility {
+        parkinson: to_pct(mean(bars.iter().map(|b| b.hl.powi(2) / (4.0 * 2f64.ln())))),
+        garman_klass: to_pct(mean(
+            bars.iter()
+                .map(|b| 0.5 * b.hl.powi(2) - (2.0 * 2f64.ln() - 1.0) * b.co.powi(2)),
+        )),
+        rogers_satchell: to_pct(mean(bars.iter().map(LogBar::rogers_satchell))),
+        yang_zhang: to_pct(yang_zhang_variance(candles)),
+    }
+}
+
+/// Valeur de volatilité retenue par l'estimateur choisi
+pub fn selectionner_volatilite(
+    estimator: VolatilityEstimator,
+    range: &RangeVolatility,
+    close_to_close: f64,
+) -> f64 {
+    match estimator {
+        VolatilityEstimator::CloseToClose => close_to_close,
+        VolatilityEstimator::Parkinson => range.parkinson,
+        VolatilityEstimator::GarmanKlass => range.garman_klass,
+        VolatilityEstimator::RogersSatchell => range.rogers_satchell,
+        VolatilityEstimator::YangZhang => range.yang_zhang,
+    }
+}
+
+/// Logs de la bougie relatifs à l'ouverture
+struct LogBar {
+    /// ln(H/L)
+    hl: f64,
+    /// ln(C/O)
+    co: f64,
+    /// ln(H/O)
+    ho: f64,
+    /// ln(L/O)
+    lo: f64,
+}
+
+impl LogBar {
+    fn from_candle(c: &Candle) -> Option<Self> {
+        if c.open <= 0.0 || c.high <= 0.0 || c.low <= 0.0 || c.close <= 0.0 || c.high < c.low {
+            return None;
+        }
+        Some(Self {
+            hl: (c.high / c.low).ln(),
+            co: (c.close / c.open).ln(),
+            ho: (c.high / c.open).ln(),
+            lo: (c.low / c.open).ln(),
+        })
+    }
+
+    /// ln(H/C)·ln(H/O) + ln(L/C)·ln(L/O)
+    fn rogers_satchell(&self) -> f64 {
+        (self.ho - self.co) * self.ho + (self.lo - self.co) * self.lo
+    }
+}
+
+/// σ²_YZ = σ²_ouverture + k·σ²_open-close + (1 − k)·σ²_RS
+/// Seules les bougies précédées d'une bougie contiguë (1 min) sont retenues :
+/// un saut entre deux jours n'est pas un saut d'ouverture.
+fn yang_zhang_variance(candles: &[Candle]) -> f64 {
+    let mut overnight = Vec::new();
+    let mut open_close = Vec::new();
+    let mut rs = Vec::new();
+
+    for pair in candles.windows(2) {
+        let (prev, cur) = (&pair[0], &pair[1]);
+        if (cur.datetime - prev.datetime).num_minutes() != 1 || prev.close <= 0.0 {
+            continue;
+        }
+        if let Some(bar) = LogBar::from_candle(cur) {
+            overnight.push((cur.open / prev.close).ln());
+            open_close.push(bar.co);
+            rs.push(bar.rogers_satchell());
+        }
+    }
+
+    let n = overnight.len();
+    if n < 2 {
+        return 0.0;
+    }
+    let k = 0.34 / (1.34 + (n + 1) as f64 / (n - 1) as f64);
+    sample_variance(&overnight)
+        + k * sample_variance(&open_close)
+        + (1.0 - k) * mean(rs)
+}
+
+fn sample_variance(values: &[f64]) -> f64 {
+    let m = mean(values.iter().copied());
+    values.iter().map(|v| (v - m).powi(2)).sum::<f64>() / (values.len() - 1) as f64
+}
+
+/// Variance par bougie → écart-type en %. Une variance négative (Garman-Klass
+/// sur très peu de bougies) est ramenée à 0.
+fn to_pct(variance: f64) -> f64 {
+    variance.max(0.0).sqrt() * 100.0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn candle(minute: i64, open: f64, high: f64, low: f64, close: f64) -> Candle {
+        Candle {
+            symbol: "EURUSD".to_string(),
+            datetime: Utc.with_ymd_and_hms(2025, 1, 6, 14, 0, 0).unwrap()
+                + Duration::minutes(minute),
+            open,
+            high,
+            low,
+            close,
+            volume: 100.0,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_flat_bars_have_zero_volatility() {
+        let candles: Vec<Candle> = (0..10).map(|m| candle(m, 1.1, 1.1, 1.1, 1.1)).collect();
+        assert_eq!(estimer(&candles), RangeVolatility::default());
+        assert_eq!(estimer(&[]), RangeVolatility::default());
+    }
+
+    #[test]
+    fn test_parkinson_single_bar() {
+        let c = candle(0, 1.1000, 1.1011, 1.0989, 1.1005);
+        let expected = ((1.1011f64 / 1.0989).ln().powi(2) / (4.0 * 2f64.ln())).sqrt() * 100.0;
+        assert!((estimer(&[c]).parkinson - expected).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_estimators_agree_on_order_of_magnitude() {
+        // Bougies symétriques alternées, contiguës
+        let candles: Vec<Candle> = (0..60)
+            .map(|m| {
+                let dir = if m % 2 == 0 { 1.0 } else { -1.0 };
+                let open = 1.1000;
+                let close = open + dir * 0.0003;
+                candle(m, open, open + 0.0006, open - 0.0006, close)
+            })
+            .collect();
+        let v = estimer(&candles);
+        for value in [v.parkinson, v.garman_klass, v.rogers_satchell, v.yang_zhang] {
+            assert!(value > 0.0);
+            assert!(value / v.parkinson > 0.5 && value / v.parkinson < 2.0);
+        }
+        assert_eq!(
+            selectionner_volatilite(VolatilityEstimator::YangZhang, &v, 9.0),
+            v.yang_zhang
+        );
+        assert_eq!(
+            selectionner_volatilite(VolatilityEstimator::CloseToClose, &v, 9.0),
+            9.0
+        );
+    }
+
+    #[test]
+    fn test_yang_zhang_ignores_non_contiguous_gaps() {
+        // Deux journées : le saut entre elles ne doit pas gonfler l'estimateur
+        let mut day = vec![
+            candle(0, 1.1000, 1.1004, 1.0996, 1.1001),
+            candle(1, 1.1001, 1.1005, 1.0997, 1.1000),
+            candle(2, 1.1000, 1.1004, 1.0996, 1.1001),
+        ];
+        let gapped: Vec<Candle> = day
+            .iter()
+            .map(|c| Candle {
+                datetime: c.datetime + Duration::days(1),
+                open: c.open + 0.0100,
+                high: c.high + 0.0100,
+                low: c.low + 0.0100,
+                close: c.close + 0.0100,
+                ..c.clone()
+            })
+            .collect();
+        let single = estimer(&day).yang_zhang;
+        day.extend(gapped);
+        let both = estimer(&day).yang_zhang;
+        assert!(both < single * 1.5);
+    }
+}
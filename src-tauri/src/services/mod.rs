@@ -1,6 +1,7 @@
 // services/mod.rs - Exports publics des services
 pub mod archive_service;
 pub mod atr;
+pub mod bootstrap;
 pub mod breakout_detector;
 #[allow(dead_code)]
 pub mod cache_service;
@@ -8,43 +9,69 @@ pub mod calendar_converter;
 pub mod calendar_scraper;
 pub mod candle_index;
 pub mod candle_loader;
+pub mod candle_resampler;
 pub mod cleanup_helpers;
 pub mod cleanup_service;
 pub mod config_service;
 pub mod contextual_atr_analyzer;
 pub mod csv_cleaner;
 pub mod csv_loader;
+pub mod data_quality;
+pub mod directional_bias;
 pub mod database_loader;
 pub mod economic_event_loader;
 pub mod entry_point_analyzer;
+pub mod entry_point_walk_forward;
 pub mod entry_timing_optimizer;
 pub mod entry_window_analyzer;
 pub mod entry_window_optimizer;
+pub mod event_catalog;
 pub mod event_correlation;
 pub mod event_duration_analyzer;
 pub mod event_metrics_aggregator;
+pub mod excursion_analyzer;
 pub mod global_analyzer;
+pub mod global_analyzer_confidence;
 pub mod global_analyzer_event_analysis;
 pub mod global_analyzer_helpers;
 pub mod global_analyzer_metrics;
 pub mod global_analyzer_types;
 pub mod import_processor;
+pub mod import_sessions;
+pub mod import_stream;
+pub mod instrument_registry;
 pub mod metrics;
 pub mod movement_analyzer;
 pub mod movement_quality_analyzer;
 pub mod pair_data;
 pub mod pair_data_stats;
+pub mod parquet_io;
 pub mod planning;
+pub mod pre_release_analyzer;
+pub mod release_seconds_analyzer;
+pub mod sampling;
+pub mod second_bars;
 pub mod session;
 pub mod session_analyzer;
+pub mod significance;
 pub mod slice_metrics_analyzer;
+pub mod source_timezone;
+pub mod staged_import;
+pub mod stats;
+pub mod straddle_backtester;
+pub mod surprise_analyzer;
 pub mod dukascopy_downloader;
 pub mod dukascopy_instruments;
 pub mod tick_aggregator;
+pub mod tick_filter;
+pub mod timezone_detector;
 pub mod volatility;
 pub mod volatility_duration_calculator;
 pub mod volatility_profile_service;
+pub mod walk_forward_optimizer;
 pub mod win_rate_calculator;
+#[cfg(test)]
+pub(crate) mod test_fixtures;
 
 pub use archive_service::*;
 pub use calendar_converter::*;
@@ -0,0 +1,395 @@
+// services/pair_data/broker_formats.rs - Exports HistData et MT5
+// Conforme .clinerules : < 300L hors tests (agrégation tick dans broker_ticks), pas d'unwrap()
+//
+// Formats reconnus sur le contenu de la première ligne (pas de header exploitable) :
+//   HistData M1   : 20240102 170000;1.104270;1.104290;1.104250;1.104290;0
+//   HistData tick : 20240102 170000123,1.104270,1.104290,0
+//   MT5 bars      : <DATE>\t<TIME>\t<OPEN>\t<HIGH>\t<LOW>\t<CLOSE>\t<TICKVOL>\t<VOL>\t<SPREAD>
+//   MT5 ticks     : <DATE>\t<TIME>\t<BID>\t<ASK>\t<LAST>\t<VOLUME>\t<FLAGS>
+//
+// Fuseaux par défaut : HistData = EST fixe (UTC-5, sans heure d'été) ;
+// MT5 = heure serveur "NY-close" (17:00 New York = 00:00 serveur, soit UTC+2/+3 selon le DST US).
+// Un fuseau source déclaré à l'import remplace ce défaut.
+// Les ticks sont agrégés en M1 enrichies, bid/ask et spread conservés (broker_ticks).
+// Lecture en continu : les bougies sont remises par lots, seules les deux dernières
+// minutes agrégées restent ouvertes.
+
+use super::broker_ticks::BrokerTicks;
+use super::types::{CsvFormat, NormalizedCandle};
+use crate::services::source_timezone::SourceTimezone;
+use chrono::NaiveDateTime;
+use std::io::Read;
+
+/// Détecte un export HistData/MT5 depuis la première ligne du fichier
+pub(super) fn detect_from_content(first_line: &str) -> Option<CsvFormat> {
+    let line = first_line.trim_start_matches('\u{feff}').trim();
+
+    if line.starts_with("<DATE>") {
+        if line.contains("<BID>") {
+            return Some(CsvFormat::Mt5Ticks);
+        }
+        if line.contains("<OPEN>") {
+            return Some(CsvFormat::Mt5Bars);
+        }
+        return None;
+    }
+
+    let semicolon: Vec<&str> = line.split(';').collect();
+    if semicolon.len() == 6 && is_histdata_stamp(semicolon[0], 6) {
+        return Some(CsvFormat::HistDataM1);
+    }
+
+    let comma: Vec<&str> = line.split(',').collect();
+    if (3..=4).contains(&comma.len()) && is_histdata_stamp(comma[0], 9) {
+        return Some(CsvFormat::HistDataTick);
+    }
+
+    None
+}
+
+/// "YYYYMMDD HHMMSS" (bougies) ou "YYYYMMDD HHMMSSfff" (ticks)
+fn is_histdata_stamp(s: &str, time_digits: usize) -> bool {
+    match s.split_once(' ') {
+        Some((date, time)) => {
+            date.len() == 8
+                && time.len() == time_digits
+                && date.bytes().chain(time.bytes()).all(|b| b.is_ascii_digit())
+        }
+        None => false,
+    }
+}
+
+/// Fuseau par défaut du format : HistData = EST fixe (Etc/GMT+5), MT5 = serveur NY-close
+pub(super) fn default_timezone(format: &CsvFormat) -> SourceTimezone {
+    match format {
+        CsvFormat::HistDataM1 | CsvFormat::HistDataTick => {
+            SourceTimezone::Iana(chrono_tz::Etc::GMTPlus5)
+        }
+        CsvFormat::Mt5Bars | CsvFormat::Mt5Ticks => SourceTimezone::BrokerNyClose,
+        _ => SourceTimezone::Utc,
+    }
+}
+
+fn parse_price(s: &str) -> Result<f64, String> {
+    s.trim()
+        .parse::<f64>()
+        .map_err(|_| format!("Prix invalide: {}", s))
+}
+
+/// Accumulateur de lecture : bougies directes + minutes agrégées depuis les ticks
+struct BrokerExportReader {
+    timezone: SourceTimezone,
+    headers: Vec<String>,
+    candles: Vec<NormalizedCandle>,
+    ticks: BrokerTicks,
+}
+
+impl BrokerExportReader {
+    fn to_utc(&self, local: NaiveDateTime) -> Result<i64, String> {
+        self.timezone.to_utc(local).map(|dt| dt.timestamp())
+    }
+
+    fn field<'r>(
+        &self,
+        record: &'r csv::StringRecord,
+        index: usize,
+        name: &str,
+    ) -> Result<&'r str, String> {
+        record
+            .get(index)
+            .ok_or_else(|| format!("{} manquant", name))
+    }
+
+    /// Colonne MT5 par nom de header (<DATE>, <OPEN>…)
+    fn column<'r>(&self, record: &'r csv::StringRecord, name: &str) -> Result<&'r str, String> {
+        let index = self
+            .headers
+            .iter()
+            .position(|h| h == name)
+            .ok_or_else(|| format!("Colonne {} absente", name))?;
+        self.field(record, index, name)
+    }
+
+    fn push_record(
+        &mut self,
+        format: &CsvFormat,
+        record: &csv::StringRecord,
+    ) -> Result<(), String> {
+        match format {
+            CsvFormat::HistDataM1 => {
+                let local =
+                    NaiveDateTime::parse_from_str(self.field(record, 0, "Date")?, "%Y%m%d %H%M%S")
+                        .map_err(|e| format!("Date HistData invalide: {}", e))?;
+                self.candles.push(NormalizedCandle {
+                    timestamp: self.to_utc(local)?,
+                    open: parse_price(self.field(record, 1, "Open")?)?,
+                    high: parse_price(self.field(record, 2, "High")?)?,
+                    low: parse_price(self.field(record, 3, "Low")?)?,
+                    close: parse_price(self.field(record, 4, "Close")?)?,
+                    volume: self
+                        .field(record, 5, "Volume")
+                        .and_then(parse_price)
+                        .unwrap_or(0.0),
+                    quotes: None,
+                });
+            }
+            CsvFormat::HistDataTick => {
+                let local = NaiveDateTime::parse_from_str(
+                    self.field(record, 0, "Date")?,
+                    "%Y%m%d %H%M%S%3f",
+                )
+                .map_err(|e| format!("Date HistData invalide: {}", e))?;
+                let bid = parse_price(self.field(record, 1, "Bid")?)?;
+                let ask = parse_price(self.field(record, 2, "Ask")?)?;
+                let datetime = self.timezone.to_utc(local)?;
+                self.ticks
+                    .push(datetime, Some(bid), Some(ask), &mut self.candles)?;
+            }
+            CsvFormat::Mt5Bars => {
+                // Export D1/W1 : pas de colonne <TIME>
+                let time = self.column(record, "<TIME>").unwrap_or("00:00:00");
+                let server = parse_mt5_datetime(self.column(record, "<DATE>")?, time)?;
+                let volume = self
+                    .column(record, "<TICKVOL>")
+                    .or_else(|_| self.column(record, "<VOL>"))
+                    .and_then(parse_price)
+                    .unwrap_or(0.0);
+                self.candles.push(NormalizedCandle {
+                    timestamp: self.to_utc(server)?,
+                    open: parse_price(self.column(record, "<OPEN>")?)?,
+                    high: parse_price(self.column(record, "<HIGH>")?)?,
+                    low: parse_price(self.column(record, "<LOW>")?)?,
+                    close: parse_price(self.column(record, "<CLOSE>")?)?,
+                    volume,
+                    quotes: None,
+                });
+            }
+            CsvFormat::Mt5Ticks => {
+                let server = parse_mt5_datetime(
+                    self.column(record, "<DATE>")?,
+                    self.column(record, "<TIME>")?,
+                )?;
+                let side = |name: &str| match self.column(record, name) {
+                    Ok(s) if !s.is_empty() => parse_price(s).map(Some),
+                    _ => Ok(None),
+                };
+                let (bid, ask) = (side("<BID>")?, side("<ASK>")?);
+                let datetime = self.timezone.to_utc(server)?;
+                self.ticks.push(datetime, bid, ask, &mut self.candles)?;
+            }
+            other => return Err(format!("Format {:?} non géré par broker_formats", other)),
+        }
+        Ok(())
+    }
+}
+
+/// Lit un export HistData/MT5 et remet les bougies UTC à `on_batch` par lots
+/// d'environ `batch_size`. `timezone` : fuseau déclaré à l'import (None = défaut du format).
+/// Retourne le nombre de ticks hors ordre ignorés.
+pub(super) fn stream_broker_export<R: Read>(
+    reader: R,
+    format: &CsvFormat,
+    timezone: Option<&SourceTimezone>,
+    batch_size: usize,
+    on_batch: &mut dyn FnMut(Vec<NormalizedCandle>) -> Result<(), String>,
+) -> Result<usize, String> {
+    let (delimiter, has_headers) = match format {
+        CsvFormat::HistDataM1 => (b';', false),
+        CsvFormat::HistDataTick => (b',', false),
+        CsvFormat::Mt5Bars | CsvFormat::Mt5Ticks => (b'\t', true),
+        other => return Err(format!("Format {:?} non géré par broker_formats", other)),
+    };
+
+    let mut csv_reader = csv::ReaderBuilder::new()
+        .has_headers(has_headers)
+        .flexible(true)
+        .trim(csv::Trim::All)
+        .delimiter(delimiter)
+        .from_reader(reader);
+
+    let headers: Vec<String> = if has_headers {
+        csv_reader
+            .headers()
+            .map_err(|e| format!("Erreur headers: {}", e))?
+            .iter()
+            .map(|h| h.trim_start_matches('\u{feff}').to_uppercase())
+            .collect()
+    } else {
+        Vec::new()
+    };
+
+    let mut export = BrokerExportReader {
+        timezone: timezone
+            .copied()
+            .unwrap_or_else(|| default_timezone(format)),
+        headers,
+        candles: Vec::new(),
+        ticks: BrokerTicks::default(),
+    };
+
+    let first_line = if has_headers { 2 } else { 1 };
+    for (idx, result) in csv_reader.records().enumerate() {
+        let parsed = result
+            .map_err(|e| format!("erreur CSV: {}", e))
+            .and_then(|record| export.push_record(format, &record));
+        if let Err(e) = parsed {
+            tracing::warn!("⚠️ Ligne {} ignorée: {}", idx + first_line, e);
+        }
+        if export.candles.len() >= batch_size {
+            on_batch(std::mem::take(&mut export.candles))?;
+        }
+    }
+
+    let late_ticks = export.ticks.late_ticks();
+    if late_ticks > 0 {
+        tracing::warn!(
+            "⚠️ {} ticks hors ordre ignorés (minute déjà close)",
+            late_ticks
+        );
+    }
+    let mut candles = export.candles;
+    export.ticks.finish(&mut candles);
+    if !candles.is_empty() {
+        on_batch(candles)?;
+    }
+    Ok(late_ticks)
+}
+
+/// "2024.01.02" + "00:00:00" / "00:00" / "00:00:00.123"
+fn parse_mt5_datetime(date: &str, time: &str) -> Result<NaiveDateTime, String> {
+    let datetime = format!("{} {}", date, time);
+    ["%Y.%m.%d %H:%M:%S%.f", "%Y.%m.%d %H:%M"]
+        .iter()
+        .find_map(|f| NaiveDateTime::parse_from_str(&datetime, f).ok())
+        .ok_or_else(|| format!("Date MT5 invalide: {}", datetime))
+}
+
+/// Décode un fichier UTF-16 LE (exports MT5) ; `bytes` sans le BOM
+pub(super) fn decode_utf16le(bytes: &[u8]) -> Result<String, String> {
+    let units: Vec<u16> = bytes
+        .chunks_exact(2)
+        .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+        .collect();
+    String::from_utf16(&units).map_err(|e| format!("UTF-16 invalide: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn read_broker_export(
+        data: &[u8],
+        format: &CsvFormat,
+        timezone: Option<&SourceTimezone>,
+    ) -> Result<Vec<NormalizedCandle>, String> {
+        let mut candles = Vec::new();
+        stream_broker_export(data, format, timezone, 2, &mut |batch| {
+            candles.extend(batch);
+            Ok(())
+        })?;
+        Ok(candles)
+    }
+
+    fn utc(y: i32, m: u32, d: u32, h: u32, min: u32) -> i64 {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .and_then(|d| d.and_hms_opt(h, min, 0))
+            .map(|dt| dt.and_utc().timestamp())
+            .unwrap()
+    }
+
+    #[test]
+    fn test_detect_from_content() {
+        let cases = [
+            (
+                "20240102 170000;1.104270;1.104290;1.104250;1.104290;0",
+                "HistDataM1",
+            ),
+            ("20240102 170000123,1.104270,1.104290,0", "HistDataTick"),
+            (
+                "<DATE>\t<TIME>\t<OPEN>\t<HIGH>\t<LOW>\t<CLOSE>\t<TICKVOL>\t<VOL>\t<SPREAD>",
+                "Mt5Bars",
+            ),
+            (
+                "\u{feff}<DATE>\t<TIME>\t<BID>\t<ASK>\t<LAST>\t<VOLUME>\t<FLAGS>",
+                "Mt5Ticks",
+            ),
+        ];
+        for (line, expected) in cases {
+            let format = detect_from_content(line).map(|f| format!("{:?}", f));
+            assert_eq!(format.as_deref(), Some(expected), "{}", line);
+        }
+        assert!(detect_from_content("Gmt time,Open,High,Low,Close,Volume").is_none());
+        assert!(detect_from_content("2024.01.02,00:00,1.1,1.2,1.0,1.1,10").is_none());
+    }
+
+    #[test]
+    fn test_histdata_m1_est_to_utc() {
+        let data = "20240102 170000;1.1042;1.1043;1.1041;1.1043;0\n\
+                    20240702 170100;1.0742;1.0745;1.0740;1.0744;0\n";
+        let candles = read_broker_export(data.as_bytes(), &CsvFormat::HistDataM1, None).unwrap();
+        // EST fixe : +5h été comme hiver
+        assert_eq!(candles[0].timestamp, utc(2024, 1, 2, 22, 0));
+        assert_eq!(candles[1].timestamp, utc(2024, 7, 2, 22, 1));
+        assert_eq!(candles[1].close, 1.0744);
+    }
+
+    #[test]
+    fn test_histdata_ticks_aggregated_to_m1() {
+        let data = "20240102 170000123,1.1000,1.1002,0\n\
+                    20240102 170030500,1.1010,1.1012,0\n\
+                    20240102 170059999,1.0990,1.0992,0\n\
+                    20240102 170100000,1.1005,1.1007,0\n";
+        let candles = read_broker_export(data.as_bytes(), &CsvFormat::HistDataTick, None).unwrap();
+        assert_eq!(candles.len(), 2);
+        let first = &candles[0];
+        assert_eq!(first.timestamp, utc(2024, 1, 2, 22, 0));
+        assert!((first.open - 1.1001).abs() < 1e-9);
+        assert!((first.high - 1.1011).abs() < 1e-9);
+        assert!((first.low - 1.0991).abs() < 1e-9);
+        assert_eq!(first.volume, 3.0);
+        // Côtés et spread conservés
+        let quotes = first.quotes.as_ref().unwrap();
+        assert_eq!((quotes.bid_open, quotes.ask_high), (1.1000, 1.1012));
+        assert!((quotes.spread_mean - 0.0002).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_mt5_bars_ny_close_follows_us_dst() {
+        let data = "<DATE>\t<TIME>\t<OPEN>\t<HIGH>\t<LOW>\t<CLOSE>\t<TICKVOL>\t<VOL>\t<SPREAD>\n\
+                    2024.01.05\t15:30:00\t1.0920\t1.0950\t1.0900\t1.0940\t812\t0\t2\n\
+                    2024.07.05\t15:30:00\t1.0820\t1.0850\t1.0800\t1.0840\t640\t0\t2\n";
+        let candles = read_broker_export(data.as_bytes(), &CsvFormat::Mt5Bars, None).unwrap();
+        // NFP 08:30 New York : 13:30 UTC en hiver (serveur UTC+2), 12:30 UTC en été (UTC+3)
+        assert_eq!(candles[0].timestamp, utc(2024, 1, 5, 13, 30));
+        assert_eq!(candles[1].timestamp, utc(2024, 7, 5, 12, 30));
+        assert_eq!(candles[0].volume, 812.0);
+    }
+
+    #[test]
+    fn test_declared_timezone_overrides_format_default() {
+        let data = "<DATE>\t<TIME>\t<OPEN>\t<HIGH>\t<LOW>\t<CLOSE>\t<TICKVOL>\n\
+                    2024.07.05\t15:30:00\t1.0820\t1.0850\t1.0800\t1.0840\t640\n";
+        let utc_server = SourceTimezone::Utc;
+        let candles =
+            read_broker_export(data.as_bytes(), &CsvFormat::Mt5Bars, Some(&utc_server)).unwrap();
+        assert_eq!(candles[0].timestamp, utc(2024, 7, 5, 15, 30));
+    }
+
+    #[test]
+    fn test_mt5_ticks_forward_fill_sides() {
+        let data = "<DATE>\t<TIME>\t<BID>\t<ASK>\t<LAST>\t<VOLUME>\t<FLAGS>\n\
+                    2024.01.05\t15:30:00.100\t1.0920\t\t\t\t2\n\
+                    2024.01.05\t15:30:00.200\t\t1.0922\t\t\t4\n\
+                    2024.01.05\t15:30:01.000\t1.0918\t\t\t\t2\n";
+        let candles = read_broker_export(data.as_bytes(), &CsvFormat::Mt5Ticks, None).unwrap();
+        assert_eq!(candles.len(), 1);
+        // Premier mid dès que les deux côtés sont connus, puis ask conservé
+        assert!((candles[0].open - 1.0921).abs() < 1e-9);
+        assert!((candles[0].close - 1.0920).abs() < 1e-9);
+        let quotes = candles[0].quotes.as_ref().unwrap();
+        assert_eq!((quotes.ask_close, quotes.bid_close), (1.0922, 1.0918));
+        assert_eq!(candles[0].volume, 2.0);
+        assert_eq!(candles[0].timestamp, utc(2024, 1, 5, 13, 30));
+    }
+}
@@ -0,0 +1,117 @@
+// services/pair_data/broker_ticks.rs - Ticks HistData/MT5 agrégés en M1 enrichies
+// Conforme .clinerules : < 150L, pas d'unwrap()
+//
+// Même agrégation que l'import tick Dukascopy (`M1Stream`) : OHLC principal = mid,
+// OHLC bid/ask et spread conservés dans `NormalizedCandle::quotes`, volume = nombre de ticks.
+
+use super::types::NormalizedCandle;
+use crate::services::tick_aggregator::{EnrichedM1, M1Stream, RawTick};
+use chrono::{DateTime, Utc};
+
+/// Agrégation en continu des ticks d'un export broker
+#[derive(Default)]
+pub(super) struct BrokerTicks {
+    stream: M1Stream,
+    bars: Vec<EnrichedM1>,
+    // MT5 : un côté vide = inchangé depuis le tick précédent
+    last_bid: Option<f64>,
+    last_ask: Option<f64>,
+}
+
+impl BrokerTicks {
+    /// Ajoute un tick ; un côté absent reprend la dernière cotation connue.
+    /// Ignoré tant qu'un des deux côtés n'a jamais été coté ; cotation nulle ou croisée rejetée.
+    /// Les minutes closes sont ajoutées à `out`.
+    pub(super) fn push(
+        &mut self,
+        datetime_utc: DateTime<Utc>,
+        bid: Option<f64>,
+        ask: Option<f64>,
+        out: &mut Vec<NormalizedCandle>,
+    ) -> Result<(), String> {
+        self.last_bid = bid.or(self.last_bid);
+        self.last_ask = ask.or(self.last_ask);
+        let (Some(bid), Some(ask)) = (self.last_bid, self.last_ask) else {
+            return Ok(());
+        };
+        if bid <= 0.0 || ask <= 0.0 || ask < bid {
+            return Err(format!("Prix invalides : bid={}, ask={}", bid, ask));
+        }
+        let tick = RawTick {
+            datetime_utc,
+            bid,
+            ask,
+            bid_volume: 0.0,
+            ask_volume: 0.0,
+        };
+        self.stream.push(&tick, &mut self.bars);
+        out.extend(self.bars.drain(..).map(to_normalized));
+        Ok(())
+    }
+
+    /// Ticks ignorés car arrivés après la clôture de leur minute
+    pub(super) fn late_ticks(&self) -> usize {
+        self.stream.late_ticks
+    }
+
+    /// Émet les minutes encore ouvertes (fin de fichier)
+    pub(super) fn finish(&mut self, out: &mut Vec<NormalizedCandle>) {
+        self.stream.finish(&mut self.bars);
+        out.extend(self.bars.drain(..).map(to_normalized));
+    }
+}
+
+fn to_normalized(mut bar: EnrichedM1) -> NormalizedCandle {
+    // Exports broker sans volume exploitable : volume = nombre de ticks
+    bar.volume = bar.tick_count as f64;
+    NormalizedCandle {
+        timestamp: bar.datetime_utc.timestamp(),
+        open: bar.open,
+        high: bar.high,
+        low: bar.low,
+        close: bar.close,
+        volume: bar.volume,
+        quotes: Some(bar),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_sides_and_spread_kept() {
+        let t0 = Utc.with_ymd_and_hms(2024, 1, 5, 13, 30, 0).unwrap();
+        let mut ticks = BrokerTicks::default();
+        let mut out = Vec::new();
+        for (sec, bid, ask) in [
+            (1, 1.0920, 1.0922),
+            (20, 1.0930, 1.0934),
+            (59, 1.0910, 1.0912),
+        ] {
+            let at = t0 + chrono::Duration::seconds(sec);
+            ticks.push(at, Some(bid), Some(ask), &mut out).unwrap();
+        }
+        // Cotation croisée rejetée
+        assert!(ticks
+            .push(
+                t0 + chrono::Duration::seconds(61),
+                Some(1.0930),
+                Some(1.0920),
+                &mut out
+            )
+            .is_err());
+        ticks.finish(&mut out);
+
+        assert_eq!(out.len(), 1);
+        let quotes = out[0].quotes.as_ref().unwrap();
+        assert_eq!(quotes.bid_open, 1.0920);
+        assert_eq!(quotes.ask_high, 1.0934);
+        assert_eq!(quotes.bid_low, 1.0910);
+        assert!((quotes.spread_close - 0.0002).abs() < 1e-9);
+        assert!((quotes.spread_high - 0.0004).abs() < 1e-9);
+        assert!((out[0].open - 1.0921).abs() < 1e-9);
+        assert_eq!(out[0].volume, 3.0);
+    }
+}
@@ -2,7 +2,7 @@
 // Conforme .clinerules : < 300 lines, retourne Result<T, VolatilityError>
 
 use crate::db::DbPool;
-use crate::models::symbol_conversion::SymbolConversion;
+use crate::models::symbol_conversion::{AliasMatchKind, SymbolAlias, SymbolConversion};
 use crate::models::VolatilityError;
 use diesel::prelude::*;
 use diesel::sql_types::{Double, Integer, Text, Bool};
@@ -145,6 +145,78 @@ pub fn delete_conversion(pool: &DbPool, symbol: &str) -> Result<(), VolatilityEr
     Ok(())
 }
 
+/// Récupère les règles d'alias, par priorité croissante puis ordre de création
+pub fn get_all_aliases(pool: &DbPool) -> Result<Vec<SymbolAlias>, VolatilityError> {
+    let mut conn = pool.get().map_err(|e| {
+        VolatilityError::DatabaseError(format!("Pool connection failed: {}", e))
+    })?;
+
+    let rows: Vec<SymbolAliasRow> = diesel::sql_query(
+        "SELECT id, pattern, match_kind, canonical, priority FROM symbol_aliases ORDER BY priority, id",
+    )
+    .load::<SymbolAliasRow>(&mut conn)
+    .map_err(|e| VolatilityError::DatabaseError(format!("Query failed: {}", e)))?;
+
+    Ok(rows
+        .into_iter()
+        .filter_map(|r| {
+            let match_kind = AliasMatchKind::parse(&r.match_kind)?;
+            Some(SymbolAlias {
+                id: Some(r.id),
+                pattern: r.pattern,
+                match_kind,
+                canonical: r.canonical,
+                priority: r.priority,
+            })
+        })
+        .collect())
+}
+
+/// Insère ou met à jour une règle d'alias (UPSERT sur motif + type)
+pub fn upsert_alias(pool: &DbPool, alias: &SymbolAlias) -> Result<(), VolatilityError> {
+    let mut conn = pool.get().map_err(|e| {
+        VolatilityError::DatabaseError(format!("Pool connection failed: {}", e))
+    })?;
+
+    diesel::sql_query(
+        "INSERT INTO symbol_aliases (pattern, match_kind, canonical, priority, updated_at)
+         VALUES (?, ?, ?, ?, CURRENT_TIMESTAMP)
+         ON CONFLICT(pattern, match_kind) DO UPDATE SET
+           canonical = excluded.canonical,
+           priority = excluded.priority,
+           updated_at = CURRENT_TIMESTAMP",
+    )
+    .bind::<Text, _>(&alias.pattern)
+    .bind::<Text, _>(alias.match_kind.as_str())
+    .bind::<Text, _>(&alias.canonical)
+    .bind::<Integer, _>(alias.priority)
+    .execute(&mut conn)
+    .map_err(|e| VolatilityError::DatabaseError(format!("Upsert failed: {}", e)))?;
+
+    tracing::info!(
+        "✅ Alias sauvegardé: {} ({}) → {}",
+        alias.pattern,
+        alias.match_kind.as_str(),
+        alias.canonical
+    );
+    Ok(())
+}
+
+/// Supprime une règle d'alias
+pub fn delete_alias(pool: &DbPool, id: i32) -> Result<(), VolatilityError> {
+    let mut conn = pool.get().map_err(|e| {
+        VolatilityError::DatabaseError(format!("Pool connection failed: {}", e))
+    })?;
+
+    diesel::sql_query("DELETE FROM symbol_aliases WHERE id = ?")
+        .bind::<Integer, _>(id)
+        .execute(&mut conn)
+        .map_err(|e| VolatilityError::DatabaseError(format!("Delete failed: {}", e)))?;
+
+    tracing::info!("🗑️ Alias supprimé: #{}", id);
+    Ok(())
+}
+
 // Helper struct pour QueryableByName avec Diesel
 #[derive(QueryableByName, Debug)]
 struct SymbolConversionRow {
@@ -159,3 +231,17 @@ struct SymbolConversionRow {
     #[diesel(sql_type = Bool)]
     hidden: bool,
 }
+
+#[derive(QueryableByName, Debug)]
+struct SymbolAliasRow {
+    #[diesel(sql_type = Integer)]
+    id: i32,
+    #[diesel(sql_type = Text)]
+    pattern: String,
+    #[diesel(sql_type = Text)]
+    match_kind: String,
+    #[diesel(sql_type = Text)]
+    canonical: String,
+    #[diesel(sql_type = Integer)]
+    priority: i32,
+}
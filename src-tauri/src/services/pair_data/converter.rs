@@ -1,10 +1,16 @@
 // services/pair_data/converter.rs - Convertisseur principal
 // Conforme .clinerules : < 150L, pas d'unwrap()
 
+use super::broker_formats;
 use super::formats::FormatParsers;
 use super::metadata::MetadataExtractor;
-use super::types::{CsvFormat, NormalizedCandle, PairMetadata};
+use super::types::{CsvFormat, NormalizedCandle, PairMetadata, StreamedRows};
+use super::utf16_reader::Utf16LeReader;
+use crate::services::import_stream::{ImportControl, ImportProgress, ImportStage, BATCH_SIZE};
+use crate::services::source_timezone::SourceTimezone;
+use std::fs::File;
 use std::path::Path;
+use std::sync::atomic::AtomicBool;
 
 /// Convertisseur de données de paires
 pub struct PairDataConverter;
@@ -35,14 +41,119 @@ impl PairDataConverter {
         CsvFormat::Generic
     }
 
-    /// Lit et normalise un fichier CSV  
-    pub fn read_and_normalize(path: &str) -> Result<Vec<NormalizedCandle>, String> {
-        use std::fs::File;
-        use std::io::{BufRead, BufReader, Seek, SeekFrom};
+    /// Détecte un export HistData/MT5 sur le contenu de la première ligne
+    /// (ces formats n'ont pas de header exploitable par `detect_format`)
+    pub fn detect_format_from_content(first_line: &str) -> Option<CsvFormat> {
+        broker_formats::detect_from_content(first_line)
+    }
+
+    /// Format HistData/MT5 du fichier, s'il en est un
+    fn detect_broker_export(path: &str) -> Result<Option<CsvFormat>, String> {
+        use std::io::Read;
+
+        let mut head = Vec::with_capacity(1024);
+        std::fs::File::open(path)
+            .map_err(|e| format!("Erreur ouverture: {}", e))?
+            .take(1024)
+            .read_to_end(&mut head)
+            .map_err(|e| e.to_string())?;
+
+        let text = match head.strip_prefix(&[0xFF, 0xFE]) {
+            Some(rest) => broker_formats::decode_utf16le(&rest[..rest.len() & !1])?,
+            None => String::from_utf8_lossy(&head).into_owned(),
+        };
+        let first_line = text.lines().next().unwrap_or_default();
+        Ok(Self::detect_format_from_content(first_line))
+    }
+
+    /// Vrai si le fichier est un export HistData/MT5 (à importer sans nettoyage)
+    pub fn is_broker_export(path: &str) -> Result<bool, String> {
+        Ok(Self::detect_broker_export(path)?.is_some())
+    }
+
+    /// Fuseau connu du fichier : celui déclaré, sinon celui du format broker.
+    /// None : CSV générique sans fuseau déclaré (lu comme UTC, fuseau inconnu en import_log).
+    pub fn known_source_timezone(
+        path: &str,
+        declared: Option<&SourceTimezone>,
+    ) -> Result<Option<SourceTimezone>, String> {
+        if let Some(timezone) = declared {
+            return Ok(Some(*timezone));
+        }
+        Ok(Self::detect_broker_export(path)?
+            .map(|format| broker_formats::default_timezone(&format)))
+    }
+
+    /// Lit et normalise un fichier CSV en bougies UTC triées.
+    /// `source_timezone` : fuseau des horodatages du fichier (None = défaut du format :
+    /// UTC, EST pour HistData, serveur NY-close pour MT5).
+    pub fn read_and_normalize(
+        path: &str,
+        source_timezone: Option<&SourceTimezone>,
+    ) -> Result<Vec<NormalizedCandle>, String> {
+        // Lecture complète : ni annulation ni progression
+        let cancelled = AtomicBool::new(false);
+        let mut on_progress = |_: &ImportProgress| {};
+        let mut ctrl = ImportControl::new(path, 0, &cancelled, &mut on_progress);
+
+        let mut candles = Vec::new();
+        Self::stream_normalized(path, source_timezone, &mut ctrl, &mut |batch| {
+            candles.extend(batch);
+            Ok(())
+        })?;
+        candles.sort_by_key(|c| c.timestamp);
+        Ok(candles)
+    }
+
+    /// Lit et normalise un fichier CSV en continu : `on_batch` reçoit des lots
+    /// d'environ `BATCH_SIZE` bougies UTC (triées dans le lot, pas entre lots).
+    /// `ctrl` remonte la progression après chaque lot et interrompt la lecture si annulé.
+    /// Retourne le nombre de bougies lues et de ticks hors ordre ignorés.
+    pub fn stream_normalized(
+        path: &str,
+        source_timezone: Option<&SourceTimezone>,
+        ctrl: &mut ImportControl,
+        on_batch: &mut dyn FnMut(Vec<NormalizedCandle>) -> Result<(), String>,
+    ) -> Result<StreamedRows, String> {
+        use std::io::{BufRead, BufReader, Read, Seek, SeekFrom};
+
+        let mut rows = 0usize;
+        let mut emit = |mut batch: Vec<NormalizedCandle>, ctrl: &mut ImportControl| {
+            ctrl.check()?;
+            batch.sort_by_key(|c| c.timestamp);
+            rows += batch.len();
+            on_batch(batch)?;
+            ctrl.report(ImportStage::Reading, rows, rows);
+            Ok::<(), String>(())
+        };
 
         // 1. Ouvrir le fichier
-        let mut file = File::open(path).map_err(|e| format!("Erreur ouverture: {}", e))?;
-        let mut buf_reader = BufReader::new(file);
+        let file = File::open(path).map_err(|e| format!("Erreur ouverture: {}", e))?;
+        let mut buf_reader = BufReader::new(ctrl.counting(file));
+
+        // Exports MT5 en UTF-16 LE (BOM FF FE) : décodés en UTF-8 au fil de la lecture
+        let is_utf16 = buf_reader
+            .fill_buf()
+            .map_err(|e| e.to_string())?
+            .starts_with(&[0xFF, 0xFE]);
+        if is_utf16 {
+            buf_reader.consume(2);
+            let mut decoded = BufReader::new(Utf16LeReader::new(buf_reader));
+            let mut first_line = String::new();
+            decoded
+                .read_line(&mut first_line)
+                .map_err(|e| e.to_string())?;
+            let format = Self::detect_format_from_content(&first_line)
+                .ok_or("Fichier UTF-16 non reconnu (export MT5 attendu)")?;
+            let late_ticks = broker_formats::stream_broker_export(
+                first_line.as_bytes().chain(decoded),
+                &format,
+                source_timezone,
+                BATCH_SIZE,
+                &mut |batch| emit(batch, ctrl),
+            )?;
+            return Self::non_empty(rows, late_ticks);
+        }
 
         // 2. Lire la première ligne pour détecter le délimiteur
         let mut first_line = String::new();
@@ -52,6 +163,25 @@ impl PairDataConverter {
             return Err("Fichier vide".to_string());
         }
 
+        // 3. Réinitialiser la position du fichier au début
+        let mut counted = buf_reader.into_inner(); // Récupérer le fichier sous-jacent
+        counted
+            .seek(SeekFrom::Start(0))
+            .map_err(|e| e.to_string())?;
+
+        // Exports HistData/MT5 : détection sur le contenu et conversion UTC propre à la source
+        if let Some(format) = Self::detect_format_from_content(&first_line) {
+            tracing::info!("🔍 Export broker détecté: {:?}", format);
+            let late_ticks = broker_formats::stream_broker_export(
+                BufReader::new(counted),
+                &format,
+                source_timezone,
+                BATCH_SIZE,
+                &mut |batch| emit(batch, ctrl),
+            )?;
+            return Self::non_empty(rows, late_ticks);
+        }
+
         let delimiter = if first_line.contains(';') {
             b';' // Format européen
         } else {
@@ -63,20 +193,13 @@ impl PairDataConverter {
             if delimiter == b';' { ";" } else { "," }
         );
 
-        // 3. Réinitialiser la position du fichier au début
-        file = buf_reader.into_inner(); // Récupérer le fichier sous-jacent
-        file.seek(SeekFrom::Start(0)).map_err(|e| e.to_string())?;
-        
-        // Créer un nouveau BufReader pour le parsing
-        let buf_reader_csv = BufReader::new(file);
-
         // 4. Configurer le CSV Reader pour streamer directement depuis le fichier (Memory Safe)
         let mut reader = csv::ReaderBuilder::new()
             .has_headers(true)
             .flexible(true)
             .trim(csv::Trim::All)
             .delimiter(delimiter)
-            .from_reader(buf_reader_csv);
+            .from_reader(BufReader::new(counted));
 
         // Lire les headers
         let headers: Vec<String> = reader
@@ -87,13 +210,15 @@ impl PairDataConverter {
             .collect();
 
         let format = Self::detect_format(&headers);
-        tracing::debug!("🔍 Format détecté: {:?}", format);
+        let timezone = source_timezone.copied().unwrap_or_default();
+        tracing::debug!(
+            "🔍 Format détecté: {:?} (fuseau source: {})",
+            format,
+            timezone
+        );
         tracing::debug!("📋 Headers: {:?}", headers);
 
-        // Pré-allouer une capacité raisonnable pour éviter les réallocations fréquentes (ex: 1 million)
-        // Un fichier de 400MB contient environ 5-6M de lignes.
-        let mut candles = Vec::with_capacity(1_000_000);
-        
+        let mut candles = Vec::with_capacity(BATCH_SIZE);
         let mut line_number = 1; // Commence à 1 (après header)
 
         for result in reader.records() {
@@ -104,26 +229,33 @@ impl PairDataConverter {
                 Err(e) => {
                     // Loguer seulement 1 erreur sur 1000 pour éviter de spammer les logs
                     if line_number % 1000 == 0 {
-                         tracing::warn!("⚠️ Ligne {} ignorée (erreur CSV): {}", line_number, e);
+                        tracing::warn!("⚠️ Ligne {} ignorée (erreur CSV): {}", line_number, e);
                     }
                     continue;
                 }
             };
 
-            match FormatParsers::parse_record(&record, &format, &headers) {
+            match FormatParsers::parse_record(&record, &format, &headers, &timezone) {
                 Ok(candle) => candles.push(candle),
                 Err(e) => tracing::warn!("⚠️ Ligne {} ignorée (parsing): {}", line_number, e),
             }
+            if candles.len() >= BATCH_SIZE {
+                let batch = std::mem::replace(&mut candles, Vec::with_capacity(BATCH_SIZE));
+                emit(batch, ctrl)?;
+            }
         }
-
-        if candles.is_empty() {
-            return Err("Aucune donnée valide trouvée dans le fichier".to_string());
+        if !candles.is_empty() {
+            emit(candles, ctrl)?;
         }
 
-        // Trier par timestamp
-        candles.sort_by_key(|c| c.timestamp);
+        Self::non_empty(rows, 0)
+    }
 
-        Ok(candles)
+    fn non_empty(rows: usize, late_ticks: usize) -> Result<StreamedRows, String> {
+        if rows == 0 {
+            return Err("Aucune donnée valide trouvée dans le fichier".to_string());
+        }
+        Ok(StreamedRows { rows, late_ticks })
     }
 
     /// Extrait les métadonnées depuis les candles
@@ -139,11 +271,16 @@ impl PairDataConverter {
         MetadataExtractor::generate_filename(metadata)
     }
 
-    /// Sauvegarde les candles normalisées dans un fichier CSV
-    pub fn save_normalized_csv(
+    /// Crée un CSV normalisé (headers écrits), à remplir par lots
+    pub fn create_normalized_csv(output_path: &Path) -> Result<csv::Writer<File>, String> {
+        MetadataExtractor::create_csv(output_path)
+    }
+
+    /// Ajoute un lot de candles normalisées au CSV
+    pub fn append_normalized_csv(
+        writer: &mut csv::Writer<File>,
         candles: &[NormalizedCandle],
-        output_path: &Path,
     ) -> Result<(), String> {
-        MetadataExtractor::save_to_csv(candles, output_path)
+        MetadataExtractor::append_to_csv(writer, candles)
     }
 }
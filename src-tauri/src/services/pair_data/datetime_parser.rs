@@ -1,14 +1,21 @@
 // services/pair_data/datetime_parser.rs - Parser de dates/timestamps
 // Conforme .clinerules : < 100L, pas d'unwrap()
 
-use chrono::{DateTime, NaiveDateTime, Utc};
+use crate::services::source_timezone::SourceTimezone;
+use chrono::NaiveDateTime;
 
 /// Parser de dates et timestamps
 pub(super) struct DateTimeParser;
 
 impl DateTimeParser {
-    /// Parse une datetime string en timestamp Unix
+    /// Parse une datetime string (supposée UTC) en timestamp Unix
+    #[cfg(test)]
     pub(super) fn parse(datetime_str: &str) -> Result<i64, String> {
+        Self::parse_in(datetime_str, &SourceTimezone::Utc)
+    }
+
+    /// Parse une datetime string exprimée dans le fuseau `timezone` en timestamp Unix UTC
+    pub(super) fn parse_in(datetime_str: &str, timezone: &SourceTimezone) -> Result<i64, String> {
         // Format: 2024.01.01 12:00 ou 2024-01-01 12:00:00
         let cleaned = datetime_str.replace(".", "-").replace("/", "-");
 
@@ -24,15 +31,13 @@ impl DateTimeParser {
 
         for format in formats {
             if let Ok(dt) = NaiveDateTime::parse_from_str(&cleaned, format) {
-                let datetime_utc = DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc);
-                return Ok(datetime_utc.timestamp());
+                return timezone.to_utc(dt).map(|utc| utc.timestamp());
             }
             
             // Essayer comme NaiveDate si NaiveDateTime échoue
             if let Ok(d) = chrono::NaiveDate::parse_from_str(&cleaned, format) {
                 if let Some(dt) = d.and_hms_opt(0, 0, 0) {
-                    let datetime_utc = DateTime::<Utc>::from_naive_utc_and_offset(dt, Utc);
-                    return Ok(datetime_utc.timestamp());
+                    return timezone.to_utc(dt).map(|utc| utc.timestamp());
                 }
             }
         }
@@ -52,6 +57,14 @@ mod tests {
         assert!(DateTimeParser::parse("2024-01-01").is_ok());
     }
 
+    #[test]
+    fn test_parse_in_source_timezone() {
+        let athens = SourceTimezone::parse("Europe/Athens").expect("fuseau");
+        let utc = DateTimeParser::parse("2024-07-01 09:00:00").expect("utc");
+        let local = DateTimeParser::parse_in("2024-07-01 12:00:00", &athens).expect("athens");
+        assert_eq!(local, utc);
+    }
+
     #[test]
     fn test_parse_datetime_invalid() {
         assert!(DateTimeParser::parse("invalid").is_err());
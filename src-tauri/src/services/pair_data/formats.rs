@@ -3,6 +3,7 @@
 
 use super::datetime_parser::DateTimeParser;
 use super::types::{CsvFormat, NormalizedCandle};
+use crate::services::source_timezone::SourceTimezone;
 
 /// Parseurs pour différents formats CSV
 pub(super) struct FormatParsers;
@@ -15,22 +16,31 @@ impl FormatParsers {
             .map_err(|_| format!("Nombre invalide: {}", s))
     }
 
-    /// Parse une ligne selon le format détecté
+    /// Parse une ligne selon le format détecté (dates exprimées dans `timezone`)
     pub(super) fn parse_record(
         record: &csv::StringRecord,
         format: &CsvFormat,
         headers: &[String],
+        timezone: &SourceTimezone,
     ) -> Result<NormalizedCandle, String> {
         match format {
-            CsvFormat::MetaTrader => Self::parse_metatrader(record),
-            CsvFormat::TradingView => Self::parse_tradingview(record),
-            CsvFormat::Dukascopy => Self::parse_dukascopy(record),
-            CsvFormat::Generic => Self::parse_generic(record, headers),
+            CsvFormat::MetaTrader => Self::parse_metatrader(record, timezone),
+            CsvFormat::TradingView => Self::parse_tradingview(record, timezone),
+            CsvFormat::Dukascopy => Self::parse_dukascopy(record, timezone),
+            CsvFormat::Generic => Self::parse_generic(record, headers, timezone),
+            // Lus par broker_formats (détection sur le contenu)
+            CsvFormat::HistDataM1
+            | CsvFormat::HistDataTick
+            | CsvFormat::Mt5Bars
+            | CsvFormat::Mt5Ticks => Err(format!("Format {:?} lu par broker_formats", format)),
         }
     }
 
     /// Parse format MetaTrader: Date,Time,Open,High,Low,Close,Volume
-    fn parse_metatrader(record: &csv::StringRecord) -> Result<NormalizedCandle, String> {
+    fn parse_metatrader(
+        record: &csv::StringRecord,
+        timezone: &SourceTimezone,
+    ) -> Result<NormalizedCandle, String> {
         if record.len() < 7 {
             return Err("Ligne incomplète".to_string());
         }
@@ -40,7 +50,7 @@ impl FormatParsers {
         let datetime_str = format!("{} {}", date, time);
 
         // Formats possibles: "2024.01.01 12:00" ou "2024-01-01 12:00:00"
-        let timestamp = DateTimeParser::parse(&datetime_str)?;
+        let timestamp = DateTimeParser::parse_in(&datetime_str, timezone)?;
 
         Ok(NormalizedCandle {
             timestamp,
@@ -50,20 +60,24 @@ impl FormatParsers {
             close: Self::parse_decimal(record.get(5).ok_or("Close column missing")?)?,
             volume: Self::parse_decimal(record.get(6).ok_or("Volume column missing")?)
                 .unwrap_or(0.0),
+            quotes: None,
         })
     }
 
     /// Parse format TradingView: time,open,high,low,close,volume
-    fn parse_tradingview(record: &csv::StringRecord) -> Result<NormalizedCandle, String> {
+    fn parse_tradingview(
+        record: &csv::StringRecord,
+        timezone: &SourceTimezone,
+    ) -> Result<NormalizedCandle, String> {
         if record.len() < 6 {
             return Err("Ligne incomplète".to_string());
         }
 
         let time_str = record.get(0).ok_or("Time manquante")?;
 
-        // TradingView peut utiliser Unix timestamp ou datetime
+        // TradingView peut utiliser Unix timestamp (toujours UTC) ou datetime
         let timestamp = if time_str.contains("-") || time_str.contains("/") {
-            DateTimeParser::parse(time_str)?
+            DateTimeParser::parse_in(time_str, timezone)?
         } else {
             time_str.parse::<i64>().map_err(|_| "Timestamp invalide")?
         };
@@ -76,17 +90,21 @@ impl FormatParsers {
             close: Self::parse_decimal(record.get(4).ok_or("Close column missing")?)?,
             volume: Self::parse_decimal(record.get(5).ok_or("Volume column missing")?)
                 .unwrap_or(0.0),
+            quotes: None,
         })
     }
 
     /// Parse format Dukascopy: Gmt time,Open,High,Low,Close,Volume
-    fn parse_dukascopy(record: &csv::StringRecord) -> Result<NormalizedCandle, String> {
+    fn parse_dukascopy(
+        record: &csv::StringRecord,
+        timezone: &SourceTimezone,
+    ) -> Result<NormalizedCandle, String> {
         if record.len() < 6 {
             return Err("Ligne incomplète".to_string());
         }
 
         let time_str = record.get(0).ok_or("Gmt time manquante")?;
-        let timestamp = DateTimeParser::parse(time_str)?;
+        let timestamp = DateTimeParser::parse_in(time_str, timezone)?;
 
         Ok(NormalizedCandle {
             timestamp,
@@ -96,6 +114,7 @@ impl FormatParsers {
             close: Self::parse_decimal(record.get(4).ok_or("Close column missing")?)?,
             volume: Self::parse_decimal(record.get(5).ok_or("Volume column missing")?)
                 .unwrap_or(0.0),
+            quotes: None,
         })
     }
 
@@ -103,6 +122,7 @@ impl FormatParsers {
     fn parse_generic(
         record: &csv::StringRecord,
         headers: &[String],
+        timezone: &SourceTimezone,
     ) -> Result<NormalizedCandle, String> {
         let headers_lower: Vec<String> = headers.iter().map(|h| h.to_lowercase()).collect();
 
@@ -130,7 +150,7 @@ impl FormatParsers {
         let volume_idx = headers_lower.iter().position(|h| h.contains("volume"));
 
         let time_str = record.get(time_idx).ok_or("Timestamp manquant")?;
-        let timestamp = DateTimeParser::parse(time_str)?;
+        let timestamp = DateTimeParser::parse_in(time_str, timezone)?;
 
         Ok(NormalizedCandle {
             timestamp,
@@ -141,6 +161,7 @@ impl FormatParsers {
             volume: volume_idx
                 .and_then(|idx| record.get(idx).and_then(|v| Self::parse_decimal(v).ok()))
                 .unwrap_or(0.0),
+            quotes: None,
         })
     }
 }
@@ -1,8 +1,10 @@
 // services/pair_data/metadata.rs - Extraction et génération de métadonnées
 // Conforme .clinerules : < 150L, pas d'unwrap()
 
+use super::symbol_aliases::canonical_symbol;
 use super::types::{NormalizedCandle, PairMetadata};
 use chrono::{DateTime, Utc};
+use std::fs::File;
 use std::path::Path;
 
 /// Extracteur de métadonnées de paires
@@ -18,8 +20,8 @@ impl MetadataExtractor {
             return Err("Pas de données pour extraire les métadonnées".to_string());
         }
 
-        // Extraire la paire depuis le nom de fichier
-        let pair = Self::extract_pair_from_filename(original_filename);
+        // Extraire la paire depuis le nom de fichier (forme canonique : EURUSD.m → EURUSD)
+        let pair = canonical_symbol(&Self::extract_pair_from_filename(original_filename));
 
         // Détecter le timeframe
         let timeframe = Self::detect_timeframe(candles)?;
@@ -54,6 +56,13 @@ impl MetadataExtractor {
         // Enlever l'extension
         let name = filename.replace(".csv", "").replace(".CSV", "");
 
+        // HistData : DAT_ASCII_EURUSD_M1_2024 / DAT_ASCII_EURUSD_T_202401
+        if let Some(rest) = name.strip_prefix("DAT_") {
+            if let Some(symbol) = rest.split('_').nth(1).filter(|s| s.len() >= 6) {
+                return symbol.to_uppercase();
+            }
+        }
+
         // Extraire les majuscules (paires forex typiquement en majuscules)
         let uppercase: String = name.chars().filter(|c| c.is_uppercase()).collect();
 
@@ -107,11 +116,8 @@ impl MetadataExtractor {
         )
     }
 
-    /// Sauvegarde les candles normalisées dans un fichier CSV
-    pub(super) fn save_to_csv(
-        candles: &[NormalizedCandle],
-        output_path: &Path,
-    ) -> Result<(), String> {
+    /// Crée un CSV normalisé (headers écrits), rempli ensuite par lots
+    pub(super) fn create_csv(output_path: &Path) -> Result<csv::Writer<File>, String> {
         let mut writer = csv::Writer::from_path(output_path)
             .map_err(|e| format!("Erreur création CSV: {}", e))?;
 
@@ -120,7 +126,14 @@ impl MetadataExtractor {
             .write_record(["timestamp", "open", "high", "low", "close", "volume"])
             .map_err(|e| format!("Erreur écriture headers: {}", e))?;
 
-        // Écrire les données
+        Ok(writer)
+    }
+
+    /// Ajoute des candles normalisées à un CSV ouvert par `create_csv`
+    pub(super) fn append_to_csv(
+        writer: &mut csv::Writer<File>,
+        candles: &[NormalizedCandle],
+    ) -> Result<(), String> {
         for candle in candles {
             writer
                 .write_record(&[
@@ -133,9 +146,6 @@ impl MetadataExtractor {
                 ])
                 .map_err(|e| format!("Erreur écriture ligne: {}", e))?;
         }
-
-        writer.flush().map_err(|e| format!("Erreur flush: {}", e))?;
-
         Ok(())
     }
 }
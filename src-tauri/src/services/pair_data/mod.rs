@@ -1,14 +1,19 @@
 // services/pair_data/mod.rs - Module de conversion de données de paires
 // Conforme .clinerules : structure modulaire < 300L
 
+mod broker_formats;
+mod broker_ticks;
 mod converter;
 mod datetime_parser;
 mod formats;
 mod metadata;
 pub mod symbol_properties;
 pub mod conversion_db;
+pub mod symbol_aliases;
 mod types;
+mod utf16_reader;
 
 // Ré-exports publics
 pub use converter::PairDataConverter;
+pub use types::{NormalizedCandle, PairMetadata, StreamedRows};
 pub use symbol_properties::*;
@@ -0,0 +1,221 @@
+// services/pair_data/symbol_aliases.rs
+// Résolution des symboles broker (EURUSD.m, EURUSDpro, #US30...) vers un symbole
+// canonique : règles utilisateur de symbol_aliases, puis décorations connues.
+
+use super::symbol_properties::pairs_pool;
+use crate::models::asset_class::strip_broker_decorations;
+use crate::models::symbol_conversion::{AliasMatchKind, SymbolAlias};
+use crate::services::pair_data::conversion_db;
+use regex::{Regex, RegexBuilder};
+use std::sync::{Arc, RwLock};
+
+/// Règles compilées, rechargées depuis la DB après chaque modification
+static ALIAS_RULES: RwLock<Option<Arc<Vec<AliasRule>>>> = RwLock::new(None);
+
+/// Règle d'alias prête à l'emploi (regex compilée une seule fois)
+pub struct AliasRule {
+    alias: SymbolAlias,
+    regex: Option<Regex>,
+}
+
+impl AliasRule {
+    pub fn compile(alias: SymbolAlias) -> Result<Self, String> {
+        let regex = match alias.match_kind {
+            AliasMatchKind::Regex => Some(
+                RegexBuilder::new(&alias.pattern)
+                    .case_insensitive(true)
+                    .build()
+                    .map_err(|e| {
+                        format!("Expression régulière invalide '{}' : {}", alias.pattern, e)
+                    })?,
+            ),
+            _ => None,
+        };
+        Ok(Self { alias, regex })
+    }
+
+    /// Symbole obtenu si la règle s'applique (symbole déjà en majuscules)
+    fn apply(&self, symbol: &str) -> Option<String> {
+        let canonical = &self.alias.canonical;
+        // Préfixe/suffixe sans cible : simple retrait du motif
+        let affix = |rest: &str| {
+            if canonical.is_empty() {
+                rest.to_string()
+            } else {
+                canonical.clone()
+            }
+        };
+        let mapped = match self.alias.match_kind {
+            AliasMatchKind::Exact => (symbol == self.alias.pattern).then(|| canonical.clone()),
+            AliasMatchKind::Prefix => symbol.strip_prefix(self.alias.pattern.as_str()).map(affix),
+            AliasMatchKind::Suffix => symbol.strip_suffix(self.alias.pattern.as_str()).map(affix),
+            AliasMatchKind::Regex => self.regex.as_ref()?.captures(symbol).map(|caps| {
+                let mut expanded = String::new();
+                caps.expand(canonical, &mut expanded);
+                expanded
+            }),
+        }?;
+        let mapped = mapped.trim().to_uppercase();
+        (!mapped.is_empty()).then_some(mapped)
+    }
+}
+
+/// Normalise et valide une règle avant enregistrement
+pub fn validate_alias(mut alias: SymbolAlias) -> Result<SymbolAlias, String> {
+    alias.pattern = alias.pattern.trim().to_string();
+    alias.canonical = alias.canonical.trim().to_uppercase();
+    if alias.pattern.is_empty() {
+        return Err("Le motif de l'alias est vide".to_string());
+    }
+    match alias.match_kind {
+        AliasMatchKind::Regex => {
+            AliasRule::compile(alias.clone())?;
+        }
+        _ => alias.pattern = alias.pattern.to_uppercase(),
+    }
+    if alias.canonical.is_empty()
+        && matches!(
+            alias.match_kind,
+            AliasMatchKind::Exact | AliasMatchKind::Regex
+        )
+    {
+        return Err("Symbole canonique requis pour une règle exacte ou regex".to_string());
+    }
+    Ok(alias)
+}
+
+/// Résout un symbole avec les règles fournies : d'abord sur le symbole brut, puis
+/// une fois les décorations broker retirées (une règle DE40 → GER40 couvre DE40.cash).
+pub fn resolve_with_rules(symbol: &str, rules: &[AliasRule]) -> String {
+    let upper = symbol.trim().to_uppercase();
+    let apply_rules = |s: &str| rules.iter().find_map(|rule| rule.apply(s));
+
+    if let Some(mapped) = apply_rules(&upper) {
+        return strip_broker_decorations(&mapped);
+    }
+    let stripped = strip_broker_decorations(&upper);
+    match apply_rules(&stripped) {
+        Some(mapped) => strip_broker_decorations(&mapped),
+        None => stripped,
+    }
+}
+
+/// Symbole canonique sous lequel une série est stockée et recherchée
+pub fn canonical_symbol(symbol: &str) -> String {
+    resolve_with_rules(symbol, &loaded_rules())
+}
+
+/// Force le rechargement des règles (après ajout/suppression d'un alias)
+pub fn invalidate_alias_cache() {
+    if let Ok(mut guard) = ALIAS_RULES.write() {
+        *guard = None;
+    }
+}
+
+fn loaded_rules() -> Arc<Vec<AliasRule>> {
+    if let Some(rules) = ALIAS_RULES.read().ok().and_then(|guard| guard.clone()) {
+        return rules;
+    }
+    // Pool pas encore enregistré : décorations connues seulement, sans mise en cache
+    let Some(pool) = pairs_pool() else {
+        return Arc::new(Vec::new());
+    };
+    let aliases = match conversion_db::get_all_aliases(pool) {
+        Ok(aliases) => aliases,
+        Err(e) => {
+            tracing::warn!("⚠️ Lecture des alias de symboles impossible : {}", e);
+            return Arc::new(Vec::new());
+        }
+    };
+
+    let rules: Vec<AliasRule> = aliases
+        .into_iter()
+        .filter_map(|alias| match AliasRule::compile(alias) {
+            Ok(rule) => Some(rule),
+            Err(e) => {
+                tracing::warn!("⚠️ Alias ignoré : {}", e);
+                None
+            }
+        })
+        .collect();
+    let rules = Arc::new(rules);
+    if let Ok(mut guard) = ALIAS_RULES.write() {
+        *guard = Some(rules.clone());
+    }
+    rules
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rule(pattern: &str, match_kind: AliasMatchKind, canonical: &str) -> AliasRule {
+        let alias = validate_alias(SymbolAlias {
+            id: None,
+            pattern: pattern.to_string(),
+            match_kind,
+            canonical: canonical.to_string(),
+            priority: 0,
+        })
+        .expect("alias valide");
+        AliasRule::compile(alias).expect("règle compilée")
+    }
+
+    #[test]
+    fn test_decorations_connues_sans_regle() {
+        assert_eq!(resolve_with_rules("EURUSD.m", &[]), "EURUSD");
+        assert_eq!(resolve_with_rules("EURUSDpro", &[]), "EURUSD");
+        assert_eq!(resolve_with_rules("GER40.cash", &[]), "GER40");
+        assert_eq!(resolve_with_rules("#US30", &[]), "US30");
+    }
+
+    #[test]
+    fn test_regle_exacte_apres_decorations() {
+        let rules = [rule("DE40", AliasMatchKind::Exact, "GER40")];
+        assert_eq!(resolve_with_rules("DE40.cash", &rules), "GER40");
+        assert_eq!(resolve_with_rules("de40", &rules), "GER40");
+        assert_eq!(resolve_with_rules("US30", &rules), "US30");
+    }
+
+    #[test]
+    fn test_regles_suffixe_et_regex() {
+        let rules = [
+            rule("VIP", AliasMatchKind::Suffix, ""),
+            rule(r"^([A-Z]{6})\.A$", AliasMatchKind::Regex, "$1"),
+            rule("^WS30", AliasMatchKind::Regex, "US30"),
+        ];
+        assert_eq!(resolve_with_rules("GBPUSDVIP", &rules), "GBPUSD");
+        assert_eq!(resolve_with_rules("eurjpy.a", &rules), "EURJPY");
+        assert_eq!(resolve_with_rules("WS30.cash", &rules), "US30");
+    }
+
+    #[test]
+    fn test_premiere_regle_gagne() {
+        let rules = [
+            rule("NAS100", AliasMatchKind::Exact, "USATEC"),
+            rule("NAS", AliasMatchKind::Prefix, "US100"),
+        ];
+        assert_eq!(resolve_with_rules("NAS100", &rules), "USATEC");
+    }
+
+    #[test]
+    fn test_validation_alias() {
+        let invalid = SymbolAlias {
+            id: None,
+            pattern: "([A-Z".to_string(),
+            match_kind: AliasMatchKind::Regex,
+            canonical: "$1".to_string(),
+            priority: 0,
+        };
+        assert!(validate_alias(invalid).is_err());
+
+        let sans_cible = SymbolAlias {
+            id: None,
+            pattern: "DE40".to_string(),
+            match_kind: AliasMatchKind::Exact,
+            canonical: " ".to_string(),
+            priority: 0,
+        };
+        assert!(validate_alias(sans_cible).is_err());
+    }
+}
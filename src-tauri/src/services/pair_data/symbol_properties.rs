@@ -4,7 +4,9 @@
 
 use crate::db::DbPool;
 use crate::models::AssetProperties;
+use crate::services::instrument_registry;
 use crate::services::pair_data::conversion_db;
+use crate::services::pair_data::symbol_aliases::canonical_symbol;
 use std::sync::OnceLock;
 
 /// Pool global pour accéder aux conversions DB depuis n'importe quel service
@@ -16,13 +18,22 @@ pub fn set_pairs_pool(pool: DbPool) {
     tracing::info!("✅ Pool pairs.db enregistré pour symbol_properties");
 }
 
-/// Retourne les propriétés complètes d'un symbole (DB override > hardcodé)
+/// Pool pairs.db enregistré au démarrage (None dans les tests unitaires)
+pub(crate) fn pairs_pool() -> Option<&'static DbPool> {
+    PAIRS_POOL.get()
+}
+
+/// Retourne les propriétés complètes d'un symbole (DB override > registre instruments)
+/// Le symbole est d'abord ramené à sa forme canonique (EURUSD.m → EURUSD)
 pub fn get_asset_properties(symbol: &str) -> AssetProperties {
+    let symbol = canonical_symbol(symbol);
     let db_override = PAIRS_POOL
         .get()
-        .and_then(|pool| conversion_db::get_conversion_for_symbol(pool, symbol).ok())
+        .and_then(|pool| conversion_db::get_conversion_for_symbol(pool, &symbol).ok())
         .flatten();
-    AssetProperties::from_symbol_with_override(symbol, db_override)
+    instrument_registry::resolve_instrument(&symbol)
+        .asset_properties()
+        .with_override(&symbol, db_override)
 }
 
 /// Retourne la valeur d'un point (Tick Size) pour un symbole donné
@@ -1,15 +1,20 @@
 // services/pair_data/types.rs - Types de données pour conversion CSV
 // Conforme .clinerules : < 50L
 
+use crate::services::tick_aggregator::EnrichedM1;
 use serde::{Deserialize, Serialize};
 
 /// Format CSV détecté
 #[derive(Debug, Clone)]
 pub enum CsvFormat {
-    MetaTrader,  // Date,Time,Open,High,Low,Close,Volume
-    TradingView, // time,open,high,low,close,volume
-    Dukascopy,   // Gmt time,Open,High,Low,Close,Volume
-    Generic,     // Generic CSV with detected columns
+    MetaTrader,   // Date,Time,Open,High,Low,Close,Volume
+    TradingView,  // time,open,high,low,close,volume
+    Dukascopy,    // Gmt time,Open,High,Low,Close,Volume
+    Generic,      // Generic CSV with detected columns
+    HistDataM1,   // 20240102 170000;O;H;L;C;V (sans header, EST sans DST)
+    HistDataTick, // 20240102 170000123,Bid,Ask,Vol (sans header, EST sans DST)
+    Mt5Bars,      // <DATE>\t<TIME>\t<OPEN>… (heure serveur NY-close)
+    Mt5Ticks,     // <DATE>\t<TIME>\t<BID>\t<ASK>… (heure serveur NY-close)
 }
 
 /// Métadonnées d'une paire importée
@@ -31,4 +36,14 @@ pub struct NormalizedCandle {
     pub low: f64,
     pub close: f64,
     pub volume: f64,
+    /// M1 agrégée depuis des ticks (OHLC bid/ask, spread) ; None pour un export de bougies
+    pub quotes: Option<EnrichedM1>,
+}
+
+/// Bilan d'une lecture en continu
+#[derive(Debug, Clone, Copy, Default)]
+pub struct StreamedRows {
+    pub rows: usize,
+    /// Ticks arrivés après la clôture de leur minute (export tick non trié), ignorés
+    pub late_ticks: usize,
 }
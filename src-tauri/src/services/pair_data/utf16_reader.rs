@@ -0,0 +1,132 @@
+// services/pair_data/utf16_reader.rs - Décodage UTF-16 LE en continu (exports MT5)
+// Conforme .clinerules : < 150L, pas d'unwrap()
+//
+// Les exports MT5 sont en UTF-16 LE : le fichier est converti en UTF-8 bloc par bloc
+// pour garder la lecture ligne à ligne (mémoire bornée) des autres formats.
+
+use std::io::{self, Read};
+
+/// Taille des blocs lus dans la source
+const CHUNK_BYTES: usize = 64 * 1024;
+
+/// Lecteur UTF-8 sur une source UTF-16 LE (BOM déjà consommé)
+pub(super) struct Utf16LeReader<R> {
+    inner: R,
+    /// Octets UTF-16 en attente : octet impair ou surrogate haut coupé entre deux blocs
+    raw: Vec<u8>,
+    /// Bloc décodé en UTF-8 et position de lecture
+    decoded: Vec<u8>,
+    pos: usize,
+    eof: bool,
+}
+
+impl<R: Read> Utf16LeReader<R> {
+    pub(super) fn new(inner: R) -> Self {
+        Self {
+            inner,
+            raw: Vec::with_capacity(CHUNK_BYTES + 4),
+            decoded: Vec::new(),
+            pos: 0,
+            eof: false,
+        }
+    }
+
+    /// Lit et décode le bloc suivant de la source
+    fn fill(&mut self) -> io::Result<()> {
+        let start = self.raw.len();
+        self.raw.resize(start + CHUNK_BYTES, 0);
+        let read = self.inner.read(&mut self.raw[start..]);
+        let read = match read {
+            Ok(read) => read,
+            Err(e) => {
+                self.raw.truncate(start);
+                return Err(e);
+            }
+        };
+        self.raw.truncate(start + read);
+        self.eof = read == 0;
+
+        let mut units: Vec<u16> = self
+            .raw
+            .chunks_exact(2)
+            .map(|pair| u16::from_le_bytes([pair[0], pair[1]]))
+            .collect();
+        let mut kept = self.raw.len() % 2;
+        // Surrogate haut en fin de bloc : sa moitié basse arrive avec le bloc suivant
+        if !self.eof && units.last().is_some_and(|u| (0xD800..0xDC00).contains(u)) {
+            units.pop();
+            kept += 2;
+        }
+
+        self.decoded.clear();
+        self.pos = 0;
+        for c in char::decode_utf16(units) {
+            let c = c.map_err(|e| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("UTF-16 invalide: {}", e),
+                )
+            })?;
+            let mut buf = [0u8; 4];
+            self.decoded
+                .extend_from_slice(c.encode_utf8(&mut buf).as_bytes());
+        }
+        let consumed = self.raw.len() - kept;
+        self.raw.drain(..consumed);
+        if self.eof {
+            // Octet impair final ignoré
+            self.raw.clear();
+        }
+        Ok(())
+    }
+}
+
+impl<R: Read> Read for Utf16LeReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.pos >= self.decoded.len() {
+            if self.eof {
+                return Ok(0);
+            }
+            self.fill()?;
+        }
+        let n = buf.len().min(self.decoded.len() - self.pos);
+        buf[..n].copy_from_slice(&self.decoded[self.pos..self.pos + n]);
+        self.pos += n;
+        Ok(n)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Source rendant 3 octets par lecture : unités et surrogates coupés entre blocs
+    struct Trickle<'a>(&'a [u8]);
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            let n = buf.len().min(3).min(self.0.len());
+            buf[..n].copy_from_slice(&self.0[..n]);
+            self.0 = &self.0[n..];
+            Ok(n)
+        }
+    }
+
+    #[test]
+    fn test_decodes_across_chunk_boundaries() {
+        let text = "<DATE>\t<TIME>\t<BID>\n2024.01.02\t00:00:01\t1.1042 €😀\n";
+        let bytes: Vec<u8> = text.encode_utf16().flat_map(u16::to_le_bytes).collect();
+
+        let mut decoded = String::new();
+        Utf16LeReader::new(Trickle(&bytes))
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, text);
+
+        // Surrogate orphelin rejeté
+        let mut invalid = String::new();
+        assert!(Utf16LeReader::new(&[0x00, 0xD8, 0x41, 0x00][..])
+            .read_to_string(&mut invalid)
+            .is_err());
+    }
+}
@@ -0,0 +1,384 @@
+// services/parquet_io.rs - Export/import Parquet de l'historique candle_data
+// Un fichier = une paire + un timeframe. Colonnes : symbol, timeframe, time (Datetime ms, UTC),
+// OHLCV, spread_*, tick_count, bid_*/ask_*. Les colonnes optionnelles absentes deviennent NULL.
+
+use crate::models::Candle;
+use crate::services::candle_resampler::timeframe_seconds;
+use crate::services::pair_data::symbol_aliases::canonical_symbol;
+use chrono::{DateTime, Utc};
+use polars::prelude::*;
+use std::fs::File;
+use std::path::Path;
+
+/// Colonnes f64 optionnelles (NULL pour les bougies sans données tick)
+const OPTIONAL_F64_COLUMNS: [&str; 13] = [
+    "spread_open",
+    "spread_high",
+    "spread_low",
+    "spread_close",
+    "spread_mean",
+    "bid_open",
+    "bid_high",
+    "bid_low",
+    "bid_close",
+    "ask_open",
+    "ask_high",
+    "ask_low",
+    "ask_close",
+];
+
+/// Contenu d'un fichier Parquet relu
+#[derive(Debug, Clone)]
+pub struct ParquetCandles {
+    pub symbol: String,
+    pub timeframe: String,
+    pub candles: Vec<Candle>,
+    /// Lignes ignorées : horodatage/OHLC absent ou OHLC incohérent
+    pub rejected_rows: usize,
+}
+
+fn erreur_polars(contexte: &str) -> impl Fn(PolarsError) -> String + '_ {
+    move |e| format!("{} : {}", contexte, e)
+}
+
+fn champ_optionnel(c: &Candle, colonne: &str) -> Option<f64> {
+    match colonne {
+        "spread_open" => c.spread_open,
+        "spread_high" => c.spread_high,
+        "spread_low" => c.spread_low,
+        "spread_close" => c.spread_close,
+        "spread_mean" => c.spread_mean,
+        "bid_open" => c.bid_open,
+        "bid_high" => c.bid_high,
+        "bid_low" => c.bid_low,
+        "bid_close" => c.bid_close,
+        "ask_open" => c.ask_open,
+        "ask_high" => c.ask_high,
+        "ask_low" => c.ask_low,
+        "ask_close" => c.ask_close,
+        _ => None,
+    }
+}
+
+fn affecter_optionnel(c: &mut Candle, colonne: &str, valeur: Option<f64>) {
+    let champ = match colonne {
+        "spread_open" => &mut c.spread_open,
+        "spread_high" => &mut c.spread_high,
+        "spread_low" => &mut c.spread_low,
+        "spread_close" => &mut c.spread_close,
+        "spread_mean" => &mut c.spread_mean,
+        "bid_open" => &mut c.bid_open,
+        "bid_high" => &mut c.bid_high,
+        "bid_low" => &mut c.bid_low,
+        "bid_close" => &mut c.bid_close,
+        "ask_open" => &mut c.ask_open,
+        "ask_high" => &mut c.ask_high,
+        "ask_low" => &mut c.ask_low,
+        "ask_close" => &mut c.ask_close,
+        _ => return,
+    };
+    *champ = valeur;
+}
+
+/// Construit le DataFrame d'export (ordre des bougies conservé)
+pub fn candles_to_dataframe(
+    symbol: &str,
+    timeframe: &str,
+    candles: &[Candle],
+) -> Result<DataFrame, String> {
+    let n = candles.len();
+    let millis: Vec<i64> = candles
+        .iter()
+        .map(|c| c.datetime.timestamp_millis())
+        .collect();
+    let time = Series::new("time".into(), millis)
+        .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+        .map_err(erreur_polars("Colonne time"))?;
+
+    let f64_col = |name: &str, f: fn(&Candle) -> f64| {
+        Series::new(name.into(), candles.iter().map(f).collect::<Vec<f64>>())
+    };
+
+    let mut columns: Vec<Column> = vec![
+        Series::new("symbol".into(), vec![symbol; n]).into(),
+        Series::new("timeframe".into(), vec![timeframe; n]).into(),
+        time.into(),
+        f64_col("open", |c| c.open).into(),
+        f64_col("high", |c| c.high).into(),
+        f64_col("low", |c| c.low).into(),
+        f64_col("close", |c| c.close).into(),
+        f64_col("volume", |c| c.volume).into(),
+        Series::new(
+            "tick_count".into(),
+            candles
+                .iter()
+                .map(|c| c.tick_count)
+                .collect::<Vec<Option<i32>>>(),
+        )
+        .into(),
+    ];
+    for name in OPTIONAL_F64_COLUMNS {
+        let values: Vec<Option<f64>> = candles.iter().map(|c| champ_optionnel(c, name)).collect();
+        columns.push(Series::new(name.into(), values).into());
+    }
+
+    DataFrame::new(columns).map_err(erreur_polars("Construction DataFrame"))
+}
+
+/// Écrit les bougies dans un fichier Parquet, retourne le nombre de lignes écrites
+pub fn exporter_parquet(
+    path: &Path,
+    symbol: &str,
+    timeframe: &str,
+    candles: &[Candle],
+) -> Result<usize, String> {
+    let mut df = candles_to_dataframe(symbol, timeframe, candles)?;
+    let file = File::create(path)
+        .map_err(|e| format!("Création {} impossible : {}", path.display(), e))?;
+    ParquetWriter::new(file)
+        .finish(&mut df)
+        .map_err(erreur_polars("Écriture Parquet"))?;
+    Ok(df.height())
+}
+
+/// Série d'une colonne (None si absente)
+fn serie<'a>(df: &'a DataFrame, colonne: &str) -> Option<&'a Series> {
+    df.column(colonne).ok().map(|c| c.as_materialized_series())
+}
+
+/// Valeur unique d'une colonne texte (une paire/un timeframe par fichier)
+fn valeur_unique(df: &DataFrame, colonne: &str) -> Result<String, String> {
+    let col = serie(df, colonne).ok_or_else(|| format!("Colonne {} absente", colonne))?;
+    let values = col.str().map_err(erreur_polars(colonne))?;
+    let mut distinct = values.into_iter().flatten();
+    let first = distinct
+        .next()
+        .ok_or_else(|| format!("Colonne {} vide", colonne))?;
+    if distinct.any(|v| v != first) {
+        return Err(format!(
+            "Plusieurs valeurs dans la colonne {} : un fichier par paire/timeframe",
+            colonne
+        ));
+    }
+    Ok(first.to_string())
+}
+
+/// Colonne numérique convertie en f64 (None si absente)
+fn colonne_f64(df: &DataFrame, colonne: &str) -> Result<Option<Vec<Option<f64>>>, String> {
+    let Some(col) = serie(df, colonne) else {
+        return Ok(None);
+    };
+    let casted = col
+        .cast(&DataType::Float64)
+        .map_err(erreur_polars(colonne))?;
+    let values = casted.f64().map_err(erreur_polars(colonne))?;
+    Ok(Some(values.into_iter().collect()))
+}
+
+/// Horodatages en millisecondes : Datetime (toute unité, lu comme UTC) ou texte RFC 3339
+fn colonne_time(df: &DataFrame) -> Result<Vec<Option<i64>>, String> {
+    let col = serie(df, "time").ok_or_else(|| "Colonne time absente".to_string())?;
+    match col.dtype() {
+        DataType::Datetime(_, _) => {
+            let millis = col
+                .cast(&DataType::Datetime(TimeUnit::Milliseconds, None))
+                .and_then(|c| c.cast(&DataType::Int64))
+                .map_err(erreur_polars("Colonne time"))?;
+            let values = millis.i64().map_err(erreur_polars("Colonne time"))?;
+            Ok(values.into_iter().collect())
+        }
+        DataType::String => {
+            let values = col.str().map_err(erreur_polars("Colonne time"))?;
+            Ok(values
+                .into_iter()
+                .map(|s| {
+                    s.and_then(|s| DateTime::parse_from_rfc3339(s).ok())
+                        .map(|d| d.timestamp_millis())
+                })
+                .collect())
+        }
+        other => Err(format!(
+            "Colonne time de type {} non supporté (Datetime ou texte RFC 3339 attendu)",
+            other
+        )),
+    }
+}
+
+/// Timeframe déclaré par le fichier : connu et cohérent avec l'écart minimal entre bougies
+fn verifier_timeframe(timeframe: &str, candles: &[Candle]) -> Result<(), String> {
+    let secs = timeframe_seconds(timeframe).ok_or_else(|| {
+        format!(
+            "Timeframe '{}' non supporté (M1, M5, M15, H1, H4 ou D1 attendu)",
+            timeframe
+        )
+    })?;
+    let ecart_min = candles
+        .windows(2)
+        .map(|w| (w[1].datetime - w[0].datetime).num_seconds())
+        .filter(|&s| s > 0)
+        .min();
+    match ecart_min {
+        Some(ecart) if ecart < secs => Err(format!(
+            "Timeframe {} incohérent : bougies espacées de {} s",
+            timeframe, ecart
+        )),
+        _ => Ok(()),
+    }
+}
+
+/// Reconstruit les bougies d'un DataFrame ; les lignes sans horodatage, sans OHLC
+/// ou à OHLC incohérent (`Candle::new`) sont ignorées et comptées
+pub fn dataframe_to_candles(df: &DataFrame) -> Result<ParquetCandles, String> {
+    let symbol = canonical_symbol(&valeur_unique(df, "symbol")?);
+    let timeframe = valeur_unique(df, "timeframe")?.trim().to_uppercase();
+    let times = colonne_time(df)?;
+
+    let mut ohlc = Vec::with_capacity(4);
+    for name in ["open", "high", "low", "close"] {
+        ohlc.push(colonne_f64(df, name)?.ok_or_else(|| format!("Colonne {} absente", name))?);
+    }
+    let volume = colonne_f64(df, "volume")?;
+    let tick_count: Option<Vec<Option<i32>>> = match serie(df, "tick_count") {
+        Some(col) => {
+            let casted = col
+                .cast(&DataType::Int32)
+                .map_err(erreur_polars("tick_count"))?;
+            let values = casted.i32().map_err(erreur_polars("tick_count"))?;
+            Some(values.into_iter().collect())
+        }
+        None => None,
+    };
+    let mut optional = Vec::new();
+    for name in OPTIONAL_F64_COLUMNS {
+        if let Some(values) = colonne_f64(df, name)? {
+            optional.push((name, values));
+        }
+    }
+
+    let mut candles = Vec::with_capacity(times.len());
+    let mut rejected_rows = 0;
+    for (i, millis) in times.iter().enumerate() {
+        let Some(datetime) = millis.and_then(DateTime::<Utc>::from_timestamp_millis) else {
+            rejected_rows += 1;
+            continue;
+        };
+        let (Some(open), Some(high), Some(low), Some(close)) =
+            (ohlc[0][i], ohlc[1][i], ohlc[2][i], ohlc[3][i])
+        else {
+            rejected_rows += 1;
+            continue;
+        };
+        let volume = volume.as_ref().and_then(|v| v[i]).unwrap_or(0.0);
+        let Ok(mut candle) = Candle::new(symbol.clone(), datetime, open, high, low, close, volume)
+        else {
+            rejected_rows += 1;
+            continue;
+        };
+        candle.tick_count = tick_count.as_ref().and_then(|v| v[i]);
+        for (name, values) in &optional {
+            affecter_optionnel(&mut candle, name, values[i]);
+        }
+        candles.push(candle);
+    }
+    candles.sort_by_key(|c| c.datetime);
+    verifier_timeframe(&timeframe, &candles)?;
+
+    Ok(ParquetCandles {
+        symbol,
+        timeframe,
+        candles,
+        rejected_rows,
+    })
+}
+
+/// Lit un fichier Parquet exporté (ou produit par un notebook avec le même schéma)
+pub fn importer_parquet(path: &Path) -> Result<ParquetCandles, String> {
+    let file =
+        File::open(path).map_err(|e| format!("Ouverture {} impossible : {}", path.display(), e))?;
+    let df = ParquetReader::new(file)
+        .finish()
+        .map_err(erreur_polars("Lecture Parquet"))?;
+    dataframe_to_candles(&df)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    fn candle(i: i64) -> Candle {
+        let mut c = Candle {
+            symbol: "EURUSD".to_string(),
+            datetime: Utc.with_ymd_and_hms(2025, 3, 3, 10, 0, 0).unwrap() + Duration::minutes(i),
+            open: 1.08,
+            high: 1.0805,
+            low: 1.0795,
+            close: 1.0802,
+            volume: 12.0,
+            ..Default::default()
+        };
+        if i % 2 == 0 {
+            c.spread_mean = Some(0.00008);
+            c.tick_count = Some(40);
+            c.bid_high = Some(1.08046);
+            c.ask_low = Some(1.07954);
+        }
+        c
+    }
+
+    #[test]
+    fn test_round_trip_keeps_tick_columns() {
+        let candles: Vec<Candle> = (0..4).map(candle).collect();
+        let path = std::env::temp_dir().join("parquet_io_round_trip.parquet");
+        assert_eq!(
+            exporter_parquet(&path, "EURUSD", "M1", &candles).unwrap(),
+            4
+        );
+
+        let read = importer_parquet(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(
+            (read.symbol.as_str(), read.timeframe.as_str()),
+            ("EURUSD", "M1")
+        );
+        assert_eq!(read.candles.len(), 4);
+        assert_eq!(read.candles[0].datetime, candles[0].datetime);
+        assert_eq!(read.candles[0].spread_mean, Some(0.00008));
+        assert_eq!(read.candles[0].tick_count, Some(40));
+        assert_eq!(read.candles[2].bid_high, Some(1.08046));
+        assert_eq!(read.candles[1].spread_mean, None);
+        assert_eq!(read.candles[1].ask_low, None);
+    }
+
+    #[test]
+    fn test_inconsistent_ohlc_rows_rejected() {
+        let mut bad = candle(1);
+        bad.high = 1.0790;
+        let df = candles_to_dataframe("eurusd", "m1", &[candle(0), bad, candle(2)]).unwrap();
+        let read = dataframe_to_candles(&df).unwrap();
+        assert_eq!(
+            (read.symbol.as_str(), read.timeframe.as_str()),
+            ("EURUSD", "M1")
+        );
+        assert_eq!(read.candles.len(), 2);
+        assert_eq!(read.rejected_rows, 1);
+    }
+
+    #[test]
+    fn test_timeframe_validated() {
+        let candles: Vec<Candle> = (0..3).map(candle).collect();
+        let unknown = candles_to_dataframe("EURUSD", "M2", &candles).unwrap();
+        assert!(dataframe_to_candles(&unknown).is_err());
+        // Bougies à la minute déclarées H1
+        let mislabelled = candles_to_dataframe("EURUSD", "H1", &candles).unwrap();
+        assert!(dataframe_to_candles(&mislabelled).is_err());
+    }
+
+    #[test]
+    fn test_mixed_symbols_rejected() {
+        let mut df = candles_to_dataframe("EURUSD", "M1", &[candle(0)]).unwrap();
+        let other = candles_to_dataframe("GBPUSD", "M1", &[candle(1)]).unwrap();
+        df.vstack_mut(&other).unwrap();
+        assert!(dataframe_to_candles(&df).is_err());
+    }
+}
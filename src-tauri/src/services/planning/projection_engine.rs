@@ -3,6 +3,7 @@ use crate::models::planning::ProjectedEvent;
 use crate::models::calendar_event::CalendarEvent;
 use crate::models::archive::Archive;
 use crate::services::archive_service::ArchiveService;
+use crate::services::event_catalog;
 use crate::schema::calendar_events;
 use diesel::prelude::*;
 use diesel::SelectableHelper;
@@ -38,7 +39,8 @@ impl ProjectionEngine {
         let mut projected = Vec::new();
 
         for event in events {
-            let occurrence_count = known_events.get(&event.description).cloned().unwrap_or(0);
+            let canonical_id = event_catalog::resolve_event_id(&event.description, &event.symbol);
+            let occurrence_count = known_events.get(&canonical_id).cloned().unwrap_or(0);
             let has_history = occurrence_count > 0;
 
             // Find best matching archive
@@ -66,6 +68,7 @@ impl ProjectionEngine {
         Ok(projected)
     }
 
+    /// Occurrences passées par annonce canonique (toutes variantes d'intitulé confondues)
     fn fetch_known_event_counts(&self) -> Result<std::collections::HashMap<String, i64>, String> {
         use diesel::dsl::count;
         
@@ -75,12 +78,16 @@ impl ProjectionEngine {
         
         let results = calendar_events::table
             .filter(calendar_events::event_time.lt(now))
-            .group_by(calendar_events::description)
-            .select((calendar_events::description, count(calendar_events::id)))
-            .load::<(String, i64)>(&mut conn)
+            .filter(calendar_events::event_id.is_not_null())
+            .group_by(calendar_events::event_id)
+            .select((calendar_events::event_id, count(calendar_events::id)))
+            .load::<(Option<String>, i64)>(&mut conn)
             .map_err(|e| e.to_string())?;
             
-        Ok(results.into_iter().collect())
+        Ok(results
+            .into_iter()
+            .filter_map(|(id, n)| id.map(|id| (id, n)))
+            .collect())
     }
 
     fn fetch_calendar_events(
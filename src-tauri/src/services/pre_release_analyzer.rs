@@ -0,0 +1,372 @@
+// services/pre_release_analyzer.rs - Étude pré-annonce (T-60 à T-5)
+// Dérive du prix, compression de l'ATR et élargissement du spread avant l'annonce :
+// si le mouvement démarre tôt, le straddle doit être placé plus tôt, voire pas du tout.
+// L'activité à l'approche de l'annonce est comparée à la même fenêtre horaire des jours
+// sans annonce : la montée habituelle de la volatilité à cette heure n'est pas un signal.
+
+use crate::models::pre_release::{PreReleaseAdvice, PreReleaseProfile};
+use crate::models::Candle;
+use crate::services::bootstrap::{mean_interval, rate_interval};
+use crate::services::stats::mean;
+use chrono::{DateTime, Duration, NaiveDate, Utc};
+use std::collections::{BTreeSet, HashSet};
+
+/// Début de l'étude avant T0
+pub const PRE_WINDOW_START_MINUTES: i64 = 60;
+/// Fin de l'étude avant T0 (positionnement de dernière minute exclu)
+pub const PRE_WINDOW_END_MINUTES: i64 = 5;
+/// Horizon du mouvement post-annonce servant de référence
+pub const POST_HORIZON_MINUTES: i64 = 15;
+/// Jours précédant chaque annonce dont la même fenêtre horaire sert de référence
+/// (jours d'annonce exclus, comme CandleIndex::get_baseline_candles)
+pub const REFERENCE_DAYS_BACK: i64 = 7;
+/// Séparation base / approche pour la compression d'ATR et de spread
+const APPROACH_MINUTES: i64 = 15;
+/// ATR ou dérive au-dessus de la référence (×) marquant un mouvement anticipé
+const ABOVE_REFERENCE_RATIO: f64 = 1.5;
+/// Bougies minimales sur les 55 minutes étudiées
+const MIN_PRE_CANDLES: usize = 40;
+/// Part du mouvement post-annonce déjà réalisée avant T-5 : mouvement anticipé
+const EARLY_DRIFT_SHARE: f64 = 0.25;
+/// Au-delà, le straddle arrive trop tard
+const SKIP_DRIFT_SHARE: f64 = 0.5;
+
+/// Mesures pré-annonce d'une occurrence
+#[derive(Debug, Clone, PartialEq)]
+pub struct PreReleaseObservation {
+    /// Clôture T-5 - ouverture T-60 (pips)
+    pub drift_pips: f64,
+    /// Clôture T+15 - ouverture T0 (pips), None sans bougies après T0
+    pub post_move_pips: Option<f64>,
+    pub atr_ratio: Option<f64>,
+    pub spread_ratio: Option<f64>,
+    /// True range par minute de T-60 à T-6 (pips), None si la bougie manque
+    pub tr_profile: Vec<Option<f64>>,
+}
+
+/// Mesures d'une occurrence sur bougies M1 couvrant [T-60, T+15).
+/// None si la fenêtre pré-annonce est trop incomplète.
+pub fn mesurer_fenetre(
+    candles: &[Candle],
+    event_time: DateTime<Utc>,
+    pip_value: f64,
+) -> Option<PreReleaseObservation> {
+    if pip_value <= 0.0 {
+        return None;
+    }
+    let start = event_time - Duration::minutes(PRE_WINDOW_START_MINUTES);
+    let end = event_time - Duration::minutes(PRE_WINDOW_END_MINUTES);
+    let approach = event_time - Duration::minutes(APPROACH_MINUTES);
+    let pre: Vec<&Candle> = candles
+        .iter()
+        .filter(|c| c.datetime >= start && c.datetime < end)
+        .collect();
+    if pre.len() < MIN_PRE_CANDLES {
+        return None;
+    }
+    let (first, last) = (pre.first()?, pre.last()?);
+    if first.datetime >= start + Duration::minutes(5) || last.datetime < end - Duration::minutes(2)
+    {
+        return None;
+    }
+
+    let n_minutes = (PRE_WINDOW_START_MINUTES - PRE_WINDOW_END_MINUTES) as usize;
+    let mut tr_profile = vec![None; n_minutes];
+    let (mut base_tr, mut approach_tr) = (Vec::new(), Vec::new());
+    let (mut base_spread, mut approach_spread) = (Vec::new(), Vec::new());
+    for c in &pre {
+        let tr = crate::services::atr::calculate_true_range(c.high, c.low, None) / pip_value;
+        let idx = (c.datetime - start).num_minutes() as usize;
+        if let Some(slot) = tr_profile.get_mut(idx) {
+            *slot = Some(tr);
+        }
+        if c.datetime < approach {
+            base_tr.push(tr);
+            base_spread.extend(c.spread_mean);
+        } else {
+            approach_tr.push(tr);
+            approach_spread.extend(c.spread_mean);
+        }
+    }
+
+    let post_move_pips = {
+        let t0 = candles
+            .iter()
+            .find(|c| c.datetime >= event_time && c.datetime < event_time + Duration::minutes(1));
+        let horizon = event_time + Duration::minutes(POST_HORIZON_MINUTES);
+        let close = candles
+            .iter()
+            .rev()
+            .find(|c| c.datetime < horizon && c.datetime >= horizon - Duration::minutes(2));
+        match (t0, close) {
+            (Some(open), Some(close)) => Some((close.close - open.open) / pip_value),
+            _ => None,
+        }
+    };
+
+    Some(PreReleaseObservation {
+        drift_pips: (last.close - first.open) / pip_value,
+        post_move_pips,
+        atr_ratio: ratio(&approach_tr, &base_tr),
+        spread_ratio: ratio(&approach_spread, &base_spread),
+        tr_profile,
+    })
+}
+
+/// Mesures sur une série M1 triée par date, une fenêtre par date d'annonce
+pub fn mesurer_sur_serie(
+    candles: &[Candle],
+    releases: &[DateTime<Utc>],
+    pip_value: f64,
+) -> Vec<PreReleaseObservation> {
+    releases
+        .iter()
+        .filter_map(|&t0| {
+            let from = t0 - Duration::minutes(PRE_WINDOW_START_MINUTES);
+            let to = t0 + Duration::minutes(POST_HORIZON_MINUTES);
+            let lo = candles.partition_point(|c| c.datetime < from);
+            let hi = candles.partition_point(|c| c.datetime < to);
+            mesurer_fenetre(&candles[lo..hi.max(lo)], t0, pip_value)
+        })
+        .collect()
+}
+
+/// Heures de la même fenêtre sur les `REFERENCE_DAYS_BACK` jours précédant chaque annonce,
+/// hors jours d'annonce (`event_days`), sans doublon
+pub fn heures_reference(
+    releases: &[DateTime<Utc>],
+    event_days: &HashSet<NaiveDate>,
+) -> Vec<DateTime<Utc>> {
+    let times: BTreeSet<DateTime<Utc>> = releases
+        .iter()
+        .flat_map(|&t0| (1..=REFERENCE_DAYS_BACK).map(move |k| t0 - Duration::days(k)))
+        .filter(|t| !event_days.contains(&t.date_naive()))
+        .collect();
+    times.into_iter().collect()
+}
+
+/// Profil agrégé ; `reference` : mêmes mesures sur les jours sans annonce (`heures_reference`).
+/// Sans référence, aucun mouvement anticipé n'est conclu. None sans occurrence exploitable.
+pub fn profil_pre_annonce(
+    observations: &[PreReleaseObservation],
+    reference: &[PreReleaseObservation],
+) -> Option<PreReleaseProfile> {
+    if observations.is_empty() {
+        return None;
+    }
+    let drifts: Vec<f64> = observations.iter().map(|o| o.drift_pips.abs()).collect();
+
+    // Dérive comparée au mouvement qui suit l'annonce
+    let paired: Vec<(f64, f64)> = observations
+        .iter()
+        .filter_map(|o| o.post_move_pips.map(|p| (o.drift_pips, p)))
+        .collect();
+    let aligned = paired.iter().filter(|(d, p)| d * p > 0.0).count();
+    let aligned_drift_rate_ci = rate_interval(aligned, paired.len());
+    let avg_post_move_pips = mean(paired.iter().map(|(_, p)| p.abs()));
+    let drift_share = if avg_post_move_pips > 0.0 {
+        mean(paired.iter().map(|(d, _)| d.abs())) / avg_post_move_pips
+    } else {
+        0.0
+    };
+
+    let atr_profile_pips = profil_moyen(observations);
+    let reference_profile_pips = profil_moyen(reference);
+    let onset_minutes_before = debut_anticipe(&atr_profile_pips, &reference_profile_pips);
+    let avg_drift_pips = mean(drifts.iter().copied());
+    let reference_drift_pips = mean(reference.iter().map(|o| o.drift_pips.abs()));
+    // Dérive plus forte que celle de la même fenêtre un jour sans annonce
+    let drift_above_reference =
+        !reference.is_empty() && avg_drift_pips > reference_drift_pips * ABOVE_REFERENCE_RATIO;
+    let leaks = aligned_drift_rate_ci.lower > 0.5 && drift_above_reference;
+    let early_move = (leaks && drift_share >= EARLY_DRIFT_SHARE) || onset_minutes_before.is_some();
+    let advice = if early_move && leaks && drift_share >= SKIP_DRIFT_SHARE {
+        PreReleaseAdvice::SkipStraddle
+    } else if early_move {
+        PreReleaseAdvice::EnterEarlier
+    } else {
+        PreReleaseAdvice::Normal
+    };
+
+    let atr_ratios: Vec<f64> = observations.iter().filter_map(|o| o.atr_ratio).collect();
+    let spread_ratios: Vec<f64> = observations.iter().filter_map(|o| o.spread_ratio).collect();
+
+    Some(PreReleaseProfile {
+        event_count: observations.len(),
+        reference_count: reference.len(),
+        avg_drift_pips,
+        reference_drift_pips,
+        avg_drift_ci: mean_interval(&drifts),
+        avg_post_move_pips,
+        drift_share,
+        aligned_drift_rate: if paired.is_empty() {
+            0.0
+        } else {
+            aligned as f64 / paired.len() as f64
+        },
+        aligned_drift_rate_ci,
+        atr_compression: if atr_ratios.is_empty() {
+            1.0
+        } else {
+            mean(atr_ratios.iter().copied())
+        },
+        spread_widening: (!spread_ratios.is_empty()).then(|| mean(spread_ratios.iter().copied())),
+        onset_minutes_before,
+        early_move,
+        advice,
+        atr_profile_pips,
+    })
+}
+
+/// True range moyen par minute sur les occurrences disposant de la bougie
+fn profil_moyen(observations: &[PreReleaseObservation]) -> Vec<f64> {
+    let n_minutes = (PRE_WINDOW_START_MINUTES - PRE_WINDOW_END_MINUTES) as usize;
+    (0..n_minutes)
+        .map(|i| {
+            mean(
+                observations
+                    .iter()
+                    .filter_map(|o| o.tr_profile.get(i).copied().flatten()),
+            )
+        })
+        .collect()
+}
+
+/// Minutes avant T0 du début de la dernière séquence d'ATR au-dessus de la référence
+/// (séquence ininterrompue jusqu'à T-6), référence = même minute les jours sans annonce
+fn debut_anticipe(profile: &[f64], reference: &[f64]) -> Option<u32> {
+    if reference.len() != profile.len() {
+        return None;
+    }
+    let run = profile
+        .iter()
+        .zip(reference)
+        .rev()
+        .take_while(|&(&tr, &base)| base > 0.0 && tr >= base * ABOVE_REFERENCE_RATIO)
+        .count();
+    (run > 0)
+        .then(|| (profile.len() - run) as u32)
+        .map(|idx| PRE_WINDOW_START_MINUTES as u32 - idx)
+}
+
+fn ratio(numerator: &[f64], denominator: &[f64]) -> Option<f64> {
+    let denominator = mean(denominator.iter().copied());
+    (!numerator.is_empty() && denominator > 0.0)
+        .then(|| mean(numerator.iter().copied()) / denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::test_fixtures::{m1_at, release_on, PIP};
+
+    /// Bougie de range `range_pips` ; spread doublé sur les 15 dernières minutes
+    fn bar(t0: DateTime<Utc>, minute: i64, open: f64, close: f64, range_pips: f64) -> Candle {
+        let high = open.max(close) + range_pips * PIP / 2.0;
+        let low = open.min(close) - range_pips * PIP / 2.0;
+        Candle {
+            spread_mean: Some(if minute >= -15 { 2.0 * PIP } else { PIP }),
+            ..m1_at(t0, minute, open, high, low, close)
+        }
+    }
+
+    /// Prix plat jusqu'à `drift_from`, puis montée de 1 pip/min (bougies plus larges) ;
+    /// après T0 : +20 pips en 15 minutes
+    fn occurrence(t0: DateTime<Utc>, drift_from: i64) -> Vec<Candle> {
+        let mut price = 1.1000;
+        (-60..15)
+            .map(|m| {
+                let step = if m >= 0 {
+                    20.0 / 15.0
+                } else if m >= drift_from {
+                    1.0
+                } else {
+                    0.0
+                };
+                let open = price;
+                price += step * PIP;
+                let range = if step > 0.0 { 4.0 } else { 1.0 };
+                bar(t0, m, open, price, range)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_occurrence_calme() {
+        let obs = mesurer_fenetre(&occurrence(release_on(2), 0), release_on(2), PIP).unwrap();
+        assert!(obs.drift_pips.abs() < 1e-6);
+        assert!((obs.post_move_pips.unwrap() - 20.0).abs() < 1e-6);
+        assert!((obs.atr_ratio.unwrap() - 1.0).abs() < 1e-6);
+        assert!((obs.spread_ratio.unwrap() - 2.0).abs() < 1e-6);
+
+        let profile = profil_pre_annonce(&[obs.clone(), obs.clone()], &[obs]).unwrap();
+        assert_eq!(profile.onset_minutes_before, None);
+        assert!(!profile.early_move);
+        assert_eq!(profile.advice, PreReleaseAdvice::Normal);
+        assert_eq!(profile.atr_profile_pips.len(), 55);
+    }
+
+    /// Annonces les jours pairs (dérive dès `event_drift_from`), même fenêtre les jours
+    /// impairs sans annonce (dérive dès `quiet_drift_from`) ; observations et référence
+    fn serie(event_drift_from: i64, quiet_drift_from: i64) -> [Vec<PreReleaseObservation>; 2] {
+        let releases: Vec<_> = (1..13).map(|i| release_on(2 * i)).collect();
+        let candles: Vec<Candle> = (1..26)
+            .map(release_on)
+            .flat_map(|t0| {
+                let drift_from = if releases.contains(&t0) {
+                    event_drift_from
+                } else {
+                    quiet_drift_from
+                };
+                occurrence(t0, drift_from)
+            })
+            .collect();
+        let event_days: HashSet<NaiveDate> = releases.iter().map(|t| t.date_naive()).collect();
+        let reference = heures_reference(&releases, &event_days);
+        assert!(reference
+            .iter()
+            .all(|t| !event_days.contains(&t.date_naive())));
+        [
+            mesurer_sur_serie(&candles, &releases, PIP),
+            mesurer_sur_serie(&candles, &reference, PIP),
+        ]
+    }
+
+    #[test]
+    fn test_mouvement_anticipe() {
+        // Dérive haussière dès T-20 les jours d'annonce seulement
+        let [observations, reference] = serie(-20, 0);
+        assert_eq!(observations.len(), 12);
+        assert!((observations[0].drift_pips - 15.0).abs() < 1e-6);
+
+        let profile = profil_pre_annonce(&observations, &reference).unwrap();
+        assert_eq!(profile.reference_count, reference.len());
+        assert_eq!(profile.onset_minutes_before, Some(20));
+        assert_eq!(profile.aligned_drift_rate, 1.0);
+        assert!((profile.drift_share - 0.75).abs() < 1e-6);
+        assert!(profile.early_move);
+        assert_eq!(profile.advice, PreReleaseAdvice::SkipStraddle);
+
+        // Sans jour de référence : pas de conclusion
+        let profile = profil_pre_annonce(&observations, &[]).unwrap();
+        assert!(!profile.early_move);
+    }
+
+    #[test]
+    fn test_saisonnalite_horaire_pas_anticipee() {
+        // Même montée dès T-20 les jours sans annonce : activité habituelle à cette heure
+        let [observations, reference] = serie(-20, -20);
+        let profile = profil_pre_annonce(&observations, &reference).unwrap();
+        assert_eq!(profile.onset_minutes_before, None);
+        assert!(!profile.early_move);
+        assert_eq!(profile.advice, PreReleaseAdvice::Normal);
+    }
+
+    #[test]
+    fn test_fenetre_incomplete() {
+        let candles = occurrence(release_on(2), 0);
+        // Bougies à partir de T-30 seulement
+        assert!(mesurer_fenetre(&candles[30..], release_on(2), PIP).is_none());
+        assert!(profil_pre_annonce(&[], &[]).is_none());
+    }
+}
@@ -0,0 +1,348 @@
+// services/release_seconds_analyzer.rs - Métriques des premières secondes après l'annonce
+// Travaille sur des barres S1/S5/S10 (second_bars) au lieu des M1 :
+// un straddle NFP se décide dans les premières secondes.
+
+use crate::models::candle::QuoteSide;
+use crate::models::release_seconds::{ReleaseSecondsProfile, SecondBarSize, SecondDetail};
+use crate::models::Candle;
+use crate::services::bootstrap::{mean_interval, rate_interval};
+use crate::services::second_bars::ReleaseWindow;
+use chrono::{DateTime, Duration, Utc};
+
+/// Horizon du profil après T0
+pub const PROFILE_HORIZON_SECONDS: i64 = 300;
+/// Période de référence avant T0 (spread et prix de référence)
+pub const PRE_RELEASE_SECONDS: i64 = 60;
+/// Offsets d'entrée testés : T0 à T0 + 60 s
+pub const MAX_ENTRY_OFFSET_SECONDS: i64 = 60;
+
+/// Profil moyen des secondes suivant l'annonce. None si aucune fenêtre exploitable.
+pub fn profil_annonce(
+    windows: &[ReleaseWindow],
+    bar_size: SecondBarSize,
+    pip_value: f64,
+) -> Option<ReleaseSecondsProfile> {
+    let bar_secs = bar_size.seconds();
+    let n_bars = (PROFILE_HORIZON_SECONDS / bar_secs) as usize;
+    let mut range_sums = vec![0.0; n_bars];
+    let mut spread_sums = vec![0.0; n_bars];
+    let mut spread_counts = vec![0usize; n_bars];
+    let mut peak_delays = Vec::new();
+    let mut peak_ranges = Vec::new();
+    let mut first_moves = Vec::new();
+    let mut normalizations = Vec::new();
+
+    for w in windows {
+        let post: Vec<(usize, &Candle)> = w
+            .bars
+            .iter()
+            .filter_map(|b| {
+                let secs = (b.datetime - w.release).num_seconds();
+                (0..PROFILE_HORIZON_SECONDS)
+                    .contains(&secs)
+                    .then_some(((secs / bar_secs) as usize, b))
+            })
+            .collect();
+        if post.is_empty() {
+            continue;
+        }
+
+        let mut peak: Option<(usize, f64)> = None;
+        for &(idx, b) in &post {
+            let range = (b.high - b.low) / pip_value;
+            range_sums[idx] += range;
+            if let Some(spread) = b.spread_mean {
+                spread_sums[idx] += spread / pip_value;
+                spread_counts[idx] += 1;
+            }
+            if !matches!(peak, Some((_, best)) if best >= range) {
+                peak = Some((idx, range));
+            }
+        }
+        if let Some((idx, range)) = peak {
+            peak_delays.push((idx as i64 * bar_secs) as f64);
+            peak_ranges.push(range);
+        }
+
+        let pre: Vec<&Candle> = w
+            .bars
+            .iter()
+            .filter(|b| b.datetime < w.release)
+            .filter(|b| b.datetime >= w.release - Duration::seconds(PRE_RELEASE_SECONDS))
+            .collect();
+        let Some(baseline_spread) =
+            median(pre.iter().filter_map(|b| b.spread_mean).collect()).filter(|s| *s > 0.0)
+        else {
+            continue;
+        };
+        let reference = pre.last().map(|b| b.close).unwrap_or(post[0].1.open);
+
+        if let Some(&(idx, _)) = post
+            .iter()
+            .find(|(_, b)| (b.high - reference).max(reference - b.low) > 2.0 * baseline_spread)
+        {
+            first_moves.push((idx as i64 * bar_secs) as f64);
+        }
+        normalizations.push(spread_normalization(&post, baseline_spread, bar_secs));
+    }
+
+    if peak_delays.is_empty() {
+        return None;
+    }
+    let n = peak_delays.len() as f64;
+
+    Some(ReleaseSecondsProfile {
+        bar_size,
+        event_count: peak_delays.len(),
+        peak_delay_seconds: mean(&peak_delays),
+        peak_range_pips: mean(&peak_ranges),
+        first_move_seconds: (!first_moves.is_empty()).then(|| mean(&first_moves)),
+        spread_normalization_seconds: (!normalizations.is_empty()).then(|| mean(&normalizations)),
+        range_profile_pips: range_sums.iter().map(|s| s / n).collect(),
+        spread_profile_pips: spread_sums
+            .iter()
+            .zip(&spread_counts)
+            .map(|(s, &c)| if c > 0 { s / c as f64 } else { 0.0 })
+            .collect(),
+    })
+}
+
+/// Straddle simulé à chaque offset T0 + k·barre, sortie à durée fixe `forward_seconds`.
+/// Comme `WinRateCalculator` : long entré sur l'ask et sorti sur le bid, short entré sur
+/// le bid et sorti sur l'ask ; la jambe dans le sens du mouvement est retenue.
+pub fn analyser_entrees(
+    windows: &[ReleaseWindow],
+    bar_size: SecondBarSize,
+    pip_value: f64,
+    forward_seconds: i64,
+    spread_threshold_pips: f64,
+) -> Vec<SecondDetail> {
+    let bar_secs = bar_size.seconds();
+    (0..=MAX_ENTRY_OFFSET_SECONDS)
+        .step_by(bar_secs as usize)
+        .map(|offset| {
+            let mut profits = Vec::new();
+            let mut spreads = Vec::new();
+            for w in windows {
+                let entry_time = w.release + Duration::seconds(offset);
+                let exit_time = entry_time + Duration::seconds(forward_seconds);
+                let (Some(entry), Some(exit)) = (
+                    bar_known_at(&w.bars, entry_time, bar_secs),
+                    bar_known_at(&w.bars, exit_time, bar_secs),
+                ) else {
+                    continue;
+                };
+                let spread_pips = entry.spread_close.unwrap_or(0.0) / pip_value;
+                let (entry_hs, exit_hs) = (half_spread(entry), half_spread(exit));
+                let long = exit.side_ohlc(QuoteSide::Bid, exit_hs).close
+                    - entry.side_ohlc(QuoteSide::Ask, entry_hs).close;
+                let short = entry.side_ohlc(QuoteSide::Bid, entry_hs).close
+                    - exit.side_ohlc(QuoteSide::Ask, exit_hs).close;
+                profits.push(long.max(short) / pip_value);
+                spreads.push(spread_pips);
+            }
+
+            let total = profits.len();
+            let wins = profits.iter().filter(|p| **p > 0.0).count();
+            let avg_spread = mean(&spreads);
+            SecondDetail {
+                offset_seconds: offset as u32,
+                win_rate: if total > 0 {
+                    wins as f64 / total as f64
+                } else {
+                    0.0
+                },
+                win_rate_ci: rate_interval(wins, total),
+                avg_net_profit_pips: mean(&profits),
+                avg_net_profit_ci: mean_interval(&profits),
+                avg_spread_pips: avg_spread,
+                sample_size: total,
+                tradable: total == 0 || avg_spread <= spread_threshold_pips,
+            }
+        })
+        .collect()
+}
+
+/// Offset au meilleur profit net parmi les offsets tradables et assez échantillonnés
+pub fn offset_optimal(details: &[SecondDetail], min_samples: usize) -> Option<u32> {
+    details
+        .iter()
+        .filter(|d| d.tradable && d.sample_size >= min_samples)
+        .max_by(|a, b| {
+            a.avg_net_profit_pips
+                .partial_cmp(&b.avg_net_profit_pips)
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .map(|d| d.offset_seconds)
+}
+
+/// Demi-spread de clôture d'une barre en prix (0 sans spread stocké)
+fn half_spread(bar: &Candle) -> f64 {
+    bar.spread_close
+        .or(bar.spread_mean)
+        .filter(|s| *s >= 0.0)
+        .unwrap_or(0.0)
+        / 2.0
+}
+
+/// Dernière barre entièrement close à l'instant `t` (pas de look-ahead)
+fn bar_known_at(bars: &[Candle], t: DateTime<Utc>, bar_secs: i64) -> Option<&Candle> {
+    bars.iter()
+        .take_while(|b| b.datetime + Duration::seconds(bar_secs) <= t)
+        .last()
+}
+
+/// Secondes entre T0 et le retour du spread sous 1.5× la référence, après son pic.
+/// Plafonné à l'horizon si le spread ne se normalise pas.
+fn spread_normalization(post: &[(usize, &Candle)], baseline: f64, bar_secs: i64) -> f64 {
+    let spread = |b: &Candle| b.spread_mean.unwrap_or(0.0);
+    let peak_pos = post
+        .iter()
+        .enumerate()
+        .max_by(|(_, a), (_, b)| {
+            spread(a.1)
+                .partial_cmp(&spread(b.1))
+                .unwrap_or(std::cmp::Ordering::Equal)
+        })
+        .map(|(i, _)| i)
+        .unwrap_or(0);
+    if spread(post[peak_pos].1) <= 1.5 * baseline {
+        return 0.0;
+    }
+    post[peak_pos..]
+        .iter()
+        .find(|(_, b)| spread(b) <= 1.5 * baseline)
+        .map(|(idx, _)| (*idx as i64 * bar_secs) as f64)
+        .unwrap_or(PROFILE_HORIZON_SECONDS as f64)
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        0.0
+    } else {
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+}
+
+fn median(mut values: Vec<f64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    Some(values[values.len() / 2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    const PIP: f64 = 0.0001;
+
+    fn release() -> DateTime<Utc> {
+        Utc.with_ymd_and_hms(2025, 2, 7, 13, 30, 0).unwrap()
+    }
+
+    fn bar(second: i64, close: f64, range_pips: f64, spread_pips: f64) -> Candle {
+        Candle {
+            symbol: "EURUSD".to_string(),
+            datetime: release() + Duration::seconds(second),
+            open: close,
+            high: close + range_pips * PIP / 2.0,
+            low: close - range_pips * PIP / 2.0,
+            close,
+            volume: 1.0,
+            spread_mean: Some(spread_pips * PIP),
+            spread_close: Some(spread_pips * PIP),
+            ..Default::default()
+        }
+    }
+
+    /// Calme avant T0, spike de range et de spread à T0+3 s, prix décalé de 20 pips
+    fn nfp_window() -> ReleaseWindow {
+        let mut bars: Vec<Candle> = (-60..0).map(|s| bar(s, 1.1000, 0.5, 1.0)).collect();
+        bars.extend((0..120).map(|s| match s {
+            0..=2 => bar(s, 1.1000, 0.5, 1.0),
+            3 => bar(s, 1.1010, 20.0, 8.0),
+            4..=9 => bar(s, 1.1020, 3.0, 4.0),
+            _ => bar(s, 1.1020, 0.5, 1.0),
+        }));
+        ReleaseWindow {
+            release: release(),
+            bars,
+        }
+    }
+
+    #[test]
+    fn test_profile_detects_peak_seconds_after_release() {
+        let profile = profil_annonce(&[nfp_window()], SecondBarSize::S1, PIP)
+            .expect("window has post-release bars");
+        assert_eq!(profile.event_count, 1);
+        assert_eq!(profile.peak_delay_seconds, 3.0);
+        assert!((profile.peak_range_pips - 20.0).abs() < 1e-6);
+        assert_eq!(profile.first_move_seconds, Some(3.0));
+        assert_eq!(profile.spread_normalization_seconds, Some(10.0));
+        assert_eq!(profile.range_profile_pips.len(), 300);
+        assert!((profile.spread_profile_pips[3] - 8.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_s5_profile_uses_bar_start_offsets() {
+        let window = nfp_window();
+        let s5 = ReleaseWindow {
+            release: window.release,
+            bars: window
+                .bars
+                .iter()
+                .filter(|b| (b.datetime - window.release).num_seconds() % 5 == 3)
+                .cloned()
+                .collect(),
+        };
+        let profile = profil_annonce(&[s5], SecondBarSize::S5, PIP).expect("bars present");
+        assert_eq!(profile.range_profile_pips.len(), 60);
+        assert_eq!(profile.peak_delay_seconds, 0.0);
+    }
+
+    #[test]
+    fn test_entries_before_spike_capture_move() {
+        let details = analyser_entrees(&[nfp_window()], SecondBarSize::S1, PIP, 30, 5.0);
+        assert_eq!(details.len(), 61);
+        // Entrée à T0 sur l'ask (1.1000 + 0.5 pip), sortie à T0+30 s sur le bid (1.1020 - 0.5 pip)
+        assert!((details[0].avg_net_profit_pips - 19.0).abs() < 1e-6);
+        // Entrée à T0+20 s : mouvement terminé ; T0 à T0+3 s sont équivalents (dernier retenu)
+        assert!(details[20].avg_net_profit_pips < 0.0);
+        assert_eq!(offset_optimal(&details, 1), Some(3));
+        assert_eq!(offset_optimal(&details, 2), None);
+    }
+
+    #[test]
+    fn test_empty_windows() {
+        assert!(profil_annonce(&[], SecondBarSize::S1, PIP).is_none());
+        let details = analyser_entrees(&[], SecondBarSize::S10, PIP, 30, 5.0);
+        assert_eq!(details.len(), 7);
+        assert!(details.iter().all(|d| d.sample_size == 0 && d.tradable));
+    }
+
+    #[test]
+    fn test_entries_use_stored_bid_ask() {
+        // Ask réel à l'entrée 3 pips au-dessus du mid, bid de sortie 2 pips sous le mid
+        let mut window = nfp_window();
+        for b in window.bars.iter_mut() {
+            let secs = (b.datetime - window.release).num_seconds();
+            if secs == -1 {
+                b.ask_open = Some(b.close + 3.0 * PIP);
+                b.ask_high = Some(b.close + 3.0 * PIP);
+                b.ask_low = Some(b.close + 3.0 * PIP);
+                b.ask_close = Some(b.close + 3.0 * PIP);
+            }
+            if secs == 29 {
+                b.bid_open = Some(b.close - 2.0 * PIP);
+                b.bid_high = Some(b.close - 2.0 * PIP);
+                b.bid_low = Some(b.close - 2.0 * PIP);
+                b.bid_close = Some(b.close - 2.0 * PIP);
+            }
+        }
+        let details = analyser_entrees(&[window], SecondBarSize::S1, PIP, 30, 5.0);
+        assert!((details[0].avg_net_profit_pips - 15.0).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,111 @@
+// services/second_bars.rs - Barres sub-minute (S1/S5/S10) construites depuis tick_data
+// Les barres sont éparses : une seconde sans tick ne produit pas de barre.
+
+use crate::models::release_seconds::SecondBarSize;
+use crate::models::Candle;
+use crate::services::database_loader::{DatabaseLoader, LoaderError};
+use crate::services::tick_aggregator::{self, RawTick};
+use chrono::{DateTime, Duration, Utc};
+
+/// Barres sub-minute autour d'une annonce (T0 = `release`)
+pub struct ReleaseWindow {
+    pub release: DateTime<Utc>,
+    pub bars: Vec<Candle>,
+}
+
+/// Agrège des ticks en barres sub-minute (prix mid, spreads en prix)
+pub fn build_second_bars(symbol: &str, ticks: &[RawTick], bar_size: SecondBarSize) -> Vec<Candle> {
+    tick_aggregator::aggregate_to_bars(ticks, bar_size.seconds())
+        .into_iter()
+        .map(|b| Candle {
+            symbol: symbol.to_string(),
+            datetime: b.datetime_utc,
+            open: b.open,
+            high: b.high,
+            low: b.low,
+            close: b.close,
+            volume: b.volume,
+            spread_open: Some(b.spread_open),
+            spread_high: Some(b.spread_high),
+            spread_low: Some(b.spread_low),
+            spread_close: Some(b.spread_close),
+            spread_mean: Some(b.spread_mean),
+            tick_count: Some(b.tick_count),
+            bid_open: Some(b.bid_open),
+            bid_high: Some(b.bid_high),
+            bid_low: Some(b.bid_low),
+            bid_close: Some(b.bid_close),
+            ask_open: Some(b.ask_open),
+            ask_high: Some(b.ask_high),
+            ask_low: Some(b.ask_low),
+            ask_close: Some(b.ask_close),
+            ..Default::default()
+        })
+        .collect()
+}
+
+/// Charge les fenêtres [T0 - before, T0 + after[ depuis tick_data.
+/// Les annonces sans ticks stockés sont ignorées.
+pub fn charger_fenetres(
+    loader: &DatabaseLoader,
+    symbol: &str,
+    releases: &[DateTime<Utc>],
+    bar_size: SecondBarSize,
+    before: Duration,
+    after: Duration,
+) -> Result<Vec<ReleaseWindow>, LoaderError> {
+    let mut windows = Vec::new();
+    for &release in releases {
+        let ticks = loader.load_ticks(symbol, release - before, release + after)?;
+        if ticks.is_empty() {
+            continue;
+        }
+        windows.push(ReleaseWindow {
+            release,
+            bars: build_second_bars(symbol, &ticks, bar_size),
+        });
+    }
+    Ok(windows)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::TimeZone;
+
+    fn tick(ms: i64, bid: f64, ask: f64) -> RawTick {
+        RawTick {
+            datetime_utc: Utc.with_ymd_and_hms(2025, 1, 10, 13, 30, 0).unwrap()
+                + Duration::milliseconds(ms),
+            bid,
+            ask,
+            bid_volume: 1.0,
+            ask_volume: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_bars_group_by_bar_size() {
+        let ticks = [
+            tick(100, 1.1000, 1.1002),
+            tick(900, 1.1004, 1.1006),
+            tick(1_200, 1.1010, 1.1014),
+            tick(6_500, 1.0990, 1.0992),
+        ];
+
+        let s1 = build_second_bars("EURUSD", &ticks, SecondBarSize::S1);
+        assert_eq!(s1.len(), 3);
+        assert_eq!(s1[0].tick_count, Some(2));
+        assert!((s1[0].high - 1.1005).abs() < 1e-10);
+        assert!((s1[1].spread_mean.unwrap_or(0.0) - 0.0004).abs() < 1e-10);
+
+        let s5 = build_second_bars("EURUSD", &ticks, SecondBarSize::S5);
+        assert_eq!(s5.len(), 2);
+        assert_eq!(s5[0].tick_count, Some(3));
+        assert_eq!((s5[1].datetime - s5[0].datetime).num_seconds(), 5);
+
+        let s10 = build_second_bars("EURUSD", &ticks, SecondBarSize::S10);
+        assert_eq!(s10.len(), 1);
+        assert!((s10[0].low - 1.0991).abs() < 1e-10);
+    }
+}
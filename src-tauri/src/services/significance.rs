@@ -0,0 +1,301 @@
+// services/significance.rs
+// Tests d'hypothèse événement vs baseline (statrs) : Mann-Whitney U, Welch t,
+// Wilcoxon signé (échantillons appariés), test de signe, tailles d'effet et correction
+// Benjamini-Hochberg pour les tests multiples.
+
+use crate::models::significance::{PairedSignificanceResult, SignificanceResult};
+use statrs::distribution::{Binomial, ContinuousCDF, DiscreteCDF, Normal, StudentsT};
+
+/// Compare deux échantillons de volatilité. None si moins de 2 valeurs d'un côté
+/// ou si les deux échantillons sont constants.
+pub fn compare_samples(event: &[f64], baseline: &[f64]) -> Option<SignificanceResult> {
+    let (n1, n2) = (event.len(), baseline.len());
+    if n1 < 2 || n2 < 2 {
+        return None;
+    }
+    let (m1, v1) = mean_variance(event);
+    let (m2, v2) = mean_variance(baseline);
+    if v1 == 0.0 && v2 == 0.0 {
+        return None;
+    }
+
+    let (u, mw_p) = mann_whitney_u(event, baseline)?;
+    let (t, df, welch_p) = welch_t_test((m1, v1, n1), (m2, v2, n2))?;
+    let pooled_sd = (((n1 - 1) as f64 * v1 + (n2 - 1) as f64 * v2) / (n1 + n2 - 2) as f64).sqrt();
+
+    Some(SignificanceResult {
+        event_sample_size: n1,
+        baseline_sample_size: n2,
+        event_mean: m1,
+        baseline_mean: m2,
+        mann_whitney_u: u,
+        mann_whitney_p_value: mw_p,
+        welch_t: t,
+        welch_df: df,
+        welch_p_value: welch_p,
+        cohens_d: if pooled_sd > 0.0 {
+            (m1 - m2) / pooled_sd
+        } else {
+            0.0
+        },
+        rank_biserial: 2.0 * u / (n1 * n2) as f64 - 1.0,
+        adjusted_p_value: None,
+    })
+}
+
+/// Compare des mesures appariées (événement, baseline de la même occurrence).
+/// None si les longueurs diffèrent ou s'il y a moins de 2 paires.
+pub fn compare_paired(event: &[f64], baseline: &[f64]) -> Option<PairedSignificanceResult> {
+    let n = event.len();
+    if n < 2 || baseline.len() != n {
+        return None;
+    }
+    let differences: Vec<f64> = event.iter().zip(baseline).map(|(e, b)| e - b).collect();
+    let (w_plus, p_value, rank_biserial) = wilcoxon_signed_rank(&differences)?;
+
+    Some(PairedSignificanceResult {
+        sample_size: n,
+        event_mean: event.iter().sum::<f64>() / n as f64,
+        baseline_mean: baseline.iter().sum::<f64>() / n as f64,
+        mean_difference: differences.iter().sum::<f64>() / n as f64,
+        wilcoxon_w: w_plus,
+        wilcoxon_p_value: p_value,
+        rank_biserial,
+        adjusted_p_value: None,
+    })
+}
+
+/// Test de signe unilatéral : probabilité d'au moins `successes` succès sur `total`
+/// si le sens était aléatoire (p = 0.5). None sans essai.
+pub fn sign_test_p_value(successes: usize, total: usize) -> Option<f64> {
+    if total == 0 || successes > total {
+        return None;
+    }
+    if successes == 0 {
+        return Some(1.0);
+    }
+    let binomial = Binomial::new(0.5, total as u64).ok()?;
+    Some(binomial.sf(successes as u64 - 1))
+}
+
+/// Test de signe bilatéral, pour un sens choisi d'après les données (le majoritaire) :
+/// min(1, 2 × p unilatéral du sens majoritaire). None sans essai.
+pub fn sign_test_two_sided_p_value(successes: usize, total: usize) -> Option<f64> {
+    if successes > total {
+        return None;
+    }
+    sign_test_p_value(successes.max(total - successes), total).map(|p| (2.0 * p).min(1.0))
+}
+
+/// p-values ajustées Benjamini-Hochberg (contrôle du taux de fausses découvertes),
+/// dans l'ordre des p-values fournies
+pub fn benjamini_hochberg(p_values: &[f64]) -> Vec<f64> {
+    let m = p_values.len();
+    let mut order: Vec<usize> = (0..m).collect();
+    order.sort_by(|&a, &b| {
+        p_values[a]
+            .partial_cmp(&p_values[b])
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    let mut adjusted = vec![1.0; m];
+    let mut running_min = 1.0f64;
+    for (rank, &idx) in order.iter().enumerate().rev() {
+        let q = p_values[idx] * m as f64 / (rank + 1) as f64;
+        running_min = running_min.min(q);
+        adjusted[idx] = running_min;
+    }
+    adjusted
+}
+
+/// U de `a` et p-value bilatérale (approximation normale avec correction de continuité)
+fn mann_whitney_u(a: &[f64], b: &[f64]) -> Option<(f64, f64)> {
+    let (n1, n2) = (a.len() as f64, b.len() as f64);
+    let mut pooled: Vec<(f64, bool)> = a
+        .iter()
+        .map(|&v| (v, true))
+        .chain(b.iter().map(|&v| (v, false)))
+        .collect();
+    pooled.sort_by(|x, y| x.0.partial_cmp(&y.0).unwrap_or(std::cmp::Ordering::Equal));
+
+    // Rangs moyens pour les ex-aequo
+    let n = pooled.len();
+    let mut rank_sum_a = 0.0;
+    let mut tie_term = 0.0;
+    let mut i = 0;
+    while i < n {
+        let mut j = i + 1;
+        while j < n && pooled[j].0 == pooled[i].0 {
+            j += 1;
+        }
+        let avg_rank = (i + j + 1) as f64 / 2.0;
+        let ties = (j - i) as f64;
+        tie_term += ties.powi(3) - ties;
+        rank_sum_a += avg_rank * pooled[i..j].iter().filter(|p| p.1).count() as f64;
+        i = j;
+    }
+
+    let u = rank_sum_a - n1 * (n1 + 1.0) / 2.0;
+    let total = n1 + n2;
+    let variance = n1 * n2 / 12.0 * ((total + 1.0) - tie_term / (total * (total - 1.0)));
+    if variance <= 0.0 {
+        return Some((u, 1.0));
+    }
+    let z = ((u - n1 * n2 / 2.0).abs() - 0.5).max(0.0) / variance.sqrt();
+    let normal = Normal::new(0.0, 1.0).ok()?;
+    Some((u, (2.0 * normal.sf(z)).min(1.0)))
+}
+
+/// (W+, p-value bilatérale, rang-bisériale apparié) sur les différences non nulles
+fn wilcoxon_signed_rank(differences: &[f64]) -> Option<(f64, f64, f64)> {
+    let mut nonzero: Vec<f64> = differences.iter().copied().filter(|d| *d != 0.0).collect();
+    if nonzero.is_empty() {
+        return Some((0.0, 1.0, 0.0));
+    }
+    nonzero.sort_by(|a, b| {
+        a.abs()
+            .partial_cmp(&b.abs())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    });
+
+    // Rangs moyens des |d| pour les ex-aequo
+    let n = nonzero.len();
+    let mut w_plus = 0.0;
+    let mut tie_term = 0.0;
+    let mut i = 0;
+    while i < n {
+        let mut j = i + 1;
+        while j < n && nonzero[j].abs() == nonzero[i].abs() {
+            j += 1;
+        }
+        let avg_rank = (i + j + 1) as f64 / 2.0;
+        let ties = (j - i) as f64;
+        tie_term += ties.powi(3) - ties;
+        w_plus += avg_rank * nonzero[i..j].iter().filter(|d| **d > 0.0).count() as f64;
+        i = j;
+    }
+
+    let nf = n as f64;
+    let total_rank = nf * (nf + 1.0) / 2.0;
+    let rank_biserial = (2.0 * w_plus - total_rank) / total_rank;
+    let variance = nf * (nf + 1.0) * (2.0 * nf + 1.0) / 24.0 - tie_term / 48.0;
+    if variance <= 0.0 {
+        return Some((w_plus, 1.0, rank_biserial));
+    }
+    let z = ((w_plus - total_rank / 2.0).abs() - 0.5).max(0.0) / variance.sqrt();
+    let normal = Normal::new(0.0, 1.0).ok()?;
+    Some((w_plus, (2.0 * normal.sf(z)).min(1.0), rank_biserial))
+}
+
+/// (t, degrés de liberté, p-value bilatérale) à partir de (moyenne, variance, n)
+fn welch_t_test(a: (f64, f64, usize), b: (f64, f64, usize)) -> Option<(f64, f64, f64)> {
+    let (m1, v1, n1) = a;
+    let (m2, v2, n2) = b;
+    let (s1, s2) = (v1 / n1 as f64, v2 / n2 as f64);
+    let se2 = s1 + s2;
+    if se2 <= 0.0 {
+        return None;
+    }
+    let t = (m1 - m2) / se2.sqrt();
+    let df = se2.powi(2) / (s1.powi(2) / (n1 - 1) as f64 + s2.powi(2) / (n2 - 1) as f64);
+    let dist = StudentsT::new(0.0, 1.0, df).ok()?;
+    Some((t, df, (2.0 * dist.sf(t.abs())).min(1.0)))
+}
+
+/// Moyenne et variance d'échantillon (n - 1)
+fn mean_variance(values: &[f64]) -> (f64, f64) {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, variance)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_clear_shift_is_significant() {
+        let baseline = [2.0, 2.5, 1.8, 2.2, 2.1, 1.9, 2.4, 2.0, 2.3, 1.7];
+        let event = [6.0, 7.5, 5.8, 8.2, 6.6, 7.1, 5.9, 6.8, 7.7, 6.3];
+        let r = compare_samples(&event, &baseline).expect("samples are valid");
+        assert_eq!(r.mann_whitney_u, 100.0);
+        assert!((r.rank_biserial - 1.0).abs() < 1e-12);
+        assert!(r.mann_whitney_p_value < 0.001);
+        assert!(r.welch_p_value < 0.001);
+        assert!(r.cohens_d > 2.0);
+    }
+
+    #[test]
+    fn test_identical_distributions_not_significant() {
+        let a = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let r = compare_samples(&a, &a).expect("samples are valid");
+        assert!((r.mann_whitney_u - 12.5).abs() < 1e-12);
+        assert!(r.mann_whitney_p_value > 0.9);
+        assert!((r.welch_p_value - 1.0).abs() < 1e-9);
+        assert_eq!(r.cohens_d, 0.0);
+    }
+
+    #[test]
+    fn test_welch_reference_values() {
+        // t = -2.0740, df = 10.209, p = 0.0643 (calculés indépendamment)
+        let a = [19.8, 20.4, 19.6, 17.8, 18.5, 18.9, 18.3, 18.9, 19.5, 22.0];
+        let b = [28.2, 26.6, 20.1, 23.3, 25.2, 22.1, 17.7, 27.6, 20.6, 13.7];
+        let r = compare_samples(&a, &b).expect("samples are valid");
+        assert!((r.welch_t + 2.0740).abs() < 1e-3);
+        assert!((r.welch_df - 10.209).abs() < 1e-3);
+        assert!((r.welch_p_value - 0.0643).abs() < 1e-3);
+    }
+
+    #[test]
+    fn test_benjamini_hochberg() {
+        let q = benjamini_hochberg(&[0.01, 0.04, 0.03, 0.20]);
+        assert!((q[0] - 0.04).abs() < 1e-12);
+        assert!((q[1] - 0.0533333).abs() < 1e-6);
+        assert!((q[2] - 0.0533333).abs() < 1e-6);
+        assert!((q[3] - 0.20).abs() < 1e-12);
+        assert!(benjamini_hochberg(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_sign_test() {
+        // 12 sur 12 : 0.5^12 ; 9 sur 10 : 11 / 1024
+        assert!((sign_test_p_value(12, 12).unwrap() - 0.5f64.powi(12)).abs() < 1e-12);
+        assert!((sign_test_p_value(9, 10).unwrap() - 11.0 / 1024.0).abs() < 1e-12);
+        assert_eq!(sign_test_p_value(0, 5), Some(1.0));
+        assert_eq!(sign_test_p_value(1, 0), None);
+
+        // Bilatéral : symétrique, double du unilatéral du sens majoritaire
+        let two_sided = sign_test_two_sided_p_value(1, 10).unwrap();
+        assert!((two_sided - 22.0 / 1024.0).abs() < 1e-12);
+        assert_eq!(sign_test_two_sided_p_value(9, 10), Some(two_sided));
+        assert_eq!(sign_test_two_sided_p_value(5, 10), Some(1.0));
+        assert_eq!(sign_test_two_sided_p_value(0, 0), None);
+    }
+
+    #[test]
+    fn test_too_few_samples() {
+        assert!(compare_samples(&[1.0], &[1.0, 2.0]).is_none());
+    }
+
+    #[test]
+    fn test_paired_shift_is_significant() {
+        // Chaque occurrence plus volatile que sa propre baseline, baselines très dispersées
+        let baseline = [1.0, 5.0, 2.0, 8.0, 3.0, 6.0, 4.0, 7.0, 9.0, 10.0];
+        let event: Vec<f64> = baseline.iter().map(|b| b + 0.5).collect();
+        let r = compare_paired(&event, &baseline).expect("pairs are valid");
+        assert_eq!(r.sample_size, 10);
+        assert!((r.mean_difference - 0.5).abs() < 1e-12);
+        assert_eq!(r.wilcoxon_w, 55.0);
+        assert!((r.rank_biserial - 1.0).abs() < 1e-12);
+        assert!(r.wilcoxon_p_value < 0.01);
+
+        // Le même décalage testé comme échantillons indépendants reste noyé dans la dispersion
+        let independent = compare_samples(&event, &baseline).expect("samples are valid");
+        assert!(independent.mann_whitney_p_value > 0.5);
+
+        assert!(compare_paired(&event, &baseline[..9]).is_none());
+        let same = compare_paired(&baseline, &baseline).expect("pairs are valid");
+        assert_eq!(same.wilcoxon_p_value, 1.0);
+    }
+}
@@ -0,0 +1,171 @@
+// services/source_timezone.rs - Fuseau horaire source d'un import
+// Les bougies sont stockées en UTC : chaque import déclare le fuseau de ses horodatages.
+// "broker NY-close" = serveur MT4/MT5 GMT+2/+3 (17:00 New York = 00:00 serveur, DST US).
+
+use chrono::{DateTime, Duration, NaiveDateTime, TimeZone, Utc};
+use chrono_tz::Tz;
+use std::fmt;
+
+/// Libellé du fuseau broker NY-close (accepté aussi : "ny-close", "nyclose")
+pub const BROKER_NY_CLOSE: &str = "broker NY-close";
+
+/// Serveur NY-close : heure serveur = heure de New York + 7h
+const NY_CLOSE_SHIFT_HOURS: i64 = 7;
+
+/// Fuseau des horodatages d'un fichier importé
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SourceTimezone {
+    /// Horodatages déjà en UTC/GMT (comportement historique)
+    #[default]
+    Utc,
+    /// Fuseau IANA (Europe/Athens, America/New_York, Etc/GMT-2…)
+    Iana(Tz),
+    /// Heure serveur broker alignée sur la clôture de New York
+    BrokerNyClose,
+}
+
+impl SourceTimezone {
+    /// "UTC", "broker NY-close" ou un nom IANA
+    pub fn parse(name: &str) -> Result<Self, String> {
+        let trimmed = name.trim();
+        let lower = trimmed.to_lowercase();
+        match lower.as_str() {
+            "" | "utc" | "gmt" | "z" => Ok(Self::Utc),
+            "broker ny-close" | "ny-close" | "nyclose" | "broker-ny-close" => {
+                Ok(Self::BrokerNyClose)
+            }
+            _ => trimmed
+                .parse::<Tz>()
+                .map(Self::Iana)
+                .map_err(|e| format!("Fuseau horaire inconnu '{}' : {}", name, e)),
+        }
+    }
+
+    /// Parse un fuseau optionnel (None ou vide = défaut du format)
+    pub fn parse_optional(name: Option<&str>) -> Result<Option<Self>, String> {
+        match name.map(str::trim).filter(|n| !n.is_empty()) {
+            Some(n) => Self::parse(n).map(Some),
+            None => Ok(None),
+        }
+    }
+
+    /// Convertit une heure locale de la source en UTC.
+    /// Heure ambiguë (retour à l'heure d'hiver) : première occurrence.
+    pub fn to_utc(&self, local: NaiveDateTime) -> Result<DateTime<Utc>, String> {
+        let converted = match self {
+            Self::Utc => return Ok(DateTime::<Utc>::from_naive_utc_and_offset(local, Utc)),
+            Self::Iana(tz) => tz
+                .from_local_datetime(&local)
+                .earliest()
+                .map(|dt| dt.with_timezone(&Utc)),
+            Self::BrokerNyClose => chrono_tz::America::New_York
+                .from_local_datetime(&(local - Duration::hours(NY_CLOSE_SHIFT_HOURS)))
+                .earliest()
+                .map(|dt| dt.with_timezone(&Utc)),
+        };
+        converted.ok_or_else(|| {
+            format!(
+                "Heure inexistante en {} (passage à l'heure d'été) : {}",
+                self, local
+            )
+        })
+    }
+
+    /// Heure locale de la source correspondant à un instant UTC (inverse de `to_utc`)
+    pub fn from_utc(&self, utc: DateTime<Utc>) -> NaiveDateTime {
+        match self {
+            Self::Utc => utc.naive_utc(),
+            Self::Iana(tz) => utc.with_timezone(tz).naive_local(),
+            Self::BrokerNyClose => {
+                utc.with_timezone(&chrono_tz::America::New_York)
+                    .naive_local()
+                    + Duration::hours(NY_CLOSE_SHIFT_HOURS)
+            }
+        }
+    }
+}
+
+impl From<Tz> for SourceTimezone {
+    fn from(tz: Tz) -> Self {
+        Self::Iana(tz)
+    }
+}
+
+impl fmt::Display for SourceTimezone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Utc => write!(f, "UTC"),
+            Self::Iana(tz) => write!(f, "{}", tz.name()),
+            Self::BrokerNyClose => write!(f, "{}", BROKER_NY_CLOSE),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::NaiveDate;
+
+    fn naive(y: i32, m: u32, d: u32, h: u32, min: u32) -> NaiveDateTime {
+        NaiveDate::from_ymd_opt(y, m, d)
+            .and_then(|d| d.and_hms_opt(h, min, 0))
+            .unwrap()
+    }
+
+    #[test]
+    fn test_parse_names() {
+        assert_eq!(SourceTimezone::parse("UTC").unwrap(), SourceTimezone::Utc);
+        assert_eq!(
+            SourceTimezone::parse("Broker NY-Close").unwrap(),
+            SourceTimezone::BrokerNyClose
+        );
+        let athens = SourceTimezone::parse("Europe/Athens").unwrap();
+        assert_eq!(athens.to_string(), "Europe/Athens");
+        assert!(SourceTimezone::parse("Mars/Olympus").is_err());
+        assert_eq!(SourceTimezone::parse_optional(Some("  ")).unwrap(), None);
+    }
+
+    #[test]
+    fn test_broker_ny_close_switches_with_us_dst() {
+        let tz = SourceTimezone::BrokerNyClose;
+        // Hiver : GMT+2
+        assert_eq!(
+            tz.to_utc(naive(2024, 1, 5, 15, 30)).unwrap().naive_utc(),
+            naive(2024, 1, 5, 13, 30)
+        );
+        // Entre le DST US (10 mars) et le DST européen (31 mars) : déjà GMT+3
+        assert_eq!(
+            tz.to_utc(naive(2024, 3, 15, 15, 30)).unwrap().naive_utc(),
+            naive(2024, 3, 15, 12, 30)
+        );
+        // Europe/Athens n'a pas encore changé d'heure à cette date
+        let athens = SourceTimezone::parse("Europe/Athens").unwrap();
+        assert_eq!(
+            athens
+                .to_utc(naive(2024, 3, 15, 15, 30))
+                .unwrap()
+                .naive_utc(),
+            naive(2024, 3, 15, 13, 30)
+        );
+    }
+
+    #[test]
+    fn test_from_utc_round_trips() {
+        let local = naive(2024, 7, 1, 15, 30);
+        for tz in [
+            SourceTimezone::BrokerNyClose,
+            SourceTimezone::parse("America/New_York").unwrap(),
+        ] {
+            assert_eq!(tz.from_utc(tz.to_utc(local).unwrap()), local);
+        }
+    }
+
+    #[test]
+    fn test_utc_is_identity() {
+        let local = naive(2024, 7, 1, 12, 0);
+        assert_eq!(
+            SourceTimezone::Utc.to_utc(local).unwrap().naive_utc(),
+            local
+        );
+    }
+}
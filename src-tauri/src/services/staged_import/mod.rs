@@ -0,0 +1,120 @@
+// services/staged_import/mod.rs - Import de bougies/ticks en deux temps dans pairs.db
+// Tous les imports (ticks, CSV, Dukascopy, Parquet) passent par `StagedImport` :
+// une session import_log par import, annulable depuis l'historique.
+
+mod publish;
+mod staging;
+
+use crate::models::import_session::ReimportMode;
+use crate::services::import_sessions;
+use crate::services::pair_data::symbol_aliases;
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Résultat de la sauvegarde en BD.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SaveResult {
+    pub symbol: String,
+    pub candles_saved: usize,
+    pub actual_count: i64,
+}
+
+/// Compteur des imports en transit (identifiant unique dans le processus)
+static STAGED_IMPORTS: AtomicU64 = AtomicU64::new(0);
+
+/// Import en deux temps : chaque lot est écrit en zone de transit
+/// (candle_staging/tick_staging, une transaction par lot), puis `commit` publie
+/// tout en une transaction sous une nouvelle session import_log. Abandonné
+/// (erreur, annulation), l'import est effacé de la zone de transit au drop :
+/// candle_data et tick_data restent inchangées.
+pub struct StagedImport<'c> {
+    conn: &'c rusqlite::Connection,
+    staging_id: String,
+    symbol: String,
+    timeframe: String,
+    source_label: String,
+    mode: Option<ReimportMode>,
+    /// Sessions actives du même fichier (retirées au commit en mode remplacement)
+    previous: Vec<i64>,
+    candles: usize,
+    ticks: usize,
+    range: Option<(DateTime<Utc>, DateTime<Utc>)>,
+    tick_range: Option<(i64, i64)>,
+}
+
+impl<'c> StagedImport<'c> {
+    /// Échoue (`ALREADY_IMPORTED`) si `source_label` a déjà été importé pour cette
+    /// paire/timeframe et qu'aucun mode de ré-import n'est fourni.
+    /// Le symbole est ramené à sa forme canonique : EURUSD.m et EURUSD forment une seule série.
+    pub fn begin(
+        conn: &'c rusqlite::Connection,
+        symbol: &str,
+        timeframe: &str,
+        source_label: &str,
+        mode: Option<ReimportMode>,
+    ) -> Result<Self, String> {
+        let symbol = symbol_aliases::canonical_symbol(symbol);
+        let previous =
+            import_sessions::verifier_reimport(conn, source_label, &symbol, timeframe, mode)?;
+        let staging_id = format!(
+            "{}-{}-{}",
+            std::process::id(),
+            Utc::now().timestamp_millis(),
+            STAGED_IMPORTS.fetch_add(1, Ordering::Relaxed)
+        );
+        Ok(Self {
+            conn,
+            staging_id,
+            symbol,
+            timeframe: timeframe.to_string(),
+            source_label: source_label.to_string(),
+            // Premier import du fichier : pas de mode à consigner
+            mode: mode.filter(|_| !previous.is_empty()),
+            previous,
+            candles: 0,
+            ticks: 0,
+            range: None,
+            tick_range: None,
+        })
+    }
+
+    pub fn symbol(&self) -> &str {
+        &self.symbol
+    }
+
+    pub fn timeframe(&self) -> &str {
+        &self.timeframe
+    }
+
+    /// Plage couverte par les bougies en transit
+    pub fn range(&self) -> Option<(DateTime<Utc>, DateTime<Utc>)> {
+        self.range
+    }
+
+    fn extend_range(&mut self, time: DateTime<Utc>) {
+        self.range = Some(match self.range {
+            Some((first, last)) => (first.min(time), last.max(time)),
+            None => (time, time),
+        });
+    }
+}
+
+impl Drop for StagedImport<'_> {
+    fn drop(&mut self) {
+        // Sans effet après un commit réussi (zone de transit déjà vidée)
+        if let Err(e) = purge_staging(self.conn, &self.staging_id) {
+            tracing::warn!("⚠️ Zone de transit non vidée ({}) : {}", self.staging_id, e);
+        }
+    }
+}
+
+fn purge_staging(conn: &rusqlite::Connection, staging_id: &str) -> Result<(), String> {
+    conn.execute(
+        "DELETE FROM candle_staging WHERE import_id = ?",
+        [staging_id],
+    )
+    .and_then(|_| conn.execute("DELETE FROM tick_staging WHERE import_id = ?", [staging_id]))
+    .map(|_| ())
+    .map_err(|e| format!("Erreur purge transit : {}", e))
+}
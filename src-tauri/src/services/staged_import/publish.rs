@@ -0,0 +1,202 @@
+// services/staged_import/publish.rs - Publication d'un import en transit
+// candle_data, tick_data, pair_metadata et session import_log en une transaction.
+
+use super::{purge_staging, SaveResult, StagedImport};
+use crate::models::import_session::ReimportMode;
+use crate::services::{candle_resampler, import_sessions};
+use chrono::Utc;
+use tracing::info;
+
+/// Publication des lots en transit, chaque bougie rattachée à la session (import_id).
+/// Suivie de `UPSERT_STAGED_CLAUSE` (import, remplacement) ou `MERGE_STAGED_CLAUSE` (fusion).
+const PUBLISH_STAGED_SQL: &str =
+    "INSERT INTO candle_data (symbol, timeframe, time, open, high, low, close, volume,
+            spread_open, spread_high, spread_low, spread_close, spread_mean, tick_count,
+            bid_open, bid_high, bid_low, bid_close, ask_open, ask_high, ask_low, ask_close,
+            imported_at, source_file, import_id)
+         SELECT ?1, ?2, time, open, high, low, close, volume,
+            spread_open, spread_high, spread_low, spread_close, spread_mean, tick_count,
+            bid_open, bid_high, bid_low, bid_close, ask_open, ask_high, ask_low, ask_close,
+            ?3, ?4, ?5
+         FROM candle_staging WHERE import_id = ?6 ORDER BY rowid";
+
+/// Les colonnes spread/tick_count absentes (CSV) conservent la valeur en place,
+/// bid/ask sont remplacées (NULL pour un CSV).
+const UPSERT_STAGED_CLAUSE: &str = "ON CONFLICT(symbol, timeframe, time) DO UPDATE SET
+            open=excluded.open, high=excluded.high, low=excluded.low,
+            close=excluded.close, volume=excluded.volume,
+            spread_open=COALESCE(excluded.spread_open, spread_open),
+            spread_high=COALESCE(excluded.spread_high, spread_high),
+            spread_low=COALESCE(excluded.spread_low, spread_low),
+            spread_close=COALESCE(excluded.spread_close, spread_close),
+            spread_mean=COALESCE(excluded.spread_mean, spread_mean),
+            tick_count=COALESCE(excluded.tick_count, tick_count),
+            bid_open=excluded.bid_open, bid_high=excluded.bid_high,
+            bid_low=excluded.bid_low, bid_close=excluded.bid_close,
+            ask_open=excluded.ask_open, ask_high=excluded.ask_high,
+            ask_low=excluded.ask_low, ask_close=excluded.ask_close,
+            imported_at=excluded.imported_at, source_file=excluded.source_file,
+            import_id=excluded.import_id";
+
+/// Fusion : les bougies déjà en place sont conservées
+const MERGE_STAGED_CLAUSE: &str = "ON CONFLICT(symbol, timeframe, time) DO NOTHING";
+
+impl StagedImport<'_> {
+    /// Publie l'import en une transaction (session import_log, candle_data,
+    /// tick_data, pair_metadata), puis recalcule les timeframes dérivés.
+    /// Remplacement : les sessions précédentes du fichier sont d'abord retirées ;
+    /// les bougies d'autres imports écrasées sont gardées pour une annulation.
+    /// `source_timezone` : None si le fuseau du fichier est inconnu (lu comme UTC).
+    /// Retourne le bilan des bougies et le nombre de ticks conservés.
+    pub fn commit(self, source_timezone: Option<&str>) -> Result<(SaveResult, usize), String> {
+        let imported_at = Utc::now().to_rfc3339();
+        let tx = self
+            .conn
+            .unchecked_transaction()
+            .map_err(|e| format!("Erreur début transaction : {}", e))?;
+
+        let merge = self.mode == Some(ReimportMode::Merge);
+        let mut derived_range = self.range;
+        if !merge {
+            for &previous in &self.previous {
+                if let Some((first, last)) = import_sessions::remplacer_session(&tx, previous)? {
+                    derived_range = Some(match derived_range {
+                        Some((start, end)) => (start.min(first), end.max(last)),
+                        None => (first, last),
+                    });
+                }
+            }
+        }
+
+        let import_id = import_sessions::ouvrir_session(
+            &tx,
+            &self.source_label,
+            &self.symbol,
+            &self.timeframe,
+            &imported_at,
+            source_timezone,
+            self.mode,
+        )?;
+        let conflict_clause = if merge {
+            MERGE_STAGED_CLAUSE
+        } else {
+            import_sessions::sauvegarder_bougies_ecrasees(
+                &tx,
+                import_id,
+                &self.symbol,
+                &self.timeframe,
+                &self.staging_id,
+            )?;
+            UPSERT_STAGED_CLAUSE
+        };
+        let inserted = tx
+            .execute(
+                &format!("{} {}", PUBLISH_STAGED_SQL, conflict_clause),
+                rusqlite::params![
+                    &self.symbol,
+                    &self.timeframe,
+                    &imported_at,
+                    &self.source_label,
+                    import_id,
+                    &self.staging_id
+                ],
+            )
+            .map_err(|e| format!("Erreur publication candle_data : {}", e))?;
+
+        let ticks_stored = match self.tick_range {
+            Some(tick_range) => self.publish_ticks(&tx, import_id, tick_range, merge)?,
+            None => 0,
+        };
+        purge_staging(&tx, &self.staging_id)?;
+
+        // Count réel en BD
+        let actual_count: i64 = tx
+            .query_row(
+                "SELECT COUNT(*) FROM candle_data WHERE symbol = ? AND timeframe = ?",
+                rusqlite::params![&self.symbol, &self.timeframe],
+                |row| row.get(0),
+            )
+            .map_err(|e| format!("Erreur COUNT : {}", e))?;
+
+        tx.execute(
+            "INSERT INTO pair_metadata (symbol, timeframe, row_count, last_updated, last_imported_file)
+             VALUES (?, ?, ?, ?, ?)
+             ON CONFLICT(symbol, timeframe) DO UPDATE SET
+                row_count=excluded.row_count, last_updated=excluded.last_updated,
+                last_imported_file=excluded.last_imported_file",
+            rusqlite::params![&self.symbol, &self.timeframe, actual_count, &imported_at, &self.source_label],
+        )
+        .map_err(|e| format!("Erreur pair_metadata : {}", e))?;
+
+        tx.execute(
+            "UPDATE import_log SET row_count = ?, expected_row_count = ? WHERE id = ?",
+            rusqlite::params![inserted as i64, self.candles as i64, import_id],
+        )
+        .map_err(|e| format!("Erreur import_log : {}", e))?;
+
+        tx.commit().map_err(|e| format!("Erreur commit : {}", e))?;
+        import_sessions::purger_sauvegardes_anciennes(
+            self.conn,
+            import_sessions::DEFAULT_BACKUP_RETENTION_DAYS,
+        )?;
+
+        // Timeframes dérivés (M5…D1) recalculés sur les jours importés (et remplacés)
+        if let Some((first, last)) = derived_range.filter(|_| self.timeframe == "M1") {
+            candle_resampler::sync_derived_timeframes(self.conn, &self.symbol, first, last)?;
+        }
+
+        info!(
+            "✅ Import #{} : {} {} sauvegardées pour {} (total BD : {})",
+            import_id, inserted, self.timeframe, self.symbol, actual_count
+        );
+        if ticks_stored > 0 {
+            info!("✅ {} ticks sauvegardés pour {}", ticks_stored, self.symbol);
+        }
+
+        Ok((
+            SaveResult {
+                symbol: self.symbol.clone(),
+                candles_saved: inserted,
+                actual_count,
+            },
+            ticks_stored,
+        ))
+    }
+
+    /// Ticks en transit publiés dans tick_data. Import ou remplacement : les ticks
+    /// présents sur la plage sont remplacés (gardés dans tick_displaced pour une
+    /// annulation) ; fusion : seuls les horodatages absents sont ajoutés.
+    fn publish_ticks(
+        &self,
+        tx: &rusqlite::Connection,
+        import_id: i64,
+        (first_ms, last_ms): (i64, i64),
+        merge: bool,
+    ) -> Result<usize, String> {
+        if !merge {
+            import_sessions::sauvegarder_ticks_ecrases(
+                tx,
+                import_id,
+                &self.symbol,
+                first_ms,
+                last_ms,
+            )?;
+            tx.execute(
+                "DELETE FROM tick_data WHERE symbol = ? AND time_ms >= ? AND time_ms <= ?",
+                rusqlite::params![&self.symbol, first_ms, last_ms],
+            )
+            .map_err(|e| format!("Erreur purge tick_data : {}", e))?;
+        }
+        tx.execute(
+            "INSERT INTO tick_data (symbol, time_ms, bid, ask, bid_volume, ask_volume, import_id)
+             SELECT ?1, s.time_ms, s.bid, s.ask, s.bid_volume, s.ask_volume, ?2
+             FROM tick_staging s
+             WHERE s.import_id = ?3 AND NOT EXISTS (
+                SELECT 1 FROM tick_data t WHERE t.symbol = ?1 AND t.time_ms = s.time_ms
+                  AND t.import_id IS NOT ?2)
+             ORDER BY s.rowid",
+            rusqlite::params![&self.symbol, import_id, &self.staging_id],
+        )
+        .map_err(|e| format!("Erreur publication tick_data : {}", e))
+    }
+}
@@ -0,0 +1,187 @@
+// services/staged_import/staging.rs - Écriture des lots en zone de transit
+// (candle_staging/tick_staging), une transaction par lot.
+
+use super::StagedImport;
+use crate::models::Candle;
+use crate::services::pair_data::NormalizedCandle;
+use crate::services::tick_aggregator::{EnrichedM1, RawTick};
+use chrono::{DateTime, Utc};
+
+/// Insertion en transit de toutes les colonnes (M1 enrichies, Parquet)
+const STAGE_FULL_SQL: &str =
+    "INSERT INTO candle_staging (import_id, time, open, high, low, close, volume,
+        spread_open, spread_high, spread_low, spread_close, spread_mean, tick_count,
+        bid_open, bid_high, bid_low, bid_close, ask_open, ask_high, ask_low, ask_close)
+     VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)";
+
+impl StagedImport<'_> {
+    /// Écrit un lot de M1 enrichies (ticks agrégés) en transit.
+    pub fn stage_enriched(&mut self, candles: &[EnrichedM1]) -> Result<(), String> {
+        let tx = self
+            .conn
+            .unchecked_transaction()
+            .map_err(|e| format!("Erreur début transaction : {}", e))?;
+        let mut stmt = tx
+            .prepare(STAGE_FULL_SQL)
+            .map_err(|e| format!("Erreur prepare : {}", e))?;
+
+        for candle in candles {
+            stmt.execute(rusqlite::params![
+                &self.staging_id,
+                candle.datetime_utc.to_rfc3339(),
+                candle.open,
+                candle.high,
+                candle.low,
+                candle.close,
+                candle.volume,
+                candle.spread_open,
+                candle.spread_high,
+                candle.spread_low,
+                candle.spread_close,
+                candle.spread_mean,
+                candle.tick_count,
+                candle.bid_open,
+                candle.bid_high,
+                candle.bid_low,
+                candle.bid_close,
+                candle.ask_open,
+                candle.ask_high,
+                candle.ask_low,
+                candle.ask_close,
+            ])
+            .map_err(|e| format!("Erreur transit ligne {} : {}", self.candles, e))?;
+            self.candles += 1;
+        }
+        drop(stmt);
+        tx.commit().map_err(|e| format!("Erreur commit : {}", e))?;
+
+        for candle in candles.first().into_iter().chain(candles.last()) {
+            self.extend_range(candle.datetime_utc);
+        }
+        Ok(())
+    }
+
+    /// Écrit des bougies d'un timeframe quelconque (import Parquet) en transit :
+    /// les spreads/bid/ask absents deviennent NULL.
+    pub fn stage_candles(&mut self, candles: &[Candle]) -> Result<(), String> {
+        let tx = self
+            .conn
+            .unchecked_transaction()
+            .map_err(|e| format!("Erreur début transaction : {}", e))?;
+        let mut stmt = tx
+            .prepare(STAGE_FULL_SQL)
+            .map_err(|e| format!("Erreur prepare : {}", e))?;
+
+        for candle in candles {
+            stmt.execute(rusqlite::params![
+                &self.staging_id,
+                candle.datetime.to_rfc3339(),
+                candle.open,
+                candle.high,
+                candle.low,
+                candle.close,
+                candle.volume,
+                candle.spread_open,
+                candle.spread_high,
+                candle.spread_low,
+                candle.spread_close,
+                candle.spread_mean,
+                candle.tick_count,
+                candle.bid_open,
+                candle.bid_high,
+                candle.bid_low,
+                candle.bid_close,
+                candle.ask_open,
+                candle.ask_high,
+                candle.ask_low,
+                candle.ask_close,
+            ])
+            .map_err(|e| format!("Erreur transit ligne {} : {}", self.candles, e))?;
+            self.candles += 1;
+            self.extend_range(candle.datetime);
+        }
+        drop(stmt);
+        tx.commit().map_err(|e| format!("Erreur commit : {}", e))
+    }
+
+    /// Écrit un lot de bougies CSV normalisées en transit : OHLCV seul, ou M1 enrichies
+    /// complètes (bid/ask, spread) pour les exports tick HistData/MT5.
+    pub fn stage_normalized(&mut self, candles: &[NormalizedCandle]) -> Result<(), String> {
+        let (ticks, candles): (Vec<&NormalizedCandle>, Vec<&NormalizedCandle>) =
+            candles.iter().partition(|c| c.quotes.is_some());
+        if !ticks.is_empty() {
+            let enriched: Vec<EnrichedM1> = ticks.iter().filter_map(|c| c.quotes.clone()).collect();
+            self.stage_enriched(&enriched)?;
+        }
+        if candles.is_empty() {
+            return Ok(());
+        }
+
+        let tx = self
+            .conn
+            .unchecked_transaction()
+            .map_err(|e| format!("Erreur début transaction : {}", e))?;
+        let mut stmt = tx
+            .prepare(
+                "INSERT INTO candle_staging (import_id, time, open, high, low, close, volume)
+                 VALUES (?, ?, ?, ?, ?, ?, ?)",
+            )
+            .map_err(|e| format!("Erreur prepare : {}", e))?;
+
+        for candle in candles {
+            let time = DateTime::<Utc>::from_timestamp(candle.timestamp, 0)
+                .ok_or_else(|| format!("Timestamp invalide : {}", candle.timestamp))?;
+            stmt.execute(rusqlite::params![
+                &self.staging_id,
+                time.to_rfc3339(),
+                candle.open,
+                candle.high,
+                candle.low,
+                candle.close,
+                candle.volume,
+            ])
+            .map_err(|e| format!("Erreur transit ligne {} : {}", self.candles, e))?;
+            self.candles += 1;
+            self.extend_range(time);
+        }
+        drop(stmt);
+        tx.commit().map_err(|e| format!("Erreur commit : {}", e))
+    }
+
+    /// Écrit un lot de ticks bruts en transit (publiés dans tick_data au commit).
+    pub fn stage_ticks(&mut self, ticks: &[RawTick]) -> Result<(), String> {
+        if ticks.is_empty() {
+            return Ok(());
+        }
+        let tx = self
+            .conn
+            .unchecked_transaction()
+            .map_err(|e| format!("Erreur début transaction : {}", e))?;
+        let mut stmt = tx
+            .prepare(
+                "INSERT INTO tick_staging (import_id, time_ms, bid, ask, bid_volume, ask_volume)
+                 VALUES (?, ?, ?, ?, ?, ?)",
+            )
+            .map_err(|e| format!("Erreur prepare : {}", e))?;
+
+        for tick in ticks {
+            let time_ms = tick.datetime_utc.timestamp_millis();
+            stmt.execute(rusqlite::params![
+                &self.staging_id,
+                time_ms,
+                tick.bid,
+                tick.ask,
+                tick.bid_volume,
+                tick.ask_volume,
+            ])
+            .map_err(|e| format!("Erreur transit tick {} : {}", self.ticks, e))?;
+            self.ticks += 1;
+            self.tick_range = Some(match self.tick_range {
+                Some((first, last)) => (first.min(time_ms), last.max(time_ms)),
+                None => (time_ms, time_ms),
+            });
+        }
+        drop(stmt);
+        tx.commit().map_err(|e| format!("Erreur commit : {}", e))
+    }
+}
@@ -0,0 +1,24 @@
+// services/stats.rs - Statistiques descriptives partagées par les analyseurs d'annonces
+
+/// Moyenne arithmétique ; 0.0 sans valeur
+pub fn mean(values: impl IntoIterator<Item = f64>) -> f64 {
+    let (sum, count) = values
+        .into_iter()
+        .fold((0.0, 0usize), |(s, n), v| (s + v, n + 1));
+    if count == 0 {
+        0.0
+    } else {
+        sum / count as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mean() {
+        assert_eq!(mean([1.0, 2.0, 6.0]), 3.0);
+        assert_eq!(mean(std::iter::empty()), 0.0);
+    }
+}
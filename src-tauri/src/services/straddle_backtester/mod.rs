@@ -0,0 +1,309 @@
+// services/straddle_backtester/mod.rs
+// Backtest straddle à ordres en attente sur toutes les occurrences d'un type d'événement.
+//
+// Contrairement à `entry_point_analyzer::simulate_straddle` (trade hypothétique unique),
+// chaque occurrence est rejouée avec buy-stop/sell-stop OCO, SL/TP ATR et timeout,
+// puis les trades sont chaînés en courbe d'équité (pips cumulés).
+
+mod simulation;
+
+pub use simulation::{simulate_occurrence, OccurrenceOutcome};
+
+use crate::models::backtest::{BacktestConfig, BacktestResult, BacktestTrade, EquityPoint};
+use crate::models::{Candle, Result, VolatilityError};
+use crate::services::pair_data::get_asset_properties;
+use chrono::{DateTime, Utc};
+use tracing::info;
+
+/// Rejoue chaque occurrence `(event_time, candles M1 autour de l'événement)`.
+pub fn run_backtest(
+    occurrences: &[(DateTime<Utc>, Vec<Candle>)],
+    symbol: &str,
+    event_type: &str,
+    config: &BacktestConfig,
+) -> Result<BacktestResult> {
+    let props = get_asset_properties(symbol);
+    if props.pip_value <= 0.0 {
+        return Err(VolatilityError::ValidationError(format!(
+            "pip_value invalide pour {symbol}"
+        )));
+    }
+    if config.straddle_distance_pips <= 0.0 || config.max_duration_minutes <= 0 {
+        return Err(VolatilityError::ValidationError(
+            "Distance straddle et durée max doivent être > 0".into(),
+        ));
+    }
+
+    let mut sorted: Vec<&(DateTime<Utc>, Vec<Candle>)> = occurrences.iter().collect();
+    sorted.sort_by_key(|(t, _)| *t);
+
+    let mut trades = Vec::new();
+    let mut unfilled = 0usize;
+    let mut skipped = 0usize;
+
+    for (event_time, candles) in sorted {
+        match simulate_occurrence(candles, *event_time, config, props.pip_value) {
+            OccurrenceOutcome::Filled(trade) => trades.push(trade),
+            OccurrenceOutcome::Unfilled => unfilled += 1,
+            OccurrenceOutcome::NoData => skipped += 1,
+        }
+    }
+
+    if trades.is_empty() && unfilled == 0 {
+        return Err(VolatilityError::InsufficientData(format!(
+            "Aucune occurrence exploitable pour {event_type} sur {symbol}"
+        )));
+    }
+
+    let stats = TradeStats::from_trades(&trades);
+    let (equity_curve, max_drawdown) = build_equity_curve(&trades);
+
+    info!(
+        "Backtest {symbol}/{event_type}: {} trades, {} non déclenchés, {} ignorés, total={:.1} pips, maxDD={:.1}",
+        trades.len(),
+        unfilled,
+        skipped,
+        stats.total,
+        max_drawdown
+    );
+
+    Ok(BacktestResult {
+        symbol: symbol.to_string(),
+        event_type: event_type.to_string(),
+        config: config.clone(),
+        occurrences: occurrences.len(),
+        unfilled_count: unfilled,
+        skipped_count: skipped,
+        equity_curve,
+        total_pips: stats.total,
+        win_rate: stats.win_rate,
+        avg_win_pips: stats.avg_win,
+        avg_loss_pips: stats.avg_loss,
+        profit_factor: stats.profit_factor,
+        expectancy_pips: stats.expectancy,
+        max_drawdown_pips: max_drawdown,
+        trades,
+        unit: props.unit,
+    })
+}
+
+/// Statistiques agrégées d'une liste de trades
+pub struct TradeStats {
+    pub total: f64,
+    pub win_rate: f64,
+    pub avg_win: f64,
+    pub avg_loss: f64,
+    pub profit_factor: Option<f64>,
+    pub expectancy: f64,
+}
+
+impl TradeStats {
+    pub fn from_trades(trades: &[BacktestTrade]) -> Self {
+        let wins: Vec<f64> = trades
+            .iter()
+            .map(|t| t.profit_pips)
+            .filter(|p| *p > 0.0)
+            .collect();
+        let losses: Vec<f64> = trades
+            .iter()
+            .map(|t| t.profit_pips)
+            .filter(|p| *p <= 0.0)
+            .collect();
+
+        let gross_profit: f64 = wins.iter().sum();
+        let gross_loss: f64 = -losses.iter().sum::<f64>();
+        let n = trades.len();
+
+        Self {
+            total: gross_profit - gross_loss,
+            win_rate: if n > 0 {
+                wins.len() as f64 / n as f64
+            } else {
+                0.0
+            },
+            avg_win: mean(&wins),
+            avg_loss: mean(&losses),
+            profit_factor: if gross_loss > 0.0 {
+                Some(gross_profit / gross_loss)
+            } else {
+                None
+            },
+            expectancy: if n > 0 {
+                (gross_profit - gross_loss) / n as f64
+            } else {
+                0.0
+            },
+        }
+    }
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        0.0
+    } else {
+        values.iter().sum::<f64>() / values.len() as f64
+    }
+}
+
+/// Courbe d'équité (pips cumulés à la sortie de chaque trade) et drawdown max
+pub fn build_equity_curve(trades: &[BacktestTrade]) -> (Vec<EquityPoint>, f64) {
+    let mut equity = 0.0f64;
+    let mut peak = 0.0f64;
+    let mut max_dd = 0.0f64;
+    let mut curve = Vec::with_capacity(trades.len());
+
+    for trade in trades {
+        equity += trade.profit_pips;
+        peak = peak.max(equity);
+        let dd = peak - equity;
+        max_dd = max_dd.max(dd);
+        curve.push(EquityPoint {
+            time: trade.exit_time.clone(),
+            equity_pips: equity,
+            drawdown_pips: dd,
+        });
+    }
+
+    (curve, max_dd)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::backtest::{ExitReason, TradeSide};
+    use chrono::{Duration, TimeZone};
+
+    /// Construit une série M1 plate autour de `event`, puis applique `path` à partir de T0
+    fn make_occurrence(event: DateTime<Utc>, path: &[(f64, f64, f64)]) -> Vec<Candle> {
+        let mut candles = Vec::new();
+        for i in (1..=20).rev() {
+            candles.push(Candle {
+                symbol: "EURUSD".to_string(),
+                datetime: event - Duration::minutes(i),
+                open: 1.1000,
+                high: 1.1002,
+                low: 1.0998,
+                close: 1.1000,
+                volume: 100.0,
+                spread_mean: Some(0.0001),
+                ..Default::default()
+            });
+        }
+        for (i, (high, low, close)) in path.iter().enumerate() {
+            candles.push(Candle {
+                symbol: "EURUSD".to_string(),
+                datetime: event + Duration::minutes(i as i64),
+                open: 1.1000,
+                high: *high,
+                low: *low,
+                close: *close,
+                volume: 100.0,
+                spread_mean: Some(0.0001),
+                ..Default::default()
+            });
+        }
+        candles
+    }
+
+    fn config() -> BacktestConfig {
+        BacktestConfig {
+            straddle_distance_pips: 5.0,
+            // ATR plat = 4 pips → SL 8 pips, TP 12 pips
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_long_take_profit() {
+        let event = Utc.with_ymd_and_hms(2025, 1, 10, 13, 30, 0).unwrap();
+        let candles = make_occurrence(event, &[(1.1006, 1.0999, 1.1005), (1.1030, 1.1004, 1.1025)]);
+        match simulate_occurrence(&candles, event, &config(), 0.0001) {
+            OccurrenceOutcome::Filled(t) => {
+                assert_eq!(t.side, TradeSide::Long);
+                assert_eq!(t.exit_reason, ExitReason::TakeProfit);
+                // Entrée 1.1005 (stop), TP = +12 pips
+                assert!((t.profit_pips - 12.0).abs() < 0.01);
+            }
+            _ => panic!("expected a filled trade"),
+        }
+    }
+
+    #[test]
+    fn test_short_stop_loss_and_oco() {
+        let event = Utc.with_ymd_and_hms(2025, 1, 10, 13, 30, 0).unwrap();
+        // Déclenche le short (bid low 1.09945 <= 1.0995), puis remonte au-delà du SL
+        let candles = make_occurrence(event, &[(1.1001, 1.0995, 1.0996), (1.1010, 1.0996, 1.1008)]);
+        match simulate_occurrence(&candles, event, &config(), 0.0001) {
+            OccurrenceOutcome::Filled(t) => {
+                assert_eq!(t.side, TradeSide::Short);
+                assert_eq!(t.exit_reason, ExitReason::StopLoss);
+                assert!((t.profit_pips + 8.0).abs() < 0.01);
+            }
+            _ => panic!("expected a filled trade"),
+        }
+    }
+
+    #[test]
+    fn test_stored_ask_high_triggers_long() {
+        let event = Utc.with_ymd_and_hms(2025, 1, 10, 13, 30, 0).unwrap();
+        // Mid + demi-spread = 1.10035 < buy-stop 1.1005, mais l'ask a touché 1.1006
+        let mut candles =
+            make_occurrence(event, &[(1.1003, 1.0999, 1.1001), (1.1002, 1.0998, 1.1000)]);
+        let spike = &mut candles[20];
+        spike.ask_open = Some(1.10005);
+        spike.ask_high = Some(1.1006);
+        spike.ask_low = Some(1.09995);
+        spike.ask_close = Some(1.10015);
+        spike.bid_open = Some(1.09995);
+        spike.bid_high = Some(1.1002);
+        spike.bid_low = Some(1.09985);
+        spike.bid_close = Some(1.10005);
+        match simulate_occurrence(&candles, event, &config(), 0.0001) {
+            OccurrenceOutcome::Filled(t) => {
+                assert_eq!(t.side, TradeSide::Long);
+                assert!((t.entry_price - 1.1005).abs() < 1e-10);
+            }
+            _ => panic!("expected a filled trade"),
+        }
+    }
+
+    #[test]
+    fn test_unfilled_when_price_stays_inside() {
+        let event = Utc.with_ymd_and_hms(2025, 1, 10, 13, 30, 0).unwrap();
+        let path = [(1.1002, 1.0998, 1.1000); 20];
+        let candles = make_occurrence(event, &path);
+        assert!(matches!(
+            simulate_occurrence(&candles, event, &config(), 0.0001),
+            OccurrenceOutcome::Unfilled
+        ));
+    }
+
+    #[test]
+    fn test_equity_curve_drawdown_and_profit_factor() {
+        let event_a = Utc.with_ymd_and_hms(2025, 1, 10, 13, 30, 0).unwrap();
+        let event_b = Utc.with_ymd_and_hms(2025, 2, 7, 13, 30, 0).unwrap();
+        let win = make_occurrence(
+            event_a,
+            &[(1.1006, 1.0999, 1.1005), (1.1030, 1.1004, 1.1025)],
+        );
+        let loss = make_occurrence(
+            event_b,
+            &[(1.1001, 1.0995, 1.0996), (1.1010, 1.0996, 1.1008)],
+        );
+
+        let result = run_backtest(
+            &[(event_b, loss), (event_a, win)],
+            "EURUSD",
+            "NFP",
+            &config(),
+        )
+        .expect("backtest should run");
+
+        assert_eq!(result.trades.len(), 2);
+        assert!((result.total_pips - 4.0).abs() < 0.01);
+        assert!((result.max_drawdown_pips - 8.0).abs() < 0.01);
+        assert!((result.profit_factor.unwrap_or(0.0) - 1.5).abs() < 0.01);
+        assert!((result.expectancy_pips - 2.0).abs() < 0.01);
+        assert!((result.win_rate - 0.5).abs() < 1e-9);
+    }
+}
@@ -0,0 +1,194 @@
+// services/straddle_backtester/simulation.rs
+// Simulation d'une occurrence : placement buy-stop/sell-stop, OCO, puis SL/TP/timeout.
+//
+// Buy-stop déclenché par le high ask, sell-stop par le low bid. Les bougies issues de
+// ticks portent l'OHLC bid/ask réel ; sinon chaque côté est reconstruit depuis le mid
+// ± spread/2 (`Candle::side_ohlc`).
+// Long : entrée sur l'ask, sortie sur le bid. Short : entrée sur le bid, sortie sur l'ask.
+
+use crate::models::backtest::{BacktestConfig, BacktestTrade, ExitReason, TradeSide};
+use crate::models::candle::QuoteSide;
+use crate::models::Candle;
+use crate::services::atr::calculate_atr_sma;
+use chrono::{DateTime, Duration, Utc};
+
+/// Issue d'une occurrence rejouée
+pub enum OccurrenceOutcome {
+    Filled(BacktestTrade),
+    Unfilled,
+    NoData,
+}
+
+struct Fill {
+    index: usize,
+    side: TradeSide,
+    price: f64,
+    half_spread: f64,
+    ambiguous: bool,
+}
+
+/// Demi-spread d'une bougie en prix (fallback si pas de données tick)
+fn half_spread(candle: &Candle, fallback: f64) -> f64 {
+    candle
+        .spread_mean
+        .or(candle.spread_close)
+        .filter(|s| *s >= 0.0)
+        .unwrap_or(fallback)
+        / 2.0
+}
+
+/// Rejoue une occurrence d'événement. `candles` doit être trié par datetime.
+pub fn simulate_occurrence(
+    candles: &[Candle],
+    event_time: DateTime<Utc>,
+    config: &BacktestConfig,
+    pip_value: f64,
+) -> OccurrenceOutcome {
+    let placement_time = event_time - Duration::minutes(config.entry_offset_minutes);
+    let fallback = config.fallback_spread_pips * pip_value;
+
+    // Dernière bougie clôturée avant le placement = prix de référence
+    let ref_idx = match candles.iter().rposition(|c| c.datetime < placement_time) {
+        Some(i) if i + 1 >= config.atr_period.max(1) => i,
+        _ => return OccurrenceOutcome::NoData,
+    };
+    let atr_start = ref_idx + 1 - config.atr_period.max(1);
+    let atr = calculate_atr_sma(&candles[atr_start..=ref_idx], config.atr_period);
+    if atr <= 0.0 {
+        return OccurrenceOutcome::NoData;
+    }
+
+    let reference = candles[ref_idx].close;
+    let distance = config.straddle_distance_pips * pip_value;
+    let buy_stop = reference + distance;
+    let sell_stop = reference - distance;
+    let expiry = event_time + Duration::minutes(config.pending_expiry_minutes);
+
+    let fill = match find_fill(candles, ref_idx + 1, expiry, buy_stop, sell_stop, fallback) {
+        Some(f) => f,
+        None => return OccurrenceOutcome::Unfilled,
+    };
+
+    OccurrenceOutcome::Filled(track_exit(
+        candles, &fill, event_time, atr, config, pip_value, fallback,
+    ))
+}
+
+/// Cherche le premier déclenchement d'un des deux ordres (l'autre est annulé, OCO)
+fn find_fill(
+    candles: &[Candle],
+    start: usize,
+    expiry: DateTime<Utc>,
+    buy_stop: f64,
+    sell_stop: f64,
+    fallback: f64,
+) -> Option<Fill> {
+    for (i, c) in candles.iter().enumerate().skip(start) {
+        if c.datetime > expiry {
+            return None;
+        }
+        let hs = half_spread(c, fallback);
+        let ask = c.side_ohlc(QuoteSide::Ask, hs);
+        let bid = c.side_ohlc(QuoteSide::Bid, hs);
+        let long_hit = ask.high >= buy_stop;
+        let short_hit = bid.low <= sell_stop;
+
+        let side = match (long_hit, short_hit) {
+            (false, false) => continue,
+            (true, false) => TradeSide::Long,
+            (false, true) => TradeSide::Short,
+            // Les deux touchés : on suppose que le stop le plus proche de l'open part en premier
+            (true, true) => {
+                if buy_stop - ask.open <= bid.open - sell_stop {
+                    TradeSide::Long
+                } else {
+                    TradeSide::Short
+                }
+            }
+        };
+
+        // Gap au-delà du stop : fill au prix d'ouverture (slippage)
+        let price = match side {
+            TradeSide::Long => buy_stop.max(ask.open),
+            TradeSide::Short => sell_stop.min(bid.open),
+        };
+        return Some(Fill {
+            index: i,
+            side,
+            price,
+            half_spread: hs,
+            ambiguous: long_hit && short_hit,
+        });
+    }
+    None
+}
+
+/// Suit le trade jusqu'au SL, TP ou timeout. SL prioritaire si les deux sont dans la même bougie.
+fn track_exit(
+    candles: &[Candle],
+    fill: &Fill,
+    event_time: DateTime<Utc>,
+    atr: f64,
+    config: &BacktestConfig,
+    pip_value: f64,
+    fallback: f64,
+) -> BacktestTrade {
+    let is_long = fill.side == TradeSide::Long;
+    let dir = if is_long { 1.0 } else { -1.0 };
+    // Côté de sortie : bid pour un long, ask pour un short
+    let exit_side = if is_long {
+        QuoteSide::Bid
+    } else {
+        QuoteSide::Ask
+    };
+    let sl = fill.price - dir * atr * config.atr_multiplier_sl;
+    let tp = fill.price + dir * atr * config.atr_multiplier_tp;
+    let fill_candle = &candles[fill.index];
+    let deadline = fill_candle.datetime + Duration::minutes(config.max_duration_minutes);
+
+    let mut exit = (
+        fill.index,
+        fill_candle.side_ohlc(exit_side, fill.half_spread).close,
+        ExitReason::Timeout,
+    );
+    let mut exit_hs = fill.half_spread;
+
+    for (i, c) in candles.iter().enumerate().skip(fill.index) {
+        let hs = half_spread(c, fallback);
+        let quote = c.side_ohlc(exit_side, hs);
+        let (worst, best) = if is_long {
+            (quote.low, quote.high)
+        } else {
+            (quote.high, quote.low)
+        };
+        exit_hs = hs;
+
+        if dir * (worst - sl) <= 0.0 {
+            exit = (i, sl, ExitReason::StopLoss);
+            break;
+        }
+        // Sur la bougie de déclenchement seul le SL est pris en compte (conservateur)
+        if i > fill.index && dir * (best - tp) >= 0.0 {
+            exit = (i, tp, ExitReason::TakeProfit);
+            break;
+        }
+        exit = (i, quote.close, ExitReason::Timeout);
+        if c.datetime >= deadline {
+            break;
+        }
+    }
+
+    let (exit_idx, exit_price, exit_reason) = exit;
+    BacktestTrade {
+        event_time: event_time.to_rfc3339(),
+        side: fill.side,
+        entry_time: fill_candle.datetime.to_rfc3339(),
+        entry_price: fill.price,
+        exit_time: candles[exit_idx].datetime.to_rfc3339(),
+        exit_price,
+        exit_reason,
+        profit_pips: dir * (exit_price - fill.price) / pip_value,
+        spread_cost_pips: (fill.half_spread + exit_hs) / pip_value,
+        ambiguous_fill: fill.ambiguous,
+    }
+}
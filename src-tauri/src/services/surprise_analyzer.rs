@@ -0,0 +1,303 @@
+// services/surprise_analyzer.rs - Étude d'événement conditionnée par la surprise
+// Surprise = actual - forecast, centrée-réduite sur les surprises antérieures de la même annonce :
+// pour un CPI ou un NFP, l'ampleur de l'écart au consensus compte plus que la publication.
+
+use crate::models::surprise_analysis::{
+    SurpriseBucket, SurpriseBucketStats, SurpriseEventReaction,
+};
+use crate::models::Candle;
+use crate::services::bootstrap::{mean_interval, rate_interval};
+use crate::services::stats::mean;
+use chrono::{DateTime, Duration, Utc};
+
+/// Horizon de mesure de la réaction après T0
+pub const DEFAULT_HORIZON_MINUTES: u32 = 15;
+/// |score| à partir duquel une surprise est "grande" (1 écart-type)
+pub const DEFAULT_BIG_SURPRISE_SCORE: f64 = 1.0;
+/// Fenêtre avant T0 servant de référence de volatilité
+pub const PRE_EVENT_MINUTES: i64 = 30;
+/// Surprises antérieures minimales pour scorer une occurrence (période de chauffe)
+const MIN_SURPRISE_HISTORY: usize = 4;
+/// Bougies pré-annonce minimales pour le seuil de whipsaw
+const MIN_PRE_EVENT_CANDLES: usize = 10;
+/// Excursion de chaque côté (en ATR M1 pré-annonce) qualifiant un whipsaw
+const WHIPSAW_ATR_MULTIPLIER: f64 = 2.0;
+
+/// Réaction d'une occurrence, en pips
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MeasuredReaction {
+    pub reaction_pips: f64,
+    pub move_pips: f64,
+    pub whipsaw: bool,
+}
+
+/// Moyenne et écart-type (échantillon) des surprises d'une annonce.
+/// None si l'historique est trop court ou si toutes les surprises sont identiques.
+fn surprise_mean_std(surprises: &[f64]) -> Option<(f64, f64)> {
+    if surprises.len() < MIN_SURPRISE_HISTORY {
+        return None;
+    }
+    let n = surprises.len() as f64;
+    let mean = surprises.iter().sum::<f64>() / n;
+    let variance = surprises.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    let std = variance.sqrt();
+    (std > f64::EPSILON).then_some((mean, std))
+}
+
+/// Score d'une surprise et écart-type des surprises antérieures qui l'a normalisée
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SurpriseScore {
+    pub score: f64,
+    pub std: f64,
+}
+
+/// Score de chaque surprise (ordre chronologique) : (surprise - moyenne) / écart-type des
+/// seules occurrences antérieures, sans biais d'anticipation. None pendant la chauffe.
+pub fn scores_surprise(surprises: &[f64]) -> Vec<Option<SurpriseScore>> {
+    surprises
+        .iter()
+        .enumerate()
+        .map(|(i, s)| {
+            surprise_mean_std(&surprises[..i]).map(|(mean, std)| SurpriseScore {
+                score: (s - mean) / std,
+                std,
+            })
+        })
+        .collect()
+}
+
+/// Classe d'une surprise : le sens vient de la surprise brute (au-dessus ou en dessous
+/// du consensus), |score| ne sert qu'à séparer grandes et petites surprises.
+pub fn classer_surprise(surprise: f64, score: f64, big_score: f64) -> SurpriseBucket {
+    if surprise.abs() <= f64::EPSILON {
+        return SurpriseBucket::InLine;
+    }
+    match (surprise > 0.0, score.abs() >= big_score) {
+        (true, true) => SurpriseBucket::BigPositive,
+        (true, false) => SurpriseBucket::SmallPositive,
+        (false, true) => SurpriseBucket::BigNegative,
+        (false, false) => SurpriseBucket::SmallNegative,
+    }
+}
+
+/// Réaction sur bougies M1 couvrant [T0 - 30 min, T0 + horizon].
+/// None si la bougie de T0, la moitié de l'horizon ou la référence pré-annonce manquent.
+pub fn mesurer_reaction(
+    candles: &[Candle],
+    event_time: DateTime<Utc>,
+    horizon_minutes: u32,
+    pip_value: f64,
+) -> Option<MeasuredReaction> {
+    if pip_value <= 0.0 || horizon_minutes == 0 {
+        return None;
+    }
+    let pre_start = event_time - Duration::minutes(PRE_EVENT_MINUTES);
+    let end = event_time + Duration::minutes(horizon_minutes as i64);
+    let pre: Vec<&Candle> = candles
+        .iter()
+        .filter(|c| c.datetime >= pre_start && c.datetime < event_time)
+        .collect();
+    let post: Vec<&Candle> = candles
+        .iter()
+        .filter(|c| c.datetime >= event_time && c.datetime < end)
+        .collect();
+
+    let first = post.first()?;
+    if first.datetime - event_time > Duration::minutes(1)
+        || post.len() * 2 < horizon_minutes as usize
+        || pre.len() < MIN_PRE_EVENT_CANDLES
+    {
+        return None;
+    }
+    let last = post.last()?;
+
+    let reference = first.open;
+    let high = post
+        .iter()
+        .map(|c| c.high)
+        .fold(f64::NEG_INFINITY, f64::max);
+    let low = post.iter().map(|c| c.low).fold(f64::INFINITY, f64::min);
+    let pre_atr = pre
+        .iter()
+        .map(|c| crate::services::atr::calculate_true_range(c.high, c.low, None))
+        .sum::<f64>()
+        / pre.len() as f64;
+    let threshold = pre_atr * WHIPSAW_ATR_MULTIPLIER;
+
+    Some(MeasuredReaction {
+        reaction_pips: (high - low) / pip_value,
+        move_pips: (last.close - reference) / pip_value,
+        whipsaw: threshold > 0.0 && high - reference >= threshold && reference - low >= threshold,
+    })
+}
+
+/// Statistiques par classe, de la plus négative à la plus positive (classes vides omises)
+pub fn statistiques_par_classe(reactions: &[SurpriseEventReaction]) -> Vec<SurpriseBucketStats> {
+    [
+        SurpriseBucket::BigNegative,
+        SurpriseBucket::SmallNegative,
+        SurpriseBucket::InLine,
+        SurpriseBucket::SmallPositive,
+        SurpriseBucket::BigPositive,
+    ]
+    .into_iter()
+    .filter_map(|bucket| {
+        let members: Vec<&SurpriseEventReaction> =
+            reactions.iter().filter(|r| r.bucket == bucket).collect();
+        if members.is_empty() {
+            return None;
+        }
+        let n = members.len();
+        let reactions_pips: Vec<f64> = members.iter().map(|r| r.reaction_pips).collect();
+        let ups = members.iter().filter(|r| r.move_pips > 0.0).count();
+        let whipsaws = members.iter().filter(|r| r.whipsaw).count();
+        Some(SurpriseBucketStats {
+            bucket,
+            event_count: n,
+            avg_surprise_score: mean(members.iter().map(|r| r.surprise_score)),
+            avg_reaction_pips: mean(reactions_pips.iter().copied()),
+            avg_reaction_ci: mean_interval(&reactions_pips),
+            avg_move_pips: mean(members.iter().map(|r| r.move_pips)),
+            up_rate: ups as f64 / n as f64,
+            up_rate_ci: rate_interval(ups, n),
+            whipsaw_rate: whipsaws as f64 / n as f64,
+            whipsaw_rate_ci: rate_interval(whipsaws, n),
+        })
+    })
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::test_fixtures::{m1, release, PIP};
+
+    /// 30 bougies calmes (range 2 pips) puis la réaction fournie à partir de T0
+    fn window(post: &[(f64, f64, f64, f64)]) -> Vec<Candle> {
+        let mut candles: Vec<Candle> = (-30..0)
+            .map(|m| m1(m, 1.1000, 1.1001, 1.0999, 1.1000))
+            .collect();
+        candles.extend(
+            post.iter()
+                .enumerate()
+                .map(|(i, &(o, h, l, c))| m1(i as i64, o, h, l, c)),
+        );
+        candles
+    }
+
+    fn reaction(
+        bucket: SurpriseBucket,
+        score: f64,
+        move_pips: f64,
+        whipsaw: bool,
+    ) -> SurpriseEventReaction {
+        SurpriseEventReaction {
+            event_time: release().to_rfc3339(),
+            actual: 0.0,
+            forecast: 0.0,
+            surprise: score,
+            surprise_score: score,
+            surprise_std: 1.0,
+            bucket,
+            reaction_pips: move_pips.abs() + 5.0,
+            move_pips,
+            whipsaw,
+        }
+    }
+
+    #[test]
+    fn test_score_et_classes() {
+        assert_eq!(surprise_mean_std(&[0.1, -0.1]), None);
+        assert_eq!(surprise_mean_std(&[0.2, 0.2, 0.2, 0.2]), None);
+        let (_, std) = surprise_mean_std(&[0.1, -0.1, 0.2, -0.2, 0.0]).unwrap();
+        assert!((std - 0.158_113).abs() < 1e-6);
+
+        assert_eq!(classer_surprise(0.0, 0.0, 1.0), SurpriseBucket::InLine);
+        assert_eq!(classer_surprise(0.3, 1.9, 1.0), SurpriseBucket::BigPositive);
+        assert_eq!(
+            classer_surprise(0.1, 0.6, 1.0),
+            SurpriseBucket::SmallPositive
+        );
+        assert_eq!(
+            classer_surprise(-0.1, -0.6, 1.0),
+            SurpriseBucket::SmallNegative
+        );
+        assert_eq!(
+            classer_surprise(-0.2, -1.0, 1.0),
+            SurpriseBucket::BigNegative
+        );
+        // Surprise positive mais sous la moyenne des surprises passées : reste positive
+        assert_eq!(
+            classer_surprise(0.1, -1.4, 1.0),
+            SurpriseBucket::BigPositive
+        );
+        assert_eq!(
+            classer_surprise(-0.1, 0.4, 1.0),
+            SurpriseBucket::SmallNegative
+        );
+    }
+
+    #[test]
+    fn test_score_sur_historique_anterieur() {
+        let surprises = [0.1, -0.1, 0.2, -0.2, 0.5, 0.0];
+        let scores = scores_surprise(&surprises);
+        assert!(scores[..4].iter().all(Option::is_none));
+        // 5e occurrence : moyenne 0 et écart-type 0.183 des quatre précédentes
+        let fifth = scores[4].unwrap();
+        assert!((fifth.std - 0.182_574).abs() < 1e-6);
+        assert!((fifth.score - 0.5 / 0.182_574).abs() < 1e-4);
+        // 6e : la surprise de 0.5 entre dans l'historique, pas avant
+        let (mean, std) = surprise_mean_std(&surprises[..5]).unwrap();
+        let sixth = scores[5].unwrap();
+        assert_eq!(sixth.std, std);
+        assert!((sixth.score - (0.0 - mean) / std).abs() < 1e-9);
+        assert!(sixth.score < 0.0);
+    }
+
+    #[test]
+    fn test_reaction_directionnelle_et_whipsaw() {
+        // Hausse franche : +30 pips sans repasser sous l'ouverture
+        let trend: Vec<_> = (0..15)
+            .map(|i| {
+                let base = 1.1000 + i as f64 * 2.0 * PIP;
+                (base, base + 3.0 * PIP, base - 0.5 * PIP, base + 2.0 * PIP)
+            })
+            .collect();
+        let r = mesurer_reaction(&window(&trend), release(), 15, PIP).unwrap();
+        assert!((r.move_pips - 30.0).abs() < 1e-6);
+        assert!((r.reaction_pips - 31.5).abs() < 1e-6);
+        assert!(!r.whipsaw);
+
+        // Pic à +15 pips puis chute à -15 pips : les deux côtés dépassent 2 × ATR pré-annonce
+        let mut spike = vec![(1.1000, 1.1015, 1.0998, 1.1012)];
+        spike.extend((1..15).map(|_| (1.1000, 1.1001, 1.0985, 1.0990)));
+        let r = mesurer_reaction(&window(&spike), release(), 15, PIP).unwrap();
+        assert!(r.whipsaw);
+        assert!(r.move_pips < 0.0);
+
+        // Sans bougie à T0 ni référence pré-annonce : pas de mesure
+        assert!(mesurer_reaction(&window(&[])[..], release(), 15, PIP).is_none());
+        assert!(mesurer_reaction(&window(&trend)[30..], release(), 15, PIP).is_none());
+    }
+
+    #[test]
+    fn test_statistiques_par_classe() {
+        let reactions = vec![
+            reaction(SurpriseBucket::BigPositive, 1.5, 20.0, false),
+            reaction(SurpriseBucket::BigPositive, 2.5, 30.0, true),
+            reaction(SurpriseBucket::SmallNegative, -0.4, -4.0, false),
+        ];
+        let stats = statistiques_par_classe(&reactions);
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].bucket, SurpriseBucket::SmallNegative);
+        assert_eq!(stats[0].up_rate, 0.0);
+
+        let big = &stats[1];
+        assert_eq!(big.bucket, SurpriseBucket::BigPositive);
+        assert_eq!(big.event_count, 2);
+        assert!((big.avg_surprise_score - 2.0).abs() < 1e-9);
+        assert!((big.avg_reaction_pips - 30.0).abs() < 1e-9);
+        assert_eq!(big.up_rate, 1.0);
+        assert_eq!(big.whipsaw_rate, 0.5);
+    }
+}
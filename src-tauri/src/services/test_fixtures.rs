@@ -0,0 +1,36 @@
+// services/test_fixtures.rs - Bougies de test partagées par les analyseurs d'annonces
+
+use crate::models::Candle;
+use chrono::{DateTime, Duration, TimeZone, Utc};
+
+/// Pip EURUSD
+pub const PIP: f64 = 0.0001;
+
+/// Publication à 12:30 UTC le `day` mars 2025
+pub fn release_on(day: u32) -> DateTime<Utc> {
+    Utc.with_ymd_and_hms(2025, 3, day, 12, 30, 0).unwrap()
+}
+
+/// Publication de référence : 12 mars 2025, 12:30 UTC
+pub fn release() -> DateTime<Utc> {
+    release_on(12)
+}
+
+/// Bougie M1 EURUSD `minute` minutes après `t0`
+pub fn m1_at(t0: DateTime<Utc>, minute: i64, open: f64, high: f64, low: f64, close: f64) -> Candle {
+    Candle {
+        symbol: "EURUSD".to_string(),
+        datetime: t0 + Duration::minutes(minute),
+        open,
+        high,
+        low,
+        close,
+        volume: 1.0,
+        ..Default::default()
+    }
+}
+
+/// Bougie M1 EURUSD `minute` minutes après `release()`
+pub fn m1(minute: i64, open: f64, high: f64, low: f64, close: f64) -> Candle {
+    m1_at(release(), minute, open, high, low, close)
+}
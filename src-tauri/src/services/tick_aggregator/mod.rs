@@ -0,0 +1,285 @@
+// services/tick_aggregator/mod.rs
+// Agrège des ticks Dukascopy (bid+ask dans un même fichier) en bougies M1 enrichies.
+//
+// Format Dukascopy attendu :
+//   Séparateur : `;`
+//   Décimal : `,` (format UE)
+//   Header : Time (EET);Ask;Bid;AskVolume;BidVolume
+//   Exemple : 2025.08.01 00:00:00.005;1,14172;1,14126;0,9;0,9
+//   Fuseau : EET par défaut, remplaçable par le fuseau source déclaré à l'import
+//
+// Lecture en continu : filtre, agrégation minute par minute et écriture par lots
+// (mémoire bornée, un an de ticks ne tient pas en RAM).
+
+mod stream;
+
+pub(crate) use stream::M1Stream;
+pub use stream::{stream_ticks_to_m1, TickBatchSink, TickStreamSummary};
+
+use crate::services::source_timezone::SourceTimezone;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Tick brut parsé depuis le CSV Dukascopy (ou converti depuis bi5)
+#[derive(Debug, Clone)]
+pub struct RawTick {
+    pub datetime_utc: DateTime<Utc>,
+    pub bid: f64,
+    pub ask: f64,
+    pub bid_volume: f64,
+    pub ask_volume: f64,
+}
+
+/// Bougie M1 enrichie avec données de spread.
+/// OHLC principal = mid ; OHLC bid et ask conservés pour les fills de stops.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnrichedM1 {
+    pub datetime_utc: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+    pub spread_open: f64,
+    pub spread_high: f64,
+    pub spread_low: f64,
+    pub spread_close: f64,
+    pub spread_mean: f64,
+    pub tick_count: i32,
+    pub bid_open: f64,
+    pub bid_high: f64,
+    pub bid_low: f64,
+    pub bid_close: f64,
+    pub ask_open: f64,
+    pub ask_high: f64,
+    pub ask_low: f64,
+    pub ask_close: f64,
+}
+
+/// Fuseau des exports CSV tick Dukascopy : EET (UTC+2 hiver / UTC+3 été via Europe/Athens)
+pub fn dukascopy_csv_timezone() -> SourceTimezone {
+    SourceTimezone::Iana(chrono_tz::Europe::Athens)
+}
+
+/// Parse une seule ligne Dukascopy.
+/// Format : `2025.08.01 00:00:00.005;1,14172;1,14126;0,9;0,9`
+/// Les prix structurellement invalides (nuls, croisés) sont rejetés ici ;
+/// tick_filter écarte ensuite spreads excessifs et pics.
+fn parse_dukascopy_line(line: &str, timezone: &SourceTimezone) -> Result<RawTick, String> {
+    let parts: Vec<&str> = line.split(';').collect();
+    if parts.len() < 5 {
+        return Err("Pas assez de colonnes".to_string());
+    }
+
+    let datetime_utc = parse_local_datetime(parts[0], timezone)?;
+    let ask = parse_eu_decimal(parts[1])?;
+    let bid = parse_eu_decimal(parts[2])?;
+    let ask_volume = parse_eu_decimal(parts[3])?;
+    let bid_volume = parse_eu_decimal(parts[4])?;
+
+    if bid <= 0.0 || ask <= 0.0 || ask < bid {
+        return Err(format!("Prix invalides : bid={}, ask={}", bid, ask));
+    }
+
+    Ok(RawTick {
+        datetime_utc,
+        bid,
+        ask,
+        bid_volume,
+        ask_volume,
+    })
+}
+
+/// Parse un timestamp local (EET par défaut) en UTC (millisecondes conservées).
+/// Formats supportés :
+///   `2025.08.01 00:00:00.005` (avec ms)
+///   `2025.08.01 00:00:00` (sans ms)
+fn parse_local_datetime(s: &str, timezone: &SourceTimezone) -> Result<DateTime<Utc>, String> {
+    // Séparer les millisecondes, réajoutées après la conversion de fuseau
+    let (base, millis) = if let Some(dot_pos) = s.rfind('.') {
+        // Vérifier que c'est bien le séparateur ms (après les secondes)
+        // et pas le séparateur de la date (2025.08.01)
+        let after_dot = &s[dot_pos + 1..];
+        if !after_dot.is_empty()
+            && after_dot.len() <= 3
+            && after_dot.chars().all(|c| c.is_ascii_digit())
+        {
+            let millis = format!("{:0<3}", after_dot).parse::<i64>().unwrap_or(0);
+            (&s[..dot_pos], millis)
+        } else {
+            (s, 0)
+        }
+    } else {
+        (s, 0)
+    };
+
+    let naive = NaiveDateTime::parse_from_str(base, "%Y.%m.%d %H:%M:%S")
+        .map_err(|e| format!("Format datetime invalide '{}' : {}", s, e))?;
+
+    // Convertir local → UTC (`earliest()` pour l'ambiguïté DST)
+    let utc = timezone.to_utc(naive)?;
+
+    Ok(utc + chrono::Duration::milliseconds(millis))
+}
+
+/// Parse un nombre avec virgule décimale européenne.
+/// `1,14172` → `1.14172`
+fn parse_eu_decimal(s: &str) -> Result<f64, String> {
+    let cleaned = s.trim().replace(',', ".");
+    cleaned
+        .parse::<f64>()
+        .map_err(|e| format!("Nombre invalide '{}' : {}", s, e))
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Agrégation ticks → M1 enrichies
+// ─────────────────────────────────────────────────────────────────────────────
+
+pub fn aggregate_to_m1(ticks: &[RawTick]) -> Vec<EnrichedM1> {
+    aggregate_to_bars(ticks, 60)
+}
+
+/// Agrège les ticks en barres de `bar_seconds` secondes (S1/S5/S10, M1…).
+/// Mêmes champs que les M1 enrichies ; `datetime_utc` = début de la barre.
+pub fn aggregate_to_bars(ticks: &[RawTick], bar_seconds: i64) -> Vec<EnrichedM1> {
+    if bar_seconds <= 0 {
+        return Vec::new();
+    }
+
+    // Grouper les ticks par barre (clé = timestamp tronqué à la barre)
+    let mut buckets: BTreeMap<i64, BarAccumulator> = BTreeMap::new();
+    for tick in ticks {
+        let secs = tick.datetime_utc.timestamp();
+        buckets
+            .entry(secs - secs.rem_euclid(bar_seconds))
+            .and_modify(|bar| bar.add(tick))
+            .or_insert_with(|| BarAccumulator::new(tick));
+    }
+
+    buckets
+        .into_iter()
+        .filter_map(|(key, bar)| bar.finish(key))
+        .collect()
+}
+
+/// Barre en cours de construction (ticks dans l'ordre d'arrivée)
+struct BarAccumulator {
+    first_bid: f64,
+    first_ask: f64,
+    last_bid: f64,
+    last_ask: f64,
+    high_mid: f64,
+    low_mid: f64,
+    total_volume: f64,
+    spread_high: f64,
+    spread_low: f64,
+    spread_sum: f64,
+    bid_high: f64,
+    bid_low: f64,
+    ask_high: f64,
+    ask_low: f64,
+    tick_count: i32,
+}
+
+impl BarAccumulator {
+    fn new(tick: &RawTick) -> Self {
+        let mut bar = Self {
+            first_bid: tick.bid,
+            first_ask: tick.ask,
+            last_bid: tick.bid,
+            last_ask: tick.ask,
+            high_mid: f64::NEG_INFINITY,
+            low_mid: f64::INFINITY,
+            total_volume: 0.0,
+            spread_high: f64::NEG_INFINITY,
+            spread_low: f64::INFINITY,
+            spread_sum: 0.0,
+            bid_high: f64::NEG_INFINITY,
+            bid_low: f64::INFINITY,
+            ask_high: f64::NEG_INFINITY,
+            ask_low: f64::INFINITY,
+            tick_count: 0,
+        };
+        bar.add(tick);
+        bar
+    }
+
+    fn add(&mut self, tick: &RawTick) {
+        // Prix mid = (bid + ask) / 2
+        let mid = (tick.bid + tick.ask) / 2.0;
+        let spread = tick.ask - tick.bid;
+
+        self.high_mid = self.high_mid.max(mid);
+        self.low_mid = self.low_mid.min(mid);
+        self.spread_high = self.spread_high.max(spread);
+        self.spread_low = self.spread_low.min(spread);
+        self.spread_sum += spread;
+        self.total_volume += tick.bid_volume + tick.ask_volume;
+        self.bid_high = self.bid_high.max(tick.bid);
+        self.bid_low = self.bid_low.min(tick.bid);
+        self.ask_high = self.ask_high.max(tick.ask);
+        self.ask_low = self.ask_low.min(tick.ask);
+        self.last_bid = tick.bid;
+        self.last_ask = tick.ask;
+        self.tick_count += 1;
+    }
+
+    /// `key` = début de la barre en secondes epoch
+    fn finish(self, key: i64) -> Option<EnrichedM1> {
+        Some(EnrichedM1 {
+            datetime_utc: DateTime::<Utc>::from_timestamp(key, 0)?,
+            open: (self.first_bid + self.first_ask) / 2.0,
+            high: self.high_mid,
+            low: self.low_mid,
+            close: (self.last_bid + self.last_ask) / 2.0,
+            volume: self.total_volume,
+            spread_open: self.first_ask - self.first_bid,
+            spread_high: self.spread_high,
+            spread_low: self.spread_low,
+            spread_close: self.last_ask - self.last_bid,
+            spread_mean: self.spread_sum / self.tick_count as f64,
+            tick_count: self.tick_count,
+            bid_open: self.first_bid,
+            bid_high: self.bid_high,
+            bid_low: self.bid_low,
+            bid_close: self.last_bid,
+            ask_open: self.first_ask,
+            ask_high: self.ask_high,
+            ask_low: self.ask_low,
+            ask_close: self.last_ask,
+        })
+    }
+}
+
+// ─────────────────────────────────────────────────────────────────────────────
+// Utilitaires
+// ─────────────────────────────────────────────────────────────────────────────
+
+/// Extrait le symbole depuis le nom du fichier.
+/// Ex : `EURUSD_Ticks_2025.08.01_2026.01.31.csv` → `EURUSD`
+pub fn extract_symbol_from_filename(path: &str) -> Result<String, String> {
+    let filename = Path::new(path)
+        .file_name()
+        .and_then(|f| f.to_str())
+        .ok_or("Nom de fichier invalide")?;
+
+    // Le symbole est la partie avant le premier '_'
+    let symbol = filename
+        .split('_')
+        .next()
+        .ok_or("Format de fichier non reconnu (attendu: SYMBOL_Ticks_...)")?;
+
+    if symbol.len() < 3 || symbol.len() > 10 {
+        return Err(format!(
+            "Symbole extrait invalide '{}' (attendu 3-10 caractères)",
+            symbol
+        ));
+    }
+
+    Ok(symbol.to_uppercase())
+}
+
+#[cfg(test)]
+mod tests;
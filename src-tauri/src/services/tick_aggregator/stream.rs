@@ -0,0 +1,248 @@
+// services/tick_aggregator/stream.rs - Lecture en continu d'un fichier tick Dukascopy
+// Filtre, agrégation minute par minute et écriture par lots (mémoire bornée).
+
+use super::{parse_dukascopy_line, BarAccumulator, EnrichedM1, RawTick};
+use crate::services::import_stream::{ImportControl, ImportStage, BATCH_SIZE};
+use crate::services::source_timezone::SourceTimezone;
+use crate::services::tick_filter::{TickFilter, TickFilterConfig, TickFilterStats};
+use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
+use std::io::{BufRead, BufReader};
+use std::path::Path;
+use tracing::{info, warn};
+
+/// Ticks conservés par lot écrit dans tick_data (stockage optionnel)
+const TICK_BATCH_SIZE: usize = 10 * BATCH_SIZE;
+/// Lignes lues entre deux vérifications d'annulation hors écriture
+const CANCEL_CHECK_LINES: usize = 100_000;
+
+/// Résumé d'un import tick en continu
+#[derive(Debug, Clone, Default)]
+pub struct TickStreamSummary {
+    /// Ticks lus (avant filtre)
+    pub total_ticks: usize,
+    /// Ticks conservés après filtre
+    pub kept_ticks: usize,
+    pub minutes_generated: usize,
+    pub date_start: Option<DateTime<Utc>>,
+    pub date_end: Option<DateTime<Utc>>,
+    /// Ticks rejetés par le filtre (croisés, spread excessif, pics)
+    pub rejected_ticks: TickFilterStats,
+    /// Ticks arrivés après la clôture de leur minute (fichier non trié), ignorés
+    pub late_ticks: usize,
+    spread_sum: f64,
+}
+
+impl TickStreamSummary {
+    pub fn avg_spread(&self) -> f64 {
+        if self.minutes_generated == 0 {
+            0.0
+        } else {
+            self.spread_sum / self.minutes_generated as f64
+        }
+    }
+
+    pub fn avg_ticks_per_minute(&self) -> f64 {
+        if self.minutes_generated == 0 {
+            0.0
+        } else {
+            self.kept_ticks as f64 / self.minutes_generated as f64
+        }
+    }
+
+    fn record(&mut self, bars: &[EnrichedM1]) {
+        if let (Some(first), Some(last)) = (bars.first(), bars.last()) {
+            self.date_start = Some(
+                self.date_start
+                    .map_or(first.datetime_utc, |d| d.min(first.datetime_utc)),
+            );
+            self.date_end = Some(
+                self.date_end
+                    .map_or(last.datetime_utc, |d| d.max(last.datetime_utc)),
+            );
+        }
+        self.minutes_generated += bars.len();
+        self.spread_sum += bars.iter().map(|c| c.spread_mean).sum::<f64>();
+    }
+}
+
+/// Reçoit chaque lot de M1 terminées et de ticks conservés
+pub type TickBatchSink<'a> = dyn FnMut(&[EnrichedM1], &[RawTick]) -> Result<(), String> + 'a;
+
+/// Lit un fichier tick Dukascopy en continu et l'agrège en M1 enrichies.
+///
+/// Le fichier contient bid ET ask sur chaque ligne.
+/// Horodatages exprimés dans `source_timezone` (défaut : `dukascopy_csv_timezone`).
+/// Les mauvais ticks sont écartés selon `filter` avant agrégation.
+/// Les M1 terminées (et les ticks conservés si `keep_ticks`) sont passées par lots à
+/// `on_batch` ; `ctrl` remonte la progression et interrompt l'import s'il est annulé.
+pub fn stream_ticks_to_m1(
+    file_path: &str,
+    filter: &TickFilterConfig,
+    source_timezone: &SourceTimezone,
+    keep_ticks: bool,
+    ctrl: &mut ImportControl,
+    on_batch: &mut TickBatchSink<'_>,
+) -> Result<TickStreamSummary, String> {
+    let path = Path::new(file_path);
+    if !path.exists() {
+        return Err(format!("Fichier introuvable : {}", file_path));
+    }
+
+    let mut summary = TickStreamSummary::default();
+
+    info!("🔄 Lecture ticks Dukascopy (flux) : {}", file_path);
+    let file =
+        std::fs::File::open(file_path).map_err(|e| format!("Erreur ouverture fichier : {}", e))?;
+    let reader = BufReader::with_capacity(256 * 1024, ctrl.counting(file));
+
+    let mut tick_filter = TickFilter::new(filter);
+    let mut aggregator = M1Stream::default();
+    let mut bars: Vec<EnrichedM1> = Vec::with_capacity(BATCH_SIZE);
+    let mut ticks: Vec<RawTick> = Vec::new();
+    let mut line_count = 0usize;
+    let mut error_count = 0usize;
+
+    let mut flush = |bars: &mut Vec<EnrichedM1>,
+                     ticks: &mut Vec<RawTick>,
+                     summary: &mut TickStreamSummary,
+                     ctrl: &mut ImportControl|
+     -> Result<(), String> {
+        ctrl.check()?;
+        on_batch(bars, ticks)?;
+        summary.record(bars);
+        bars.clear();
+        ticks.clear();
+        ctrl.report(
+            ImportStage::Reading,
+            summary.total_ticks,
+            summary.minutes_generated,
+        );
+        Ok(())
+    };
+
+    for line_result in reader.lines() {
+        let line = line_result.map_err(|e| format!("Erreur lecture ligne : {}", e))?;
+        line_count += 1;
+
+        // Skip header
+        if line_count == 1 && line.contains("Time") {
+            continue;
+        }
+        if line_count.is_multiple_of(CANCEL_CHECK_LINES) {
+            ctrl.check()?;
+        }
+
+        let tick = match parse_dukascopy_line(&line, source_timezone) {
+            Ok(tick) => tick,
+            Err(_) => {
+                error_count += 1;
+                if error_count <= 5 {
+                    warn!(
+                        "⚠ Ligne {} ignorée : {}",
+                        line_count,
+                        &line[..line.len().min(80)]
+                    );
+                }
+                continue;
+            }
+        };
+        summary.total_ticks += 1;
+
+        if let Some(tick) = tick_filter.push(tick) {
+            summary.kept_ticks += 1;
+            aggregator.push(&tick, &mut bars);
+            if keep_ticks {
+                ticks.push(tick);
+            }
+        }
+        if bars.len() >= BATCH_SIZE || ticks.len() >= TICK_BATCH_SIZE {
+            flush(&mut bars, &mut ticks, &mut summary, ctrl)?;
+        }
+    }
+
+    for tick in tick_filter.finish() {
+        summary.kept_ticks += 1;
+        aggregator.push(&tick, &mut bars);
+        if keep_ticks {
+            ticks.push(tick);
+        }
+    }
+    aggregator.finish(&mut bars);
+    flush(&mut bars, &mut ticks, &mut summary, ctrl)?;
+
+    if error_count > 5 {
+        warn!("⚠ {} lignes ignorées au total", error_count);
+    }
+    summary.rejected_ticks = tick_filter.stats();
+    summary.late_ticks = aggregator.late_ticks;
+    if summary.total_ticks == 0 {
+        return Err("Aucun tick valide trouvé dans le fichier".to_string());
+    }
+    if summary.rejected_ticks.total() > 0 {
+        warn!(
+            "⚠ {} ticks rejetés (croisés={}, spread={}, pics={})",
+            summary.rejected_ticks.total(),
+            summary.rejected_ticks.crossed,
+            summary.rejected_ticks.wide_spread,
+            summary.rejected_ticks.spikes
+        );
+    }
+    if summary.late_ticks > 0 {
+        warn!(
+            "⚠ {} ticks hors ordre ignorés (minute déjà close)",
+            summary.late_ticks
+        );
+    }
+
+    info!(
+        "✅ {} M1 enrichies générées depuis {} ticks, spread moyen = {:.5}, ~{:.0} ticks/min",
+        summary.minutes_generated,
+        summary.total_ticks,
+        summary.avg_spread(),
+        summary.avg_ticks_per_minute(),
+    );
+    Ok(summary)
+}
+
+/// Agrégation M1 en continu : seules la minute courante et la précédente restent
+/// ouvertes (léger désordre toléré), les minutes antérieures sont émises.
+/// Partagée avec les exports tick HistData/MT5.
+#[derive(Default)]
+pub(crate) struct M1Stream {
+    open: BTreeMap<i64, BarAccumulator>,
+    last_emitted: Option<i64>,
+    /// Ticks d'une minute déjà émise (fichier non trié), ignorés
+    pub(crate) late_ticks: usize,
+}
+
+impl M1Stream {
+    pub(crate) fn push(&mut self, tick: &RawTick, out: &mut Vec<EnrichedM1>) {
+        let secs = tick.datetime_utc.timestamp();
+        let key = secs - secs.rem_euclid(60);
+        if self.last_emitted.is_some_and(|k| key <= k) {
+            self.late_ticks += 1;
+            return;
+        }
+        self.open
+            .entry(key)
+            .and_modify(|bar| bar.add(tick))
+            .or_insert_with(|| BarAccumulator::new(tick));
+
+        while let Some(entry) = self.open.first_entry() {
+            if *entry.key() >= key - 60 {
+                break;
+            }
+            let (k, bar) = entry.remove_entry();
+            self.last_emitted = Some(k);
+            out.extend(bar.finish(k));
+        }
+    }
+
+    pub(crate) fn finish(&mut self, out: &mut Vec<EnrichedM1>) {
+        while let Some((k, bar)) = self.open.pop_first() {
+            self.last_emitted = Some(k);
+            out.extend(bar.finish(k));
+        }
+    }
+}
@@ -0,0 +1,219 @@
+// services/tick_aggregator/tests.rs - Parsing Dukascopy, agrégation M1 et lecture en continu
+
+use super::*;
+use crate::services::import_stream::{ImportControl, ImportProgress};
+use crate::services::tick_filter::TickFilterConfig;
+use chrono::Timelike;
+use std::sync::atomic::AtomicBool;
+
+#[test]
+fn test_parse_eu_decimal() {
+    assert!((parse_eu_decimal("1,14172").unwrap() - 1.14172).abs() < 1e-10);
+    assert!((parse_eu_decimal("0,9").unwrap() - 0.9).abs() < 1e-10);
+    assert!(parse_eu_decimal("abc").is_err());
+}
+
+#[test]
+fn test_extract_symbol() {
+    assert_eq!(
+        extract_symbol_from_filename("/tmp/EURUSD_Ticks_2025.csv").unwrap(),
+        "EURUSD"
+    );
+    assert_eq!(
+        extract_symbol_from_filename("XAUUSD_Ticks_2025.08.01_2026.01.31.csv").unwrap(),
+        "XAUUSD"
+    );
+}
+
+#[test]
+fn test_parse_local_datetime() {
+    let eet = dukascopy_csv_timezone();
+    // Hiver (EET = UTC+2) : 2025.01.15 10:30:00 EET → 08:30:00 UTC
+    let dt = parse_local_datetime("2025.01.15 10:30:00.123", &eet).unwrap();
+    assert_eq!(dt.hour(), 8);
+    assert_eq!(dt.minute(), 30);
+    assert_eq!(dt.timestamp_subsec_millis(), 123);
+}
+
+#[test]
+fn test_parse_dukascopy_line() {
+    let eet = dukascopy_csv_timezone();
+    let line = "2025.08.01 00:00:00.005;1,14172;1,14126;0,9;0,9";
+    let tick = parse_dukascopy_line(line, &eet).unwrap();
+    assert!((tick.ask - 1.14172).abs() < 1e-10);
+    assert!((tick.bid - 1.14126).abs() < 1e-10);
+    assert!((tick.ask_volume - 0.9).abs() < 1e-10);
+}
+
+#[test]
+fn test_aggregate_single_minute() {
+    let eet = dukascopy_csv_timezone();
+    let lines = vec![
+        "2025.08.01 00:00:00.005;1,14172;1,14126;0,9;0,9",
+        "2025.08.01 00:00:17.117;1,14191;1,14130;0,54;0,9",
+        "2025.08.01 00:00:56.088;1,14191;1,14135;0,54;0,67",
+    ];
+    let ticks: Vec<RawTick> = lines
+        .iter()
+        .map(|l| parse_dukascopy_line(l, &eet).unwrap())
+        .collect();
+
+    let candles = aggregate_to_m1(&ticks);
+    assert_eq!(candles.len(), 1);
+
+    let c = &candles[0];
+    assert_eq!(c.tick_count, 3);
+    // open mid = (1.14126+1.14172)/2 = 1.14149
+    assert!((c.open - 1.14149).abs() < 1e-4);
+    // spread_open = 1.14172 - 1.14126 = 0.00046
+    assert!((c.spread_open - 0.00046).abs() < 1e-6);
+    // Côtés séparés : ask high 1.14191, bid low 1.14126, bid close 1.14135
+    assert!((c.ask_high - 1.14191).abs() < 1e-10);
+    assert!((c.bid_low - 1.14126).abs() < 1e-10);
+    assert!((c.bid_close - 1.14135).abs() < 1e-10);
+}
+
+#[test]
+fn test_aggregate_multi_minutes_produces_multiple_candles() {
+    let eet = dukascopy_csv_timezone();
+    // Tick à minute 0
+    let line0 = "2025.08.01 00:00:30.000;1,14172;1,14126;0,9;0,9";
+    // Tick à minute 1
+    let line1 = "2025.08.01 00:01:15.000;1,14200;1,14150;0,5;0,5";
+    // Tick à minute 3 (minute 2 manquante → pas de candle pour min 2)
+    let line3 = "2025.08.01 00:03:05.000;1,14210;1,14160;0,8;0,8";
+
+    let ticks: Vec<RawTick> = vec![line0, line1, line3]
+        .iter()
+        .map(|l| parse_dukascopy_line(l, &eet).unwrap())
+        .collect();
+
+    let candles = aggregate_to_m1(&ticks);
+    // 3 ticks dans 3 minutes distinctes → 3 candles
+    assert_eq!(candles.len(), 3);
+
+    // Vérifier que les timestamps sont triés (BTreeMap)
+    for i in 1..candles.len() {
+        assert!(candles[i].datetime_utc > candles[i - 1].datetime_utc);
+    }
+    // Chaque candle a tick_count=1 (un seul tick par minute)
+    for c in &candles {
+        assert_eq!(c.tick_count, 1);
+    }
+}
+
+#[test]
+fn test_empty_ticks_no_candles() {
+    let candles = aggregate_to_m1(&[]);
+    assert!(candles.is_empty());
+}
+
+#[test]
+fn test_spread_mean_exact_value() {
+    let eet = dukascopy_csv_timezone();
+    // 2 ticks dans la même minute avec spreads différents
+    let lines = vec![
+        "2025.01.15 10:00:10.000;1,10050;1,10000;1,0;1,0", // spread = 0.00050
+        "2025.01.15 10:00:40.000;1,10080;1,10020;1,0;1,0", // spread = 0.00060
+    ];
+    let ticks: Vec<RawTick> = lines
+        .iter()
+        .map(|l| parse_dukascopy_line(l, &eet).unwrap())
+        .collect();
+
+    let candles = aggregate_to_m1(&ticks);
+    assert_eq!(candles.len(), 1);
+    let c = &candles[0];
+    assert_eq!(c.tick_count, 2);
+    // spread_mean = (0.00050 + 0.00060) / 2 = 0.00055
+    assert!((c.spread_mean - 0.00055).abs() < 1e-8);
+    // spread_high = 0.00060
+    assert!((c.spread_high - 0.00060).abs() < 1e-8);
+    // spread_low = 0.00050
+    assert!((c.spread_low - 0.00050).abs() < 1e-8);
+    // volume = (1+1) + (1+1) = 4
+    assert!((c.volume - 4.0).abs() < 1e-10);
+}
+
+#[test]
+fn test_single_tick_produces_valid_candle() {
+    let eet = dukascopy_csv_timezone();
+    let line = "2025.01.15 10:00:00.000;1,10050;1,10000;1,0;1,0";
+    let tick = parse_dukascopy_line(line, &eet).unwrap();
+
+    let candles = aggregate_to_m1(&[tick]);
+    assert_eq!(candles.len(), 1);
+    let c = &candles[0];
+    assert_eq!(c.tick_count, 1);
+    // open == close == mid = (1.10000 + 1.10050) / 2 = 1.10025
+    assert!((c.open - c.close).abs() < 1e-10);
+    assert!((c.open - 1.10025).abs() < 1e-6);
+    // high == low == open pour un seul tick
+    assert!((c.high - c.low).abs() < 1e-10);
+}
+
+#[test]
+fn test_invalid_line_rejected() {
+    let eet = dukascopy_csv_timezone();
+    // ask < bid → invalide
+    let result = parse_dukascopy_line("2025.01.15 10:00:00.000;1,10000;1,10050;1,0;1,0", &eet);
+    assert!(result.is_err());
+}
+
+#[test]
+fn test_stream_matches_batch_aggregation_in_small_batches() {
+    // 5 minutes, dernier tick de la minute 1 légèrement en retard (toléré),
+    // tick de la minute 0 arrivant après la minute 3 (ignoré)
+    let lines = [
+        "Time (EET);Ask;Bid;AskVolume;BidVolume",
+        "2025.08.01 00:00:10.000;1,14172;1,14126;0,9;0,9",
+        "2025.08.01 00:01:10.000;1,14180;1,14130;0,5;0,5",
+        "2025.08.01 00:02:05.000;1,14190;1,14140;0,5;0,5",
+        "2025.08.01 00:01:59.000;1,14185;1,14135;0,5;0,5",
+        "2025.08.01 00:03:30.000;1,14200;1,14150;0,5;0,5",
+        "2025.08.01 00:00:50.000;1,14100;1,14050;0,5;0,5",
+        "2025.08.01 00:04:00.000;1,14210;1,14160;0,8;0,8",
+    ];
+    let path = std::env::temp_dir().join("EURUSD_Ticks_stream_test.csv");
+    std::fs::write(&path, lines.join("\n")).unwrap();
+
+    let eet = dukascopy_csv_timezone();
+    let cancelled = AtomicBool::new(false);
+    let mut on_progress = |_: &ImportProgress| {};
+    let mut ctrl = ImportControl::new("ticks", 0, &cancelled, &mut on_progress);
+    let mut streamed = Vec::new();
+    let mut stored_ticks = 0;
+    let summary = stream_ticks_to_m1(
+        path.to_str().unwrap(),
+        &TickFilterConfig::default(),
+        &eet,
+        true,
+        &mut ctrl,
+        &mut |bars, ticks| {
+            streamed.extend_from_slice(bars);
+            stored_ticks += ticks.len();
+            Ok(())
+        },
+    )
+    .unwrap();
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(summary.total_ticks, 7);
+    assert_eq!(summary.late_ticks, 1);
+    assert_eq!(summary.minutes_generated, 5);
+    assert_eq!(stored_ticks, 7);
+
+    let ticks: Vec<RawTick> = lines[1..]
+        .iter()
+        .filter(|l| !l.starts_with("2025.08.01 00:00:50"))
+        .map(|l| parse_dukascopy_line(l, &eet).unwrap())
+        .collect();
+    let batch = aggregate_to_m1(&ticks);
+    assert_eq!(streamed.len(), batch.len());
+    for (a, b) in streamed.iter().zip(&batch) {
+        assert_eq!(a.datetime_utc, b.datetime_utc);
+        assert_eq!(a.tick_count, b.tick_count);
+        assert!((a.close - b.close).abs() < 1e-10);
+    }
+    assert!((summary.avg_spread() - 0.0005).abs() < 1e-4);
+}
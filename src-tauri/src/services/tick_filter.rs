@@ -0,0 +1,261 @@
+// services/tick_filter.rs - Filtrage des mauvais ticks avant agrégation
+// Un seul tick aberrant fausse le high/low de sa minute et tous les ATR calculés dessus.
+// Étapes : cotations croisées/négatives → plafond de spread → pics (écart à la médiane).
+// Le filtre travaille en continu (`TickFilter`) pour les imports de plusieurs Go.
+
+use crate::services::tick_aggregator::RawTick;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+
+/// Paramètres du filtre (envoyés depuis le frontend, défauts sinon)
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TickFilterConfig {
+    /// Rejette bid/ask ≤ 0 et ask < bid
+    pub reject_crossed: bool,
+    /// Spread max en % du mid (None = pas de plafond)
+    pub max_spread_pct: Option<f64>,
+    /// Écart max du mid à la médiane glissante, en % (None = pas de filtre de pics)
+    pub max_deviation_pct: Option<f64>,
+    /// Taille de la fenêtre centrée de la médiane (ticks, impaire)
+    pub median_window: usize,
+}
+
+impl Default for TickFilterConfig {
+    fn default() -> Self {
+        Self {
+            reject_crossed: true,
+            max_spread_pct: Some(0.5),
+            max_deviation_pct: Some(0.25),
+            median_window: 11,
+        }
+    }
+}
+
+/// Nombre de ticks rejetés par étape
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TickFilterStats {
+    pub crossed: usize,
+    pub wide_spread: usize,
+    pub spikes: usize,
+}
+
+impl TickFilterStats {
+    pub fn total(&self) -> usize {
+        self.crossed + self.wide_spread + self.spikes
+    }
+}
+
+/// Filtre en continu : les ticks sont poussés un par un et ressortent dans l'ordre,
+/// avec un retard de `median_window / 2` ticks (fenêtre centrée du filtre de pics).
+/// Mémoire bornée par la fenêtre, quelle que soit la taille du fichier.
+pub struct TickFilter {
+    config: TickFilterConfig,
+    stats: TickFilterStats,
+    half: usize,
+    /// Ticks retenus aux étapes 1-2 : jusqu'à `half` déjà décidés (historique) + en attente
+    window: VecDeque<RawTick>,
+    /// Position dans `window` du prochain tick à décider
+    cursor: usize,
+    sorted: Vec<f64>,
+}
+
+impl TickFilter {
+    pub fn new(config: &TickFilterConfig) -> Self {
+        let half = config.median_window.max(3) / 2;
+        Self {
+            config: *config,
+            stats: TickFilterStats::default(),
+            half,
+            window: VecDeque::with_capacity(2 * half + 2),
+            cursor: 0,
+            sorted: Vec::with_capacity(2 * half + 1),
+        }
+    }
+
+    pub fn stats(&self) -> TickFilterStats {
+        self.stats
+    }
+
+    /// Cotations croisées/négatives et plafond de spread
+    fn passe_etapes_simples(&mut self, t: &RawTick) -> bool {
+        if self.config.reject_crossed && (t.bid <= 0.0 || t.ask <= 0.0 || t.ask < t.bid) {
+            self.stats.crossed += 1;
+            return false;
+        }
+        if let Some(max_pct) = self.config.max_spread_pct {
+            let mid = (t.bid + t.ask) / 2.0;
+            if mid > 0.0 && (t.ask - t.bid) / mid * 100.0 > max_pct {
+                self.stats.wide_spread += 1;
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Pousse un tick ; retourne le tick décidé à cette étape s'il est conservé
+    pub fn push(&mut self, tick: RawTick) -> Option<RawTick> {
+        if !self.passe_etapes_simples(&tick) {
+            return None;
+        }
+        if self.config.max_deviation_pct.is_none() {
+            return Some(tick);
+        }
+        self.window.push_back(tick);
+        if self.cursor + self.half < self.window.len() {
+            self.decide()
+        } else {
+            None
+        }
+    }
+
+    /// Décide les ticks restants (fenêtre tronquée en fin de flux)
+    pub fn finish(&mut self) -> Vec<RawTick> {
+        let mut kept = Vec::new();
+        while self.cursor < self.window.len() {
+            kept.extend(self.decide());
+        }
+        kept
+    }
+
+    /// Médiane centrée : un vrai mouvement entraîne la médiane, un tick isolé non
+    fn decide(&mut self) -> Option<RawTick> {
+        let max_deviation = self.config.max_deviation_pct.unwrap_or(f64::INFINITY);
+        let i = self.cursor;
+        let lo = i.saturating_sub(self.half);
+        let hi = (i + self.half + 1).min(self.window.len());
+        self.sorted.clear();
+        self.sorted
+            .extend(self.window.range(lo..hi).map(|t| (t.bid + t.ask) / 2.0));
+        self.sorted
+            .sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+        let median = self.sorted[self.sorted.len() / 2];
+        let tick = &self.window[i];
+        let mid = (tick.bid + tick.ask) / 2.0;
+        let ok = median <= 0.0 || ((mid - median) / median).abs() * 100.0 <= max_deviation;
+        let decided = ok.then(|| tick.clone());
+        if !ok {
+            self.stats.spikes += 1;
+        }
+
+        self.cursor += 1;
+        if self.cursor > self.half {
+            self.window.pop_front();
+            self.cursor -= 1;
+        }
+        decided
+    }
+}
+
+/// Filtre les ticks (ordre chronologique conservé) et compte les rejets
+pub fn filtrer_ticks(
+    ticks: Vec<RawTick>,
+    config: &TickFilterConfig,
+) -> (Vec<RawTick>, TickFilterStats) {
+    let mut filter = TickFilter::new(config);
+    let mut kept = Vec::with_capacity(ticks.len());
+    for tick in ticks {
+        kept.extend(filter.push(tick));
+    }
+    kept.extend(filter.finish());
+    (kept, filter.stats())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone, Utc};
+
+    fn tick(i: i64, bid: f64, ask: f64) -> RawTick {
+        RawTick {
+            datetime_utc: Utc.with_ymd_and_hms(2025, 1, 15, 10, 0, 0).unwrap()
+                + Duration::milliseconds(i * 200),
+            bid,
+            ask,
+            bid_volume: 1.0,
+            ask_volume: 1.0,
+        }
+    }
+
+    #[test]
+    fn test_rejects_crossed_wide_and_spike() {
+        let mut ticks: Vec<RawTick> = (0..30)
+            .map(|i| tick(i, 1.1000 + i as f64 * 0.00001, 1.1002 + i as f64 * 0.00001))
+            .collect();
+        ticks[5] = tick(5, 1.1010, 1.1000); // croisé
+        ticks[10] = tick(10, 1.0900, 1.1100); // spread ~1.8 %
+        ticks[20] = tick(20, 1.1050, 1.1052); // 50 pips au-dessus
+        ticks[25] = tick(25, -1.0, 1.1); // négatif
+
+        let (kept, stats) = filtrer_ticks(ticks, &TickFilterConfig::default());
+        assert_eq!(
+            stats,
+            TickFilterStats {
+                crossed: 2,
+                wide_spread: 1,
+                spikes: 1
+            }
+        );
+        assert_eq!(kept.len(), 26);
+        assert!(kept
+            .windows(2)
+            .all(|w| w[0].datetime_utc < w[1].datetime_utc));
+    }
+
+    #[test]
+    fn test_genuine_move_is_kept() {
+        // Saut de 60 pips soutenu : la médiane suit, aucun rejet
+        let ticks: Vec<RawTick> = (0..40)
+            .map(|i| {
+                let bid = if i < 20 { 1.1000 } else { 1.1060 };
+                tick(i, bid, bid + 0.0002)
+            })
+            .collect();
+        let (kept, stats) = filtrer_ticks(ticks, &TickFilterConfig::default());
+        assert_eq!(stats.total(), 0);
+        assert_eq!(kept.len(), 40);
+    }
+
+    #[test]
+    fn test_streaming_catches_spikes_at_edges() {
+        let mut ticks: Vec<RawTick> = (0..50)
+            .map(|i| {
+                tick(
+                    i,
+                    1.1000 + (i % 7) as f64 * 0.00002,
+                    1.1002 + (i % 7) as f64 * 0.00002,
+                )
+            })
+            .collect();
+        // Pics en début et en fin de flux : fenêtre tronquée d'un côté
+        ticks[1] = tick(1, 1.1060, 1.1062);
+        ticks[48] = tick(48, 1.0940, 1.0942);
+
+        let mut filter = TickFilter::new(&TickFilterConfig::default());
+        let mut kept: Vec<RawTick> = Vec::new();
+        for t in ticks {
+            kept.extend(filter.push(t));
+        }
+        // Aucun tick ne sort avant d'avoir sa demi-fenêtre suivante
+        assert_eq!(kept.len(), 50 - 5 - 1);
+        kept.extend(filter.finish());
+
+        assert_eq!(filter.stats().spikes, 2);
+        assert_eq!(kept.len(), 48);
+        assert!(kept
+            .windows(2)
+            .all(|w| w[0].datetime_utc < w[1].datetime_utc));
+    }
+
+    #[test]
+    fn test_disabled_stages() {
+        let config = TickFilterConfig {
+            reject_crossed: false,
+            max_spread_pct: None,
+            max_deviation_pct: None,
+            ..Default::default()
+        };
+        let (kept, stats) = filtrer_ticks(vec![tick(0, 1.2, 1.1)], &config);
+        assert_eq!((kept.len(), stats.total()), (1, 0));
+    }
+}
@@ -0,0 +1,211 @@
+// services/timezone_detector/detection.rs - Score des fuseaux candidats sur les annonces
+// Pour chaque candidat, ratio true range à la minute d'annonce attendue / référence.
+
+use super::{candidate_timezones, BASELINE_MINUTES, MIN_EVENTS, SPIKE_RATIO_THRESHOLD};
+use crate::models::timezone_detection::{TimezoneCandidate, TimezoneDetectionReport};
+use crate::models::Candle;
+use crate::services::atr::calculate_true_range;
+use crate::services::source_timezone::SourceTimezone;
+use chrono::{DateTime, Utc};
+use std::collections::{HashMap, HashSet};
+
+/// Minutes juste avant l'annonce exclues de la référence (positionnement pré-annonce)
+const BASELINE_GAP_MINUTES: i64 = 2;
+/// Nombre d'annonces à partir duquel l'échantillon ne pénalise plus la confiance
+const FULL_CONFIDENCE_EVENTS: usize = 30;
+/// Candidats conservés dans le rapport
+const MAX_CANDIDATES: usize = 10;
+
+/// Index minute (horodatage stocké lu comme UTC) → position dans `candles`
+fn index_minutes(candles: &[Candle]) -> HashMap<i64, usize> {
+    candles
+        .iter()
+        .enumerate()
+        .map(|(i, c)| (c.datetime.timestamp().div_euclid(60), i))
+        .collect()
+}
+
+fn true_range_at(candles: &[Candle], index: &HashMap<i64, usize>, minute: i64) -> Option<f64> {
+    let c = &candles[*index.get(&minute)?];
+    let prev_close = index.get(&(minute - 1)).map(|&i| candles[i].close);
+    Some(calculate_true_range(c.high, c.low, prev_close))
+}
+
+/// Ratio true range à l'annonce (minute stockée attendue ou suivante) / médiane de référence
+fn spike_ratio(candles: &[Candle], index: &HashMap<i64, usize>, minute: i64) -> Option<f64> {
+    let spike = true_range_at(candles, index, minute)?
+        .max(true_range_at(candles, index, minute + 1).unwrap_or(0.0));
+    let baseline: Vec<f64> = (minute - BASELINE_MINUTES..minute - BASELINE_GAP_MINUTES)
+        .filter_map(|m| true_range_at(candles, index, m))
+        .collect();
+    if baseline.len() < (BASELINE_MINUTES / 2) as usize {
+        return None;
+    }
+    median(baseline).filter(|b| *b > 0.0).map(|b| spike / b)
+}
+
+/// Minute stockée attendue pour une annonce si les données étaient dans `timezone`
+fn expected_minute(timezone: &SourceTimezone, event: DateTime<Utc>) -> i64 {
+    timezone
+        .from_utc(event)
+        .and_utc()
+        .timestamp()
+        .div_euclid(60)
+}
+
+/// Cherche le fuseau qui aligne le mieux les pics de true range sur les annonces.
+/// `candles` : M1 de la paire telles que stockées ; `events` : annonces à fort impact (UTC).
+pub fn detecter_fuseau(
+    symbol: &str,
+    candles: &[Candle],
+    events: &[DateTime<Utc>],
+) -> TimezoneDetectionReport {
+    let index = index_minutes(candles);
+    let mut seen: HashSet<Vec<i64>> = HashSet::new();
+    let mut candidates = Vec::new();
+
+    for timezone in candidate_timezones() {
+        let minutes: Vec<i64> = events
+            .iter()
+            .map(|e| expected_minute(&timezone, *e))
+            .collect();
+        if !seen.insert(minutes.clone()) {
+            continue;
+        }
+        let ratios: Vec<f64> = minutes
+            .iter()
+            .filter_map(|m| spike_ratio(candles, &index, *m))
+            .collect();
+        if ratios.is_empty() {
+            continue;
+        }
+        let hits = ratios
+            .iter()
+            .filter(|r| **r >= SPIKE_RATIO_THRESHOLD)
+            .count();
+        candidates.push(TimezoneCandidate {
+            timezone: timezone.to_string(),
+            events_scored: ratios.len(),
+            hit_rate: hits as f64 / ratios.len() as f64,
+            median_spike_ratio: median(ratios).unwrap_or(0.0),
+        });
+    }
+
+    // Part des annonces avec pic d'abord : une variante sans heure d'été ne rate que la moitié
+    candidates.sort_by(|a, b| {
+        b.hit_rate
+            .total_cmp(&a.hit_rate)
+            .then(b.median_spike_ratio.total_cmp(&a.median_spike_ratio))
+    });
+    candidates.truncate(MAX_CANDIDATES);
+
+    let best = candidates
+        .first()
+        .filter(|c| c.events_scored >= MIN_EVENTS && c.hit_rate > 0.0);
+    let confidence = best.map_or(0.0, |best| {
+        let separation = candidates
+            .get(1)
+            .map_or(1.0, |r| 1.0 - r.hit_rate / best.hit_rate);
+        let sample = (best.events_scored as f64 / FULL_CONFIDENCE_EVENTS as f64).min(1.0);
+        (100.0 * (2.0 * separation).min(1.0) * best.hit_rate * sample).clamp(0.0, 100.0)
+    });
+    let suggested_timezone = best.map(|c| c.timezone.clone());
+
+    TimezoneDetectionReport {
+        symbol: symbol.to_string(),
+        events_tested: events.len(),
+        already_utc: suggested_timezone.as_deref() == Some("UTC"),
+        suggested_timezone,
+        confidence,
+        candidates,
+    }
+}
+
+fn median(mut values: Vec<f64>) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    values.sort_by(|a, b| a.partial_cmp(b).unwrap_or(std::cmp::Ordering::Equal));
+    Some(values[values.len() / 2])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{Duration, TimeZone};
+
+    /// NFP-like à 13:30 UTC : 3 annonces en hiver, 3 en été
+    fn events() -> Vec<DateTime<Utc>> {
+        [(1, 5), (2, 2), (3, 1), (6, 7), (7, 5), (8, 2)]
+            .iter()
+            .map(|(m, d)| Utc.with_ymd_and_hms(2024, *m, *d, 13, 30, 0).unwrap())
+            .collect()
+    }
+
+    /// Journées M1 complètes stockées dans `timezone`, avec un pic à chaque annonce
+    fn stored_candles(timezone: &SourceTimezone) -> Vec<Candle> {
+        let spikes: HashSet<i64> = events()
+            .iter()
+            .map(|e| expected_minute(timezone, *e))
+            .collect();
+        let mut candles = Vec::new();
+        for event in events() {
+            let day = timezone
+                .from_utc(event)
+                .date()
+                .and_hms_opt(0, 0, 0)
+                .unwrap()
+                .and_utc();
+            for m in 0..1440 {
+                let datetime = day + Duration::minutes(m);
+                let range = if spikes.contains(&(datetime.timestamp() / 60)) {
+                    0.0030
+                } else {
+                    0.0002 + (m % 5) as f64 * 0.00002
+                };
+                candles.push(Candle {
+                    symbol: "EURUSD".to_string(),
+                    datetime,
+                    open: 1.1,
+                    high: 1.1 + range,
+                    low: 1.1,
+                    close: 1.1,
+                    volume: 1.0,
+                    ..Default::default()
+                });
+            }
+        }
+        candles
+    }
+
+    #[test]
+    fn test_detects_dst_timezone() {
+        let athens = SourceTimezone::parse("Europe/Athens").unwrap();
+        let report = detecter_fuseau("EURUSD", &stored_candles(&athens), &events());
+        assert_eq!(report.suggested_timezone.as_deref(), Some("Europe/Athens"));
+        assert!(!report.already_utc);
+        // La variante fixe UTC+2 ne colle qu'aux annonces d'hiver
+        let fixed = report
+            .candidates
+            .iter()
+            .find(|c| c.timezone == "Etc/GMT-2")
+            .unwrap();
+        assert!((fixed.hit_rate - 0.5).abs() < 1e-9);
+        assert!(report.confidence > 0.0);
+    }
+
+    #[test]
+    fn test_utc_data_needs_no_rewrite() {
+        let report = detecter_fuseau("EURUSD", &stored_candles(&SourceTimezone::Utc), &events());
+        assert_eq!(report.suggested_timezone.as_deref(), Some("UTC"));
+        assert!(report.already_utc);
+    }
+
+    #[test]
+    fn test_too_few_events() {
+        let candles = stored_candles(&SourceTimezone::Utc);
+        let report = detecter_fuseau("EURUSD", &candles, &events()[..2]);
+        assert_eq!(report.suggested_timezone, None);
+        assert_eq!(report.confidence, 0.0);
+    }
+}
@@ -0,0 +1,45 @@
+// services/timezone_detector/mod.rs - Détection du fuseau source d'une paire importée
+// Un CSV importé avec le mauvais fuseau décale les pics de volatilité des annonces
+// (NFP à 15:30 au lieu de 13:30…). On teste chaque décalage horaire fixe et les variantes
+// avec heure d'été : le bon fuseau place les pics de true range sur les minutes d'annonce.
+
+mod detection;
+mod rewrite;
+
+pub use detection::detecter_fuseau;
+pub use rewrite::reecrire_en_utc;
+
+use crate::services::source_timezone::SourceTimezone;
+
+/// Fenêtre de référence avant l'annonce (minutes)
+pub const BASELINE_MINUTES: i64 = 30;
+/// True range à l'annonce ≥ seuil × médiane de référence → pic
+pub const SPIKE_RATIO_THRESHOLD: f64 = 3.0;
+/// Annonces exploitables minimum pour suggérer un fuseau
+pub const MIN_EVENTS: usize = 5;
+
+/// Fuseaux testés : UTC, décalages fixes UTC-12…UTC+14, puis fuseaux avec heure d'été.
+/// À signature égale (mêmes minutes attendues), le premier de la liste est retenu.
+pub fn candidate_timezones() -> Vec<SourceTimezone> {
+    let mut candidates = vec![SourceTimezone::Utc];
+    // Convention POSIX inversée : Etc/GMT-2 = UTC+2
+    candidates.extend(
+        (-12i32..=14)
+            .filter(|h| *h != 0)
+            .filter_map(|h| SourceTimezone::parse(&format!("Etc/GMT{:+}", -h)).ok()),
+    );
+    candidates.extend(
+        [
+            "Europe/London",
+            "Europe/Berlin",
+            "Europe/Athens",
+            "America/New_York",
+            "America/Chicago",
+            "Australia/Sydney",
+        ]
+        .iter()
+        .filter_map(|name| SourceTimezone::parse(name).ok()),
+    );
+    candidates.push(SourceTimezone::BrokerNyClose);
+    candidates
+}
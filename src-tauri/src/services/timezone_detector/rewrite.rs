@@ -0,0 +1,280 @@
+// services/timezone_detector/rewrite.rs - Réécriture en UTC des bougies d'une paire
+// Relues depuis le fuseau enregistré de chaque import, puis fuseau consigné dans import_log.
+
+use crate::models::timezone_detection::TimezoneCorrectionResult;
+use crate::services::candle_resampler::{self, RESAMPLE_SOURCE};
+use crate::services::import_sessions;
+use crate::services::source_timezone::SourceTimezone;
+use chrono::{DateTime, NaiveDateTime, Utc};
+use std::collections::HashSet;
+
+/// Préfixe temporaire pendant la réécriture (évite les collisions sur la clé unique)
+const PENDING_PREFIX: &str = "pending|";
+
+/// Horodatage stocké : RFC 3339 ou "YYYY-MM-DD HH:MM:SS"
+fn parse_stored_time(s: &str) -> Option<NaiveDateTime> {
+    DateTime::parse_from_rfc3339(s)
+        .map(|d| d.naive_utc())
+        .ok()
+        .or_else(|| NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S").ok())
+}
+
+/// Bougies d'un même import candidates à la réécriture
+struct GroupeImport {
+    import_id: Option<i64>,
+    source_file: String,
+    timeframe: String,
+    /// Fuseau enregistré dans import_log (None : inconnu, horodatages lus comme UTC)
+    recorded: Option<SourceTimezone>,
+}
+
+/// Imports de `symbol` à réécrire : fuseau inconnu, ou import désigné par `source_file`.
+/// Un import déjà enregistré dans `timezone` est refusé (décalage appliqué deux fois) ;
+/// sans fichier désigné, les imports au fuseau déclaré (Dukascopy, ticks, Parquet…) sont gardés.
+fn groupes_a_reecrire(
+    conn: &rusqlite::Connection,
+    symbol: &str,
+    source_file: Option<&str>,
+    timezone: &SourceTimezone,
+) -> Result<Vec<GroupeImport>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT c.import_id, c.source_file, c.timeframe, l.source_timezone
+             FROM candle_data c LEFT JOIN import_log l ON l.id = c.import_id
+             WHERE c.symbol = ?1 AND c.source_file IS NOT ?2 AND (?3 IS NULL OR c.source_file = ?3)
+             GROUP BY c.import_id, c.source_file, c.timeframe",
+        )
+        .map_err(|e| format!("Erreur prepare : {}", e))?;
+    let rows: Vec<(Option<i64>, String, String, Option<String>)> = stmt
+        .query_map(
+            rusqlite::params![symbol, RESAMPLE_SOURCE, source_file],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?)),
+        )
+        .map_err(|e| format!("Erreur lecture candle_data : {}", e))?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("Erreur lecture candle_data : {}", e))?;
+
+    let mut groupes = Vec::new();
+    for (import_id, file, timeframe, recorded) in rows {
+        let recorded = SourceTimezone::parse_optional(recorded.as_deref())?;
+        if recorded == Some(*timezone) {
+            if source_file.is_some() {
+                return Err(format!(
+                    "{} ({}) est déjà enregistré en {} : réécriture refusée",
+                    file, timeframe, timezone
+                ));
+            }
+            continue;
+        }
+        if recorded.is_some() && source_file.is_none() {
+            continue;
+        }
+        groupes.push(GroupeImport {
+            import_id,
+            source_file: file,
+            timeframe,
+            recorded,
+        });
+    }
+    if groupes.is_empty() {
+        return Err(format!(
+            "Aucune bougie {} à fuseau inconnu : désigner le fichier à corriger",
+            symbol
+        ));
+    }
+    Ok(groupes)
+}
+
+/// Réécrit en UTC les bougies importées de `symbol` (limitées à `source_file` si fourni),
+/// relues comme heures locales de `timezone`, puis recalcule les timeframes dérivés.
+/// Chaque import est relu depuis son fuseau enregistré ; import_log garde ensuite `timezone`.
+/// Les bougies sans heure UTC (heure locale inexistante, doublon de l'heure d'hiver,
+/// horodatage illisible) sont supprimées : aucune ne reste à l'ancien horodatage.
+/// Annulé si une bougie corrigée tombe sur une bougie d'un autre import.
+pub fn reecrire_en_utc(
+    conn: &mut rusqlite::Connection,
+    symbol: &str,
+    source_file: Option<&str>,
+    timezone: &SourceTimezone,
+) -> Result<TimezoneCorrectionResult, String> {
+    let tx = conn
+        .transaction()
+        .map_err(|e| format!("Erreur début transaction : {}", e))?;
+    let groupes = groupes_a_reecrire(&tx, symbol, source_file, timezone)?;
+
+    let mut rewritten = 0usize;
+    let mut deleted = 0usize;
+    let mut range: Option<(DateTime<Utc>, DateTime<Utc>)> = None;
+    for groupe in &groupes {
+        let rows: Vec<(i64, String)> = {
+            let mut stmt = tx
+                .prepare(
+                    "SELECT rowid, time FROM candle_data
+                     WHERE symbol = ?1 AND timeframe = ?2 AND source_file = ?3 AND import_id IS ?4",
+                )
+                .map_err(|e| format!("Erreur prepare : {}", e))?;
+            let rows = stmt
+                .query_map(
+                    rusqlite::params![
+                        symbol,
+                        groupe.timeframe,
+                        groupe.source_file,
+                        groupe.import_id
+                    ],
+                    |row| Ok((row.get(0)?, row.get(1)?)),
+                )
+                .map_err(|e| format!("Erreur lecture candle_data : {}", e))?
+                .collect::<Result<Vec<_>, _>>()
+                .map_err(|e| format!("Erreur lecture candle_data : {}", e))?;
+            rows
+        };
+
+        let mut stmt = tx
+            .prepare("UPDATE candle_data SET time = ? WHERE rowid = ?")
+            .map_err(|e| format!("Erreur prepare : {}", e))?;
+        let mut delete = tx
+            .prepare("DELETE FROM candle_data WHERE rowid = ?")
+            .map_err(|e| format!("Erreur prepare : {}", e))?;
+        let mut seen = HashSet::new();
+        for (rowid, time) in &rows {
+            let stored = parse_stored_time(time);
+            // Heure locale du fichier : inverse de la conversion faite à l'import
+            let local = stored.map(|stored| {
+                groupe
+                    .recorded
+                    .map_or(stored, |recorded| recorded.from_utc(stored.and_utc()))
+            });
+            // Heure inexistante, ou deux heures du fichier fusionnées par l'heure d'hiver
+            let utc = local
+                .and_then(|local| timezone.to_utc(local).ok())
+                .filter(|utc| seen.insert(*utc));
+            let (Some(stored), Some(utc)) = (stored, utc) else {
+                delete
+                    .execute([rowid])
+                    .map_err(|e| format!("Erreur suppression ligne {} : {}", rowid, e))?;
+                deleted += 1;
+                if let Some(stored) = stored {
+                    let stored = stored.and_utc();
+                    range = Some(
+                        range.map_or((stored, stored), |(a, b)| (a.min(stored), b.max(stored))),
+                    );
+                }
+                continue;
+            };
+            stmt.execute(rusqlite::params![
+                format!("{}{}", PENDING_PREFIX, utc.to_rfc3339()),
+                rowid
+            ])
+            .map_err(|e| format!("Erreur réécriture ligne {} : {}", rowid, e))?;
+            rewritten += 1;
+
+            // Plage couvrant anciens et nouveaux horodatages (resynchronisation des dérivés)
+            let stored = stored.and_utc();
+            let (lo, hi) = (stored.min(utc), stored.max(utc));
+            range = Some(range.map_or((lo, hi), |(a, b)| (a.min(lo), b.max(hi))));
+        }
+    }
+
+    let prefix_len = PENDING_PREFIX.len() as i64 + 1;
+    let pending = format!("{}%", PENDING_PREFIX);
+    // Bougies dérivées à la place visée : recalculées par sync_derived_timeframes
+    tx.execute(
+        "DELETE FROM candle_data AS c
+         WHERE c.symbol = ?2 AND c.source_file = ?4 AND EXISTS (
+             SELECT 1 FROM candle_data p
+             WHERE p.symbol = c.symbol AND p.timeframe = c.timeframe
+               AND p.time LIKE ?3 AND substr(p.time, ?1) = c.time)",
+        rusqlite::params![prefix_len, symbol, pending, RESAMPLE_SOURCE],
+    )
+    .map_err(|e| format!("Erreur purge dérivées : {}", e))?;
+
+    let (collisions, example): (i64, Option<String>) = tx
+        .query_row(
+            "SELECT COUNT(*), MIN(c.time) FROM candle_data p
+             JOIN candle_data c ON c.symbol = p.symbol AND c.timeframe = p.timeframe
+                 AND c.time = substr(p.time, ?1)
+             WHERE p.symbol = ?2 AND p.time LIKE ?3",
+            rusqlite::params![prefix_len, symbol, pending],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+        .map_err(|e| format!("Erreur recherche collisions : {}", e))?;
+    if collisions > 0 {
+        return Err(format!(
+            "{} bougie(s) corrigée(s) tomberaient sur des bougies d'autres imports (dès {}) : \
+             réécriture annulée, annuler ou remplacer ces imports d'abord",
+            collisions,
+            example.unwrap_or_default()
+        ));
+    }
+
+    tx.execute(
+        "UPDATE candle_data SET time = substr(time, ?1) WHERE symbol = ?2 AND time LIKE ?3",
+        rusqlite::params![prefix_len, symbol, pending],
+    )
+    .map_err(|e| format!("Erreur finalisation réécriture : {}", e))?;
+
+    enregistrer_fuseau(&tx, symbol, &groupes, timezone)?;
+
+    tx.commit()
+        .map_err(|e| format!("Erreur commit réécriture : {}", e))?;
+
+    let derived_rows = match range {
+        Some((start, end)) => candle_resampler::sync_derived_timeframes(conn, symbol, start, end)?,
+        None => 0,
+    };
+
+    Ok(TimezoneCorrectionResult {
+        symbol: symbol.to_string(),
+        source_timezone: timezone.to_string(),
+        rows_rewritten: rewritten,
+        rows_deleted: deleted,
+        derived_rows,
+    })
+}
+
+/// Enregistre `timezone` dans import_log pour les imports réécrits ; les bougies
+/// antérieures aux sessions d'import sont rattachées à une nouvelle session.
+fn enregistrer_fuseau(
+    tx: &rusqlite::Connection,
+    symbol: &str,
+    groupes: &[GroupeImport],
+    timezone: &SourceTimezone,
+) -> Result<(), String> {
+    let name = timezone.to_string();
+    for groupe in groupes {
+        if let Some(id) = groupe.import_id {
+            tx.execute(
+                "UPDATE import_log SET source_timezone = ? WHERE id = ?",
+                rusqlite::params![name, id],
+            )
+            .map_err(|e| format!("Erreur mise à jour import_log : {}", e))?;
+            continue;
+        }
+        let id = import_sessions::ouvrir_session(
+            tx,
+            &groupe.source_file,
+            symbol,
+            &groupe.timeframe,
+            &Utc::now().to_rfc3339(),
+            Some(&name),
+            None,
+        )?;
+        tx.execute(
+            "UPDATE candle_data SET import_id = ?1
+             WHERE symbol = ?2 AND timeframe = ?3 AND source_file = ?4 AND import_id IS NULL",
+            rusqlite::params![id, symbol, groupe.timeframe, groupe.source_file],
+        )
+        .and_then(|rows| {
+            tx.execute(
+                "UPDATE import_log SET row_count = ? WHERE id = ?",
+                rusqlite::params![rows as i64, id],
+            )
+        })
+        .map_err(|e| format!("Erreur rattachement session : {}", e))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+#[path = "rewrite_tests.rs"]
+mod tests;
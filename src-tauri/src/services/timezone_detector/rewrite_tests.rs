@@ -0,0 +1,94 @@
+// services/timezone_detector/rewrite_tests.rs - Réécriture UTC : refus, collisions, heure d'été
+
+use super::*;
+
+fn insert_import(conn: &rusqlite::Connection, file: &str, timezone: Option<&str>) -> i64 {
+    import_sessions::ouvrir_session(conn, file, "EURUSD", "M1", "2024-01-01", timezone, None)
+        .unwrap()
+}
+
+fn insert_m1(conn: &rusqlite::Connection, import_id: i64, file: &str, time: &str) {
+    conn.execute(
+        "INSERT INTO candle_data (symbol, timeframe, time, open, high, low, close, volume, source_file, import_id)
+         VALUES ('EURUSD', 'M1', ?, 1.1, 1.1, 1.1, 1.1, 1.0, ?, ?)",
+        rusqlite::params![time, file, import_id],
+    )
+    .unwrap();
+}
+
+fn times(conn: &rusqlite::Connection, file: &str) -> Vec<String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT time FROM candle_data WHERE source_file = ? AND timeframe = 'M1' ORDER BY time",
+        )
+        .unwrap();
+    let rows = stmt
+        .query_map([file], |row| row.get(0))
+        .unwrap()
+        .collect::<Result<Vec<String>, _>>()
+        .unwrap();
+    rows
+}
+
+#[test]
+fn test_rewrite_refused_twice_and_declared_imports_kept() {
+    let (_dir, mut conn) = crate::db::temp_pairs_db();
+    let athens = SourceTimezone::parse("Europe/Athens").unwrap();
+    let csv = insert_import(&conn, "broker.csv", None);
+    insert_m1(&conn, csv, "broker.csv", "2024-01-05T15:30:00+00:00");
+    let duka = insert_import(&conn, "dukascopy-EURUSD", Some("UTC"));
+    insert_m1(&conn, duka, "dukascopy-EURUSD", "2024-01-08T15:30:00+00:00");
+
+    let result = reecrire_en_utc(&mut conn, "EURUSD", None, &athens).unwrap();
+    assert_eq!(result.rows_rewritten, 1);
+    assert_eq!(times(&conn, "broker.csv"), ["2024-01-05T13:30:00+00:00"]);
+    // Import au fuseau déclaré non touché
+    assert_eq!(
+        times(&conn, "dukascopy-EURUSD"),
+        ["2024-01-08T15:30:00+00:00"]
+    );
+
+    // Deuxième passage : rien à fuseau inconnu, fichier déjà en Europe/Athens
+    assert!(reecrire_en_utc(&mut conn, "EURUSD", None, &athens).is_err());
+    assert!(reecrire_en_utc(&mut conn, "EURUSD", Some("broker.csv"), &athens).is_err());
+    assert_eq!(times(&conn, "broker.csv"), ["2024-01-05T13:30:00+00:00"]);
+
+    // Fuseau déclaré corrigé depuis l'heure locale d'origine (15:30 Athènes = 14:30 Paris)
+    let paris = SourceTimezone::parse("Europe/Paris").unwrap();
+    reecrire_en_utc(&mut conn, "EURUSD", Some("broker.csv"), &paris).unwrap();
+    assert_eq!(times(&conn, "broker.csv"), ["2024-01-05T14:30:00+00:00"]);
+}
+
+#[test]
+fn test_collision_aborts_rewrite() {
+    let (_dir, mut conn) = crate::db::temp_pairs_db();
+    let athens = SourceTimezone::parse("Europe/Athens").unwrap();
+    let csv = insert_import(&conn, "broker.csv", None);
+    insert_m1(&conn, csv, "broker.csv", "2024-01-05T15:30:00+00:00");
+    let duka = insert_import(&conn, "dukascopy-EURUSD", Some("UTC"));
+    insert_m1(&conn, duka, "dukascopy-EURUSD", "2024-01-05T13:30:00+00:00");
+
+    let err = reecrire_en_utc(&mut conn, "EURUSD", None, &athens).unwrap_err();
+    assert!(err.contains("1 bougie(s)"));
+    // Transaction annulée : aucune bougie perdue ni décalée
+    assert_eq!(times(&conn, "broker.csv"), ["2024-01-05T15:30:00+00:00"]);
+    assert_eq!(
+        times(&conn, "dukascopy-EURUSD"),
+        ["2024-01-05T13:30:00+00:00"]
+    );
+}
+
+#[test]
+fn test_nonexistent_local_hour_deleted_without_false_collision() {
+    let (_dir, mut conn) = crate::db::temp_pairs_db();
+    let athens = SourceTimezone::parse("Europe/Athens").unwrap();
+    let csv = insert_import(&conn, "broker.csv", None);
+    // 03:30 n'existe pas à Athènes le 31/03/2024 ; 06:30 (UTC+3) devient 03:30 UTC
+    insert_m1(&conn, csv, "broker.csv", "2024-03-31T03:30:00+00:00");
+    insert_m1(&conn, csv, "broker.csv", "2024-03-31T06:30:00+00:00");
+
+    let result = reecrire_en_utc(&mut conn, "EURUSD", None, &athens).unwrap();
+    assert_eq!(result.rows_rewritten, 1);
+    assert_eq!(result.rows_deleted, 1);
+    assert_eq!(times(&conn, "broker.csv"), ["2024-03-31T03:30:00+00:00"]);
+}
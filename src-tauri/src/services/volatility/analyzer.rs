@@ -11,7 +11,7 @@ use super::volatility_heuristics::VolatilityHeuristics;
 use crate::db::DbPool;
 use crate::models::{
     AnalysisResult, Candle, Result, RiskLevel, TradingRecommendation,
-    VolatilityError,
+    VolatilityError, VolatilityEstimator,
 };
 use chrono::Datelike;
 use tracing::info;
@@ -19,12 +19,22 @@ use tracing::info;
 /// Analyseur de volatilité principal
 pub struct VolatilityAnalyzer {
     candles: Vec<Candle>,
+    estimator: VolatilityEstimator,
 }
 
 impl VolatilityAnalyzer {
     /// Crée un nouvel analyseur avec les bougies fournies
     pub fn new(candles: Vec<Candle>) -> Self {
-        Self { candles }
+        Self {
+            candles,
+            estimator: VolatilityEstimator::default(),
+        }
+    }
+
+    /// Choisit l'estimateur de volatilité utilisé pour classer les quarters
+    pub fn with_estimator(mut self, estimator: VolatilityEstimator) -> Self {
+        self.estimator = estimator;
+        self
     }
 
     /// Effectue l'analyse complète et retourne le résultat
@@ -163,7 +173,7 @@ impl VolatilityAnalyzer {
         }
 
         // 2. Trouve le meilleur quarter
-        let best_quarter = BestQuarterFinder::find_best_quarter(&stats_15min).unwrap_or((0, 0));
+        let best_quarter = BestQuarterFinder::find_best_quarter(&stats_15min, self.estimator).unwrap_or((0, 0));
 
         // 2. Calcule les métriques globales
         let global_metrics =
@@ -217,6 +227,7 @@ impl VolatilityAnalyzer {
             global_metrics,
             point_value,
             unit,
+            volatility_estimator: self.estimator,
         })
     }
 }
@@ -1,7 +1,8 @@
 // services/volatility/best_quarter_finder.rs - Détection du meilleur quarter (15 min)
 // Module séparé pour respecter la limite de taille (metrics.rs < 300L)
 
-use crate::models::Stats15Min;
+use crate::models::{Stats15Min, VolatilityEstimator};
+use crate::services::metrics::selectionner_volatilite;
 
 /// Détecteur du meilleur quarter pour stratégie STRADDLE
 pub(super) struct BestQuarterFinder;
@@ -19,8 +20,13 @@ impl BestQuarterFinder {
     /// Changement V6: Priorité absolue à l'amplitude (Pips) et Volatilité.
     /// On accepte plus de bruit (Noise) si le mouvement est puissant.
     ///
+    /// `estimator` choisit la mesure de volatilité utilisée (close-to-close par défaut).
+    ///
     /// Retourne (hour, quarter) du meilleur moment de la journée
-    pub(super) fn find_best_quarter(stats_15min: &[Stats15Min]) -> Option<(u8, u8)> {
+    pub(super) fn find_best_quarter(
+        stats_15min: &[Stats15Min],
+        estimator: VolatilityEstimator,
+    ) -> Option<(u8, u8)> {
         const VOLATILITY_IDEAL: f64 = 2.0; // 2.0% = volatilité cible (Forex)
         const RANGE_IDEAL: f64 = 25.0; // 25 pips = référence (CORRIGÉ: Unité Pips, pas Prix)
 
@@ -32,12 +38,15 @@ impl BestQuarterFinder {
             .iter()
             .filter(|q| q.candle_count > 0)
             .map(|q| {
+                let volatility =
+                    selectionner_volatilite(estimator, &q.range_volatility, q.volatility_mean);
+
                 // ============================================
                 // SCORE COMPOSITE STRADDLE (V6 - High Volatility)
                 // ============================================
 
                 // 1. VOLATILITÉ (40%) - PRIMARY
-                let volatility_score = (q.volatility_mean / VOLATILITY_IDEAL).min(1.0) * 40.0;
+                let volatility_score = (volatility / VOLATILITY_IDEAL).min(1.0) * 40.0;
 
                 // 2. RANGE RÉEL (30%) - NEW PRIMARY
                 // On veut des Pips ! Un mouvement de 50 pips "sale" vaut mieux qu'un 10 pips "propre"
@@ -74,7 +83,7 @@ impl BestQuarterFinder {
                 // ============================================
 
                 // BONUS: Volatilité excellente (> 2.0%)
-                if q.volatility_mean > 2.0 {
+                if volatility > 2.0 {
                     total_score += 10.0;
                 }
 
@@ -90,7 +99,7 @@ impl BestQuarterFinder {
 
                 // PÉNALITÉ: Volatilité anémique (< 0.5%) - Seuil baissé pour Forex
                 // FIX: Désactivé si le Spike est significatif (> 15 pips)
-                if q.volatility_mean < 0.5 && q.max_true_range < 15.0 {
+                if volatility < 0.5 && q.max_true_range < 15.0 {
                     total_score -= 20.0;
                 }
 
@@ -85,6 +85,7 @@ impl EventCorrelator {
                         volatility_hour: event_hour,
                         volatility_increase,
                         correlation_score,
+                        significance: None,
                     });
                 }
             }
@@ -2,7 +2,7 @@
 // Conforme .clinerules : < 150L, pas d'unwrap()
 
 use super::utils::{max, mean};
-use crate::models::{Candle, HourlyStats, Result};
+use crate::models::{Candle, HourlyStats, RangeVolatility, Result};
 use crate::services::MetricsCalculator;
 use std::collections::HashMap;
 use tracing::debug;
@@ -53,6 +53,7 @@ impl<'a> HourlyStatsCalculator<'a> {
                     volume_imbalance_mean: 0.0,
                     noise_ratio_mean: 0.0,
                     breakout_percentage: 0.0,
+                    range_volatility: RangeVolatility::default(),
                     events: Vec::new(),
                 });
             }
@@ -77,6 +78,7 @@ impl<'a> HourlyStatsCalculator<'a> {
         let shadow_ratios = calc.calculer_ratios_ombres();
         let noise_ratios = calc.calculer_ratio_bruit();
         let tr_dist = calc.calculer_distribution_true_range()?;
+        let range_volatility = calc.calculer_volatilite_range();
 
         // Normalisation des valeurs (Pips/Points) — DB override en priorité
         let asset_props = crate::services::pair_data::symbol_properties::get_asset_properties(&self.symbol);
@@ -122,6 +124,7 @@ impl<'a> HourlyStatsCalculator<'a> {
             volume_imbalance_mean: direction_strength, // Remplacé par direction_strength
             noise_ratio_mean,
             breakout_percentage,
+            range_volatility,
             events: Vec::new(), // Sera rempli après par l'analyseur
         })
     }
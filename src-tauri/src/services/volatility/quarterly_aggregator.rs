@@ -1,7 +1,7 @@
 // services/volatility/quarterly_aggregator.rs - Agrégation des stats par quarter sur toute la période
 // Calcule les moyennes historiques de peak_duration, half_life et trade_exp
 
-use crate::models::Stats15Min;
+use crate::models::{RangeVolatility, Stats15Min};
 
 /// Agrégateur de statistiques par quarter
 pub(super) struct QuarterlyAggregator;
@@ -46,6 +46,7 @@ impl QuarterlyAggregator {
                             volume_imbalance_mean: 0.0,
                             noise_ratio_mean: 0.0,
                             breakout_percentage: 0.0,
+                            range_volatility: RangeVolatility::default(),
                             events: Vec::new(),
                             peak_duration_minutes: None,
                             volatility_half_life_minutes: None,
@@ -82,6 +83,15 @@ impl QuarterlyAggregator {
                             instances.iter().map(|s| s.noise_ratio_mean).sum::<f64>() / count;
                         let breakout_percentage_avg =
                             instances.iter().map(|s| s.breakout_percentage).sum::<f64>() / count;
+                        let range_avg = |f: fn(&RangeVolatility) -> f64| {
+                            instances.iter().map(|s| f(&s.range_volatility)).sum::<f64>() / count
+                        };
+                        let range_volatility_avg = RangeVolatility {
+                            parkinson: range_avg(|r| r.parkinson),
+                            garman_klass: range_avg(|r| r.garman_klass),
+                            rogers_satchell: range_avg(|r| r.rogers_satchell),
+                            yang_zhang: range_avg(|r| r.yang_zhang),
+                        };
 
                         // Calculer les moyennes des peak/half-life/trade_exp
                         let peak_duration_with_values: Vec<u16> = instances
@@ -178,6 +188,7 @@ impl QuarterlyAggregator {
                             volume_imbalance_mean: volume_imbalance_mean_avg,
                             noise_ratio_mean: noise_ratio_mean_avg,
                             breakout_percentage: breakout_percentage_avg,
+                            range_volatility: range_volatility_avg,
                             events: Vec::new(),
                             peak_duration_minutes: None, // Remplacé par _mean
                             volatility_half_life_minutes: None, // Remplacé par _mean
@@ -206,6 +217,7 @@ impl QuarterlyAggregator {
                         volume_imbalance_mean: 0.0,
                         noise_ratio_mean: 0.0,
                         breakout_percentage: 0.0,
+                        range_volatility: RangeVolatility::default(),
                         events: Vec::new(),
                         peak_duration_minutes: None,
                         volatility_half_life_minutes: None,
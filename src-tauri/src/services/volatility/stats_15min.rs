@@ -2,7 +2,7 @@
 // Conforme .clinerules : < 150L, pas d'unwrap()
 
 use super::utils::{max, mean};
-use crate::models::{AssetProperties, Candle, Result, Stats15Min};
+use crate::models::{AssetProperties, Candle, RangeVolatility, Result, Stats15Min};
 use crate::services::{MetricsCalculator, VolatilityDurationAnalyzer};
 use chrono::Timelike;
 use std::collections::HashMap;
@@ -64,6 +64,7 @@ impl<'a> Stats15MinCalculator<'a> {
                         volume_imbalance_mean: 0.0,
                         noise_ratio_mean: 0.0,
                         breakout_percentage: 0.0,
+                        range_volatility: RangeVolatility::default(),
                         events: Vec::new(),
                         peak_duration_minutes: None,
                         volatility_half_life_minutes: None,
@@ -110,6 +111,7 @@ impl<'a> Stats15MinCalculator<'a> {
                 volume_imbalance_mean: 0.0,
                 noise_ratio_mean: 0.0,
                 breakout_percentage: 0.0,
+                range_volatility: RangeVolatility::default(),
                 events: Vec::new(),
                 peak_duration_minutes: None,
                 volatility_half_life_minutes: None,
@@ -134,6 +136,7 @@ impl<'a> Stats15MinCalculator<'a> {
         let tick_qualities = calc.calculer_qualite_tick();
         let noise_ratios = calc.calculer_ratio_bruit();
         let tr_dist = calc.calculer_distribution_true_range()?;
+        let range_volatility = calc.calculer_volatilite_range();
 
         // Normalisation des valeurs (Pips/Points) — DB override en priorité
         let symbol = candles
@@ -251,6 +254,7 @@ impl<'a> Stats15MinCalculator<'a> {
             volume_imbalance_mean: direction_strength,
             noise_ratio_mean,
             breakout_percentage,
+            range_volatility,
             events: Vec::new(),
             peak_duration_minutes: peak_duration,
             volatility_half_life_minutes: half_life,
@@ -93,6 +93,7 @@ impl VolatilityHeuristics {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::models::RangeVolatility;
 
     fn create_test_slice(atr: f64, events_count: usize) -> Stats15Min {
         Stats15Min {
@@ -107,6 +108,7 @@ mod tests {
             volume_imbalance_mean: 0.1,
             noise_ratio_mean: 2.0,
             breakout_percentage: 30.0,
+            range_volatility: RangeVolatility::default(),
             candle_count: 15,
             events: vec![EventInHour {
                 event_name: "Test Event".to_string(),
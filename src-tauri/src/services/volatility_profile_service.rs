@@ -52,17 +52,7 @@ impl VolatilityProfileService {
 
     /// Get profile by AssetType enum (converts to string)
     pub fn get_profile_by_type(&self, asset_type: AssetType) -> Result<VolatilityProfile, String> {
-        let type_str = match asset_type {
-            AssetType::ForexMajor => "ForexMajor",
-            AssetType::ForexJpy => "ForexJpy",
-            AssetType::Gold => "Gold",
-            AssetType::Silver => "Silver",
-            AssetType::Crypto => "Crypto",
-            AssetType::Index => "Index",
-            AssetType::Commodity => "Commodity",
-            AssetType::Unknown => "Unknown",
-        };
-        self.get_profile(type_str)
+        self.get_profile(asset_type.as_str())
     }
 
     /// List all volatility profiles
@@ -0,0 +1,372 @@
+// services/walk_forward_optimizer/mod.rs
+// Optimisation walk-forward des paramètres straddle (offset, distance, SL/TP, durée).
+//
+// Les occurrences sont découpées en fenêtres glissantes : la meilleure combinaison
+// est choisie sur la fenêtre in-sample puis appliquée telle quelle sur les occurrences
+// suivantes (out-of-sample). Seule la performance OOS est représentative.
+
+mod search;
+
+pub use search::{build_candidates, widest_config};
+
+use crate::models::backtest::{BacktestConfig, BacktestTrade};
+use crate::models::walk_forward::{
+    OptimizationObjective, ParameterGrid, WalkForwardConfig, WalkForwardResult, WalkForwardWindow,
+};
+use crate::models::{Candle, Result, VolatilityError};
+use crate::services::pair_data::get_asset_properties;
+use crate::services::straddle_backtester::{
+    build_equity_curve, simulate_occurrence, OccurrenceOutcome, TradeStats,
+};
+use chrono::{DateTime, Utc};
+use std::collections::HashMap;
+use tracing::info;
+
+type Occurrence = (DateTime<Utc>, Vec<Candle>);
+
+/// Plafond du profit factor objectif : une fenêtre sans perte n'est pas infiniment meilleure
+pub const PROFIT_FACTOR_CAP: f64 = 10.0;
+
+/// Lance l'optimisation walk-forward sur les occurrences d'un type d'événement.
+pub fn run_walk_forward(
+    occurrences: &[Occurrence],
+    symbol: &str,
+    event_type: &str,
+    config: &WalkForwardConfig,
+) -> Result<WalkForwardResult> {
+    let props = get_asset_properties(symbol);
+    let is_len = config.in_sample_occurrences;
+    let oos_len = config.out_of_sample_occurrences;
+    if props.pip_value <= 0.0 || is_len == 0 || oos_len == 0 {
+        return Err(VolatilityError::ValidationError(
+            "Fenêtres in-sample / out-of-sample doivent être > 0".into(),
+        ));
+    }
+    if occurrences.len() <= is_len {
+        return Err(VolatilityError::InsufficientData(format!(
+            "{} occurrences pour une fenêtre in-sample de {}",
+            occurrences.len(),
+            is_len
+        )));
+    }
+
+    validate_grid(&config.grid)?;
+
+    let candidates = build_candidates(&config.grid, &config.search, &config.base);
+    if candidates.is_empty() {
+        return Err(VolatilityError::ValidationError(
+            "Grille de paramètres vide".into(),
+        ));
+    }
+
+    let mut sorted: Vec<&Occurrence> = occurrences.iter().collect();
+    sorted.sort_by_key(|(t, _)| *t);
+
+    let mut windows = Vec::new();
+    let mut oos_trades: Vec<BacktestTrade> = Vec::new();
+    let mut is_expectancies = Vec::new();
+    let mut start = 0usize;
+
+    while start + is_len < sorted.len() {
+        let in_sample = &sorted[start..start + is_len];
+        let oos_end = (start + is_len + oos_len).min(sorted.len());
+        let out_sample = &sorted[start + is_len..oos_end];
+
+        let best = fit_in_sample(in_sample, &candidates, config, props.pip_value);
+        let (trades, best_config, is_score, is_trades) = match best {
+            Some((cfg, score, n, expectancy)) => {
+                is_expectancies.push(expectancy);
+                (
+                    replay(out_sample, &cfg, props.pip_value),
+                    Some(cfg),
+                    score,
+                    n,
+                )
+            }
+            None => (Vec::new(), None, 0.0, 0),
+        };
+        let stats = TradeStats::from_trades(&trades);
+
+        windows.push(WalkForwardWindow {
+            index: windows.len(),
+            in_sample_start: in_sample[0].0.to_rfc3339(),
+            in_sample_end: in_sample[is_len - 1].0.to_rfc3339(),
+            out_of_sample_start: out_sample[0].0.to_rfc3339(),
+            out_of_sample_end: out_sample[out_sample.len() - 1].0.to_rfc3339(),
+            best_config,
+            in_sample_score: is_score,
+            in_sample_trades: is_trades,
+            out_of_sample_trades: trades.len(),
+            out_of_sample_total_pips: stats.total,
+            out_of_sample_win_rate: stats.win_rate,
+            out_of_sample_expectancy_pips: stats.expectancy,
+            out_of_sample_profit_factor: stats.profit_factor,
+        });
+        oos_trades.extend(trades);
+        start += oos_len;
+    }
+
+    let stats = TradeStats::from_trades(&oos_trades);
+    let (equity_curve, max_dd) = build_equity_curve(&oos_trades);
+    let mean_is = if is_expectancies.is_empty() {
+        0.0
+    } else {
+        is_expectancies.iter().sum::<f64>() / is_expectancies.len() as f64
+    };
+
+    info!(
+        "Walk-forward {symbol}/{event_type}: {} fenêtres, {} combinaisons, OOS total={:.1} pips",
+        windows.len(),
+        candidates.len(),
+        stats.total
+    );
+
+    Ok(WalkForwardResult {
+        symbol: symbol.to_string(),
+        event_type: event_type.to_string(),
+        combinations_tested: candidates.len(),
+        most_selected_entry_offset: most_selected_offset(&windows),
+        windows,
+        out_of_sample_equity_curve: equity_curve,
+        out_of_sample_total_pips: stats.total,
+        out_of_sample_win_rate: stats.win_rate,
+        out_of_sample_expectancy_pips: stats.expectancy,
+        out_of_sample_profit_factor: stats.profit_factor,
+        out_of_sample_max_drawdown_pips: max_dd,
+        walk_forward_efficiency: if mean_is > 0.0 {
+            stats.expectancy / mean_is
+        } else {
+            0.0
+        },
+        out_of_sample_trades: oos_trades,
+        unit: props.unit,
+    })
+}
+
+/// Meilleure combinaison in-sample : (config, score, nb trades, espérance)
+fn fit_in_sample(
+    in_sample: &[&Occurrence],
+    candidates: &[BacktestConfig],
+    config: &WalkForwardConfig,
+    pip_value: f64,
+) -> Option<(BacktestConfig, f64, usize, f64)> {
+    let mut best: Option<(BacktestConfig, f64, usize, f64)> = None;
+    for candidate in candidates {
+        let trades = replay(in_sample, candidate, pip_value);
+        if trades.len() < config.min_in_sample_trades.max(1) {
+            continue;
+        }
+        let stats = TradeStats::from_trades(&trades);
+        let score = objective_score(&stats, config.objective);
+        // Égalité (ex. profit factor plafonné) départagée par l'espérance
+        let is_better = match &best {
+            Some((_, best_score, _, best_expectancy)) => {
+                (score, stats.expectancy) > (*best_score, *best_expectancy)
+            }
+            None => true,
+        };
+        if is_better {
+            best = Some((candidate.clone(), score, trades.len(), stats.expectancy));
+        }
+    }
+    best
+}
+
+fn replay(
+    occurrences: &[&Occurrence],
+    config: &BacktestConfig,
+    pip_value: f64,
+) -> Vec<BacktestTrade> {
+    occurrences
+        .iter()
+        .filter_map(
+            |(t, candles)| match simulate_occurrence(candles, *t, config, pip_value) {
+                OccurrenceOutcome::Filled(trade) => Some(trade),
+                _ => None,
+            },
+        )
+        .collect()
+}
+
+fn objective_score(stats: &TradeStats, objective: OptimizationObjective) -> f64 {
+    match objective {
+        OptimizationObjective::Expectancy => stats.expectancy,
+        OptimizationObjective::TotalPips => stats.total,
+        // Sans perte, le profit factor est non borné : plafonné avec des gains, 0 sans gain
+        OptimizationObjective::ProfitFactor => match stats.profit_factor {
+            Some(pf) => pf.min(PROFIT_FACTOR_CAP),
+            None if stats.total > 0.0 => PROFIT_FACTOR_CAP,
+            None => 0.0,
+        },
+    }
+}
+
+/// Mêmes contrôles que `run_backtest`, appliqués à chaque valeur de la grille
+fn validate_grid(grid: &ParameterGrid) -> Result<()> {
+    if grid.entry_offsets_minutes.iter().any(|o| *o < 0) {
+        return Err(VolatilityError::ValidationError(
+            "Offsets d'entrée de la grille doivent être >= 0".into(),
+        ));
+    }
+    if grid.straddle_distances_pips.iter().any(|d| *d <= 0.0)
+        || grid.max_durations_minutes.iter().any(|d| *d <= 0)
+    {
+        return Err(VolatilityError::ValidationError(
+            "Distances straddle et durées max de la grille doivent être > 0".into(),
+        ));
+    }
+    if grid
+        .atr_multipliers_sl
+        .iter()
+        .chain(&grid.atr_multipliers_tp)
+        .any(|m| *m <= 0.0)
+    {
+        return Err(VolatilityError::ValidationError(
+            "Multiplicateurs ATR SL/TP de la grille doivent être > 0".into(),
+        ));
+    }
+    Ok(())
+}
+
+fn most_selected_offset(windows: &[WalkForwardWindow]) -> Option<i64> {
+    let mut counts: HashMap<i64, usize> = HashMap::new();
+    for cfg in windows.iter().filter_map(|w| w.best_config.as_ref()) {
+        *counts.entry(cfg.entry_offset_minutes).or_default() += 1;
+    }
+    counts
+        .into_iter()
+        .max_by_key(|(offset, n)| (*n, -offset))
+        .map(|(offset, _)| offset)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::walk_forward::SearchMode;
+    use chrono::{Duration, TimeZone};
+
+    /// Occurrence avec cassure haussière nette à T0
+    fn breakout_up(event: DateTime<Utc>) -> Occurrence {
+        let mut candles = Vec::new();
+        for i in -20i64..30 {
+            let (high, low, close) = if i < 0 {
+                (1.1002, 1.0998, 1.1000)
+            } else {
+                let p = 1.1000 + 0.0005 * (i + 1) as f64;
+                (p + 0.0002, p - 0.0002, p)
+            };
+            candles.push(Candle {
+                symbol: "EURUSD".to_string(),
+                datetime: event + Duration::minutes(i),
+                open: 1.1000,
+                high,
+                low,
+                close,
+                volume: 100.0,
+                spread_mean: Some(0.0001),
+                ..Default::default()
+            });
+        }
+        (event, candles)
+    }
+
+    fn small_config() -> WalkForwardConfig {
+        WalkForwardConfig {
+            in_sample_occurrences: 4,
+            out_of_sample_occurrences: 2,
+            min_in_sample_trades: 2,
+            grid: ParameterGrid {
+                entry_offsets_minutes: vec![2],
+                straddle_distances_pips: vec![5.0, 10.0],
+                atr_multipliers_sl: vec![2.0],
+                atr_multipliers_tp: vec![2.0, 3.0],
+                max_durations_minutes: vec![15],
+            },
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_windows_are_rolling_and_out_of_sample() {
+        let occurrences: Vec<Occurrence> = (0..8)
+            .map(|m| breakout_up(Utc.with_ymd_and_hms(2024, 1 + m, 5, 13, 30, 0).unwrap()))
+            .collect();
+
+        let result = run_walk_forward(&occurrences, "EURUSD", "NFP", &small_config())
+            .expect("walk-forward should run");
+
+        // 8 occurrences, IS=4, OOS=2 → fenêtres à 0 et 2
+        assert_eq!(result.windows.len(), 2);
+        assert_eq!(result.combinations_tested, 4);
+        assert!(result.windows[0].out_of_sample_start > result.windows[0].in_sample_end);
+        assert_eq!(result.out_of_sample_trades.len(), 4);
+        assert!(result.out_of_sample_total_pips > 0.0);
+        assert_eq!(result.most_selected_entry_offset, Some(2));
+    }
+
+    #[test]
+    fn test_random_search_is_reproducible_subset() {
+        let grid = ParameterGrid::default();
+        let base = BacktestConfig::default();
+        let search = SearchMode::Random {
+            samples: 10,
+            seed: 3,
+        };
+        let a = build_candidates(&grid, &search, &base);
+        let b = build_candidates(&grid, &search, &base);
+        assert_eq!(a.len(), 10);
+        for (x, y) in a.iter().zip(b.iter()) {
+            assert_eq!(x.entry_offset_minutes, y.entry_offset_minutes);
+            assert_eq!(x.straddle_distance_pips, y.straddle_distance_pips);
+        }
+    }
+
+    #[test]
+    fn test_insufficient_occurrences() {
+        let occurrences = vec![breakout_up(
+            Utc.with_ymd_and_hms(2024, 1, 5, 13, 30, 0).unwrap(),
+        )];
+        assert!(run_walk_forward(&occurrences, "EURUSD", "NFP", &small_config()).is_err());
+    }
+
+    #[test]
+    fn test_invalid_grid_rejected() {
+        let occurrences: Vec<Occurrence> = (0..8)
+            .map(|m| breakout_up(Utc.with_ymd_and_hms(2024, 1 + m, 5, 13, 30, 0).unwrap()))
+            .collect();
+
+        let mut config = small_config();
+        config.grid.straddle_distances_pips = vec![5.0, 0.0];
+        assert!(run_walk_forward(&occurrences, "EURUSD", "NFP", &config).is_err());
+
+        let mut config = small_config();
+        config.grid.max_durations_minutes = vec![-15];
+        assert!(run_walk_forward(&occurrences, "EURUSD", "NFP", &config).is_err());
+    }
+
+    #[test]
+    fn test_profit_factor_without_losses() {
+        let no_trade = TradeStats::from_trades(&[]);
+        assert_eq!(
+            objective_score(&no_trade, OptimizationObjective::ProfitFactor),
+            0.0
+        );
+
+        let occurrences: Vec<Occurrence> = (0..8)
+            .map(|m| breakout_up(Utc.with_ymd_and_hms(2024, 1 + m, 5, 13, 30, 0).unwrap()))
+            .collect();
+        let refs: Vec<&Occurrence> = occurrences.iter().collect();
+        let trades = replay(&refs, &small_config().base, 0.0001);
+        let stats = TradeStats::from_trades(&trades);
+        assert!(stats.profit_factor.is_none() && stats.total > 0.0);
+        assert_eq!(
+            objective_score(&stats, OptimizationObjective::ProfitFactor),
+            PROFIT_FACTOR_CAP
+        );
+
+        let mut config = small_config();
+        config.objective = OptimizationObjective::ProfitFactor;
+        let result = run_walk_forward(&occurrences, "EURUSD", "NFP", &config).unwrap();
+        assert!(result.windows.iter().all(|w| w.in_sample_score.is_finite()));
+    }
+}
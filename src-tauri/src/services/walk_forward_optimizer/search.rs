@@ -0,0 +1,75 @@
+// services/walk_forward_optimizer/search.rs
+// Génération des combinaisons candidates (grille complète ou tirage aléatoire)
+
+use crate::models::backtest::BacktestConfig;
+use crate::models::walk_forward::{ParameterGrid, SearchMode};
+use crate::services::sampling::SplitMix64;
+
+/// Produit les configurations candidates à partir de la grille et des paramètres fixes
+pub fn build_candidates(
+    grid: &ParameterGrid,
+    search: &SearchMode,
+    base: &BacktestConfig,
+) -> Vec<BacktestConfig> {
+    let all = cartesian_product(grid, base);
+    match search {
+        SearchMode::Grid => all,
+        SearchMode::Random { samples, seed } => {
+            if *samples >= all.len() {
+                return all;
+            }
+            // Tirage sans remise (Fisher-Yates partiel)
+            let mut pool = all;
+            let mut rng = SplitMix64::new(*seed);
+            for i in 0..*samples {
+                let j = i + rng.next_index(pool.len() - i);
+                pool.swap(i, j);
+            }
+            pool.truncate(*samples);
+            pool
+        }
+    }
+}
+
+fn cartesian_product(grid: &ParameterGrid, base: &BacktestConfig) -> Vec<BacktestConfig> {
+    let mut out = Vec::new();
+    for &offset in &grid.entry_offsets_minutes {
+        for &distance in &grid.straddle_distances_pips {
+            for &sl in &grid.atr_multipliers_sl {
+                for &tp in &grid.atr_multipliers_tp {
+                    for &duration in &grid.max_durations_minutes {
+                        out.push(BacktestConfig {
+                            entry_offset_minutes: offset,
+                            straddle_distance_pips: distance,
+                            atr_multiplier_sl: sl,
+                            atr_multiplier_tp: tp,
+                            max_duration_minutes: duration,
+                            ..base.clone()
+                        });
+                    }
+                }
+            }
+        }
+    }
+    out
+}
+
+/// Configuration couvrant la plus grande fenêtre de données requise par la grille
+/// (utilisée pour charger les bougies une seule fois par occurrence)
+pub fn widest_config(grid: &ParameterGrid, base: &BacktestConfig) -> BacktestConfig {
+    BacktestConfig {
+        entry_offset_minutes: grid
+            .entry_offsets_minutes
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or(base.entry_offset_minutes),
+        max_duration_minutes: grid
+            .max_durations_minutes
+            .iter()
+            .copied()
+            .max()
+            .unwrap_or(base.max_duration_minutes),
+        ..base.clone()
+    }
+}
@@ -1,4 +1,5 @@
-use crate::models::{Candle, Result, VolatilityError};
+use crate::models::candle::QuoteSide;
+use crate::models::{Candle, ConfidenceInterval, Result, VolatilityError};
 
 /// Résultat d'un trade simulé
 #[derive(Debug, Clone, Copy, PartialEq)]
@@ -17,7 +18,10 @@ pub struct WinRateMetrics {
     pub losses: usize,
     pub whipsaws: usize,
     pub win_rate: f64,
+    /// Bornes bootstrap 5% / 95% du win rate
+    pub win_rate_ci: ConfidenceInterval,
     pub whipsaw_rate: f64,
+    pub whipsaw_rate_ci: ConfidenceInterval,
     #[allow(dead_code)]
     pub avg_profit_pips: f64,
     #[allow(dead_code)]
@@ -25,7 +29,13 @@ pub struct WinRateMetrics {
     pub risk_reward_ratio: f64,
 }
 
-/// Suit un trade jusqu'à TP, SL ou timeout
+/// Demi-spread d'une bougie en prix (0 sans données de spread)
+pub fn half_spread(candle: &Candle) -> f64 {
+    candle.spread_mean.filter(|s| *s >= 0.0).unwrap_or(0.0) / 2.0
+}
+
+/// Suit un trade jusqu'à TP, SL ou timeout.
+/// Un long se clôture sur le bid, un short sur l'ask : TP/SL sont testés sur ce côté.
 pub fn track_trade(
     candles: &[Candle],
     start_index: usize,
@@ -38,34 +48,40 @@ pub fn track_trade(
     let mut hit_tp = false;
     let mut hit_sl = false;
     let mut was_in_profit = false;
+    let exit_side = if is_long {
+        QuoteSide::Bid
+    } else {
+        QuoteSide::Ask
+    };
 
     for candle in candles
         .iter()
         .skip(start_index + 1)
         .take(candles.len().min(start_index + max_minutes) - start_index - 1)
     {
+        let quote = candle.side_ohlc(exit_side, half_spread(candle));
         if is_long {
-            if candle.high >= tp {
+            if quote.high >= tp {
                 hit_tp = true;
                 break;
             }
-            if candle.low <= sl {
+            if quote.low <= sl {
                 hit_sl = true;
                 break;
             }
-            if candle.close > entry_price {
+            if quote.close > entry_price {
                 was_in_profit = true;
             }
         } else {
-            if candle.low <= tp {
+            if quote.low <= tp {
                 hit_tp = true;
                 break;
             }
-            if candle.high >= sl {
+            if quote.high >= sl {
                 hit_sl = true;
                 break;
             }
-            if candle.close < entry_price {
+            if quote.close < entry_price {
                 was_in_profit = true;
             }
         }
@@ -1,10 +1,12 @@
 mod helpers;
 
 pub use helpers::{
-    calculer_atr_a_index, find_candle_index, track_trade, TradeOutcome, WinRateMetrics,
+    calculer_atr_a_index, find_candle_index, half_spread, track_trade, TradeOutcome, WinRateMetrics,
 };
 
+use crate::models::candle::QuoteSide;
 use crate::models::{Candle, Result};
+use crate::services::bootstrap::rate_interval;
 use chrono::DateTime;
 use chrono::Utc;
 use tracing::info;
@@ -13,6 +15,8 @@ use tracing::info;
 pub struct WinRateCalculator<'a> {
     candles: &'a [Candle],
     event_time: DateTime<Utc>,
+    /// Distances SL/TP fixes en prix, prioritaires sur les multiplicateurs ATR
+    fixed_stops: Option<(f64, f64)>,
 }
 
 impl<'a> WinRateCalculator<'a> {
@@ -21,9 +25,16 @@ impl<'a> WinRateCalculator<'a> {
         Self {
             candles,
             event_time,
+            fixed_stops: None,
         }
     }
 
+    /// Utilise des distances SL/TP fixes en prix (ex. percentiles MAE/MFE historiques)
+    pub fn with_fixed_stops(mut self, fixed_stops: Option<(f64, f64)>) -> Self {
+        self.fixed_stops = fixed_stops;
+        self
+    }
+
     /// Simule un trade avec paramètres donnés
     pub fn simulate_trade(
         &self,
@@ -43,20 +54,24 @@ impl<'a> WinRateCalculator<'a> {
 
         let atr = calculer_atr_a_index(self.candles, entry_index)?;
         let entry_candle = &self.candles[entry_index];
-        let entry_price = entry_candle.close;
-        let sl_distance = atr * atr_multiplier_sl;
-        let tp_distance = atr * atr_multiplier_tp;
+        let hs = half_spread(entry_candle);
+        // Long entre sur l'ask, short sur le bid
+        let entry_long = entry_candle.side_ohlc(QuoteSide::Ask, hs).close;
+        let entry_short = entry_candle.side_ohlc(QuoteSide::Bid, hs).close;
+        let (sl_distance, tp_distance) = self
+            .fixed_stops
+            .unwrap_or((atr * atr_multiplier_sl, atr * atr_multiplier_tp));
 
-        let sl_long = entry_price - sl_distance;
-        let tp_long = entry_price + tp_distance;
+        let sl_long = entry_long - sl_distance;
+        let tp_long = entry_long + tp_distance;
 
-        let sl_short = entry_price + sl_distance;
-        let tp_short = entry_price - tp_distance;
+        let sl_short = entry_short + sl_distance;
+        let tp_short = entry_short - tp_distance;
 
         let outcome_long = track_trade(
             self.candles,
             entry_index,
-            entry_price,
+            entry_long,
             tp_long,
             sl_long,
             max_duration_minutes,
@@ -66,7 +81,7 @@ impl<'a> WinRateCalculator<'a> {
         let outcome_short = track_trade(
             self.candles,
             entry_index,
-            entry_price,
+            entry_short,
             tp_short,
             sl_short,
             max_duration_minutes,
@@ -116,10 +131,15 @@ impl<'a> WinRateCalculator<'a> {
             losses,
             whipsaws,
             win_rate,
+            win_rate_ci: rate_interval(wins, total),
             whipsaw_rate,
+            whipsaw_rate_ci: rate_interval(whipsaws, total),
             avg_profit_pips: 0.0,
             avg_loss_pips: 0.0,
-            risk_reward_ratio: atr_multiplier_tp / atr_multiplier_sl,
+            risk_reward_ratio: match self.fixed_stops {
+                Some((sl, tp)) => tp / sl,
+                None => atr_multiplier_tp / atr_multiplier_sl,
+            },
         })
     }
 }
@@ -188,4 +208,19 @@ mod tests {
             .expect("Failed to simulate");
         assert_eq!(outcome, TradeOutcome::Loss);
     }
+
+    #[test]
+    fn test_fixed_stops_override_atr() {
+        let candles: Vec<Candle> = (-30..60)
+            .map(|i| create_test_candle(i, 1.1000, 0.0010))
+            .collect();
+
+        let event_time = DateTime::from_timestamp(1609459200, 0)
+            .expect("Invalid timestamp");
+        let metrics = WinRateCalculator::new(&candles, event_time)
+            .with_fixed_stops(Some((0.0010, 0.0040)))
+            .calculer_taux_reussite(15, 2.0, 3.0, 60)
+            .expect("Failed to calculate");
+        assert!((metrics.risk_reward_ratio - 4.0).abs() < 1e-9);
+    }
 }
@@ -0,0 +1,93 @@
+// composables/useManualImport.ts - Imports manuels (CSV OHLC, ticks, Parquet) ramenés à un rapport commun
+import { invoke } from '@tauri-apps/api/core'
+import { IMPORT_CANCELLED } from '../types/importProgress'
+import type { ReimportMode } from '../types/importSession'
+
+export interface ImportFileResult {
+  file_path: string
+  import_status: string
+  import_message: string
+  late_ticks?: number
+}
+
+export interface ImportReport {
+  successful: number
+  failed: number
+  total_files: number
+  results: ImportFileResult[]
+  /** Ticks hors ordre ignorés (exports tick HistData/MT5 non triés) */
+  late_ticks?: number
+}
+
+/** Bilan de import_pair_data (Parquet) */
+interface ImportSummary {
+  total_files: number
+  successful: number
+  failed: number
+  pairs_updated: string[]
+  timeframes: string[]
+  errors: string[]
+  already_imported: string[]
+  late_ticks?: number
+}
+
+export interface ImportOptions {
+  /** null = défaut du format */
+  sourceTimezone: string | null
+  /** null = un fichier déjà importé est signalé sans être réimporté */
+  reimportMode: ReimportMode | null
+}
+
+export async function importCsvFiles(paths: string[], options: ImportOptions): Promise<ImportReport> {
+  const report = await invoke<ImportReport>('import_and_clean_files', { paths, ...options })
+  return {
+    ...report,
+    late_ticks: report.results.reduce((sum, r) => sum + (r.late_ticks ?? 0), 0)
+  }
+}
+
+/** Un fichier à la fois ; une annulation interrompt les fichiers suivants */
+export async function importTickFiles(paths: string[], options: ImportOptions): Promise<ImportReport> {
+  const results: ImportFileResult[] = []
+  for (const path of paths) {
+    try {
+      const res = await invoke<{ minutes_generated: number; late_ticks?: number }>('import_tick_file', {
+        filePath: path,
+        ...options
+      })
+      results.push({
+        file_path: path,
+        import_status: 'success',
+        import_message: res.late_ticks
+          ? `Importé (${res.minutes_generated} min, ${res.late_ticks} ticks hors ordre ignorés)`
+          : `Importé (${res.minutes_generated} min)`
+      })
+    } catch (e) {
+      results.push({ file_path: path, import_status: 'failed', import_message: String(e) })
+      if (String(e) === IMPORT_CANCELLED) break
+    }
+  }
+
+  const successful = results.filter(r => r.import_status === 'success').length
+  return { total_files: paths.length, successful, failed: results.length - successful, results }
+}
+
+export async function importParquetFiles(paths: string[], options: ImportOptions): Promise<ImportReport> {
+  const summary = await invoke<ImportSummary>('import_pair_data', { paths, ...options })
+
+  // Les erreurs sont au format "fichier: message"
+  const failedNames = summary.errors.map(e => e.split(': ')[0])
+  return {
+    total_files: summary.total_files,
+    successful: summary.successful,
+    failed: summary.failed,
+    late_ticks: summary.late_ticks,
+    results: paths.map(path => {
+      const name = path.split('/').pop() ?? path
+      const error = summary.errors[failedNames.indexOf(name)]
+      return error
+        ? { file_path: path, import_status: 'failed', import_message: error.slice(name.length + 2) }
+        : { file_path: path, import_status: 'success', import_message: 'Importé' }
+    })
+  }
+}
@@ -1,9 +1,10 @@
 import { ref } from 'vue'
 import { invoke } from '@tauri-apps/api/core'
 import { eventTranslations } from '../stores/eventTranslations'
+import type { ReleaseSecondsProfile } from '../types/entryAnalysis'
 
 // Types from Tauri command results
-export interface PeakDelayData { peak_delay_minutes: number; peak_atr: number; event_minute: number; confidence: number; event_count: number; event_type: string; optimal_entry_seconds_before: number; event_date_min: string; event_date_max: string }
+export interface PeakDelayData { peak_delay_minutes: number; peak_atr: number; event_minute: number; confidence: number; event_count: number; event_type: string; optimal_entry_seconds_before: number; event_date_min: string; event_date_max: string; release_seconds?: ReleaseSecondsProfile | null }
 export interface DecayProfileData { peak_atr: number; decay_rate_pips_per_minute: number; decay_speed: string; recommended_timeout_minutes: number; event_count: number; event_type: string }
 export interface EventType { name: string; count: number }
 export interface EventTypeData { types: EventType[] }
@@ -86,6 +86,19 @@ export interface HeatmapData {
   event_types: Array<{ name: string; count: number; has_data?: boolean }>
   data: Record<string, Record<string, number>>
   counts?: Record<string, Record<string, number>>
+  significance?: Record<string, Record<string, PairedSignificanceResult>>
+}
+
+/** Test apparié d'une cellule : chaque occurrence vs sa propre baseline (Wilcoxon signé, bilatéral) */
+export interface PairedSignificanceResult {
+  sample_size: number
+  event_mean: number
+  baseline_mean: number
+  mean_difference: number
+  wilcoxon_w: number
+  wilcoxon_p_value: number
+  rank_biserial: number
+  adjusted_p_value: number | null
 }
 
 export interface HeatmapLoadedFor {
@@ -11,10 +11,23 @@ export interface ConversionEntry {
   hidden?: boolean
 }
 
+export type AliasMatchKind = 'exact' | 'prefix' | 'suffix' | 'regex'
+
+/** Règle ramenant un symbole broker (EURUSD.m, #US30...) à son symbole canonique */
+export interface SymbolAlias {
+  id?: number | null
+  pattern: string
+  match_kind: AliasMatchKind
+  /** Vide = simple retrait du préfixe/suffixe */
+  canonical: string
+  priority: number
+}
+
 export const useConversionStore = defineStore('conversions', () => {
   const conversions = ref<ConversionEntry[]>([])
   const loading = ref(false)
   const updateSignal = ref(0) // Signal pour forcer les rafraîchissements
+  const aliases = ref<SymbolAlias[]>([])
 
   async function loadConversions() {
     loading.value = true
@@ -29,6 +42,29 @@ export const useConversionStore = defineStore('conversions', () => {
     }
   }
 
+  async function loadAliases() {
+    try {
+      aliases.value = await invoke<SymbolAlias[]>('list_symbol_aliases')
+    } catch (e) {
+      console.error('Failed to load symbol aliases:', e)
+    }
+  }
+
+  async function saveAlias(alias: SymbolAlias) {
+    await invoke('save_symbol_alias', { alias })
+    await loadAliases()
+  }
+
+  async function deleteAlias(id: number) {
+    await invoke('delete_symbol_alias', { id })
+    await loadAliases()
+  }
+
+  /** Symbole canonique sous lequel `symbol` serait importé */
+  function resolveAlias(symbol: string): Promise<string> {
+    return invoke<string>('resolve_symbol_alias', { symbol })
+  }
+
   function getUnitForSymbol(symbol: string): string {
     const s = symbol.toUpperCase()
     // 1. Chercher dans les réglages (BD ou hardcodés enrichis)
@@ -69,6 +105,11 @@ export const useConversionStore = defineStore('conversions', () => {
     loading,
     updateSignal,
     loadConversions,
+    aliases,
+    loadAliases,
+    saveAlias,
+    deleteAlias,
+    resolveAlias,
     getUnitForSymbol,
     pipsToDisplayValue,
     isSymbolHidden
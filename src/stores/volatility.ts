@@ -2,8 +2,8 @@ import { defineStore } from 'pinia'
 import { ref, shallowRef, computed } from 'vue'
 import { invoke } from '@tauri-apps/api/core'
 import { useRetroAnalysisCache } from '../composables/useRetroAnalysisCache'
-import type { SymbolInfo, AnalysisResult, HourlyStats } from './volatilityTypes'
-export type { SymbolInfo, AnalysisResult, HourlyStats, Stats15Min, GlobalMetrics, EventInHour, CalendarEvent, CorrelatedEvent } from './volatilityTypes'
+import type { SymbolInfo, AnalysisResult, HourlyStats, VolatilityEstimator } from './volatilityTypes'
+export type { SymbolInfo, AnalysisResult, HourlyStats, VolatilityEstimator, RangeVolatility, Stats15Min, GlobalMetrics, EventInHour, CalendarEvent, CorrelatedEvent } from './volatilityTypes'
 
 export const useVolatilityStore = defineStore('volatility', () => {
   const symbols = ref<SymbolInfo[]>([])
@@ -39,7 +39,8 @@ export const useVolatilityStore = defineStore('volatility', () => {
     symbol: string, 
     calendarId?: number | null,
     dateStart?: string | null,
-    dateEnd?: string | null
+    dateEnd?: string | null,
+    volatilityEstimator?: VolatilityEstimator | null
   ) {
     loading.value = true
     error.value = ''
@@ -56,6 +57,7 @@ export const useVolatilityStore = defineStore('volatility', () => {
       const invokeParams: Record<string, unknown> = { symbol, calendarId: cid }
       if (dateStart) invokeParams.dateStart = dateStart
       if (dateEnd) invokeParams.dateEnd = dateEnd
+      if (volatilityEstimator) invokeParams.volatilityEstimator = volatilityEstimator
       
       const result = await invoke<AnalysisResult>('analyze_symbol', invokeParams)
       analysisResult.value = result
@@ -10,6 +10,15 @@ export interface EventInHour {
   volatility_increase: number
 }
 
+export type VolatilityEstimator = 'CloseToClose' | 'Parkinson' | 'GarmanKlass' | 'RogersSatchell' | 'YangZhang'
+
+export interface RangeVolatility {
+  parkinson: number
+  garman_klass: number
+  rogers_satchell: number
+  yang_zhang: number
+}
+
 export interface HourlyStats {
   hour: number
   candle_count: number
@@ -23,6 +32,7 @@ export interface HourlyStats {
   volume_imbalance_mean: number
   noise_ratio_mean: number
   breakout_percentage: number
+  range_volatility?: RangeVolatility
   events: EventInHour[]
 }
 
@@ -40,6 +50,7 @@ export interface Stats15Min {
   volume_imbalance_mean: number
   noise_ratio_mean: number
   breakout_percentage: number
+  range_volatility?: RangeVolatility
   events: EventInHour[]
   peak_duration_minutes?: number
   volatility_half_life_minutes?: number
@@ -98,4 +109,5 @@ export interface AnalysisResult {
   point_value: number
   unit: string
   correlated_events: CorrelatedEvent[]
+  volatility_estimator?: VolatilityEstimator
 }
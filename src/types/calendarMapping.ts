@@ -0,0 +1,45 @@
+// types/calendarMapping.ts — Import de calendriers par mapping de colonnes
+// (commandes list_calendar_mapping_presets / preview_calendar_mapping, import_calendar_files { mapping })
+
+export type ImpactScale = 'auto' | 'bulls' | 'numeric' | 'words'
+
+/** Colonne désignée par son en-tête (insensible à la casse) ou son index ("0" = première) */
+export interface CalendarColumnMapping {
+  /** "investing", "fxstreet", "tradingeconomics" ou "custom" */
+  preset: string
+  date_column: string
+  time_column: string | null
+  currency_column: string | null
+  country_column: string | null
+  event_column: string
+  impact_column: string
+  actual_column: string | null
+  forecast_column: string | null
+  previous_column: string | null
+  /** Format chrono ("%d/%m/%Y") ou "auto" */
+  date_format: string
+  /** "UTC", nom IANA ou "broker NY-close" */
+  timezone: string
+  impact_scale: ImpactScale
+  delimiter: string
+}
+
+export interface CalendarMappedEvent {
+  /** "YYYY-MM-DD HH:MM:SS" UTC */
+  event_time: string
+  currency: string
+  impact: 'H' | 'M' | 'L'
+  description: string
+  actual: number | null
+  forecast: number | null
+  previous: number | null
+}
+
+export interface CalendarMappingPreview {
+  headers: string[]
+  rows_read: number
+  events_parsed: number
+  rows_rejected: number
+  rejections: string[]
+  sample: CalendarMappedEvent[]
+}
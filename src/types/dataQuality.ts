@@ -0,0 +1,42 @@
+// types/dataQuality.ts — Rapport de qualité des données M1 (commande analyze_data_quality)
+
+export interface DataGap {
+  start: string
+  end: string
+  /** Minutes de marché ouvert manquantes */
+  missing_minutes: number
+}
+
+export interface DayCoverage {
+  date: string
+  expected_minutes: number
+  present_minutes: number
+  coverage_pct: number
+}
+
+export interface MonthlyQuality {
+  /** "YYYY-MM" */
+  month: string
+  expected_minutes: number
+  present_minutes: number
+  coverage_pct: number
+  gap_count: number
+  zero_range_runs: number
+  ohlc_errors: number
+  duplicate_timestamps: number
+  spread_outliers: number
+  price_jumps: number
+}
+
+export interface DataQualityReport {
+  symbol: string
+  total_candles: number
+  first_candle: string | null
+  last_candle: string | null
+  expected_minutes: number
+  present_minutes: number
+  coverage_pct: number
+  months: MonthlyQuality[]
+  largest_gaps: DataGap[]
+  low_coverage_days: DayCoverage[]
+}
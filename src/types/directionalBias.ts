@@ -0,0 +1,49 @@
+// types/directionalBias.ts — Sens attendu selon la surprise (commande analyze_directional_bias)
+
+import type { ConfidenceInterval } from './entryAnalysis'
+
+export type CurrencySide = 'base' | 'quote'
+export type SurpriseSign = 'positive' | 'negative'
+export type MoveDirection = 'up' | 'down'
+/** one_sided : stop unique dans le sens attendu ; straddle : sens non prévisible */
+export type EntryMode = 'one_sided' | 'straddle'
+
+export interface HorizonHitRate {
+  minutes: number
+  sample_size: number
+  up_rate: number
+  /** Part des occurrences dans le sens théorique (null sans sens théorique : indice) */
+  hit_rate: number | null
+  hit_rate_ci: ConfidenceInterval | null
+  avg_move_pips: number
+}
+
+export interface DirectionalBiasRow {
+  event_id: string
+  event_type: string
+  currency: string
+  side: CurrencySide
+  surprise_sign: SurpriseSign
+  /** null pour un indice : sa devise de cotation ne dit rien de son sens */
+  theoretical_direction: MoveDirection | null
+  /** Sens majoritaire observé à 5 minutes */
+  expected_direction: MoveDirection
+  bias_strength: number
+  bias_strength_ci: ConfidenceInterval
+  /** p-value du test de signe bilatéral à 5 minutes, ajustée Benjamini-Hochberg sur toutes les lignes */
+  adjusted_p_value: number
+  entry_mode: EntryMode
+  sample_size: number
+  /** Horizons 1, 5 et 15 minutes */
+  horizons: HorizonHitRate[]
+}
+
+export interface DirectionalBiasResult {
+  pair: string
+  base_currency: string | null
+  quote_currency: string
+  event_count: number
+  analyzed_count: number
+  overall: HorizonHitRate[]
+  rows: DirectionalBiasRow[]
+}
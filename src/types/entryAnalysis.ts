@@ -1,22 +1,72 @@
 // types/entryAnalysis.ts — Types pour l'analyse de point d'entrée (Phase 2/4)
 
+import type { PreReleaseAdvice, PreReleaseProfile } from './preRelease'
+
+/** Bornes bootstrap 5% / 95% */
+export interface ConfidenceInterval {
+  lower: number
+  upper: number
+}
+
 export interface MinuteDetail {
   offset: number
   win_rate: number
+  win_rate_ci: ConfidenceInterval
   avg_net_profit_pips: number
+  avg_net_profit_ci: ConfidenceInterval
   avg_spread_pips: number
+  avg_spread_ci: ConfidenceInterval
   sample_size: number
   tradable: boolean
 }
 
+/** Offset retenu en walk-forward et sa performance hors échantillon */
+export interface EntryWalkForward {
+  windows: number
+  most_selected_offset_minutes: number
+  out_of_sample_sample_size: number
+  out_of_sample_win_rate: number
+  out_of_sample_avg_net_profit_pips: number
+  out_of_sample_avg_net_profit_ci: ConfidenceInterval
+}
+
+export type SecondBarSize = 'S1' | 'S5' | 'S10'
+
+/** Straddle simulé à T0 + offset_seconds (barres construites depuis les ticks) */
+export interface SecondDetail {
+  offset_seconds: number
+  win_rate: number
+  win_rate_ci: ConfidenceInterval
+  avg_net_profit_pips: number
+  avg_net_profit_ci: ConfidenceInterval
+  avg_spread_pips: number
+  sample_size: number
+  tradable: boolean
+}
+
+/** Profil moyen des secondes suivant l'annonce */
+export interface ReleaseSecondsProfile {
+  bar_size: SecondBarSize
+  event_count: number
+  peak_delay_seconds: number
+  peak_range_pips: number
+  first_move_seconds: number | null
+  spread_normalization_seconds: number | null
+  range_profile_pips: number[]
+  spread_profile_pips: number[]
+}
+
 export interface EntryAnalysisResult {
   symbol: string
   event_type: string
   optimal_offset_minutes: number
   optimal_entry_time_label: string
   real_win_rate: number
+  real_win_rate_ci: ConfidenceInterval
   avg_net_profit_pips: number
+  avg_net_profit_ci: ConfidenceInterval
   avg_spread_at_entry_pips: number
+  avg_spread_at_entry_ci: ConfidenceInterval
   avg_movement_pips: number
   peak_minute: number
   movement_duration_minutes: number
@@ -26,6 +76,16 @@ export interface EntryAnalysisResult {
   non_tradable_minutes: number[]
   minute_details: MinuteDetail[]
   unit: string
+  optimal_offset_seconds?: number | null
+  second_details?: SecondDetail[]
+  /** Jours exclus pour couverture M1 insuffisante */
+  excluded_days?: number
+  /** Dérive et compression de T-60 à T-5 avant les annonces du quarter */
+  pre_release?: PreReleaseProfile | null
+  /** Conseil pré-annonce répercuté sur l'entrée */
+  entry_advice?: PreReleaseAdvice
+  /** Offset optimal ci-dessus ajusté sur tout l'historique ; ici sa validation walk-forward */
+  walk_forward?: EntryWalkForward | null
 }
 
 export interface EntryPointParams {
@@ -36,4 +96,8 @@ export interface EntryPointParams {
   forward_minutes?: number
   spread_threshold_pips?: number
   min_samples?: number
+  bar_size?: SecondBarSize
+  forward_seconds?: number
+  /** Couverture M1 minimale (%) d'un jour pour être analysé */
+  min_day_coverage_pct?: number
 }
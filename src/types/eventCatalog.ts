@@ -0,0 +1,39 @@
+// types/eventCatalog.ts — Catalogue canonique des annonces
+// (commandes list_event_catalog / resolve_event_name / save_canonical_event / delete_canonical_event)
+
+export type EventCategory =
+  | 'employment'
+  | 'inflation'
+  | 'rates'
+  | 'pmi'
+  | 'speech'
+  | 'growth'
+  | 'consumption'
+  | 'other'
+
+export type EventAliasKind = 'exact' | 'fuzzy'
+
+export interface EventAlias {
+  alias: string
+  kind: EventAliasKind
+}
+
+export interface CanonicalEvent {
+  /** Identifiant stable ("usd_nfp"), stocké dans calendar_events.event_id */
+  id: string
+  name: string
+  country: string
+  currency: string
+  category: EventCategory
+  /** "H", "M" ou "L" */
+  default_impact: string
+  aliases: EventAlias[]
+}
+
+export interface EventResolution {
+  /** Identifiant du catalogue, ou "raw:<intitulé normalisé>" hors catalogue */
+  event_id: string
+  name: string
+  catalogued: boolean
+  category: EventCategory | null
+}
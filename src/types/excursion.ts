@@ -0,0 +1,73 @@
+// types/excursion.ts — Distributions MFE/MAE des deux jambes du straddle (commande analyze_excursions)
+// calculer_metriques_evenement tire ses SL/TP des mêmes percentiles (publications passées), ATR en repli
+
+export interface LegExcursion {
+  /** Minutes après T0 du déclenchement */
+  trigger_minute: number
+  mfe_pips: number
+  mae_pips: number
+}
+
+export interface HorizonExcursion {
+  horizon_minutes: number
+  /** null : jambe non déclenchée avant l'horizon */
+  long: LegExcursion | null
+  short: LegExcursion | null
+}
+
+export interface OccurrenceExcursion {
+  event_time: string
+  horizons: HorizonExcursion[]
+}
+
+export interface HistogramBin {
+  lower_pips: number
+  upper_pips: number
+  count: number
+}
+
+export interface ExcursionDistribution {
+  count: number
+  mean: number
+  p25: number
+  p50: number
+  p75: number
+  p90: number
+  p95: number
+  max: number
+  histogram: HistogramBin[]
+}
+
+export interface LegDistribution {
+  triggered_count: number
+  mfe: ExcursionDistribution
+  mae: ExcursionDistribution
+}
+
+export interface ExcursionStops {
+  stop_loss_pips: number
+  take_profit_pips: number
+  /** Part des déclenchements dont la MAE reste sous le SL */
+  sl_survival_rate: number
+  /** Part des déclenchements dont la MFE atteint le TP */
+  tp_reach_rate: number
+}
+
+export interface HorizonDistribution {
+  horizon_minutes: number
+  long: LegDistribution | null
+  short: LegDistribution | null
+  stops: ExcursionStops | null
+}
+
+export interface ExcursionStudyResult {
+  pair: string
+  event_type: string
+  straddle_distance_pips: number
+  sl_percentile: number
+  tp_percentile: number
+  event_count: number
+  analyzed_count: number
+  horizons: HorizonDistribution[]
+  occurrences: OccurrenceExcursion[]
+}
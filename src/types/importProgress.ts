@@ -0,0 +1,19 @@
+// types/importProgress.ts — Progression des imports en continu (événement "import-progress", commande cancel_import)
+
+export type ImportStage = 'reading' | 'committing' | 'done' | 'cancelled'
+
+export interface ImportProgress {
+  file: string
+  stage: ImportStage
+  bytes_read: number
+  total_bytes: number
+  /** 0-100, sur les octets lus */
+  percent: number
+  /** Lignes (ticks ou bougies) lues */
+  rows_read: number
+  /** Bougies écrites en zone de transit */
+  rows_written: number
+}
+
+/** Message d'erreur d'un import annulé */
+export const IMPORT_CANCELLED = 'Import annulé'
@@ -0,0 +1,57 @@
+// types/importSession.ts — Historique des imports (list_import_sessions, get_import_session, rollback_import,
+// confirm_import_session, purge_import_backups)
+
+export type ImportKind = 'candles' | 'calendar'
+
+/** Conduite face à un fichier déjà importé (paramètre reimportMode des commandes d'import) */
+export type ReimportMode = 'replace' | 'merge'
+
+export interface ImportSession {
+  kind: ImportKind
+  id: number
+  filename: string
+  /** Paire (bougies) ou nom du calendrier */
+  target: string
+  timeframe: string | null
+  imported_at: string
+  /** 'new' | 'replace' | 'merge' (null pour les imports antérieurs aux sessions) */
+  mode: string | null
+  /** 'success' | 'confirmed' (validée, sauvegardes purgées) | 'replaced' | 'rolled_back' | 'deleted' */
+  status: string
+  row_count: number
+  /** Lignes encore en base portant l'identifiant de la session */
+  live_rows: number
+  source_timezone: string | null
+}
+
+export interface ImportSessionDetail {
+  session: ImportSession
+  first_time: string | null
+  last_time: string | null
+  tick_rows: number
+  /** Lignes d'autres imports écrasées, restaurées à l'annulation */
+  displaced_rows: number
+  /** Ticks d'autres imports purgés, restaurés à l'annulation */
+  displaced_ticks: number
+  can_rollback: boolean
+}
+
+/** Bilan d'une validation : sauvegardes des lignes écrasées purgées */
+export interface BackupPurgeResult {
+  sessions_confirmed: number
+  rows_purged: number
+  ticks_purged: number
+}
+
+export interface RollbackResult {
+  kind: ImportKind
+  id: number
+  rows_deleted: number
+  rows_restored: number
+  ticks_deleted: number
+  /** Ticks d'imports antérieurs remis en place */
+  ticks_restored: number
+}
+
+/** Préfixe de l'erreur d'un fichier déjà importé sans reimportMode */
+export const ALREADY_IMPORTED = 'Fichier déjà importé'
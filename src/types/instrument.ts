@@ -0,0 +1,42 @@
+// types/instrument.ts — Registre des instruments (list_instruments, get_instrument, save_instrument, delete_instrument)
+
+export type AssetType =
+  | 'ForexMajor'
+  | 'ForexJpy'
+  | 'Gold'
+  | 'Silver'
+  | 'Crypto'
+  | 'Index'
+  | 'Commodity'
+  | 'Unknown'
+
+/** Coûts de trading de repli (M1 sans données tick) */
+export interface TradingCostProfile {
+  spread_min: number
+  spread_max: number
+  spread_avg: number
+  slippage: number
+  spread_multiplier_event: number
+  entry_slippage_multiplier: number
+}
+
+export interface Instrument {
+  symbol: string
+  display_name: string
+  category: string
+  asset_class: AssetType
+  /** Décimales du prix coté */
+  digits: number
+  pip_size: number
+  /** 'pips' | 'points' | '$' */
+  pip_unit: string
+  display_digits: number
+  contract_size: number
+  /** Valeur d'un pip pour un contrat, en devise de cotation */
+  point_value: number
+  quote_currency: string
+  /** '24/5', '24/7' ou '23/5' */
+  trading_hours: string
+  dukascopy: boolean
+  costs: TradingCostProfile
+}
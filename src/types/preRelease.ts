@@ -0,0 +1,42 @@
+// types/preRelease.ts — Étude pré-annonce T-60 à T-5
+// (commandes analyze_pre_release_drift / scan_pre_release_drift, champ pre_release d'analyze_entry_points)
+
+import type { ConfidenceInterval } from './entryAnalysis'
+
+/** normal : placement habituel ; enter_earlier : avant onset_minutes_before ; skip_straddle : déconseillé */
+export type PreReleaseAdvice = 'normal' | 'enter_earlier' | 'skip_straddle'
+
+export interface PreReleaseProfile {
+  event_count: number
+  /** Fenêtres de référence : même heure, jours sans annonce */
+  reference_count: number
+  avg_drift_pips: number
+  /** Dérive moyenne de la même fenêtre les jours sans annonce */
+  reference_drift_pips: number
+  avg_drift_ci: ConfidenceInterval
+  avg_post_move_pips: number
+  /** Dérive T-60 → T-5 rapportée au mouvement T0 → T+15 */
+  drift_share: number
+  aligned_drift_rate: number
+  aligned_drift_rate_ci: ConfidenceInterval
+  /** < 1 : ATR comprimé à l'approche de l'annonce */
+  atr_compression: number
+  spread_widening: number | null
+  onset_minutes_before: number | null
+  early_move: boolean
+  advice: PreReleaseAdvice
+  /** True range moyen par minute, T-60 à T-6 */
+  atr_profile_pips: number[]
+}
+
+export interface PreReleaseEventRow {
+  event_id: string
+  event_type: string
+  currency: string
+  profile: PreReleaseProfile
+}
+
+export interface PreReleaseScanResult {
+  pair: string
+  rows: PreReleaseEventRow[]
+}
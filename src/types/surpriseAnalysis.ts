@@ -0,0 +1,51 @@
+// types/surpriseAnalysis.ts — Réaction par classe de surprise (commande analyze_surprise_buckets)
+
+import type { ConfidenceInterval } from './entryAnalysis'
+
+export type SurpriseBucket =
+  | 'big_negative'
+  | 'small_negative'
+  | 'in_line'
+  | 'small_positive'
+  | 'big_positive'
+
+export interface SurpriseEventReaction {
+  event_time: string
+  actual: number
+  forecast: number
+  /** actual - forecast */
+  surprise: number
+  /** (surprise - moyenne) / écart-type des surprises antérieures de l'annonce */
+  surprise_score: number
+  /** Écart-type des surprises antérieures ayant servi au score */
+  surprise_std: number
+  bucket: SurpriseBucket
+  reaction_pips: number
+  /** Positif = hausse entre l'ouverture T0 et la clôture à l'horizon */
+  move_pips: number
+  whipsaw: boolean
+}
+
+export interface SurpriseBucketStats {
+  bucket: SurpriseBucket
+  event_count: number
+  avg_surprise_score: number
+  avg_reaction_pips: number
+  avg_reaction_ci: ConfidenceInterval
+  avg_move_pips: number
+  up_rate: number
+  up_rate_ci: ConfidenceInterval
+  whipsaw_rate: number
+  whipsaw_rate_ci: ConfidenceInterval
+}
+
+export interface SurpriseAnalysisResult {
+  event_type: string
+  pair: string
+  horizon_minutes: number
+  big_surprise_score: number
+  event_count: number
+  analyzed_count: number
+  buckets: SurpriseBucketStats[]
+  reactions: SurpriseEventReaction[]
+}
@@ -0,0 +1,30 @@
+// types/timezoneDetection.ts — Détection du fuseau source (commandes detect_source_timezone / apply_source_timezone)
+
+export interface TimezoneCandidate {
+  /** "UTC", nom IANA (Etc/GMT-2, Europe/Athens…) ou "broker NY-close" */
+  timezone: string
+  events_scored: number
+  /** Médiane du ratio true range à l'annonce / référence des 30 min précédentes */
+  median_spike_ratio: number
+  /** Part des annonces avec un pic (0-1) */
+  hit_rate: number
+}
+
+export interface TimezoneDetectionReport {
+  symbol: string
+  events_tested: number
+  suggested_timezone: string | null
+  /** 0-100 */
+  confidence: number
+  already_utc: boolean
+  candidates: TimezoneCandidate[]
+}
+
+export interface TimezoneCorrectionResult {
+  symbol: string
+  source_timezone: string
+  rows_rewritten: number
+  /** Bougies supprimées faute d'heure UTC (heure d'été inexistante, doublon) */
+  rows_deleted: number
+  derived_rows: number
+}